@@ -0,0 +1,138 @@
+# English messages for API errors, keyed by error code. These match the
+# errors' built-in messages, which are used for any code missing here.
+#
+# Each entry is one line, `Code = template`. Placeholders like `{ $max }`
+# are filled from the error's details; a variant holding a single value
+# names it `{ $value }`.
+#
+# SiteDeletionNotConfirmed is left out: its message adds two counts, which
+# a template can't.
+
+InvalidUsername = Invalid username: { $value }
+InvalidDisplayName = Invalid display name: { $value }
+UsernameTaken = That username is already taken
+InvalidEmail = Invalid email: { $value }
+EmailTaken = An account with that email already exists
+InvalidPassword = Invalid password: { $value }
+UnverifiedEmail = Email not yet verified
+RegistrationClosed = This community is not accepting new members right now
+InvalidInviteBaseUrl = Invalid invite base URL: { $value }
+RequiresModeratorPermissions = Moderator permissions required
+RequiresColeaderPermissions = Coleader permissions required
+RequiresLeaderPermissions = Leader permissions required
+RequiresSuperuser = Superuser permissions required
+CannotImpersonateSuperuser = Cannot impersonate a superuser
+NotImpersonating = Not impersonating a user
+NotAllowedWhileImpersonating = Not allowed while impersonating a user
+IncorrectPassword = Incorrect password
+SpaceHasAuctionHistory = Cannot delete space with auction history
+MismatchedInviteEmail = Mismatched invite email
+ControlCharacters = { $field } cannot contain control characters
+FieldTooLong = Field too long
+InvalidInvite = Invalid invite
+AlreadyMember = Already a member of this community
+MemberNotFound = Member not found
+CannotRemoveSelf = Cannot remove yourself from community
+CannotRemoveHigherRole = Cannot remove user with higher role
+CannotChangeRole = Cannot change role of this user
+CannotInviteRole = Invites can only grant roles below your own
+CannotChangeSelfRole = Cannot change own role
+CannotPromoteToLeader = Cannot promote to leader
+LeaderMustTransferFirst = Cannot leave community as leader (must transfer leadership first)
+OrphanedAccountNotFound = Orphaned account not found
+OrphanedAccountHasLockedBalance = Cannot resolve orphaned account with locked balance from outstanding bids
+NoActiveMembersForDistribution = No active members to distribute balance to
+SpanTooLarge = Span too large: { $value }
+SpaceNameNotUnique = A space with the name '{ $name }' already exists in this site
+InsufficientPermissions = Insufficient permissions. Required: { $required }
+AuctionNotFound = Auction not found
+AuctionAlreadyStarted = Auction has already started
+AuctionAlreadyEnded = Auction has already ended
+ProxyBiddingNotYetOpen = Proxy bidding for this auction opens at { $opens_at }
+ProxyBiddingClosed = Proxy bidding can't be changed once the first round has ended
+InvalidMaxItems = Max items must be at least 1 and at most the { $available } spaces available in the auction
+AuctionNotCanceled = Only canceled auctions can be permanently deleted
+AuctionNotConcluded = Auction hasn't concluded yet
+NotPossessionHolder = Only the space's winner or a moderator can get its receipt
+AuctionStartNotInFuture = Auction start time must be in the future
+AuctionStartInPast = Auction start time cannot be in the past
+InvalidPossessionPeriod = Possession start must be before possession end
+InvalidAuctionParams = Invalid auction parameters: { $value }
+InvalidRoundDurationBounds = Round duration bounds can't be negative, and the minimum can't exceed the maximum
+InvalidWinnerConstraints = Invalid winner constraints: { $value }
+RoundSpaceResultNotFound = Round space result not found
+BidNotFound = Bid not found
+DuplicateBid = You have already bid on this space in this round
+RoundEnded = Round has ended
+AuctionRoundNotFound = Auction round not found
+RoundNotStarted = Round has not started yet
+InvalidCursor = Invalid pagination cursor
+UserNotFound = User not found
+CommunityNotFound = Community not found
+SiteNotFound = Site not found
+SpaceNotFound = Space not found
+SiteImageNotFound = Site image not found
+SiteImageCommunityMismatch = Site image belongs to a different community
+ImageTooLarge = Image too large. Maximum size is 1MB, received { $size } bytes
+InvalidImageFormat = Invalid image format. File must be a valid image (JPEG, PNG, etc.)
+SiteDescriptionTooLong = Site description too long. Maximum is { $max } characters, received { $size }
+SpaceDescriptionTooLong = Space description too long. Maximum is { $max } characters, received { $size }
+CommunityDescriptionTooLong = Community description too long. Maximum is { $max } characters, received { $size }
+SiteNameTooLong = Site name too long. Maximum is { $max } characters, received { $size }
+SpaceNameTooLong = Space name too long. Maximum is { $max } characters, received { $size }
+InvalidEligibilityPoints = Eligibility points must be a finite, non-negative number
+JournalNoteTooLong = Journal note too long. Maximum is { $max } characters, received { $size }
+ModeratorNotesTooLong = Moderator notes too long. Maximum is { $max } characters, received { $size }
+InternalStatusTooLong = Internal status too long. Maximum is { $max } characters, received { $size }
+InvalidTimezone = Invalid timezone: { $timezone }
+TimezoneRequired = A timezone is required when { $field } is set
+InvalidSpanUnits = { $field } can't use { $units }
+CommunityInviteNotFound = Community invite not found
+TooManyPendingInvites = The community has { $count } outstanding invites, the most allowed; delete one to issue another
+CommunityLimitReached = You already lead { $max } communities, the most allowed
+OpenHoursNotFound = Open hours not found
+InvalidOpenHours = Invalid open hours: { $value }
+AuctionParamsNotFound = Auction params not found
+NoDefaultAuctionParams = No auction params given and the community has no default
+ExceedsEligibility = Exceeds eligibility. Available: { $available }, Required: { $required }
+AlreadyWinningSpace = Cannot bid on a space you are already winning at a price of { $price }
+SpaceNotAvailable = Space is not available for bidding
+SpaceDeleted = Space has been deleted
+InvalidSpaceOrder = A new space order must list each of the site's spaces once
+SiteDeleted = Site has been deleted
+UserValueNotFound = User value not found
+ProxyBiddingNotFound = Proxy bidding settings not found
+TokenNotFound = Token not found
+InvalidTokenAction = Invalid token action
+TokenAlreadyUsed = Token already used
+TokenExpired = Token expired
+InvalidRecoveryCode = Invalid username or recovery code
+AccountRecoveryRateLimited = Too many account recovery attempts. Please try again later.
+UserIsLeader = Cannot delete user who is a leader of a community
+AccountNotFound = Account not found
+InsufficientBalance = Insufficient balance
+AmountNotQuantized = Amount has finer resolution than the currency's { $minor_units } minor units
+UnquantizedReservePrices = Spaces have reserve prices finer than the currency's { $minor_units } minor units: { $space_names }
+AmountMustBePositive = Amount must be positive
+AmountMustBeNonNegative = Amount must not be negative
+AmountMustBeNonZero = Amount must be non-zero
+NegativeTreasuryAmountNotAllowed = Negative amounts are only allowed for distribution corrections in DistributedClearing mode targeting all active members
+InvalidTreasuryOperation = Invalid treasury operation for this currency mode
+InvalidCreditLimitOperation = Invalid credit limit operation for this currency mode
+CurrencyModeImmutable = Currency mode cannot be changed after community creation
+CurrencyModeUnderConstruction = This mode is under construction
+InvalidCurrencyName = Invalid currency name (max 50 characters)
+InvalidCurrencySymbol = Invalid currency symbol (max 5 characters)
+JournalLinesDoNotSumToZero = Journal entry lines must sum to zero, got { $value }
+DuplicateAccountInJournalEntry = Duplicate account in journal entry
+CannotResetDuringActiveAuction = Cannot reset balances while auctions are active
+SiteHasFinancialHistory = Cannot delete site with financial history
+SiteHasActiveAuction = Cannot delete a site while one of its auctions is active
+AlreadySubscribed = Community already has an active subscription
+NoSubscriptionFound = No subscription found for this community
+SubscriptionPastDue = Subscription payment is past due. Please update your payment method.
+StorageLimitExceeded = Storage limit exceeded. Current: { $current } bytes, limit: { $limit } bytes, estimated after operation: { $estimated_size_after_operation } bytes
+MaintenanceMode = TinyLVT is down for maintenance. Please try again shortly.
+DataExportRateLimited = Your data can only be exported once per hour
+UnsupportedContentType = Request body must be JSON, sent as Content-Type: application/json
+InvalidRequestBody = Invalid request body: { $value }
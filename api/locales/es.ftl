@@ -0,0 +1,134 @@
+# Spanish messages for API errors, keyed by error code. Codes missing here
+# fall back to en.ftl. See there for the format.
+#
+# Details that are themselves English text, such as why a password or a set
+# of auction parameters was rejected, are interpolated as they are.
+
+InvalidUsername = Nombre de usuario no válido: { $value }
+InvalidDisplayName = Nombre visible no válido: { $value }
+UsernameTaken = Ese nombre de usuario ya está en uso
+InvalidEmail = Correo electrónico no válido: { $value }
+EmailTaken = Ya existe una cuenta con ese correo electrónico
+InvalidPassword = Contraseña no válida: { $value }
+UnverifiedEmail = El correo electrónico aún no está verificado
+RegistrationClosed = Esta comunidad no está aceptando nuevos miembros por ahora
+InvalidInviteBaseUrl = URL base de invitación no válida: { $value }
+RequiresModeratorPermissions = Se requieren permisos de moderador
+RequiresColeaderPermissions = Se requieren permisos de colíder
+RequiresLeaderPermissions = Se requieren permisos de líder
+RequiresSuperuser = Se requieren permisos de superusuario
+CannotImpersonateSuperuser = No se puede suplantar a un superusuario
+NotImpersonating = No se está suplantando a ningún usuario
+NotAllowedWhileImpersonating = No está permitido mientras se suplanta a un usuario
+IncorrectPassword = Contraseña incorrecta
+SpaceHasAuctionHistory = No se puede eliminar un espacio con historial de subastas
+MismatchedInviteEmail = El correo electrónico no coincide con el de la invitación
+ControlCharacters = { $field } no puede contener caracteres de control
+FieldTooLong = Campo demasiado largo
+InvalidInvite = Invitación no válida
+AlreadyMember = Ya eres miembro de esta comunidad
+MemberNotFound = Miembro no encontrado
+CannotRemoveSelf = No puedes quitarte a ti mismo de la comunidad
+CannotRemoveHigherRole = No se puede quitar a un usuario con un rol superior
+CannotChangeRole = No se puede cambiar el rol de este usuario
+CannotInviteRole = Las invitaciones solo pueden otorgar roles inferiores al tuyo
+CannotChangeSelfRole = No puedes cambiar tu propio rol
+CannotPromoteToLeader = No se puede ascender a líder
+LeaderMustTransferFirst = No puedes salir de la comunidad siendo líder (primero debes transferir el liderazgo)
+OrphanedAccountNotFound = Cuenta huérfana no encontrada
+OrphanedAccountHasLockedBalance = No se puede resolver una cuenta huérfana con saldo bloqueado por pujas pendientes
+NoActiveMembersForDistribution = No hay miembros activos entre los que distribuir el saldo
+SpanTooLarge = Duración demasiado larga: { $value }
+SpaceNameNotUnique = Ya existe un espacio llamado '{ $name }' en este sitio
+InsufficientPermissions = Permisos insuficientes. Se requiere: { $required }
+AuctionNotFound = Subasta no encontrada
+AuctionAlreadyStarted = La subasta ya ha comenzado
+AuctionAlreadyEnded = La subasta ya ha terminado
+ProxyBiddingNotYetOpen = La puja automática para esta subasta se abre el { $opens_at }
+ProxyBiddingClosed = La puja automática no se puede cambiar una vez terminada la primera ronda
+InvalidMaxItems = El máximo de artículos debe ser al menos 1 y como mucho los { $available } espacios disponibles en la subasta
+AuctionNotCanceled = Solo las subastas canceladas se pueden eliminar definitivamente
+AuctionNotConcluded = La subasta aún no ha concluido
+NotPossessionHolder = Solo el ganador del espacio o un moderador puede obtener su recibo
+AuctionStartNotInFuture = La hora de inicio de la subasta debe estar en el futuro
+AuctionStartInPast = La hora de inicio de la subasta no puede estar en el pasado
+InvalidPossessionPeriod = El inicio de la posesión debe ser anterior a su fin
+InvalidAuctionParams = Parámetros de subasta no válidos: { $value }
+InvalidRoundDurationBounds = Los límites de duración de ronda no pueden ser negativos, y el mínimo no puede superar el máximo
+InvalidWinnerConstraints = Restricciones de ganadores no válidas: { $value }
+RoundSpaceResultNotFound = Resultado de la ronda para el espacio no encontrado
+BidNotFound = Puja no encontrada
+DuplicateBid = Ya has pujado por este espacio en esta ronda
+RoundEnded = La ronda ha terminado
+AuctionRoundNotFound = Ronda de subasta no encontrada
+RoundNotStarted = La ronda aún no ha comenzado
+InvalidCursor = Cursor de paginación no válido
+UserNotFound = Usuario no encontrado
+CommunityNotFound = Comunidad no encontrada
+SiteNotFound = Sitio no encontrado
+SpaceNotFound = Espacio no encontrado
+SiteImageNotFound = Imagen del sitio no encontrada
+SiteImageCommunityMismatch = La imagen del sitio pertenece a otra comunidad
+ImageTooLarge = Imagen demasiado grande. El tamaño máximo es 1 MB; se recibieron { $size } bytes
+InvalidImageFormat = Formato de imagen no válido. El archivo debe ser una imagen válida (JPEG, PNG, etc.)
+SiteDescriptionTooLong = Descripción del sitio demasiado larga. El máximo es { $max } caracteres; se recibieron { $size }
+SpaceDescriptionTooLong = Descripción del espacio demasiado larga. El máximo es { $max } caracteres; se recibieron { $size }
+CommunityDescriptionTooLong = Descripción de la comunidad demasiado larga. El máximo es { $max } caracteres; se recibieron { $size }
+SiteNameTooLong = Nombre del sitio demasiado largo. El máximo es { $max } caracteres; se recibieron { $size }
+SpaceNameTooLong = Nombre del espacio demasiado largo. El máximo es { $max } caracteres; se recibieron { $size }
+InvalidEligibilityPoints = Los puntos de elegibilidad deben ser un número finito y no negativo
+JournalNoteTooLong = Nota del asiento demasiado larga. El máximo es { $max } caracteres; se recibieron { $size }
+ModeratorNotesTooLong = Notas de moderación demasiado largas. El máximo es { $max } caracteres; se recibieron { $size }
+InternalStatusTooLong = Estado interno demasiado largo. El máximo es { $max } caracteres; se recibieron { $size }
+InvalidTimezone = Zona horaria no válida: { $timezone }
+TimezoneRequired = Se requiere una zona horaria cuando se indica { $field }
+InvalidSpanUnits = { $field } no puede usar { $units }
+CommunityInviteNotFound = Invitación a la comunidad no encontrada
+TooManyPendingInvites = La comunidad tiene { $count } invitaciones pendientes, el máximo permitido; elimina una para emitir otra
+CommunityLimitReached = Ya lideras { $max } comunidades, el máximo permitido
+OpenHoursNotFound = Horario no encontrado
+InvalidOpenHours = Horario no válido: { $value }
+AuctionParamsNotFound = Parámetros de subasta no encontrados
+NoDefaultAuctionParams = No se indicaron parámetros de subasta y la comunidad no tiene unos predeterminados
+ExceedsEligibility = Supera la elegibilidad. Disponible: { $available }, requerida: { $required }
+AlreadyWinningSpace = No puedes pujar por un espacio que ya vas ganando a un precio de { $price }
+SpaceNotAvailable = El espacio no está disponible para pujar
+SpaceDeleted = El espacio ha sido eliminado
+InvalidSpaceOrder = El nuevo orden debe incluir cada espacio del sitio una sola vez
+SiteDeleted = El sitio ha sido eliminado
+UserValueNotFound = Valor del usuario no encontrado
+ProxyBiddingNotFound = Configuración de puja automática no encontrada
+TokenNotFound = Token no encontrado
+InvalidTokenAction = Acción de token no válida
+TokenAlreadyUsed = El token ya se ha usado
+TokenExpired = El token ha caducado
+InvalidRecoveryCode = Nombre de usuario o código de recuperación no válido
+AccountRecoveryRateLimited = Demasiados intentos de recuperación de cuenta. Inténtalo de nuevo más tarde.
+UserIsLeader = No se puede eliminar a un usuario que es líder de una comunidad
+AccountNotFound = Cuenta no encontrada
+InsufficientBalance = Saldo insuficiente
+AmountNotQuantized = El importe tiene más precisión que las { $minor_units } unidades menores de la moneda
+UnquantizedReservePrices = Hay espacios con precios de reserva más precisos que las { $minor_units } unidades menores de la moneda: { $space_names }
+AmountMustBePositive = El importe debe ser positivo
+AmountMustBeNonNegative = El importe no puede ser negativo
+AmountMustBeNonZero = El importe no puede ser cero
+NegativeTreasuryAmountNotAllowed = Los importes negativos solo se permiten para corregir distribuciones en el modo DistributedClearing dirigidas a todos los miembros activos
+InvalidTreasuryOperation = Operación de tesorería no válida para este modo de moneda
+InvalidCreditLimitOperation = Operación de límite de crédito no válida para este modo de moneda
+CurrencyModeImmutable = El modo de moneda no se puede cambiar después de crear la comunidad
+CurrencyModeUnderConstruction = Este modo está en construcción
+InvalidCurrencyName = Nombre de moneda no válido (máximo 50 caracteres)
+InvalidCurrencySymbol = Símbolo de moneda no válido (máximo 5 caracteres)
+JournalLinesDoNotSumToZero = Las líneas del asiento deben sumar cero; suman { $value }
+DuplicateAccountInJournalEntry = Cuenta duplicada en el asiento
+CannotResetDuringActiveAuction = No se pueden restablecer los saldos mientras haya subastas activas
+SiteHasFinancialHistory = No se puede eliminar un sitio con historial financiero
+SiteHasActiveAuction = No se puede eliminar un sitio mientras una de sus subastas está activa
+AlreadySubscribed = La comunidad ya tiene una suscripción activa
+NoSubscriptionFound = No se encontró ninguna suscripción para esta comunidad
+SubscriptionPastDue = El pago de la suscripción está vencido. Actualiza tu método de pago.
+StorageLimitExceeded = Límite de almacenamiento superado. Actual: { $current } bytes, límite: { $limit } bytes, estimado tras la operación: { $estimated_size_after_operation } bytes
+MaintenanceMode = TinyLVT está en mantenimiento. Inténtalo de nuevo en breve.
+DataExportRateLimited = Solo puedes exportar tus datos una vez por hora
+UnsupportedContentType = El cuerpo de la solicitud debe ser JSON, enviado como Content-Type: application/json
+InvalidRequestBody = Cuerpo de la solicitud no válido: { $value }
@@ -0,0 +1,4 @@
+-- Opt-in: show the community description on the public invite preview to
+-- people who have not joined yet.
+ALTER TABLE communities
+    ADD COLUMN description_public BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,2 @@
+DROP INDEX idx_bids_user_id_created_at_id;
+ALTER TABLE bids DROP COLUMN id;
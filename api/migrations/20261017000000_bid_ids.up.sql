@@ -0,0 +1,6 @@
+-- Bids are keyed by (space_id, round_id, user_id), which gives no stable
+-- tiebreaker for keyset pagination of a user's bid history. Add a surrogate
+-- id so (created_at, id) totally orders bids.
+ALTER TABLE bids
+    ADD COLUMN id UUID NOT NULL UNIQUE DEFAULT gen_random_uuid();
+CREATE INDEX idx_bids_user_id_created_at_id ON bids (user_id, created_at, id);
@@ -0,0 +1,3 @@
+DROP TABLE notifications_sent;
+DROP TYPE NOTIFICATION_KIND;
+ALTER TABLE users DROP COLUMN email_possession_reminders;
@@ -0,0 +1,18 @@
+-- Per-user opt-out for reminder emails sent shortly before a won possession
+-- period ends.
+ALTER TABLE users
+    ADD COLUMN email_possession_reminders BOOLEAN NOT NULL DEFAULT true;
+
+CREATE TYPE NOTIFICATION_KIND AS ENUM ('possession_ending');
+
+-- One row per notification delivered. The primary key makes each
+-- (user, kind, reference) notification send at most once, even across
+-- concurrent scheduler instances.
+CREATE TABLE notifications_sent (
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    kind NOTIFICATION_KIND NOT NULL,
+    -- Identifies the subject of the notification, e.g. a possession
+    reference_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (user_id, kind, reference_id)
+);
@@ -0,0 +1,7 @@
+-- Seed for the auction's randomized decisions (tie-breaks between equal
+-- bids), so a concluded auction can be replayed exactly for auditing.
+-- Existing auctions get a seed derived from their id.
+ALTER TABLE auctions ADD COLUMN random_seed BIGINT;
+UPDATE auctions
+SET random_seed = ('x' || substr(md5(id::text), 1, 16))::BIT(64)::BIGINT;
+ALTER TABLE auctions ALTER COLUMN random_seed SET NOT NULL;
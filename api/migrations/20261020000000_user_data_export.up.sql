@@ -0,0 +1,2 @@
+-- When the user last exported their data; exports are limited to one per hour.
+ALTER TABLE users ADD COLUMN data_exported_at TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+-- Sandbox auctions run like real ones but never touch balances: bids skip
+-- the credit check, nothing is locked, and completion writes no settlement.
+ALTER TABLE auctions ADD COLUMN sandbox BOOLEAN NOT NULL DEFAULT FALSE;
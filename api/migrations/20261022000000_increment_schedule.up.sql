@@ -0,0 +1,5 @@
+-- Per-round bid increments: a list of [round_num, increment] breakpoints,
+-- each applying from its round onwards. Empty means the flat bid_increment
+-- applies to every round.
+ALTER TABLE auction_params
+ADD COLUMN increment_schedule JSONB NOT NULL DEFAULT '[]';
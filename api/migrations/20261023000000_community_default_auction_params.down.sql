@@ -0,0 +1 @@
+ALTER TABLE communities DROP COLUMN community_default_auction_params_id;
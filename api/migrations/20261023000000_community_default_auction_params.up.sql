@@ -0,0 +1,5 @@
+-- Auction params that sites created in the community inherit when they don't
+-- specify their own. NULL means sites must always specify params.
+ALTER TABLE communities
+ADD COLUMN community_default_auction_params_id UUID
+REFERENCES auction_params (id);
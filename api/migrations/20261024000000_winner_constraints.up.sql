@@ -0,0 +1,4 @@
+-- Community rules on who may win, e.g. [{"MaxWinsPerUser": 2}], enforced when
+-- the auction concludes.
+ALTER TABLE auctions
+ADD COLUMN winner_constraints JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1 @@
+ALTER TABLE use_proxy_bidding DROP COLUMN max_total;
@@ -0,0 +1,3 @@
+-- Optional cap on the total a member's proxy will commit across standing wins
+-- and active bids. NULL means no budget cap beyond max_items.
+ALTER TABLE use_proxy_bidding ADD COLUMN max_total AMOUNT;
@@ -0,0 +1,4 @@
+-- Leaders can temporarily stop accepting new members. Existing invites stay
+-- listed but can't be accepted while registration is closed.
+ALTER TABLE communities
+ADD COLUMN registration_open BOOLEAN NOT NULL DEFAULT true;
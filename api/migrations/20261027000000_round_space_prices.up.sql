@@ -0,0 +1,13 @@
+-- The ask price of each space in a round: the previous round's value plus the
+-- round's bid increment, or the reserve price before any round has produced
+-- a value. Written when the round is created so bids, proxy bidding, and
+-- round results all use the same number. Rounds created before this table
+-- existed get their rows filled in on first read.
+CREATE TABLE round_space_prices (
+    round_id UUID NOT NULL REFERENCES auction_rounds (id) ON DELETE CASCADE,
+    space_id UUID NOT NULL REFERENCES spaces (id) ON DELETE CASCADE,
+    -- Negative for chores, like reserve_price
+    ask_price NUMERIC(20, 6) NOT NULL,
+    PRIMARY KEY (round_id, space_id)
+);
+CREATE INDEX idx_round_space_prices_space_id ON round_space_prices (space_id);
@@ -0,0 +1 @@
+ALTER TABLE community_invites DROP COLUMN role;
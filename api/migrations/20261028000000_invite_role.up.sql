@@ -0,0 +1,4 @@
+-- The role a member joins with when accepting this invite. Inviters can only
+-- grant roles below their own.
+ALTER TABLE community_invites
+ADD COLUMN role ROLE NOT NULL DEFAULT 'member';
@@ -0,0 +1 @@
+ALTER TABLE auction_params DROP COLUMN min_rounds;
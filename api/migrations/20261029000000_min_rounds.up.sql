@@ -0,0 +1,4 @@
+-- Fewest rounds the auction runs before a round with no new bids can
+-- conclude it. NULL means no minimum.
+ALTER TABLE auction_params
+ADD COLUMN min_rounds INTEGER;
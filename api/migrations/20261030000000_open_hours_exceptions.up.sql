@@ -0,0 +1,12 @@
+-- Per-date overrides of the weekly open hours, such as a holiday closure or
+-- a special opening. Takes precedence over open_hours_weekday for its date.
+CREATE TABLE open_hours_exceptions (
+    open_hours_id UUID NOT NULL REFERENCES open_hours (id) ON DELETE CASCADE,
+    date DATE NOT NULL, -- Local date
+    -- Closed exceptions have no times; open ones replace that day's hours.
+    closed BOOLEAN NOT NULL,
+    open_time TIME,  -- Local time
+    close_time TIME, -- Local time (if before open_time, is next day)
+    PRIMARY KEY (open_hours_id, date),
+    CHECK (closed = (open_time IS NULL) AND closed = (close_time IS NULL))
+);
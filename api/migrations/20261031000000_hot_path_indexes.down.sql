@@ -0,0 +1,4 @@
+DROP INDEX idx_auctions_site_id_start_at;
+DROP INDEX idx_community_membership_schedule_community_email;
+DROP INDEX idx_community_invites_email_normalized;
+DROP INDEX idx_user_eligibilities_round_id;
@@ -0,0 +1,17 @@
+-- Indexes for hot lookups that otherwise scan the whole table. bids and
+-- round_space_results already have (round_id, user_id) and (round_id)
+-- indexes, and the user_eligibilities primary key covers lookups by both
+-- user and round; the round-only index here serves per-round reads and the
+-- cascade from auction_rounds.
+--
+-- Migrations run inside a transaction, so CONCURRENTLY isn't available. IF
+-- NOT EXISTS keeps this safe to apply where an index was already created by
+-- hand (concurrently) ahead of the deploy.
+CREATE INDEX IF NOT EXISTS idx_user_eligibilities_round_id
+ON user_eligibilities (round_id);
+CREATE INDEX IF NOT EXISTS idx_community_invites_email_normalized
+ON community_invites (email_normalized);
+CREATE INDEX IF NOT EXISTS idx_community_membership_schedule_community_email
+ON community_membership_schedule (community_id, email_normalized);
+CREATE INDEX IF NOT EXISTS idx_auctions_site_id_start_at
+ON auctions (site_id, start_at);
@@ -0,0 +1,4 @@
+-- Support staff who may impersonate other users. Granted directly in the
+-- database; there is no API for it.
+ALTER TABLE users
+ADD COLUMN is_superuser BOOLEAN NOT NULL DEFAULT false;
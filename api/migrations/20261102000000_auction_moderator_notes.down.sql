@@ -0,0 +1,3 @@
+ALTER TABLE auctions
+DROP COLUMN moderator_notes,
+DROP COLUMN internal_status;
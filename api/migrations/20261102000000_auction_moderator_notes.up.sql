@@ -0,0 +1,4 @@
+-- Moderators' scratchpad on an auction, never shown to regular members.
+ALTER TABLE auctions
+ADD COLUMN moderator_notes TEXT,
+ADD COLUMN internal_status TEXT;
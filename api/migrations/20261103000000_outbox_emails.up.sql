@@ -0,0 +1,18 @@
+-- Emails waiting to be sent, so they survive a restart. The scheduler sends
+-- due rows, retrying failures with backoff, and keeps sent rows for a record.
+CREATE TABLE outbox_emails (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    recipient VARCHAR(255) NOT NULL,
+    -- Which kind of email this is, e.g. 'community_invite'
+    template TEXT NOT NULL,
+    -- The rendered email: subject, html_body, and text_body
+    payload JSONB NOT NULL,
+    -- Failed sends so far
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_outbox_emails_pending ON outbox_emails (next_attempt_at)
+WHERE sent_at IS NULL;
@@ -0,0 +1,2 @@
+DROP TABLE auction_reports;
+DROP TYPE AUCTION_CONCLUSION;
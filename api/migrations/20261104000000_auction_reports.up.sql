@@ -0,0 +1,17 @@
+CREATE TYPE AUCTION_CONCLUSION AS ENUM ('ended', 'terminated', 'canceled');
+
+-- An auction's outcome, written once in the transaction that concludes it so
+-- the numbers stand even if its rounds, bids, or results are later removed.
+CREATE TABLE auction_reports (
+    auction_id UUID PRIMARY KEY REFERENCES auctions (id) ON DELETE CASCADE,
+    -- ended: bidding finished; terminated: ended by the system before bidding
+    -- finished (round cap reached, site deleted); canceled: by a coleader
+    conclusion AUCTION_CONCLUSION NOT NULL,
+    -- Sum of the final prices of awarded spaces. Negative for chores.
+    total_revenue NUMERIC(20, 6) NOT NULL,
+    awarded_spaces INTEGER NOT NULL,
+    unawarded_spaces INTEGER NOT NULL,
+    distinct_winners INTEGER NOT NULL,
+    round_count INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
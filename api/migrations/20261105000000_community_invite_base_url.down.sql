@@ -0,0 +1 @@
+ALTER TABLE communities DROP COLUMN invite_base_url;
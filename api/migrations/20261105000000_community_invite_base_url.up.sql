@@ -0,0 +1,3 @@
+-- White-labeled communities can send invite links on their own domain
+-- instead of the instance's BASE_URL.
+ALTER TABLE communities ADD COLUMN invite_base_url TEXT;
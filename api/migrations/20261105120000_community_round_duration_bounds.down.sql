@@ -0,0 +1,3 @@
+ALTER TABLE communities
+    DROP COLUMN min_round_duration,
+    DROP COLUMN max_round_duration;
@@ -0,0 +1,5 @@
+-- Communities can bound the round durations their auctions use. Null falls
+-- back to the instance's bound, if any.
+ALTER TABLE communities
+    ADD COLUMN min_round_duration INTERVAL,
+    ADD COLUMN max_round_duration INTERVAL;
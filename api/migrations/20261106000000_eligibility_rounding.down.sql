@@ -0,0 +1,2 @@
+ALTER TABLE auction_params DROP COLUMN eligibility_rounding;
+DROP TYPE ELIGIBILITY_ROUNDING;
@@ -0,0 +1,6 @@
+CREATE TYPE ELIGIBILITY_ROUNDING AS ENUM ('floor', 'round');
+
+-- How eligibility recomputed between rounds (activity divided by the
+-- round's threshold) is rounded to whole points.
+ALTER TABLE auction_params
+ADD COLUMN eligibility_rounding ELIGIBILITY_ROUNDING NOT NULL DEFAULT 'floor';
@@ -0,0 +1 @@
+DROP TABLE bid_withdrawals;
@@ -0,0 +1,14 @@
+-- Bids withdrawn before their round ended. Withdrawing deletes the bid, so
+-- this keeps the record moderators need to settle disputes afterwards.
+CREATE TABLE bid_withdrawals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    space_id UUID NOT NULL REFERENCES spaces (id) ON DELETE CASCADE,
+    round_id UUID NOT NULL REFERENCES auction_rounds (id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (id),
+    -- When the withdrawn bid was placed
+    bid_created_at TIMESTAMPTZ NOT NULL,
+    withdrawn_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_bid_withdrawals_space_id ON bid_withdrawals (space_id);
+CREATE INDEX idx_bid_withdrawals_round_id ON bid_withdrawals (round_id);
+CREATE INDEX idx_bid_withdrawals_user_id ON bid_withdrawals (user_id);
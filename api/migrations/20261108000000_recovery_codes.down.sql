@@ -0,0 +1,4 @@
+ALTER TABLE users DROP COLUMN recovery_window_start;
+ALTER TABLE users DROP COLUMN recovery_attempts;
+ALTER TABLE users DROP COLUMN session_generation;
+DROP TABLE recovery_codes;
@@ -0,0 +1,17 @@
+-- One-time codes for regaining an account without access to its email. Only
+-- argon2 hashes are stored, and generating a new set deletes the old one.
+CREATE TABLE recovery_codes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    code_hash VARCHAR(255) NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_recovery_codes_user_id ON recovery_codes (user_id);
+
+-- Sessions record the generation at login and end once it's bumped.
+ALTER TABLE users ADD COLUMN session_generation INTEGER NOT NULL DEFAULT 0;
+-- Recovery attempts within the rate limit window that started at
+-- recovery_window_start.
+ALTER TABLE users ADD COLUMN recovery_attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE users ADD COLUMN recovery_window_start TIMESTAMPTZ;
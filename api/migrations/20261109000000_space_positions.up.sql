@@ -0,0 +1,4 @@
+-- Display order of a site's spaces, set by coleaders. Listings order by
+-- position, then name, so spaces a site has never reordered stay in name
+-- order.
+ALTER TABLE spaces ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,6 @@
+-- Languages the backend has messages in
+CREATE TYPE LOCALE AS ENUM ('en', 'es');
+
+-- The language a user chose for messages; NULL follows their browser's
+-- Accept-Language
+ALTER TABLE users ADD COLUMN locale LOCALE;
@@ -0,0 +1 @@
+ALTER TABLE spaces RENAME COLUMN display_order TO position;
@@ -0,0 +1,18 @@
+-- Spaces list in insertion order until a coleader reorders them, rather
+-- than in name order. Sites that were never reordered keep the order they
+-- displayed in, by name, numbered from 1 as a reorder would number them.
+ALTER TABLE spaces RENAME COLUMN position TO display_order;
+
+UPDATE spaces SET display_order = numbered.display_order
+FROM (
+    SELECT id, ROW_NUMBER() OVER (
+        PARTITION BY site_id ORDER BY name, created_at
+    )::INTEGER AS display_order
+    FROM spaces
+    WHERE site_id IN (
+        SELECT site_id FROM spaces
+        GROUP BY site_id
+        HAVING MAX(display_order) = 0
+    )
+) numbered
+WHERE spaces.id = numbered.id;
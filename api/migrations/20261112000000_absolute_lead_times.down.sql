@@ -0,0 +1,2 @@
+-- Nothing to undo: hours and days in place of days and months are still
+-- valid intervals under the earlier validation.
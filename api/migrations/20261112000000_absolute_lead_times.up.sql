@@ -0,0 +1,39 @@
+-- Round durations and lead times must now be in hours or smaller units,
+-- and possession periods in days or smaller, so existing rows pass the same
+-- validation as new ones when they're edited or copied. A day becomes 24
+-- hours and a month 30 days, the lengths Postgres gives them when taking
+-- an interval's epoch.
+UPDATE auction_params
+SET round_duration = make_interval(
+    secs => EXTRACT(EPOCH FROM round_duration)
+)
+WHERE EXTRACT(DAY FROM round_duration) <> 0
+    OR EXTRACT(MONTH FROM round_duration) <> 0
+    OR EXTRACT(YEAR FROM round_duration) <> 0;
+
+UPDATE sites
+SET auction_lead_time = make_interval(
+        secs => EXTRACT(EPOCH FROM auction_lead_time)
+    ),
+    proxy_bidding_lead_time = make_interval(
+        secs => EXTRACT(EPOCH FROM proxy_bidding_lead_time)
+    )
+WHERE EXTRACT(DAY FROM auction_lead_time) <> 0
+    OR EXTRACT(MONTH FROM auction_lead_time) <> 0
+    OR EXTRACT(YEAR FROM auction_lead_time) <> 0
+    OR EXTRACT(DAY FROM proxy_bidding_lead_time) <> 0
+    OR EXTRACT(MONTH FROM proxy_bidding_lead_time) <> 0
+    OR EXTRACT(YEAR FROM proxy_bidding_lead_time) <> 0;
+
+UPDATE sites
+SET possession_period = possession_period
+    - make_interval(months => (
+        12 * EXTRACT(YEAR FROM possession_period)
+        + EXTRACT(MONTH FROM possession_period)
+    )::INTEGER)
+    + make_interval(days => 30 * (
+        12 * EXTRACT(YEAR FROM possession_period)
+        + EXTRACT(MONTH FROM possession_period)
+    )::INTEGER)
+WHERE EXTRACT(MONTH FROM possession_period) <> 0
+    OR EXTRACT(YEAR FROM possession_period) <> 0;
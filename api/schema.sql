@@ -19,6 +19,9 @@ CREATE TYPE ROLE AS ENUM ('member', 'moderator', 'coleader', 'leader');
 -- Token actions for email verification and password reset
 CREATE TYPE TOKEN_ACTION AS ENUM ('email_verification', 'password_reset');
 
+-- Languages the backend has messages in
+CREATE TYPE LOCALE AS ENUM ('en', 'es');
+
 -- # The currency modes
 --
 -- - points_allocation: members are issued points by the treasury to use in
@@ -140,8 +143,21 @@ CREATE TABLE communities (
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
     name VARCHAR(255) NOT NULL,
     description TEXT,
+    -- Whether the description is shown on the public invite preview to
+    -- people who are not yet members.
+    description_public BOOLEAN NOT NULL DEFAULT false,
     -- Whether new members are active (eligible for distributions) by default.
     new_members_default_active BOOLEAN NOT NULL DEFAULT true,
+    -- Whether invites can currently be accepted. Leaders close registration
+    -- to pause growth without revoking outstanding invites.
+    registration_open BOOLEAN NOT NULL DEFAULT true,
+    -- Overrides the instance's BASE_URL in invite links, for communities
+    -- served from their own domain. Null uses BASE_URL.
+    invite_base_url TEXT,
+    -- Shortest and longest round duration the community's auctions may use.
+    -- Null falls back to the instance's bound, if any.
+    min_round_duration INTERVAL,
+    max_round_duration INTERVAL,
     currency_mode CURRENCY_MODE NOT NULL DEFAULT 'distributed_clearing',
     -- The default credit limit given to members within a community.
     -- Can be overridden on a per-member basis.
@@ -227,6 +243,22 @@ CREATE TABLE users (
     -- If email ownership has been verified; part of signup flow, required to
     -- create or join communities
     email_verified BOOLEAN NOT NULL DEFAULT false,
+    -- Send a reminder email shortly before a won possession period ends
+    email_possession_reminders BOOLEAN NOT NULL DEFAULT true,
+    -- When the user last exported their data; limited to one per hour
+    data_exported_at TIMESTAMPTZ,
+    -- The language the user chose for messages; NULL follows their browser's
+    -- Accept-Language
+    locale LOCALE,
+    -- Recorded in each session at login; bumping it ends every session
+    session_generation INTEGER NOT NULL DEFAULT 0,
+    -- Account recovery attempts in the rate limit window starting at
+    -- recovery_window_start
+    recovery_attempts INTEGER NOT NULL DEFAULT 0,
+    recovery_window_start TIMESTAMPTZ,
+    -- Support staff who may impersonate other users. Granted directly in the
+    -- database; there is no API for it.
+    is_superuser BOOLEAN NOT NULL DEFAULT false,
     -- Set when a user with auction history deletes their account: PII is
     -- anonymized and the row preserved to maintain referential integrity and
     -- distinguish between different deleted users in that history. Also
@@ -266,6 +298,17 @@ CREATE TABLE tokens (
     updated_at TIMESTAMPTZ NOT NULL
 );
 
+-- One-time codes for regaining an account without access to its email. Only
+-- argon2 hashes are stored, and generating a new set deletes the old one.
+CREATE TABLE recovery_codes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    code_hash VARCHAR(255) NOT NULL,
+    used_at TIMESTAMPTZ, -- set when the code is consumed
+    created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_recovery_codes_user_id ON recovery_codes (user_id);
+
 CREATE TABLE community_members (
     -- Cascade: if a community is deleted, memberships are deleted too
     community_id UUID NOT NULL REFERENCES communities (id) ON DELETE CASCADE,
@@ -294,8 +337,12 @@ CREATE TABLE community_invites (
     email_normalized VARCHAR(255)
         GENERATED ALWAYS AS (lower(email)) STORED,
     single_use BOOLEAN NOT NULL,
+    -- Role granted on acceptance, at most one level below the inviter's.
+    role ROLE NOT NULL DEFAULT 'member',
     created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_community_invites_email_normalized
+ON community_invites (email_normalized);
 
 -- A future schedule of community membership that results in automatic
 -- updating of the `is_active` state.
@@ -324,18 +371,33 @@ CREATE TABLE community_membership_schedule (
     created_at TIMESTAMPTZ NOT NULL,
     updated_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_community_membership_schedule_community_email
+ON community_membership_schedule (community_id, email_normalized);
+
+CREATE TYPE ELIGIBILITY_ROUNDING AS ENUM ('floor', 'round');
 
 -- Auction parameters are immutable and copy-on-write if they are used in a
 -- past auction.
 CREATE TABLE auction_params (
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-    -- Length of time of each round.
+    -- Length of time of each round. Hours or smaller units only, since a day
+    -- isn't a fixed length across a DST change.
     round_duration INTERVAL NOT NULL,
     -- 20 digits total, with 6 units of precision
     bid_increment NUMERIC(20, 6) NOT NULL,
     -- Eligibility requirements as the auction progresses. Determines each
     -- round's eligibility_threshold
     activity_rule_params JSONB NOT NULL,
+    -- Per-round bid increments: a list of [round_num, increment] breakpoints,
+    -- each applying from its round onwards. Empty means the flat
+    -- bid_increment applies to every round.
+    increment_schedule JSONB NOT NULL DEFAULT '[]',
+    -- Fewest rounds the auction runs before a round with no new bids can
+    -- conclude it. NULL means no minimum.
+    min_rounds INTEGER,
+    -- How eligibility recomputed between rounds (activity divided by the
+    -- round's threshold) is rounded to whole points.
+    eligibility_rounding ELIGIBILITY_ROUNDING NOT NULL DEFAULT 'floor',
     created_at TIMESTAMPTZ NOT NULL,
     updated_at TIMESTAMPTZ NOT NULL
 );
@@ -359,6 +421,19 @@ CREATE TABLE open_hours_weekday (
     PRIMARY KEY (open_hours_id, day_of_week)
 );
 
+-- Per-date overrides of the weekly open hours, such as a holiday closure or
+-- a special opening. Takes precedence over open_hours_weekday for its date.
+CREATE TABLE open_hours_exceptions (
+    open_hours_id UUID NOT NULL REFERENCES open_hours (id) ON DELETE CASCADE,
+    date DATE NOT NULL, -- Local date
+    -- Closed exceptions have no times; open ones replace that day's hours.
+    closed BOOLEAN NOT NULL,
+    open_time TIME,  -- Local time
+    close_time TIME, -- Local time (if before open_time, is next day)
+    PRIMARY KEY (open_hours_id, date),
+    CHECK (closed = (open_time IS NULL) AND closed = (close_time IS NULL))
+);
+
 -- Images for sites or spaces.
 CREATE TABLE site_images (
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
@@ -377,6 +452,13 @@ CREATE TABLE site_images (
 ALTER TABLE communities
 ADD COLUMN community_image_id UUID REFERENCES site_images (id);
 
+-- Auction params that sites created in the community inherit when they don't
+-- specify their own. NULL means sites must always specify params. Out of line
+-- since auction_params is declared after communities.
+ALTER TABLE communities
+ADD COLUMN community_default_auction_params_id UUID
+REFERENCES auction_params (id);
+
 -- A location consisting of indivisible spaces available for rent, and for
 -- which auctions take place.
 CREATE TABLE sites (
@@ -388,12 +470,14 @@ CREATE TABLE sites (
 
     -- Auction auto scheduling parameters --
 
-    -- Duration of possession and period between auctions.
+    -- Duration of possession and period between auctions. Days or smaller
+    -- units, added in the site's timezone.
     possession_period INTERVAL NOT NULL,
     -- Amount of time before the change in possession that the auction begins.
+    -- Hours or smaller units only.
     auction_lead_time INTERVAL NOT NULL,
     -- Amount of time before the start of auction that the auction row exists
-    -- and proxy bids can be prepared.
+    -- and proxy bids can be prepared. Hours or smaller units only.
     proxy_bidding_lead_time INTERVAL NOT NULL,
     -- If not present, the site is assumed to be open all the time.
     open_hours_id UUID REFERENCES open_hours (id) ON DELETE SET NULL,
@@ -439,6 +523,9 @@ CREATE TABLE spaces (
     reserve_price NUMERIC(20, 6) NOT NULL DEFAULT 0,
     -- Image is optional if the location is otherwise well-described.
     site_image_id UUID REFERENCES site_images (id) ON DELETE SET NULL,
+    -- Display order within the site. New spaces go last, and coleaders can
+    -- reorder them. Listings order by display_order, then name.
+    display_order INTEGER NOT NULL DEFAULT 0,
     -- Soft delete (default): hides from UI, preserves auction history
     -- referencing this space. A hard delete cascades to auction history; the
     -- application checks for auction history before allowing one.
@@ -480,9 +567,24 @@ CREATE TABLE auctions (
     -- Scheduler failure tracking for debugging and backoff
     scheduler_failure_count INTEGER NOT NULL DEFAULT 0,
     scheduler_last_failed_at TIMESTAMPTZ,
+    -- Seeds the PRNG for randomized decisions (tie-breaks between equal bids),
+    -- together with the round number, so results can be replayed for audit.
+    random_seed BIGINT NOT NULL,
+    -- Sandbox auctions run like real ones but never touch balances: bids skip
+    -- the credit check, nothing is locked, and completion writes no
+    -- settlement. They're also excluded from possessions and reminders.
+    sandbox BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Community rules on who may win, e.g. [{"MaxWinsPerUser": 2}]. Wins
+    -- breaking them are dropped when the auction concludes, leaving those
+    -- spaces unawarded.
+    winner_constraints JSONB NOT NULL DEFAULT '[]',
     created_at TIMESTAMPTZ NOT NULL,
-    updated_at TIMESTAMPTZ NOT NULL
+    updated_at TIMESTAMPTZ NOT NULL,
+    -- Moderators' scratchpad on an auction, never shown to regular members.
+    moderator_notes TEXT,
+    internal_status TEXT
 );
+CREATE INDEX idx_auctions_site_id_start_at ON auctions (site_id, start_at);
 
 -- Not directly editable by users (only read/list), since the system manages
 -- the auction rounds itself.
@@ -524,6 +626,20 @@ CREATE INDEX idx_round_space_results_round_space ON round_space_results (
     round_id, space_id
 );
 
+-- The ask price of each space in a round: the previous round's value plus the
+-- round's bid increment, or the reserve price before any round has produced
+-- a value. Written when the round is created so bids, proxy bidding, and
+-- round results all use the same number. Rounds created before this table
+-- existed get their rows filled in on first read.
+CREATE TABLE round_space_prices (
+    round_id UUID NOT NULL REFERENCES auction_rounds (id) ON DELETE CASCADE,
+    space_id UUID NOT NULL REFERENCES spaces (id) ON DELETE CASCADE,
+    -- Negative for chores, like reserve_price
+    ask_price NUMERIC(20, 6) NOT NULL,
+    PRIMARY KEY (round_id, space_id)
+);
+CREATE INDEX idx_round_space_prices_space_id ON round_space_prices (space_id);
+
 -- All bids for spaces in an auction round that meet (are) the minimum bid
 -- increment.
 --
@@ -532,6 +648,8 @@ CREATE INDEX idx_round_space_results_round_space ON round_space_results (
 -- At the end of a round one of the valid bidders is chosen randomly as the
 -- round winner.
 CREATE TABLE bids (
+    -- Surrogate key; tiebreaker for keyset pagination on (created_at, id)
+    id UUID NOT NULL UNIQUE DEFAULT gen_random_uuid(),
     space_id UUID NOT NULL REFERENCES spaces (id) ON DELETE CASCADE,
     round_id UUID NOT NULL REFERENCES auction_rounds (id) ON DELETE CASCADE,
     user_id UUID NOT NULL REFERENCES users (id),
@@ -543,6 +661,22 @@ CREATE INDEX idx_bids_user_id ON bids (user_id);
 CREATE INDEX idx_bids_round_id ON bids (round_id);
 CREATE INDEX idx_bids_space_id ON bids (space_id);
 CREATE INDEX idx_bids_round_id_user_id ON bids (round_id, user_id);
+CREATE INDEX idx_bids_user_id_created_at_id ON bids (user_id, created_at, id);
+
+-- Bids withdrawn before their round ended. Withdrawing deletes the bid, so
+-- this keeps the record moderators need to settle disputes afterwards.
+CREATE TABLE bid_withdrawals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    space_id UUID NOT NULL REFERENCES spaces (id) ON DELETE CASCADE,
+    round_id UUID NOT NULL REFERENCES auction_rounds (id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users (id),
+    -- When the withdrawn bid was placed
+    bid_created_at TIMESTAMPTZ NOT NULL,
+    withdrawn_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_bid_withdrawals_space_id ON bid_withdrawals (space_id);
+CREATE INDEX idx_bid_withdrawals_round_id ON bid_withdrawals (round_id);
+CREATE INDEX idx_bid_withdrawals_user_id ON bid_withdrawals (user_id);
 
 -- User eligibility across auction rounds.
 --
@@ -557,6 +691,7 @@ CREATE TABLE user_eligibilities (
     PRIMARY KEY (user_id, round_id),
     CHECK (eligibility >= 0)
 );
+CREATE INDEX idx_user_eligibilities_round_id ON user_eligibilities (round_id);
 
 -- User-assigned values for each space, for proxy bidding.
 --
@@ -583,10 +718,14 @@ CREATE INDEX idx_user_values_user_id ON user_values (user_id);
 -- max_items defines how many items the user is willing to win. The proxy
 -- bidding system will bid for up to that many items, attempting to maximize
 -- the user's surplus (max_value - current_price).
+--
+-- max_total optionally caps the summed prices of the spaces the proxy is
+-- standing on or bidding for; NULL means no budget cap.
 CREATE TABLE use_proxy_bidding (
     user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
     auction_id UUID NOT NULL REFERENCES auctions (id) ON DELETE CASCADE,
     max_items INTEGER NOT NULL,
+    max_total AMOUNT,
     -- Writer-side dirty flag: set TRUE in the writer's own transaction by
     -- proxy settings and user-value saves; cleared only by the processor's
     -- claim transaction. The flag derives re-selection ordering from the
@@ -755,3 +894,58 @@ CREATE TABLE audit_log (
     details JSONB, -- anything relevant: old/new values, diffs, etc.
     created_at TIMESTAMPTZ NOT NULL
 );
+
+-- Notifications
+
+CREATE TYPE NOTIFICATION_KIND AS ENUM ('possession_ending');
+
+-- One row per notification delivered. The primary key makes each
+-- (user, kind, reference) notification send at most once, even across
+-- concurrent scheduler instances.
+CREATE TABLE notifications_sent (
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    kind NOTIFICATION_KIND NOT NULL,
+    -- Identifies the subject of the notification, e.g. a possession
+    reference_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (user_id, kind, reference_id)
+);
+
+-- Email outbox
+
+-- Emails waiting to be sent, so they survive a restart. The scheduler sends
+-- due rows, retrying failures with backoff, and keeps sent rows for a record.
+CREATE TABLE outbox_emails (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    recipient VARCHAR(255) NOT NULL,
+    -- Which kind of email this is, e.g. 'community_invite'
+    template TEXT NOT NULL,
+    -- The rendered email: subject, html_body, and text_body
+    payload JSONB NOT NULL,
+    -- Failed sends so far
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_outbox_emails_pending ON outbox_emails (next_attempt_at)
+WHERE sent_at IS NULL;
+
+CREATE TYPE AUCTION_CONCLUSION AS ENUM ('ended', 'terminated', 'canceled');
+
+-- An auction's outcome, written once in the transaction that concludes it so
+-- the numbers stand even if its rounds, bids, or results are later removed.
+CREATE TABLE auction_reports (
+    auction_id UUID PRIMARY KEY REFERENCES auctions (id) ON DELETE CASCADE,
+    -- ended: bidding finished; terminated: ended by the system before bidding
+    -- finished (round cap reached, site deleted); canceled: by a coleader
+    conclusion AUCTION_CONCLUSION NOT NULL,
+    -- Sum of the final prices of awarded spaces. Negative for chores.
+    total_revenue NUMERIC(20, 6) NOT NULL,
+    awarded_spaces INTEGER NOT NULL,
+    unawarded_spaces INTEGER NOT NULL,
+    distinct_winners INTEGER NOT NULL,
+    round_count INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
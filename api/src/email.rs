@@ -8,12 +8,18 @@ use secrecy::ExposeSecret;
 use secrecy::SecretBox;
 
 pub struct EmailService {
+    /// None when no email provider is configured; every send then fails.
     #[cfg(not(feature = "mock-email"))]
-    client: Resend,
+    client: Option<Resend>,
     from_address: String,
+    #[cfg(feature = "mock-email")]
+    configured: bool,
+    /// Emails "sent" so far, for tests to inspect
+    #[cfg(feature = "mock-email")]
+    sent: std::sync::Mutex<Vec<(String, EmailTemplate)>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EmailTemplate {
     pub subject: String,
     pub html_body: String,
@@ -25,14 +31,44 @@ impl EmailService {
     pub fn new(api_key: SecretBox<String>, from_address: String) -> Self {
         let client = Resend::new(api_key.expose_secret());
         Self {
-            client,
+            client: Some(client),
             from_address,
         }
     }
 
     #[cfg(feature = "mock-email")]
     pub fn new(_api_key: SecretBox<String>, from_address: String) -> Self {
-        Self { from_address }
+        Self {
+            from_address,
+            configured: true,
+            sent: Default::default(),
+        }
+    }
+
+    /// A service with no email provider, for instances that run without one.
+    /// Sends return an error, which callers already log without failing the
+    /// request.
+    #[cfg(not(feature = "mock-email"))]
+    pub fn disabled(from_address: String) -> Self {
+        Self {
+            client: None,
+            from_address,
+        }
+    }
+
+    #[cfg(feature = "mock-email")]
+    pub fn disabled(from_address: String) -> Self {
+        Self {
+            from_address,
+            configured: false,
+            sent: Default::default(),
+        }
+    }
+
+    /// Recipient and template of every email sent so far, oldest first.
+    #[cfg(feature = "mock-email")]
+    pub fn sent_emails(&self) -> Vec<(String, EmailTemplate)> {
+        self.sent.lock().unwrap().clone()
     }
 
     #[tracing::instrument(skip(self), fields(to = %to_email))]
@@ -42,6 +78,9 @@ impl EmailService {
         to_email: &str,
         template: EmailTemplate,
     ) -> Result<()> {
+        let Some(client) = &self.client else {
+            anyhow::bail!("No email provider is configured");
+        };
         let email = CreateEmailBaseOptions::new(
             &self.from_address,
             [to_email],
@@ -50,7 +89,7 @@ impl EmailService {
         .with_html(&template.html_body)
         .with_text(&template.text_body);
 
-        self.client
+        client
             .emails
             .send(email)
             .await
@@ -67,12 +106,19 @@ impl EmailService {
         to_email: &str,
         template: EmailTemplate,
     ) -> Result<()> {
+        if !self.configured {
+            anyhow::bail!("No email provider is configured");
+        }
         tracing::info!(
             "Test mode: Mock email sent to: {} from: {} with subject: {}",
             to_email,
             self.from_address,
             template.subject
         );
+        self.sent
+            .lock()
+            .unwrap()
+            .push((to_email.to_string(), template));
         Ok(())
     }
 
@@ -84,39 +130,8 @@ impl EmailService {
         verification_token: &str,
         base_url: &str,
     ) -> Result<()> {
-        let verification_link =
-            format!("{}/verify_email?token={}", base_url, verification_token);
-
-        let template = EmailTemplate {
-            subject: "Verify your email address".to_string(),
-            html_body: format!(
-                r#"
-                <h2>Welcome to TinyLVT, {}!</h2>
-                <p>Thank you for signing up. Please click the link below to verify your email address:</p>
-                <p><a href="{}" style="background-color: #007bff; color: white; padding: 10px 20px; text-decoration: none; border-radius: 5px;">Verify Email</a></p>
-                <p>Or copy and paste this link in your browser:</p>
-                <p>{}</p>
-                <p>This link will expire in 24 hours.</p>
-                <p>If you didn't create an account, you can safely ignore this email.</p>
-                "#,
-                username, verification_link, verification_link
-            ),
-            text_body: format!(
-                r#"
-Welcome to TinyLVT, {}!
-
-Thank you for signing up. Please visit the following link to verify your email address:
-
-{}
-
-This link will expire in 24 hours.
-
-If you didn't create an account, you can safely ignore this email.
-                "#,
-                username, verification_link
-            ),
-        };
-
+        let template =
+            verification_email(username, verification_token, base_url);
         self.send_email(to_email, template).await
     }
 
@@ -128,26 +143,90 @@ If you didn't create an account, you can safely ignore this email.
         reset_token: &str,
         base_url: &str,
     ) -> Result<()> {
-        let reset_link =
-            format!("{}/reset_password?token={}", base_url, reset_token);
-
-        let template = EmailTemplate {
-            subject: "Reset your password".to_string(),
-            html_body: format!(
-                r#"
-                <h2>Password Reset Request</h2>
-                <p>Hi {},</p>
-                <p>We received a request to reset your password for your TinyLVT account.</p>
-                <p><a href="{}" style="background-color: #dc3545; color: white; padding: 10px 20px; text-decoration: none; border-radius: 5px;">Reset Password</a></p>
-                <p>Or copy and paste this link in your browser:</p>
-                <p>{}</p>
-                <p>This link will expire in 1 hour.</p>
-                <p>If you didn't request this password reset, you can safely ignore this email. Your password will not be changed.</p>
-                "#,
-                username, reset_link, reset_link
-            ),
-            text_body: format!(
-                r#"
+        let template = password_reset_email(username, reset_token, base_url);
+        self.send_email(to_email, template).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn send_community_invite_email(
+        &self,
+        to_email: &str,
+        community_name: &str,
+        invite_id: &str,
+        base_url: &str,
+    ) -> Result<()> {
+        let template =
+            community_invite_email(community_name, invite_id, base_url);
+        self.send_email(to_email, template).await
+    }
+}
+
+/// Asks a new user to verify their email address.
+pub fn verification_email(
+    username: &str,
+    verification_token: &str,
+    base_url: &str,
+) -> EmailTemplate {
+    let verification_link =
+        format!("{}/verify_email?token={}", base_url, verification_token);
+
+    EmailTemplate {
+        subject: "Verify your email address".to_string(),
+        html_body: format!(
+            r#"
+            <h2>Welcome to TinyLVT, {}!</h2>
+            <p>Thank you for signing up. Please click the link below to verify your email address:</p>
+            <p><a href="{}" style="background-color: #007bff; color: white; padding: 10px 20px; text-decoration: none; border-radius: 5px;">Verify Email</a></p>
+            <p>Or copy and paste this link in your browser:</p>
+            <p>{}</p>
+            <p>This link will expire in 24 hours.</p>
+            <p>If you didn't create an account, you can safely ignore this email.</p>
+            "#,
+            username, verification_link, verification_link
+        ),
+        text_body: format!(
+            r#"
+Welcome to TinyLVT, {}!
+
+Thank you for signing up. Please visit the following link to verify your email address:
+
+{}
+
+This link will expire in 24 hours.
+
+If you didn't create an account, you can safely ignore this email.
+            "#,
+            username, verification_link
+        ),
+    }
+}
+
+/// Sends a user the link to reset their password.
+pub fn password_reset_email(
+    username: &str,
+    reset_token: &str,
+    base_url: &str,
+) -> EmailTemplate {
+    let reset_link =
+        format!("{}/reset_password?token={}", base_url, reset_token);
+
+    EmailTemplate {
+        subject: "Reset your password".to_string(),
+        html_body: format!(
+            r#"
+            <h2>Password Reset Request</h2>
+            <p>Hi {},</p>
+            <p>We received a request to reset your password for your TinyLVT account.</p>
+            <p><a href="{}" style="background-color: #dc3545; color: white; padding: 10px 20px; text-decoration: none; border-radius: 5px;">Reset Password</a></p>
+            <p>Or copy and paste this link in your browser:</p>
+            <p>{}</p>
+            <p>This link will expire in 1 hour.</p>
+            <p>If you didn't request this password reset, you can safely ignore this email. Your password will not be changed.</p>
+            "#,
+            username, reset_link, reset_link
+        ),
+        text_body: format!(
+            r#"
 Password Reset Request
 
 Hi {},
@@ -161,41 +240,37 @@ Please visit the following link to reset your password:
 This link will expire in 1 hour.
 
 If you didn't request this password reset, you can safely ignore this email. Your password will not be changed.
-                "#,
-                username, reset_link
-            ),
-        };
-
-        self.send_email(to_email, template).await
+            "#,
+            username, reset_link
+        ),
     }
+}
 
-    #[tracing::instrument(skip(self))]
-    pub async fn send_community_invite_email(
-        &self,
-        to_email: &str,
-        community_name: &str,
-        invite_id: &str,
-        base_url: &str,
-    ) -> Result<()> {
-        let invite_link = format!("{}/accept-invite/{}", base_url, invite_id);
-
-        let template = EmailTemplate {
-            subject: format!("You've been invited to join {}", community_name),
-            html_body: format!(
-                r#"
-                <h2>You've been invited to join "{}"!</h2>
-                <p>You've been invited to become a member of the <strong>{}</strong> community on TinyLVT.</p>
-                <p>To accept your invitation, please sign up or log in to your TinyLVT account:</p>
-                <p><a href="{}" style="background-color: #007bff; color: white; padding: 10px 20px; text-decoration: none; border-radius: 5px;">Accept Invitation</a></p>
-                <p>Or copy and paste this link in your browser:</p>
-                <p>{}</p>
-                <p>Once you're logged in, you'll be able to see and accept your invitation in the Communities section.</p>
-                <p>If you didn't expect this invitation, you can safely ignore this email.</p>
-                "#,
-                community_name, community_name, invite_link, invite_link
-            ),
-            text_body: format!(
-                r#"
+/// Invites someone to join a community.
+pub fn community_invite_email(
+    community_name: &str,
+    invite_id: &str,
+    base_url: &str,
+) -> EmailTemplate {
+    let invite_link = format!("{}/accept-invite/{}", base_url, invite_id);
+
+    EmailTemplate {
+        subject: format!("You've been invited to join {}", community_name),
+        html_body: format!(
+            r#"
+            <h2>You've been invited to join "{}"!</h2>
+            <p>You've been invited to become a member of the <strong>{}</strong> community on TinyLVT.</p>
+            <p>To accept your invitation, please sign up or log in to your TinyLVT account:</p>
+            <p><a href="{}" style="background-color: #007bff; color: white; padding: 10px 20px; text-decoration: none; border-radius: 5px;">Accept Invitation</a></p>
+            <p>Or copy and paste this link in your browser:</p>
+            <p>{}</p>
+            <p>Once you're logged in, you'll be able to see and accept your invitation in the Communities section.</p>
+            <p>If you didn't expect this invitation, you can safely ignore this email.</p>
+            "#,
+            community_name, community_name, invite_link, invite_link
+        ),
+        text_body: format!(
+            r#"
 You've been invited to join "{}"!
 
 You've been invited to become a member of the {} community on TinyLVT.
@@ -207,11 +282,137 @@ To accept your invitation, please sign up or log in to your TinyLVT account:
 Once you're logged in, you'll be able to see and accept your invitation in the Communities section.
 
 If you didn't expect this invitation, you can safely ignore this email.
-                "#,
-                community_name, community_name, invite_link
-            ),
-        };
+            "#,
+            community_name, community_name, invite_link
+        ),
+    }
+}
 
-        self.send_email(to_email, template).await
+/// Reminds a winner that their possession of a space ends soon.
+pub fn possession_ending_email(
+    username: &str,
+    space_name: &str,
+    site_name: &str,
+    possession_end_at: &str,
+    base_url: &str,
+) -> EmailTemplate {
+    let profile_link = format!("{}/profile", base_url);
+
+    EmailTemplate {
+        subject: format!("Your possession of {} ends soon", space_name),
+        html_body: format!(
+            r#"
+            <h2>Hi {},</h2>
+            <p>Your possession of <strong>{}</strong> at <strong>{}</strong> ends at {}.</p>
+            <p>If you'd like to keep using this space, check whether an upcoming auction includes it.</p>
+            <p>You can turn off these reminders on your <a href="{}">profile page</a>.</p>
+            "#,
+            username, space_name, site_name, possession_end_at, profile_link
+        ),
+        text_body: format!(
+            r#"
+Hi {},
+
+Your possession of {} at {} ends at {}.
+
+If you'd like to keep using this space, check whether an upcoming auction includes it.
+
+You can turn off these reminders on your profile page:
+
+{}
+            "#,
+            username, space_name, site_name, possession_end_at, profile_link
+        ),
+    }
+}
+
+/// Tells a user their password was changed, so they can act if they didn't
+/// change it. Rendered up front since it's queued in the outbox rather than
+/// sent inline.
+pub fn password_changed_email(username: &str) -> EmailTemplate {
+    EmailTemplate {
+        subject: "Your password was changed".to_string(),
+        html_body: format!(
+            r#"
+            <h2>Your password was changed</h2>
+            <p>Hi {},</p>
+            <p>The password for your TinyLVT account was just changed, and any other password reset links we sent you no longer work.</p>
+            <p>If you made this change, no action is needed. If you didn't, reset your password right away and contact support.</p>
+            "#,
+            username
+        ),
+        text_body: format!(
+            r#"
+Your password was changed
+
+Hi {},
+
+The password for your TinyLVT account was just changed, and any other password reset links we sent you no longer work.
+
+If you made this change, no action is needed. If you didn't, reset your password right away and contact support.
+            "#,
+            username
+        ),
+    }
+}
+
+/// Every kind of email the server sends, so templates can be previewed
+/// without sending them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailKind {
+    Verification,
+    PasswordReset,
+    PasswordChanged,
+    CommunityInvite,
+    PossessionEnding,
+}
+
+impl EmailKind {
+    pub const ALL: [EmailKind; 5] = [
+        EmailKind::Verification,
+        EmailKind::PasswordReset,
+        EmailKind::PasswordChanged,
+        EmailKind::CommunityInvite,
+        EmailKind::PossessionEnding,
+    ];
+
+    /// Name used in preview URLs.
+    pub fn name(self) -> &'static str {
+        match self {
+            EmailKind::Verification => "verification",
+            EmailKind::PasswordReset => "password_reset",
+            EmailKind::PasswordChanged => "password_changed",
+            EmailKind::CommunityInvite => "community_invite",
+            EmailKind::PossessionEnding => "possession_ending",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// The email rendered with canned data, linking to `base_url`.
+    pub fn sample(self, base_url: &str) -> EmailTemplate {
+        match self {
+            EmailKind::Verification => {
+                verification_email("alice", "sample-token", base_url)
+            }
+            EmailKind::PasswordReset => {
+                password_reset_email("alice", "sample-token", base_url)
+            }
+            EmailKind::PasswordChanged => password_changed_email("alice"),
+            EmailKind::CommunityInvite => community_invite_email(
+                "Sample Community",
+                "00000000-0000-0000-0000-000000000000",
+                base_url,
+            ),
+            EmailKind::PossessionEnding => possession_ending_email(
+                "alice",
+                "Desk 1",
+                "Sample Site",
+                "2025-01-01 17:00 UTC",
+                base_url,
+            ),
+        }
     }
 }
@@ -0,0 +1,41 @@
+//! Ids for rows the API inserts, generated here rather than by the database's
+//! `gen_random_uuid()` defaults so that tests can make them deterministic.
+//! Mirrors [`crate::time::TimeSource`].
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct IdSource {
+    /// Next id to hand out, for a sequential source.
+    next: Option<Arc<AtomicU64>>,
+}
+
+impl IdSource {
+    /// Random (v4) ids.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { next: None }
+    }
+
+    /// Ids count up from 1, so a test issuing requests one at a time sees
+    /// the same ids on every run. Only for tests: the ids of verification and
+    /// reset tokens become guessable.
+    pub fn sequential() -> Self {
+        Self {
+            next: Some(Arc::new(AtomicU64::new(1))),
+        }
+    }
+
+    pub fn new_id(&self) -> Uuid {
+        match &self.next {
+            Some(next) => {
+                Uuid::from_u128(next.fetch_add(1, Ordering::Relaxed).into())
+            }
+            None => Uuid::new_v4(),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Support impersonation: a superuser's session temporarily logs in as
+//! another user. The session keeps who is really behind it and until when,
+//! and once that passes the session is purged so the next request is logged
+//! out rather than still acting as the target.
+//!
+//! While impersonating, a superuser can't act on the account itself (see
+//! [`Impersonation::refuse`]), and every other mutating request is written
+//! to the audit log.
+
+use actix_identity::IdentityExt;
+use actix_session::{Session, SessionExt};
+use actix_web::{
+    Error,
+    dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready},
+    web,
+};
+use jiff::Timestamp;
+use payloads::{ApiError, UserId};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::{
+    future::{Ready, ready},
+    rc::Rc,
+};
+
+use crate::{LocalBoxFuture, routes::RouteError, store, time::TimeSource};
+
+const SESSION_KEY: &str = "impersonation";
+
+/// Mutating requests that already audit the impersonation themselves.
+const SELF_AUDITED_PATHS: &[&str] = &["/api/stop_impersonating", "/api/logout"];
+
+/// Session state of an impersonated login.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Impersonation {
+    /// The superuser acting as the logged in user.
+    pub impersonator_id: UserId,
+    pub expires_at: Timestamp,
+}
+
+impl Impersonation {
+    pub fn get(session: &Session) -> Option<Self> {
+        session.get(SESSION_KEY).ok().flatten()
+    }
+
+    pub fn insert(&self, session: &Session) -> anyhow::Result<()> {
+        Ok(session.insert(SESSION_KEY, self)?)
+    }
+
+    pub fn remove(session: &Session) {
+        session.remove(SESSION_KEY);
+    }
+
+    /// Refuse an account-level action, such as deleting the account or
+    /// replacing its recovery codes, if the session is an impersonation.
+    pub fn refuse(session: &Session) -> Result<(), RouteError> {
+        match Self::get(session) {
+            Some(_) => {
+                Err(RouteError::Api(ApiError::NotAllowedWhileImpersonating))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Logs out sessions whose impersonation has expired, and audits mutating
+/// requests under the rest. Must sit inside the identity middleware.
+///
+/// A request whose audit entry can't be written fails rather than running
+/// unaudited.
+pub struct ImpersonationMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for ImpersonationMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = ImpersonationMiddlewareService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(ImpersonationMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct ImpersonationMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for ImpersonationMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let session = req.get_session();
+        let time_source = req.app_data::<web::Data<TimeSource>>().cloned();
+        let mut audited = None;
+        if let Some(impersonation) = Impersonation::get(&session) {
+            match &time_source {
+                Some(time_source)
+                    if time_source.now() < impersonation.expires_at =>
+                {
+                    if !req.method().is_safe()
+                        && !SELF_AUDITED_PATHS.contains(&req.path())
+                    {
+                        audited = Some(impersonation);
+                    }
+                }
+                _ => {
+                    tracing::info!(
+                        impersonator_id = %impersonation.impersonator_id,
+                        "Impersonation expired; logging out"
+                    );
+                    session.purge();
+                }
+            }
+        }
+        let target_id = req
+            .get_identity()
+            .ok()
+            .and_then(|identity| identity.id().ok())
+            .and_then(|id| id.parse::<uuid::Uuid>().ok())
+            .map(UserId);
+        let pool = req.app_data::<web::Data<PgPool>>().cloned();
+
+        let service = self.service.clone();
+        Box::pin(async move {
+            if let (
+                Some(impersonation),
+                Some(target_id),
+                Some(pool),
+                Some(time_source),
+            ) = (audited, target_id, pool, time_source)
+            {
+                store::audit_impersonated_request(
+                    &impersonation.impersonator_id,
+                    &target_id,
+                    req.method().as_str(),
+                    req.path(),
+                    &pool,
+                    &time_source,
+                )
+                .await
+                .map_err(RouteError::from)?;
+            }
+            service.call(req).await
+        })
+    }
+}
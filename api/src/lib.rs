@@ -1,8 +1,16 @@
 pub mod email;
+pub mod id_source;
+pub mod impersonation;
+pub mod maintenance;
+pub mod messages;
 pub mod password;
 pub mod pubsub;
+pub mod receipt;
+pub mod request_id;
 pub mod routes;
 pub mod scheduler;
+pub mod session_generation;
+pub mod static_files;
 pub mod store;
 pub mod stripe_service;
 pub mod telemetry;
@@ -21,8 +29,19 @@ use secrecy::{ExposeSecret, SecretBox};
 use sqlx::PgPool;
 use std::net::TcpListener;
 
+use crate::id_source::IdSource;
+use crate::impersonation::ImpersonationMiddleware;
+use crate::maintenance::{MaintenanceMiddleware, MaintenanceMode};
+use crate::messages::LocaleMiddleware;
+use crate::password::RecoveryRateLimit;
+use crate::request_id::RequestIdMiddleware;
+use crate::session_generation::SessionGenerationMiddleware;
 use crate::time::TimeSource;
 
+/// Migrations embedded in the binary. Run on startup, and checked by the
+/// readiness probe.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
 /// Build the server, but not await it.
 ///
 /// Returns the port that the server has bound to by modifying the config.
@@ -30,7 +49,9 @@ pub async fn build(
     config: &mut Config,
     db_pool: PgPool,
     time_source: TimeSource,
+    id_source: IdSource,
     stripe_service: std::sync::Arc<stripe_service::StripeService>,
+    email_service: std::sync::Arc<email::EmailService>,
     pubsub: pubsub::PubSub,
 ) -> std::io::Result<(Server, ServerHandle)> {
     // Spawn the Postgres listener task that forwards NOTIFYs from the
@@ -42,6 +63,8 @@ pub async fn build(
         let bus = pubsub.clone();
         tokio::spawn(pubsub::run_listener(db_url, bus));
     }
+    // A malformed message catalog should stop startup, not the first error
+    messages::load_catalogs();
     // Initialize session key from config or generate a temporary one
     let secret_key = match &config.session_master_key {
         Some(master_key) => {
@@ -66,26 +89,57 @@ pub async fn build(
             Key::generate()
         }
     };
+    let receipt_signing_key = match &config.receipt_signing_key {
+        Some(key) => SecretBox::new(Box::new(key.expose_secret().clone())),
+        None => {
+            tracing::warn!(
+                "No RECEIPT_SIGNING_KEY provided; using temporary key. \
+                Possession receipts will not verify after a restart or on \
+                other instances. Generate a key with: openssl rand -hex 32"
+            );
+            SecretBox::new(Box::new(hex::encode(Key::generate().signing())))
+        }
+    };
     let db_pool = web::Data::new(db_pool);
     let time_source = web::Data::new(time_source);
+    let id_source = web::Data::new(id_source);
     let pubsub = web::Data::new(pubsub);
 
-    let email_service = web::Data::new(email::EmailService::new(
-        secrecy::SecretBox::new(Box::new(
-            config.email_api_key.expose_secret().clone(),
-        )),
-        config.email_from_address.clone(),
-    ));
-
+    let email_service = web::Data::from(email_service);
     let stripe_service = web::Data::from(stripe_service);
 
     // Clone config for use in closure
     let allowed_origins = config.allowed_origins.clone();
+    let cors_max_age =
+        usize::try_from(config.cors_max_age.as_secs()).unwrap_or(0);
+    let static_dir = config.static_dir.clone();
+    let max_body_size = config.max_body_size;
+    let max_image_body_size = config.max_image_body_size;
+    let session_max_age = config.session_max_age.map(|d| d.unsigned_abs());
+    let session_idle_timeout =
+        config.session_idle_timeout.map(|d| d.unsigned_abs());
     let app_config = web::Data::new(AppConfig {
         base_url: config.base_url.clone(),
         stripe_monthly_price_id: config.stripe_monthly_price_id.clone(),
         stripe_annual_price_id: config.stripe_annual_price_id.clone(),
+        round_duration_bounds: config.round_duration_bounds,
+        possession_reminder_window: config.possession_reminder_window,
+        admin_token: config
+            .admin_token
+            .as_ref()
+            .map(|t| SecretBox::new(Box::new(t.expose_secret().clone()))),
+        require_email_verification: config.require_email_verification,
+        email_verification_grace: config.email_verification_grace,
+        max_pending_invites: config.max_pending_invites,
+        max_communities_per_leader: config.max_communities_per_leader,
+        receipt_signing_key,
+        email_preview: config.email_preview && cfg!(debug_assertions),
     });
+    let maintenance_mode =
+        web::Data::new(MaintenanceMode::new(config.maintenance_mode));
+    let recovery_rate_limit = web::Data::new(RecoveryRateLimit::new(
+        config.max_recovery_attempts_per_minute,
+    ));
 
     // OS assigns the port if binding to 0
     let listener = TcpListener::bind(format!("{}:{}", config.ip, config.port))?;
@@ -95,20 +149,40 @@ pub async fn build(
         let mut cors = Cors::default()
             .allow_any_method()
             .allow_any_header()
-            .supports_credentials();
+            .expose_headers([
+                request_id::REQUEST_ID,
+                actix_web::http::header::RETRY_AFTER,
+            ])
+            .supports_credentials()
+            .max_age(cors_max_age);
 
         for origin in &allowed_origins {
             cors = cors.allowed_origin(origin);
         }
 
         App::new()
+            .wrap(MaintenanceMiddleware(maintenance_mode.get_ref().clone()))
             .wrap(cors)
             // Add security headers middleware before authentication
             .wrap(SecurityHeadersMiddleware)
+            // Inside the session, so expired impersonations are logged out
+            // before the identity is read
+            .wrap(ImpersonationMiddleware)
+            // Inside the identity, so error messages can follow the user's
+            // chosen locale
+            .wrap(LocaleMiddleware)
+            // Inside the identity, so sessions ended by a password recovery
+            // are logged out before the handler sees them
+            .wrap(SessionGenerationMiddleware)
             // Use signed cookie to track user id
             // Redis would be better (can invalidate sessions; persists between
             // deployments), but this is ok for now
-            .wrap(IdentityMiddleware::default())
+            .wrap(
+                IdentityMiddleware::builder()
+                    .login_deadline(session_max_age)
+                    .visit_deadline(session_idle_timeout)
+                    .build(),
+            )
             .wrap(
                 SessionMiddleware::builder(
                     CookieSessionStore::default(),
@@ -120,20 +194,28 @@ pub async fn build(
                 )
                 .build(),
             )
-            .service(routes::api_services())
+            // Outermost, so every response carries the id
+            .wrap(RequestIdMiddleware)
+            .service(routes::api_services(routes::json_config(
+                max_image_body_size,
+            )))
+            .configure(|cfg| {
+                if let Some(dir) = &static_dir {
+                    cfg.service(static_files::static_files_service(dir));
+                }
+            })
             .app_data(db_pool.clone())
-            .app_data(
-                web::JsonConfig::default()
-                    // 1 MB image as JSON-serialized Vec<u8>
-                    // expands ~4-5x (each byte becomes 1-3
-                    // digits + comma)
-                    .limit(6 * 1024 * 1024),
-            )
+            // Oversized bodies are rejected with 413 before being buffered
+            .app_data(routes::json_config(max_body_size))
+            .app_data(web::PayloadConfig::new(max_body_size))
             .app_data(time_source.clone())
+            .app_data(id_source.clone())
             .app_data(email_service.clone())
             .app_data(stripe_service.clone())
             .app_data(app_config.clone())
             .app_data(pubsub.clone())
+            .app_data(maintenance_mode.clone())
+            .app_data(recovery_rate_limit.clone())
     })
     .listen(listener)?
     .run();
@@ -152,8 +234,13 @@ pub struct Config {
     /// List of allowed CORS origins. Use "*" to allow any origin (development
     /// only)
     pub allowed_origins: Vec<String>,
-    /// Email service API key (e.g., Resend API key)
-    pub email_api_key: SecretBox<String>,
+    /// How long browsers may cache a CORS preflight response before sending
+    /// another, sent as `Access-Control-Max-Age`. Browsers cap this
+    /// themselves, Chromium at two hours.
+    pub cors_max_age: jiff::SignedDuration,
+    /// Email service API key (e.g., Resend API key). Only optional when email
+    /// verification isn't required; without it, no emails are sent.
+    pub email_api_key: Option<SecretBox<String>>,
     /// Email from address
     pub email_from_address: String,
     /// Base URL for email links (e.g., "https://yourdomain.com" or "http://localhost:8080")
@@ -161,6 +248,20 @@ pub struct Config {
     /// Optional master key for session cookies (base64-encoded 64-byte key)
     /// If not provided, a random key will be generated on each startup
     pub session_master_key: Option<SecretBox<String>>,
+    /// How long after logging in a session ends, however active. Unlimited
+    /// if unset.
+    ///
+    /// Both session limits are checked by the server against timestamps
+    /// kept in the signed session cookie. The cookie itself stays a browser
+    /// session cookie, so the UI, which sends it with every fetch
+    /// (`credentials: include`), keeps presenting it after a limit passes.
+    /// The API then answers 401 and clears it, and the UI shows the user as
+    /// logged out.
+    pub session_max_age: Option<jiff::SignedDuration>,
+    /// How long a session may go without a request before it ends. Each
+    /// request restarts the clock and re-sends the cookie. Unlimited if
+    /// unset.
+    pub session_idle_timeout: Option<jiff::SignedDuration>,
     /// Stripe secret API key
     pub stripe_api_key: SecretBox<String>,
     /// Stripe webhook endpoint secret
@@ -169,8 +270,74 @@ pub struct Config {
     pub stripe_monthly_price_id: String,
     /// Stripe Price ID for the annual plan
     pub stripe_annual_price_id: String,
+    /// Round duration bounds for communities that don't set their own.
+    /// Either may be unset, leaving that side unbounded.
+    pub round_duration_bounds: payloads::RoundDurationBounds,
+    /// How long before a possession ends its winner is sent a reminder email
+    pub possession_reminder_window: jiff::SignedDuration,
+    /// How often the scheduler deletes expired verification and password
+    /// reset tokens
+    pub token_cleanup_interval: jiff::SignedDuration,
+    /// Start in maintenance mode. Can be toggled at runtime via the admin
+    /// route.
+    pub maintenance_mode: bool,
+    /// Bearer token for operator-only admin routes. Admin routes are disabled
+    /// if not set.
+    pub admin_token: Option<SecretBox<String>>,
+    /// Whether new accounts must verify their email address. When off,
+    /// accounts are created already verified, for instances run without an
+    /// email provider.
+    pub require_email_verification: bool,
+    /// How long after signup an unverified account may still browse what
+    /// normally requires a verified email, such as its received invites.
+    /// Actions that change anything always require verification. Zero, the
+    /// default, requires verification from the start.
+    pub email_verification_grace: jiff::SignedDuration,
+    /// Most outstanding invites a community may have at once
+    pub max_pending_invites: i64,
+    /// Most account recovery attempts per minute across all accounts,
+    /// checked before any recovery code is hashed. Up to a minute's worth
+    /// may be made at once.
+    pub max_recovery_attempts_per_minute: u32,
+    /// Most communities one user may lead before they can't create another.
+    /// Unlimited if unset.
+    pub max_communities_per_leader: Option<i64>,
+    /// Secret for signing possession receipts. If not provided, a random key
+    /// is generated on each startup and earlier receipts stop verifying.
+    pub receipt_signing_key: Option<SecretBox<String>>,
+    /// Email of an existing account to make a superuser at startup, for
+    /// bootstrapping a new deployment. The account must already be verified.
+    pub bootstrap_superuser_email: Option<String>,
+    /// Serve the development routes that render each email template with
+    /// sample data. Ignored in release builds.
+    pub email_preview: bool,
+    /// Directory of the built UI to serve at the root, with deep links falling
+    /// back to its index.html. Not served if unset.
+    pub static_dir: Option<std::path::PathBuf>,
+    /// Largest request body accepted, in bytes
+    pub max_body_size: usize,
+    /// Largest request body accepted by routes that upload images, in bytes
+    pub max_image_body_size: usize,
 }
 
+/// Default for [`Config::cors_max_age`].
+pub const DEFAULT_CORS_MAX_AGE: jiff::SignedDuration =
+    jiff::SignedDuration::from_hours(1);
+
+/// Default for [`Config::max_pending_invites`].
+pub const DEFAULT_MAX_PENDING_INVITES: i64 = 100;
+
+/// Default for [`Config::max_recovery_attempts_per_minute`].
+pub const DEFAULT_MAX_RECOVERY_ATTEMPTS_PER_MINUTE: u32 = 20;
+
+/// Default for [`Config::max_body_size`].
+pub const DEFAULT_MAX_BODY_SIZE: usize = 256 * 1024;
+
+/// Default for [`Config::max_image_body_size`]. A 1 MB image as a
+/// JSON-serialized `Vec<u8>` expands ~4-5x (each byte becomes 1-3 digits + a
+/// comma).
+pub const DEFAULT_MAX_IMAGE_BODY_SIZE: usize = 6 * 1024 * 1024;
+
 /// Runtime configuration shared across the application as app_data.
 /// Contains only the fields needed by route handlers at runtime.
 pub struct AppConfig {
@@ -180,6 +347,24 @@ pub struct AppConfig {
     pub stripe_monthly_price_id: String,
     /// Stripe Price ID for the annual plan
     pub stripe_annual_price_id: String,
+    /// Round duration bounds for communities that don't set their own
+    pub round_duration_bounds: payloads::RoundDurationBounds,
+    /// How long before a possession ends its winner is sent a reminder email
+    pub possession_reminder_window: jiff::SignedDuration,
+    /// Bearer token for operator-only admin routes
+    pub admin_token: Option<SecretBox<String>>,
+    /// Whether new accounts must verify their email address
+    pub require_email_verification: bool,
+    /// How long after signup unverified accounts may browse
+    pub email_verification_grace: jiff::SignedDuration,
+    /// Most outstanding invites a community may have at once
+    pub max_pending_invites: i64,
+    /// Most communities one user may lead, if limited
+    pub max_communities_per_leader: Option<i64>,
+    /// Secret for signing possession receipts
+    pub receipt_signing_key: SecretBox<String>,
+    /// Whether the email preview routes are served
+    pub email_preview: bool,
 }
 
 impl Config {
@@ -196,6 +381,20 @@ impl Config {
         ))
     }
 
+    /// Email service for the configured provider, or a disabled one if no API
+    /// key is set.
+    pub fn create_email_service(&self) -> email::EmailService {
+        match &self.email_api_key {
+            Some(key) => email::EmailService::new(
+                SecretBox::new(Box::new(key.expose_secret().clone())),
+                self.email_from_address.clone(),
+            ),
+            None => {
+                email::EmailService::disabled(self.email_from_address.clone())
+            }
+        }
+    }
+
     pub fn from_env() -> Self {
         use std::env::var;
 
@@ -206,21 +405,51 @@ impl Config {
             .filter(|s| !s.is_empty())
             .collect();
 
+        let require_email_verification = var("REQUIRE_EMAIL_VERIFICATION")
+            .map_or(true, |v| !(v == "false" || v == "0"));
+        let email_api_key = var("EMAIL_API_KEY").ok();
+        if require_email_verification && email_api_key.is_none() {
+            panic!(
+                "EMAIL_API_KEY must be set unless REQUIRE_EMAIL_VERIFICATION \
+                is false"
+            );
+        }
+        let email_from_address = if email_api_key.is_some() {
+            var("EMAIL_FROM_ADDRESS").expect("EMAIL_FROM_ADDRESS must be set")
+        } else {
+            var("EMAIL_FROM_ADDRESS").unwrap_or_default()
+        };
+
         Config {
             database_url: var("DATABASE_URL").unwrap(),
             ip: var("IP_ADDRESS").unwrap(),
             port: var("PORT").unwrap().parse().unwrap(),
             allowed_origins,
-            email_api_key: SecretBox::new(Box::new(
-                var("EMAIL_API_KEY").expect("EMAIL_API_KEY must be set"),
-            )),
-            email_from_address: var("EMAIL_FROM_ADDRESS")
-                .expect("EMAIL_FROM_ADDRESS must be set"),
+            cors_max_age: var("CORS_MAX_AGE_SECONDS")
+                .map(|s| {
+                    jiff::SignedDuration::from_secs(
+                        s.parse()
+                            .expect("CORS_MAX_AGE_SECONDS must be a number"),
+                    )
+                })
+                .unwrap_or(DEFAULT_CORS_MAX_AGE),
+            email_api_key: email_api_key.map(|k| SecretBox::new(Box::new(k))),
+            email_from_address,
             base_url: var("BASE_URL")
                 .unwrap_or_else(|_| "http://localhost:8080".to_string()),
             session_master_key: var("SESSION_MASTER_KEY")
                 .ok()
                 .map(|k| SecretBox::new(Box::new(k))),
+            session_max_age: var("SESSION_MAX_AGE_HOURS").ok().map(|h| {
+                jiff::SignedDuration::from_hours(
+                    h.parse().expect("SESSION_MAX_AGE_HOURS must be a number"),
+                )
+            }),
+            session_idle_timeout: var("SESSION_IDLE_MINUTES").ok().map(|m| {
+                jiff::SignedDuration::from_mins(
+                    m.parse().expect("SESSION_IDLE_MINUTES must be a number"),
+                )
+            }),
             stripe_api_key: SecretBox::new(Box::new(
                 var("STRIPE_API_KEY").expect("STRIPE_API_KEY must be set"),
             )),
@@ -232,6 +461,92 @@ impl Config {
                 .expect("STRIPE_MONTHLY_PRICE_ID must be set"),
             stripe_annual_price_id: var("STRIPE_ANNUAL_PRICE_ID")
                 .expect("STRIPE_ANNUAL_PRICE_ID must be set"),
+            round_duration_bounds: payloads::RoundDurationBounds {
+                min: var("MIN_ROUND_DURATION_SECONDS").ok().map(|s| {
+                    jiff::SignedDuration::from_secs(
+                        s.parse().expect(
+                            "MIN_ROUND_DURATION_SECONDS must be a number",
+                        ),
+                    )
+                }),
+                max: var("MAX_ROUND_DURATION_SECONDS").ok().map(|s| {
+                    jiff::SignedDuration::from_secs(
+                        s.parse().expect(
+                            "MAX_ROUND_DURATION_SECONDS must be a number",
+                        ),
+                    )
+                }),
+            },
+            possession_reminder_window: jiff::SignedDuration::from_hours(
+                var("POSSESSION_REMINDER_HOURS")
+                    .map(|h| {
+                        h.parse().expect(
+                            "POSSESSION_REMINDER_HOURS must be a number",
+                        )
+                    })
+                    .unwrap_or(24),
+            ),
+            token_cleanup_interval: jiff::SignedDuration::from_mins(
+                var("TOKEN_CLEANUP_MINUTES")
+                    .map(|m| {
+                        m.parse()
+                            .expect("TOKEN_CLEANUP_MINUTES must be a number")
+                    })
+                    .unwrap_or(60),
+            ),
+            maintenance_mode: var("MAINTENANCE_MODE")
+                .is_ok_and(|v| v == "true" || v == "1"),
+            admin_token: var("ADMIN_TOKEN")
+                .ok()
+                .map(|t| SecretBox::new(Box::new(t))),
+            require_email_verification,
+            email_verification_grace: jiff::SignedDuration::from_hours(
+                var("EMAIL_VERIFICATION_GRACE_HOURS")
+                    .map(|h| {
+                        h.parse().expect(
+                            "EMAIL_VERIFICATION_GRACE_HOURS must be a number",
+                        )
+                    })
+                    .unwrap_or(0),
+            ),
+            max_pending_invites: var("MAX_PENDING_INVITES")
+                .map(|n| {
+                    n.parse().expect("MAX_PENDING_INVITES must be a number")
+                })
+                .unwrap_or(DEFAULT_MAX_PENDING_INVITES),
+            max_recovery_attempts_per_minute: var(
+                "MAX_RECOVERY_ATTEMPTS_PER_MINUTE",
+            )
+            .map(|n| {
+                n.parse()
+                    .expect("MAX_RECOVERY_ATTEMPTS_PER_MINUTE must be a number")
+            })
+            .unwrap_or(DEFAULT_MAX_RECOVERY_ATTEMPTS_PER_MINUTE),
+            max_communities_per_leader: var("MAX_COMMUNITIES_PER_LEADER")
+                .ok()
+                .filter(|n| !n.is_empty())
+                .map(|n| {
+                    n.parse()
+                        .expect("MAX_COMMUNITIES_PER_LEADER must be a number")
+                }),
+            receipt_signing_key: var("RECEIPT_SIGNING_KEY")
+                .ok()
+                .filter(|k| !k.is_empty())
+                .map(|k| SecretBox::new(Box::new(k))),
+            bootstrap_superuser_email: var("BOOTSTRAP_SUPERUSER_EMAIL")
+                .ok()
+                .filter(|e| !e.is_empty()),
+            email_preview: var("EMAIL_PREVIEW")
+                .is_ok_and(|v| v == "true" || v == "1"),
+            static_dir: var("STATIC_DIR").ok().map(Into::into),
+            max_body_size: var("MAX_BODY_BYTES")
+                .map(|b| b.parse().expect("MAX_BODY_BYTES must be a number"))
+                .unwrap_or(DEFAULT_MAX_BODY_SIZE),
+            max_image_body_size: var("MAX_IMAGE_BODY_BYTES")
+                .map(|b| {
+                    b.parse().expect("MAX_IMAGE_BODY_BYTES must be a number")
+                })
+                .unwrap_or(DEFAULT_MAX_IMAGE_BODY_SIZE),
         }
     }
 }
@@ -297,6 +612,7 @@ where
         Box::pin(async move {
             let is_api_endpoint = req.path().starts_with("/api")
                 && req.path() != "/api/health_check"
+                && req.path() != "/api/ready"
                 && req.path() != "/api/platform_stats";
 
             let res = service.call(req).await?;
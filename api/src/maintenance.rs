@@ -0,0 +1,114 @@
+//! Maintenance mode: while active, every `/api` request except the health
+//! check and the toggle itself is answered with a 503 and `Retry-After`,
+//! without reaching route handlers. Lets operators run migrations or deploys
+//! without stopping the server.
+
+use actix_web::{
+    Error, HttpResponse,
+    body::EitherBody,
+    dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready},
+    http::header::{HeaderValue, RETRY_AFTER},
+};
+use payloads::ApiError;
+use std::{
+    future::{Ready, ready},
+    rc::Rc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crate::{LocalBoxFuture, routes::RouteError};
+
+/// Seconds clients are told to wait before retrying.
+const RETRY_AFTER_SECS: u32 = 60;
+
+/// Paths that keep working during maintenance.
+const EXEMPT_PATHS: &[&str] =
+    &["/api/health_check", "/api/ready", "/api/admin/maintenance"];
+
+/// Shared, runtime-toggleable maintenance flag. Cheap to clone; all clones
+/// see the same state.
+#[derive(Clone, Default)]
+pub struct MaintenanceMode(Arc<AtomicBool>);
+
+impl MaintenanceMode {
+    pub fn new(enabled: bool) -> Self {
+        Self(Arc::new(AtomicBool::new(enabled)))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn set(&self, enabled: bool) {
+        self.0.store(enabled, Ordering::Relaxed);
+    }
+}
+
+pub struct MaintenanceMiddleware(pub MaintenanceMode);
+
+impl<S, B> Transform<S, ServiceRequest> for MaintenanceMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = MaintenanceMiddlewareService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(MaintenanceMiddlewareService {
+            service: Rc::new(service),
+            mode: self.0.clone(),
+        }))
+    }
+}
+
+pub struct MaintenanceMiddlewareService<S> {
+    service: Rc<S>,
+    mode: MaintenanceMode,
+}
+
+impl<S, B> Service<ServiceRequest> for MaintenanceMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let blocked = self.mode.is_enabled()
+            && req.path().starts_with("/api")
+            && !EXEMPT_PATHS.contains(&req.path());
+
+        if blocked {
+            // Built from the error, so the locale middleware can translate it
+            let mut res = HttpResponse::from_error(RouteError::Api(
+                ApiError::MaintenanceMode,
+            ));
+            res.headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(RETRY_AFTER_SECS));
+            return Box::pin(ready(Ok(req
+                .into_response(res)
+                .map_into_right_body())));
+        }
+
+        let service = self.service.clone();
+        Box::pin(async move {
+            let res = service.call(req).await?;
+            Ok(res.map_into_left_body())
+        })
+    }
+}
@@ -0,0 +1,346 @@
+//! Localized messages for API errors. An error body carries the error's
+//! code and details for clients to match on, plus a message for people to
+//! read, rendered from a per-locale catalog.
+//!
+//! Catalogs are compiled in from `api/locales/<tag>.ftl`, written in a subset
+//! of Fluent: one `Code = template` entry per line, with `{ $name }`
+//! placeholders filled from the error's details. A code missing from a
+//! catalog falls back to English, and one missing from English to the
+//! error's built-in message.
+//!
+//! The locale is the user's chosen one if they are signed in and have
+//! chosen one, otherwise the best supported match for `Accept-Language`,
+//! otherwise English.
+
+use actix_identity::IdentityExt;
+use actix_web::{
+    Error,
+    body::{BoxBody, EitherBody},
+    dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready},
+    http::header::ACCEPT_LANGUAGE,
+    web,
+};
+use payloads::{ApiError, Locale, UserId};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::PgPool;
+use std::{
+    collections::HashMap,
+    future::{Ready, ready},
+    rc::Rc,
+    sync::LazyLock,
+};
+
+use crate::{LocalBoxFuture, routes::RouteError, store};
+
+/// Message templates by error code.
+struct Catalog(HashMap<&'static str, &'static str>);
+
+impl Catalog {
+    /// Parse a catalog, skipping blank lines and `#` comments.
+    ///
+    /// Panics on a malformed entry; catalogs are compiled in, so this fails
+    /// at startup, and in tests, rather than at the first error.
+    fn parse(source: &'static str) -> Self {
+        let entries = source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (code, template) =
+                    line.split_once('=').unwrap_or_else(|| {
+                        panic!("malformed catalog entry: {line}")
+                    });
+                (code.trim(), template.trim())
+            })
+            .collect();
+        Self(entries)
+    }
+}
+
+static CATALOGS: LazyLock<HashMap<Locale, Catalog>> = LazyLock::new(|| {
+    HashMap::from([
+        (
+            Locale::En,
+            Catalog::parse(include_str!("../locales/en.ftl")),
+        ),
+        (
+            Locale::Es,
+            Catalog::parse(include_str!("../locales/es.ftl")),
+        ),
+    ])
+});
+
+/// Parse every catalog now, so a malformed one stops the server at startup.
+pub fn load_catalogs() {
+    LazyLock::force(&CATALOGS);
+}
+
+/// The error's message in `locale`.
+pub fn render(error: &ApiError, locale: Locale) -> String {
+    let value = serde_json::to_value(error).unwrap_or(Value::Null);
+    let Some(code) = value.get("code").and_then(Value::as_str) else {
+        return error.to_string();
+    };
+    let args = template_args(error, value.get("details"));
+    [locale, Locale::En]
+        .into_iter()
+        .filter_map(|locale| CATALOGS.get(&locale)?.0.get(code))
+        .find_map(|template| fill(template, &args))
+        .unwrap_or_else(|| error.to_string())
+}
+
+/// Values for a template's placeholders: each field of the error's details,
+/// or for a variant holding a single value, that value as `value`. Nested
+/// errors have no codes of their own, so they are filled in with their
+/// built-in message.
+fn template_args(
+    error: &ApiError,
+    details: Option<&Value>,
+) -> HashMap<String, String> {
+    let nested = match error {
+        ApiError::InvalidAuctionParams(e) => Some(e.to_string()),
+        ApiError::InvalidWinnerConstraints(e) => Some(e.to_string()),
+        ApiError::InvalidOpenHours(e) => Some(e.to_string()),
+        _ => None,
+    };
+    match (nested, details) {
+        (Some(message), _) => HashMap::from([("value".into(), message)]),
+        (None, Some(Value::Object(fields))) => fields
+            .iter()
+            .filter_map(|(name, value)| Some((name.clone(), scalar(value)?)))
+            .collect(),
+        (None, Some(value)) => scalar(value)
+            .map(|value| HashMap::from([("value".into(), value)]))
+            .unwrap_or_default(),
+        (None, None) => HashMap::new(),
+    }
+}
+
+/// A JSON scalar as it would print in Rust, so `3.0` prints as `3` like the
+/// `f64` it came from.
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => i.to_string(),
+            (None, Some(u), _) => u.to_string(),
+            (None, None, Some(f)) => f.to_string(),
+            (None, None, None) => n.to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// Fill a template's placeholders, or None if one has no value.
+fn fill(template: &str, args: &HashMap<String, String>) -> Option<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let end = start + rest[start..].find('}')?;
+        let name = rest[start + 1..end].trim().strip_prefix('$')?;
+        out.push_str(args.get(name)?);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// The supported locale an `Accept-Language` header prefers most, if any.
+/// Ranges are weighed by their `q` values, and ties go to the one listed
+/// first.
+pub fn from_accept_language(header: &str) -> Option<Locale> {
+    let mut ranges: Vec<(f32, Locale)> = header
+        .split(',')
+        .filter_map(|range| {
+            let mut params = range.split(';');
+            let locale = Locale::from_tag(params.next()?.trim())?;
+            let q = match params.find_map(|p| p.trim().strip_prefix("q=")) {
+                Some(q) => q.parse().ok()?,
+                None => 1.0,
+            };
+            (q > 0.0).then_some((q, locale))
+        })
+        .collect();
+    ranges.sort_by(|a, b| b.0.total_cmp(&a.0));
+    ranges.first().map(|&(_, locale)| locale)
+}
+
+/// An error response body: the error's code and details, and its message.
+#[derive(Serialize)]
+pub struct ErrorBody<'a> {
+    #[serde(flatten)]
+    pub error: &'a ApiError,
+    pub message: String,
+}
+
+impl<'a> ErrorBody<'a> {
+    pub fn new(error: &'a ApiError, locale: Locale) -> Self {
+        Self {
+            error,
+            message: render(error, locale),
+        }
+    }
+}
+
+/// Rewrites the message of API error responses into the caller's locale.
+/// Error responses are built in English, since building them has no access
+/// to the request. Must sit inside the identity middleware, so it can look
+/// up the signed-in user's chosen locale.
+pub struct LocaleMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for LocaleMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = LocaleMiddlewareService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(LocaleMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct LocaleMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for LocaleMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let accepted = req
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(from_accept_language);
+        let user_id = req
+            .get_identity()
+            .ok()
+            .and_then(|identity| identity.id().ok())
+            .and_then(|id| id.parse::<uuid::Uuid>().ok())
+            .map(UserId);
+        let pool = req.app_data::<web::Data<PgPool>>().cloned();
+        let service = self.service.clone();
+
+        Box::pin(async move {
+            let res = service.call(req).await?;
+            let Some(RouteError::Api(error)) = res
+                .response()
+                .error()
+                .and_then(|e| e.as_error::<RouteError>())
+            else {
+                return Ok(res.map_into_left_body());
+            };
+
+            // Only error responses pay for the lookup
+            let chosen = match (user_id, pool) {
+                (Some(user_id), Some(pool)) => {
+                    store::get_user_locale(&user_id, &pool)
+                        .await
+                        .unwrap_or_else(|e| {
+                            tracing::error!(
+                                "Failed to look up user locale: {e:?}"
+                            );
+                            None
+                        })
+                }
+                _ => None,
+            };
+            let locale = chosen.or(accepted).unwrap_or(Locale::En);
+            if locale == Locale::En {
+                return Ok(res.map_into_left_body());
+            }
+
+            let body = serde_json::to_vec(&ErrorBody::new(error, locale))?;
+            Ok(res.map_body(|_, _| EitherBody::right(BoxBody::new(body))))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalogs_parse() {
+        load_catalogs();
+        // Every translated code exists in English
+        let english = &CATALOGS[&Locale::En].0;
+        for code in CATALOGS[&Locale::Es].0.keys() {
+            assert!(english.contains_key(code), "{code} missing from en.ftl");
+        }
+    }
+
+    #[test]
+    fn exceeds_eligibility_in_each_locale() {
+        let error = ApiError::ExceedsEligibility {
+            available: 3.0,
+            required: 4.5,
+        };
+        assert_eq!(
+            render(&error, Locale::En),
+            "Exceeds eligibility. Available: 3, Required: 4.5"
+        );
+        assert_eq!(render(&error, Locale::En), error.to_string());
+        assert_eq!(
+            render(&error, Locale::Es),
+            "Supera la elegibilidad. Disponible: 3, requerida: 4.5"
+        );
+    }
+
+    #[test]
+    fn messages_without_a_template_fall_back() {
+        // A variant's single value fills `{ $value }`
+        let error = ApiError::InvalidUsername("too short".into());
+        assert_eq!(
+            render(&error, Locale::Es),
+            "Nombre de usuario no válido: too short"
+        );
+        // No template can sum counts, so this keeps its built-in message
+        let error = ApiError::SiteDeletionNotConfirmed(
+            payloads::responses::SiteDependencies {
+                spaces: 2,
+                concluded_auctions: 1,
+                pending_auctions: 1,
+                images: 0,
+                user_values: 0,
+            },
+        );
+        assert_eq!(render(&error, Locale::Es), error.to_string());
+    }
+
+    #[test]
+    fn accept_language() {
+        assert_eq!(from_accept_language("es-MX,es;q=0.9"), Some(Locale::Es));
+        assert_eq!(
+            from_accept_language("fr-CA, en;q=0.5, es;q=0.8"),
+            Some(Locale::Es)
+        );
+        assert_eq!(from_accept_language("en, es"), Some(Locale::En));
+        assert_eq!(from_accept_language("es;q=0"), None);
+        // Unknown locales aren't matched, so the caller falls back to English
+        assert_eq!(from_accept_language("fr, de;q=0.5"), None);
+        assert_eq!(from_accept_language("*"), None);
+    }
+}
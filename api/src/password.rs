@@ -2,14 +2,20 @@ use payloads::ApiError;
 
 use crate::store::{self, StoreError};
 use crate::telemetry::spawn_blocking_with_tracing;
+use crate::time::TimeSource;
 use anyhow::Context;
 use argon2::password_hash::SaltString;
 use argon2::{
     Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier,
     Version,
 };
+use jiff_sqlx::ToSqlx;
 use secrecy::{ExposeSecret, SecretBox};
 use sqlx::PgPool;
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum AuthError {
@@ -25,19 +31,47 @@ pub struct Credentials {
     password: SecretBox<String>,
 }
 
+impl Credentials {
+    pub fn new(username: String, password: SecretBox<String>) -> Self {
+        Self { username, password }
+    }
+}
+
+/// Verified against when the username is unknown, so that path costs the same
+/// argon2 work as a wrong password and response timing doesn't reveal which
+/// usernames exist. Must use the same params as `compute_password_hash`.
+const DUMMY_PASSWORD_HASH: &str = "$argon2id$v=19$m=15000,t=2,p=1$\
+    gZiV/M1gPc22ElAH/Jh1Hw$\
+    CWOrkoo7oJBQ/iyh7uJ0LO2aLEfrHwTWllSAxT0zRno";
+
+tokio::task_local! {
+    /// Hash verifications performed by `validate_credentials` and
+    /// `recover_account`, when running inside
+    /// [`count_password_verifications`].
+    static VERIFICATIONS: Arc<AtomicUsize>;
+}
+
+/// Run `f`, returning its output and the number of password hash
+/// verifications it performed. Lets tests assert that every login path does
+/// the expensive hash without relying on wall-clock timing.
+pub async fn count_password_verifications<F: Future>(
+    f: F,
+) -> (F::Output, usize) {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let output = VERIFICATIONS.scope(counter.clone(), f).await;
+    (output, counter.load(Ordering::SeqCst))
+}
+
+/// Unknown usernames and wrong passwords both fail with
+/// [`AuthError::InvalidCredentials`] after exactly one hash verification.
 #[tracing::instrument(name = "Validate credentials", skip(credentials, pool))]
 pub async fn validate_credentials(
     credentials: Credentials,
     pool: &PgPool,
 ) -> Result<payloads::UserId, AuthError> {
     let mut user_id = None;
-    // fallback password hash to prevent timing differences
-    let mut expected_password_hash = SecretBox::new(Box::new(
-        "$argon2id$v=19$m=15000,t=2,p=1$\
-        gZiV/M1gPc22ElAH/Jh1Hw$\
-        CWOrkoo7oJBQ/iyh7uJ0LO2aLEfrHwTWllSAxT0zRno"
-            .to_string(),
-    ));
+    let mut expected_password_hash =
+        SecretBox::new(Box::new(DUMMY_PASSWORD_HASH.to_string()));
 
     if let Some((stored_user_id, stored_password_hash)) =
         get_stored_credentials(&credentials.username, pool).await?
@@ -46,8 +80,14 @@ pub async fn validate_credentials(
         expected_password_hash = stored_password_hash;
     }
 
+    let counter = VERIFICATIONS.try_with(Arc::clone).ok();
     spawn_blocking_with_tracing(move || {
-        verify_password_hash(expected_password_hash, credentials.password)
+        let result =
+            verify_password_hash(expected_password_hash, credentials.password);
+        if let Some(counter) = counter {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+        result
     })
     .await
     .context("Failed to spawn blocking task.")??;
@@ -102,6 +142,7 @@ pub async fn change_password(
     user_id: payloads::UserId,
     password: SecretBox<String>,
     pool: &PgPool,
+    time_source: &TimeSource,
 ) -> Result<(), anyhow::Error> {
     // Validate password before expensive hash operation
     let password_validation =
@@ -117,18 +158,201 @@ pub async fn change_password(
     sqlx::query(
         r#"
         UPDATE users
-        SET password_hash = $1
+        SET password_hash = $1, updated_at = $3
         WHERE id = $2
         "#,
     )
     .bind(password_hash.expose_secret())
     .bind(user_id)
+    .bind(time_source.now().to_sqlx())
     .execute(pool)
     .await
     .context("Failed to change user's password in the database.")?;
     Ok(())
 }
 
+/// Characters recovery codes are drawn from: uppercase letters and digits,
+/// minus the easily confused 0/O and 1/I. 32 of them, so each character
+/// carries 5 bits.
+const RECOVERY_CODE_ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Characters per code, for 50 bits of entropy.
+const RECOVERY_CODE_LEN: usize = 10;
+
+/// Replace the user's recovery codes with a fresh set, returning the codes
+/// in the `XXXXX-XXXXX` form they're shown in. Only their hashes are kept.
+#[tracing::instrument(
+    name = "Generate recovery codes",
+    skip(pool, time_source)
+)]
+pub async fn generate_recovery_codes(
+    user_id: &payloads::UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Vec<String>, StoreError> {
+    let codes = (0..store::RECOVERY_CODE_COUNT)
+        .map(|_| new_recovery_code())
+        .collect::<Vec<_>>();
+    let normalized = codes
+        .iter()
+        .map(|code| SecretBox::new(Box::new(normalize_recovery_code(code))))
+        .collect::<Vec<_>>();
+    let hashes = spawn_blocking_with_tracing(move || {
+        normalized
+            .into_iter()
+            .map(|code| {
+                compute_password_hash(code)
+                    .map(|hash| hash.expose_secret().clone())
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })
+    .await
+    .map_err(anyhow::Error::from)?
+    .context("Failed to hash recovery codes")?;
+    store::replace_recovery_codes(user_id, &hashes, pool, time_source).await?;
+    Ok(codes)
+}
+
+fn new_recovery_code() -> String {
+    use rand_core::RngCore;
+    let mut bytes = [0u8; RECOVERY_CODE_LEN];
+    rand_core::OsRng.fill_bytes(&mut bytes);
+    let chars = bytes
+        .iter()
+        .map(|b| RECOVERY_CODE_ALPHABET[usize::from(b % 32)] as char)
+        .collect::<String>();
+    let (first, second) = chars.split_at(RECOVERY_CODE_LEN / 2);
+    format!("{first}-{second}")
+}
+
+/// Codes are compared without case, separators, or surrounding space, so
+/// they can be typed however is convenient.
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Account recovery attempts allowed across every account, whether or not the
+/// username exists. Each attempt costs [`store::RECOVERY_CODE_COUNT`] hash
+/// verifications, and the per-account limit doesn't cover unknown usernames,
+/// so this bounds the hashing recovery can be made to do.
+///
+/// A token bucket holding a minute's worth of attempts, refilled evenly.
+pub struct RecoveryRateLimit {
+    /// Time for one attempt to refill
+    interval: jiff::SignedDuration,
+    /// When the bucket is full again, if it's been drawn from
+    full_at: Mutex<Option<jiff::Timestamp>>,
+}
+
+impl RecoveryRateLimit {
+    pub fn new(attempts_per_minute: u32) -> Self {
+        let attempts =
+            i32::try_from(attempts_per_minute.max(1)).unwrap_or(i32::MAX);
+        Self {
+            interval: jiff::SignedDuration::from_mins(1) / attempts,
+            full_at: Mutex::new(None),
+        }
+    }
+
+    /// Take an attempt from the bucket, or return false if it's empty.
+    pub fn try_claim(&self, now: jiff::Timestamp) -> bool {
+        let mut full_at = self.full_at.lock().unwrap();
+        let start = full_at.map_or(now, |full_at| full_at.max(now));
+        if now.duration_until(start) + self.interval
+            > jiff::SignedDuration::from_mins(1)
+        {
+            return false;
+        }
+        *full_at = Some(start + self.interval);
+        true
+    }
+}
+
+/// Set a new password for the account named `username` using one of its
+/// recovery codes, ending all of its sessions. Returns the account's id.
+///
+/// Every attempt, whether the username exists or not, costs
+/// [`store::RECOVERY_CODE_COUNT`] hash verifications, padding with a dummy
+/// hash, so timing doesn't reveal how many codes an account has left.
+#[tracing::instrument(
+    name = "Recover account",
+    skip(recovery_code, new_password, pool, time_source)
+)]
+pub async fn recover_account(
+    username: &str,
+    recovery_code: SecretBox<String>,
+    new_password: SecretBox<String>,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<payloads::UserId, StoreError> {
+    let password_validation =
+        payloads::requests::validate_password(new_password.expose_secret());
+    if let Some(error_message) = password_validation.error_message() {
+        return Err(ApiError::InvalidPassword(error_message.to_string()).into());
+    }
+
+    let user_id =
+        store::claim_recovery_attempt(username, pool, time_source).await?;
+    let stored = match &user_id {
+        Some(user_id) => store::unused_recovery_codes(user_id, pool).await?,
+        None => Vec::new(),
+    };
+
+    let mut hashes = stored
+        .into_iter()
+        .map(|(code_id, hash)| (Some(code_id), hash))
+        .collect::<Vec<_>>();
+    hashes.resize(
+        hashes.len().max(store::RECOVERY_CODE_COUNT),
+        (None, DUMMY_PASSWORD_HASH.to_string()),
+    );
+
+    let candidate = normalize_recovery_code(recovery_code.expose_secret());
+    let counter = VERIFICATIONS.try_with(Arc::clone).ok();
+    let (matched, password_hash) = spawn_blocking_with_tracing(move || {
+        // No early exit, so a match costs the same as a miss
+        let mut matched = None;
+        for (code_id, hash) in hashes {
+            let verified = verify_password_hash(
+                SecretBox::new(Box::new(hash)),
+                SecretBox::new(Box::new(candidate.clone())),
+            )
+            .is_ok();
+            if let Some(counter) = &counter {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            if verified && matched.is_none() {
+                matched = code_id;
+            }
+        }
+        let password_hash =
+            matched.map(|_| compute_password_hash(new_password));
+        (matched, password_hash)
+    })
+    .await
+    .map_err(anyhow::Error::from)?;
+
+    let (Some(user_id), Some(code_id), Some(password_hash)) =
+        (user_id, matched, password_hash)
+    else {
+        return Err(ApiError::InvalidRecoveryCode.into());
+    };
+    let password_hash =
+        password_hash.context("Failed to hash recovered password")?;
+    store::recover_account(
+        &user_id,
+        code_id,
+        password_hash.expose_secret(),
+        pool,
+        time_source,
+    )
+    .await?;
+    Ok(user_id)
+}
+
 #[derive(serde::Deserialize)]
 pub struct NewUserDetails {
     pub username: String,
@@ -143,29 +367,20 @@ pub struct NewUserDetails {
 )]
 pub async fn create_user(
     new_user_details: NewUserDetails,
+    email_verified: bool,
     pool: &PgPool,
     time_source: &crate::time::TimeSource,
+    id_source: &crate::id_source::IdSource,
 ) -> Result<payloads::UserId, StoreError> {
-    // Validate password before expensive hash operation
-    let password_validation = payloads::requests::validate_password(
-        new_user_details.password.expose_secret(),
-    );
-    if let Some(error_message) = password_validation.error_message() {
-        return Err(ApiError::InvalidPassword(error_message.to_string()).into());
-    }
-
-    let password_hash = spawn_blocking_with_tracing(move || {
-        compute_password_hash(new_user_details.password)
-    })
-    .await
-    .map_err(anyhow::Error::from)?
-    .context("Failed to hash password")?;
+    let password_hash = hash_new_password(new_user_details.password).await?;
     let new_user_id = store::create_user(
         pool,
         &new_user_details.username,
         &new_user_details.email,
         password_hash.expose_secret(),
+        email_verified,
         time_source,
+        id_source,
     )
     .await?
     .id;
@@ -178,6 +393,56 @@ pub async fn create_user(
     Ok(new_user_id)
 }
 
+/// Create an unverified user and their email verification token in one
+/// transaction.
+#[tracing::instrument(
+    name = "Create account",
+    skip(new_user_details, pool, time_source, id_source),
+    fields(username=tracing::field::Empty, user_id=tracing::field::Empty)
+)]
+pub async fn create_unverified_user(
+    new_user_details: NewUserDetails,
+    verification_expires_at: jiff::Timestamp,
+    pool: &PgPool,
+    time_source: &crate::time::TimeSource,
+    id_source: &crate::id_source::IdSource,
+) -> Result<(store::User, store::TokenId), StoreError> {
+    let password_hash = hash_new_password(new_user_details.password).await?;
+    let (user, token_id) = store::create_account(
+        &new_user_details.username,
+        &new_user_details.email,
+        password_hash.expose_secret(),
+        verification_expires_at,
+        pool,
+        time_source,
+        id_source,
+    )
+    .await?;
+    tracing::Span::current()
+        .record("username", tracing::field::display(&user.username))
+        .record("user_id", tracing::field::display(&user.id));
+    Ok((user, token_id))
+}
+
+/// Validate a signup password, then hash it off the async runtime.
+async fn hash_new_password(
+    password: SecretBox<String>,
+) -> Result<SecretBox<String>, StoreError> {
+    // Validate password before expensive hash operation
+    let password_validation =
+        payloads::requests::validate_password(password.expose_secret());
+    if let Some(error_message) = password_validation.error_message() {
+        return Err(ApiError::InvalidPassword(error_message.to_string()).into());
+    }
+
+    let password_hash =
+        spawn_blocking_with_tracing(move || compute_password_hash(password))
+            .await
+            .map_err(anyhow::Error::from)?
+            .context("Failed to hash password")?;
+    Ok(password_hash)
+}
+
 fn compute_password_hash(
     password: SecretBox<String>,
 ) -> Result<SecretBox<String>, anyhow::Error> {
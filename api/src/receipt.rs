@@ -0,0 +1,42 @@
+//! Signing and verification of possession receipts.
+//!
+//! A receipt is signed with HMAC-SHA256 over its compact JSON serialization,
+//! keyed by the instance's receipt signing key. Verification re-serializes
+//! the receipt it is given, so any change to a field, including the
+//! formatting of the price, fails the check.
+
+use hmac::{Hmac, Mac};
+use payloads::responses::{PossessionReceipt, SignedPossessionReceipt};
+use secrecy::{ExposeSecret, SecretBox};
+use sha2::Sha256;
+
+fn mac(receipt: &PossessionReceipt, key: &SecretBox<String>) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key.expose_secret().as_bytes())
+            .expect("HMAC accepts keys of any length");
+    let payload =
+        serde_json::to_vec(receipt).expect("receipts always serialize");
+    mac.update(&payload);
+    mac
+}
+
+/// Sign a receipt with the instance key.
+pub fn sign(
+    receipt: PossessionReceipt,
+    key: &SecretBox<String>,
+) -> SignedPossessionReceipt {
+    let signature = hex::encode(mac(&receipt, key).finalize().into_bytes());
+    SignedPossessionReceipt { receipt, signature }
+}
+
+/// Whether the signature matches the receipt under the instance key. The
+/// comparison is constant-time.
+pub fn verify(
+    signed: &SignedPossessionReceipt,
+    key: &SecretBox<String>,
+) -> bool {
+    let Ok(signature) = hex::decode(&signed.signature) else {
+        return false;
+    };
+    mac(&signed.receipt, key).verify_slice(&signature).is_ok()
+}
@@ -0,0 +1,69 @@
+//! Operator-only routes, authorized by the `ADMIN_TOKEN` bearer token rather
+//! than a user session. Disabled entirely when no token is configured.
+
+use actix_web::{HttpRequest, HttpResponse, get, http::header, post, web};
+use payloads::requests;
+use secrecy::ExposeSecret;
+use sqlx::PgPool;
+use subtle::ConstantTimeEq;
+
+use crate::{AppConfig, maintenance::MaintenanceMode, store, time::TimeSource};
+
+use super::RouteError;
+
+fn require_admin(
+    request: &HttpRequest,
+    config: &AppConfig,
+) -> Result<(), RouteError> {
+    let Some(admin_token) = &config.admin_token else {
+        return Err(RouteError::NotFound(anyhow::anyhow!(
+            "admin routes are disabled"
+        )));
+    };
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    // Compared in constant time, so response timing doesn't reveal how much
+    // of a guess matched
+    let matches = provided.is_some_and(|provided| {
+        provided
+            .as_bytes()
+            .ct_eq(admin_token.expose_secret().as_bytes())
+            .into()
+    });
+    if !matches {
+        return Err(RouteError::AuthError(anyhow::anyhow!(
+            "invalid admin token"
+        )));
+    }
+    Ok(())
+}
+
+#[post("/admin/maintenance")]
+pub async fn set_maintenance_mode(
+    request: HttpRequest,
+    details: web::Json<requests::SetMaintenanceMode>,
+    config: web::Data<AppConfig>,
+    maintenance_mode: web::Data<MaintenanceMode>,
+) -> Result<HttpResponse, RouteError> {
+    require_admin(&request, &config)?;
+    maintenance_mode.set(details.enabled);
+    tracing::warn!(enabled = details.enabled, "Maintenance mode changed");
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Counts of email verification and password reset tokens, e.g. to see how
+/// many verification emails are outstanding.
+#[get("/admin/token_stats")]
+pub async fn token_stats(
+    request: HttpRequest,
+    config: web::Data<AppConfig>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    require_admin(&request, &config)?;
+    let stats = store::get_token_stats(&pool, &time_source).await?;
+    Ok(HttpResponse::Ok().json(stats))
+}
@@ -1,10 +1,10 @@
 use actix_identity::Identity;
-use actix_web::{HttpResponse, post, web};
-use payloads::{AuctionId, AuctionRoundId, SpaceId};
+use actix_web::{HttpResponse, get, post, web};
+use payloads::{AuctionId, AuctionRoundId, SpaceId, requests};
 use sqlx::PgPool;
 
 use crate::routes::{RouteError, get_user_id};
-use crate::{store, time::TimeSource};
+use crate::{id_source::IdSource, store, time::TimeSource};
 
 #[post("/create_auction")]
 pub async fn create_auction(
@@ -12,21 +12,71 @@ pub async fn create_auction(
     details: web::Json<payloads::Auction>,
     pool: web::Data<PgPool>,
     time_source: web::Data<TimeSource>,
+    id_source: web::Data<IdSource>,
+    config: web::Data<crate::AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let auction_id =
-        store::create_auction(&details, &user_id, &pool, &time_source).await?;
+    let auction_id = store::create_auction(
+        &details,
+        &user_id,
+        &config.round_duration_bounds,
+        &pool,
+        &time_source,
+        &id_source,
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(auction_id))
 }
 
+#[post("/simulate_auction")]
+pub async fn simulate_auction(
+    user: Identity,
+    details: web::Json<requests::SimulateAuction>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let result = store::simulate_auction(
+        &details.site_id,
+        &details.auction_params,
+        &user_id,
+        &pool,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
 #[post("/auction")]
 pub async fn get_auction(
     user: Identity,
     auction_id: web::Json<payloads::AuctionId>,
+    options: web::Query<requests::ReadOptions>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let auction =
+        store::read_auction(&auction_id, &user_id, &options, &pool).await?;
+    Ok(HttpResponse::Ok().json(auction))
+}
+
+#[post("/recreate_auction")]
+pub async fn recreate_auction(
+    user: Identity,
+    details: web::Json<payloads::requests::RecreateAuction>,
     pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+    id_source: web::Data<IdSource>,
+    config: web::Data<crate::AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let auction = store::read_auction(&auction_id, &user_id, &pool).await?;
+    let auction = store::recreate_auction(
+        &details,
+        &user_id,
+        &config.round_duration_bounds,
+        &pool,
+        &time_source,
+        &id_source,
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(auction))
 }
 
@@ -65,6 +115,33 @@ pub async fn cancel_auction(
     Ok(HttpResponse::Ok().finish())
 }
 
+/// Set an auction's moderator notes and internal status (moderator+ only)
+#[post("/update_auction_notes")]
+pub async fn update_auction_notes(
+    user: Identity,
+    details: web::Json<payloads::requests::UpdateAuctionNotes>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    store::update_auction_notes(&details, &user_id, &pool, &time_source)
+        .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// An auction's report once it has concluded, or null
+#[post("/auction_report")]
+pub async fn get_auction_report(
+    user: Identity,
+    details: web::Json<payloads::requests::GetAuctionReport>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let report =
+        store::get_auction_report(&details.auction_id, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(report))
+}
+
 #[post("/auctions")]
 pub async fn list_auctions(
     user: Identity,
@@ -76,6 +153,18 @@ pub async fn list_auctions(
     Ok(HttpResponse::Ok().json(auctions))
 }
 
+#[post("/community_auctions")]
+pub async fn list_community_auctions(
+    user: Identity,
+    community_id: web::Json<payloads::CommunityId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let auctions =
+        store::list_community_auctions(&community_id, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(auctions))
+}
+
 #[post("/auction_round")]
 pub async fn get_auction_round(
     user: Identity,
@@ -94,11 +183,89 @@ pub async fn list_auction_rounds(
     pool: web::Data<PgPool>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let rounds =
-        store::list_auction_rounds(&auction_id, &user_id, &pool).await?;
+    let details = requests::ListAuctionRounds {
+        auction_id: auction_id.into_inner(),
+        from_round: None,
+        to_round: None,
+        limit: None,
+    };
+    let rounds = store::list_auction_rounds(&details, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(rounds))
+}
+
+#[post("/auction_rounds_filtered")]
+pub async fn list_auction_rounds_filtered(
+    user: Identity,
+    details: web::Json<requests::ListAuctionRounds>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let rounds = store::list_auction_rounds(&details, &user_id, &pool).await?;
     Ok(HttpResponse::Ok().json(rounds))
 }
 
+#[post("/auction_rounds_since")]
+pub async fn list_auction_rounds_since(
+    user: Identity,
+    details: web::Json<requests::ListAuctionRoundsSince>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let rounds = store::list_auction_rounds_since(
+        &details.auction_id,
+        details.since,
+        &user_id,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(rounds))
+}
+
+#[post("/round_thresholds")]
+pub async fn list_round_thresholds(
+    user: Identity,
+    auction_id: web::Json<AuctionId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let thresholds =
+        store::list_round_thresholds(&auction_id, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(thresholds))
+}
+
+#[post("/my_standing_wins")]
+pub async fn my_standing_wins(
+    user: Identity,
+    details: web::Json<requests::MyStandingWins>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let wins =
+        store::list_my_standing_wins(&details.auction_id, &user_id, &pool)
+            .await?;
+    Ok(HttpResponse::Ok().json(wins))
+}
+
+#[post("/space_current_price")]
+pub async fn get_space_current_price(
+    user: Identity,
+    space_id: web::Json<SpaceId>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let price = store::get_space_current_price(
+        &space_id,
+        &user_id,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(price))
+}
+
 #[post("/round_space_result")]
 pub async fn get_round_space_result(
     user: Identity,
@@ -126,6 +293,17 @@ pub async fn list_round_space_results_for_round(
     Ok(HttpResponse::Ok().json(rounds))
 }
 
+#[post("/round_prices")]
+pub async fn get_round_prices(
+    user: Identity,
+    round_id: web::Json<AuctionRoundId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let prices = store::get_round_prices(&round_id, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(prices))
+}
+
 #[post("/get_eligibility")]
 pub async fn get_eligibility(
     user: Identity,
@@ -150,6 +328,18 @@ pub async fn list_eligibility(
     Ok(HttpResponse::Ok().json(eligibilities))
 }
 
+#[post("/list_round_eligibility")]
+pub async fn list_round_eligibility(
+    user: Identity,
+    auction_id: web::Json<AuctionId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let eligibilities =
+        store::list_round_eligibility(&auction_id, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(eligibilities))
+}
+
 #[post("/create_bid")]
 pub async fn create_bid(
     user: Identity,
@@ -187,6 +377,72 @@ pub async fn list_bids(
     Ok(HttpResponse::Ok().json(bids))
 }
 
+#[post("/bid_history")]
+pub async fn get_bid_history(
+    user: Identity,
+    details: web::Json<requests::GetBidHistory>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let page = store::get_bid_history(&details, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(page))
+}
+
+#[post("/space_bid_log")]
+pub async fn space_bid_log(
+    user: Identity,
+    details: web::Json<requests::SpaceBidLog>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let log = store::get_space_bid_log(&details, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(log))
+}
+
+#[post("/possession_receipt")]
+pub async fn possession_receipt(
+    user: Identity,
+    details: web::Json<requests::PossessionReceipt>,
+    pool: web::Data<PgPool>,
+    config: web::Data<crate::AppConfig>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let receipt =
+        store::get_possession_receipt(&details, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok()
+        .json(crate::receipt::sign(receipt, &config.receipt_signing_key)))
+}
+
+/// Public, so whoever a receipt is shown to can check it without an account.
+#[post("/verify_receipt")]
+pub async fn verify_receipt(
+    receipt: web::Json<payloads::responses::SignedPossessionReceipt>,
+    config: web::Data<crate::AppConfig>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(crate::receipt::verify(
+        &receipt,
+        &config.receipt_signing_key,
+    ))
+}
+
+#[get("/my_possessions")]
+pub async fn my_possessions(
+    user: Identity,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+    config: web::Data<crate::AppConfig>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let possessions = store::list_user_possessions(
+        &user_id,
+        config.possession_reminder_window,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(possessions))
+}
+
 #[post("/delete_bid")]
 pub async fn delete_bid(
     user: Identity,
@@ -200,3 +456,16 @@ pub async fn delete_bid(
         .await?;
     Ok(HttpResponse::Ok().finish())
 }
+
+#[post("/clear_my_bids")]
+pub async fn clear_my_bids(
+    user: Identity,
+    round_id: web::Json<AuctionRoundId>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let withdrawn =
+        store::clear_my_bids(&round_id, &user_id, &pool, &time_source).await?;
+    Ok(HttpResponse::Ok().json(withdrawn))
+}
@@ -15,11 +15,20 @@ pub async fn create_community(
     user: Identity,
     details: web::Json<CreateCommunity>,
     pool: web::Data<PgPool>,
+    config: web::Data<crate::AppConfig>,
     time_source: web::Data<crate::time::TimeSource>,
+    id_source: web::Data<crate::id_source::IdSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let community =
-        store::create_community(&details, user_id, &pool, &time_source).await?;
+    let community = store::create_community(
+        &details,
+        user_id,
+        config.max_communities_per_leader,
+        &pool,
+        &time_source,
+        &id_source,
+    )
+    .await?;
     // return the community id so we can start using for other things
     Ok(HttpResponse::Ok().json(community.id))
 }
@@ -37,6 +46,38 @@ pub async fn get_communities(
     Ok(HttpResponse::Ok().json(communities))
 }
 
+/// The caller's role in each of their communities, for views that only
+/// need to know what the user may do, such as the global nav.
+#[get("/my_roles")]
+pub async fn list_my_roles(
+    user: Identity,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let roles = store::list_my_roles(&user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(roles))
+}
+
+/// The community, the caller's role, and what the community detail page
+/// summarizes, in one request.
+#[post("/community_overview")]
+pub async fn get_community_overview(
+    user: Identity,
+    community_id: web::Json<CommunityId>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let overview = store::get_community_overview(
+        &community_id,
+        &user_id,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(overview))
+}
+
 #[post("/invite_member")]
 pub async fn invite_community_member(
     user: Identity,
@@ -53,6 +94,8 @@ pub async fn invite_community_member(
         &validated_member,
         &details.0.new_member_email,
         details.0.single_use,
+        details.0.role,
+        config.max_pending_invites,
         &pool,
         &time_source,
     )
@@ -69,7 +112,10 @@ pub async fn invite_community_member(
                 email,
                 &community.name,
                 &invite_id.to_string(),
-                &config.base_url,
+                community
+                    .invite_base_url
+                    .as_deref()
+                    .unwrap_or(&config.base_url),
             )
             .await
         {
@@ -86,23 +132,56 @@ pub async fn invite_community_member(
 pub async fn get_received_invites(
     user: Identity,
     pool: web::Data<PgPool>,
+    config: web::Data<crate::AppConfig>,
+    time_source: web::Data<crate::time::TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let invites = store::get_received_invites(&user_id, &pool).await?;
+    let invites = store::get_received_invites(
+        &user_id,
+        config.email_verification_grace,
+        &pool,
+        &time_source,
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(invites))
 }
 
+/// Count the invites the user has received
+#[get("/received_invites/count")]
+pub async fn count_received_invites(
+    user: Identity,
+    pool: web::Data<PgPool>,
+    config: web::Data<crate::AppConfig>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let count = store::count_received_invites(
+        &user_id,
+        config.email_verification_grace,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(count))
+}
+
 /// Get the invites that have been issued for a community (moderator+ only)
 #[post("/issued_invites")]
 pub async fn get_issued_invites(
     user: Identity,
     community_id: web::Json<CommunityId>,
     pool: web::Data<PgPool>,
+    config: web::Data<crate::AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
     let validated_member =
         get_validated_member(&user_id, &community_id, &pool).await?;
-    let invites = store::get_issued_invites(&validated_member, &pool).await?;
+    let invites = store::get_issued_invites(
+        &validated_member,
+        config.max_pending_invites,
+        &pool,
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(invites))
 }
 
@@ -129,6 +208,15 @@ pub async fn get_invite_community_name(
     Ok(HttpResponse::Ok().json(community_name))
 }
 
+#[get("/community_preview/{invite_id}")]
+pub async fn get_community_preview(
+    path: web::Path<payloads::InviteId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let preview = store::get_community_preview(&path, &pool).await?;
+    Ok(HttpResponse::Ok().json(preview))
+}
+
 #[post("/accept_invite/{invite_id}")]
 pub async fn accept_invite(
     user: Identity,
@@ -144,16 +232,50 @@ pub async fn accept_invite(
 #[post("/members")]
 pub async fn get_members(
     user: Identity,
-    community_id: web::Json<CommunityId>,
+    details: web::Json<GetMembersBody>,
     pool: web::Data<PgPool>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
+    let details = match details.into_inner() {
+        GetMembersBody::CommunityId(community_id) => requests::GetMembers {
+            community_id,
+            active_only: false,
+        },
+        GetMembersBody::Details(details) => details,
+    };
     let validated_member =
-        get_validated_member(&user_id, &community_id, &pool).await?;
-    let members = store::get_members(&validated_member, &pool).await?;
+        get_validated_member(&user_id, &details.community_id, &pool).await?;
+    let members =
+        store::get_members(&validated_member, details.active_only, &pool)
+            .await?;
     Ok(HttpResponse::Ok().json(members))
 }
 
+/// The members endpoint predates [`requests::GetMembers`], so it also takes
+/// the bare community id older clients send.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+pub enum GetMembersBody {
+    CommunityId(CommunityId),
+    Details(requests::GetMembers),
+}
+
+/// Profile of a fellow member of the given community
+#[post("/member_profile")]
+pub async fn get_member_profile(
+    user: Identity,
+    details: web::Json<requests::GetMemberProfile>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &details.community_id, &pool).await?;
+    let profile =
+        store::get_member_profile(&validated_member, &details.username, &pool)
+            .await?;
+    Ok(HttpResponse::Ok().json(profile))
+}
+
 /// Set the community schedule all at once.
 #[post("/membership_schedule")]
 pub async fn set_membership_schedule(
@@ -189,6 +311,20 @@ pub async fn get_membership_schedule(
     Ok(HttpResponse::Ok().json(schedule))
 }
 
+/// Aggregate stats about the community (coleader+ only)
+#[post("/get_community_stats")]
+pub async fn get_community_stats(
+    user: Identity,
+    community_id: web::Json<CommunityId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &community_id, &pool).await?;
+    let stats = store::get_community_stats(&validated_member, &pool).await?;
+    Ok(HttpResponse::Ok().json(stats))
+}
+
 /// Update a member's active status (moderator+ only)
 #[post("/update_member_active_status")]
 pub async fn update_member_active_status(
@@ -347,12 +483,117 @@ pub async fn update_community_details(
     user: Identity,
     details: web::Json<requests::UpdateCommunityDetails>,
     pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
     let validated_member =
         get_validated_member(&user_id, &details.community_id, &pool).await?;
-    let community =
-        store::update_community_details(&validated_member, &details, &pool)
-            .await?;
+    let community = store::update_community_details(
+        &validated_member,
+        &details,
+        &pool,
+        &time_source,
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(community))
 }
+
+/// Open or close the community to new members (leader only)
+#[post("/set_registration_open")]
+pub async fn set_registration_open(
+    user: Identity,
+    details: web::Json<requests::SetRegistrationOpen>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &details.community_id, &pool).await?;
+    store::set_registration_open(
+        &validated_member,
+        details.registration_open,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Set or clear the base URL of the community's invite links (leader only)
+#[post("/set_invite_base_url")]
+pub async fn set_invite_base_url(
+    user: Identity,
+    details: web::Json<requests::SetInviteBaseUrl>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &details.community_id, &pool).await?;
+    store::set_invite_base_url(
+        &validated_member,
+        details.invite_base_url.as_deref(),
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Set the community's round duration bounds (leader only)
+#[post("/set_round_duration_bounds")]
+pub async fn set_round_duration_bounds(
+    user: Identity,
+    details: web::Json<requests::SetRoundDurationBounds>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &details.community_id, &pool).await?;
+    store::set_round_duration_bounds(
+        &validated_member,
+        &details.bounds,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Set or clear the community's default auction params (coleader+ only)
+#[post("/set_community_default_params")]
+pub async fn set_community_default_params(
+    user: Identity,
+    details: web::Json<requests::SetCommunityDefaultParams>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+    config: web::Data<crate::AppConfig>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &details.community_id, &pool).await?;
+    store::set_community_default_params(
+        &validated_member,
+        details.params.as_ref(),
+        &config.round_duration_bounds,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[post("/get_community_default_params")]
+pub async fn get_community_default_params(
+    user: Identity,
+    community_id: web::Json<CommunityId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let validated_member =
+        get_validated_member(&user_id, &community_id, &pool).await?;
+    let params =
+        store::get_community_default_params(&validated_member, &pool).await?;
+    Ok(HttpResponse::Ok().json(params))
+}
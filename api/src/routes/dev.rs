@@ -0,0 +1,44 @@
+//! Development-only routes for working on the server without external
+//! services. They answer 404 unless `EMAIL_PREVIEW` is set, and always in
+//! release builds.
+
+use actix_web::{HttpResponse, get, web};
+
+use crate::{AppConfig, email::EmailKind};
+
+use super::RouteError;
+
+fn require_email_preview(config: &AppConfig) -> Result<(), RouteError> {
+    if !config.email_preview {
+        return Err(RouteError::NotFound(anyhow::anyhow!(
+            "email previews are disabled"
+        )));
+    }
+    Ok(())
+}
+
+/// Names of the email kinds that can be previewed.
+#[get("/dev/email_preview")]
+pub async fn email_preview_kinds(
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, RouteError> {
+    require_email_preview(&config)?;
+    let kinds: Vec<&str> = EmailKind::ALL.iter().map(|k| k.name()).collect();
+    Ok(HttpResponse::Ok().json(kinds))
+}
+
+/// The HTML body of an email kind, rendered with sample data.
+#[get("/dev/email_preview/{kind}")]
+pub async fn email_preview(
+    path: web::Path<String>,
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, RouteError> {
+    require_email_preview(&config)?;
+    let kind = EmailKind::from_name(&path).ok_or_else(|| {
+        RouteError::NotFound(anyhow::anyhow!("no email kind {path}"))
+    })?;
+    let template = kind.sample(&config.base_url);
+    Ok(HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(template.html_body))
+}
@@ -1,14 +1,18 @@
 use actix_identity::Identity;
+use actix_session::Session;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, get, post, web};
 use jiff::Span;
 use secrecy::SecretBox;
 use sqlx::PgPool;
 
 use crate::AppConfig;
+use crate::id_source::IdSource;
+use crate::impersonation::Impersonation;
 use crate::password::{
-    AuthError, Credentials, NewUserDetails, change_password, create_user,
-    validate_credentials,
+    AuthError, Credentials, NewUserDetails, RecoveryRateLimit, change_password,
+    create_unverified_user, create_user, validate_credentials,
 };
+use crate::session_generation::SessionGeneration;
 use crate::store::{self, TokenAction, TokenId};
 use crate::time::TimeSource;
 
@@ -17,6 +21,7 @@ use super::{RouteError, get_user_id};
 #[post("/login")]
 pub async fn login(
     request: HttpRequest,
+    session: Session,
     credentials: web::Json<Credentials>,
     pool: web::Data<PgPool>,
 ) -> Result<HttpResponse, RouteError> {
@@ -26,12 +31,16 @@ pub async fn login(
         Ok(user_id) => {
             tracing::Span::current()
                 .record("user_id", tracing::field::display(&user_id));
-            Identity::login(&request.extensions(), user_id.to_string())
-                .map_err(|e| RouteError::UnexpectedError(e.into()))?;
+            // A fresh login replaces any impersonation in this session
+            Impersonation::remove(&session);
+            log_in(&request, &session, &user_id, &pool).await?;
             Ok(HttpResponse::Ok().finish())
         }
         Err(e) => {
             let e = match e {
+                // Unknown username and wrong password share this variant, and
+                // so an identical response body; only the logged source
+                // differs.
                 AuthError::InvalidCredentials(_) => {
                     RouteError::AuthError(e.into())
                 }
@@ -44,6 +53,24 @@ pub async fn login(
     }
 }
 
+/// Log the session in as `user_id`, recording the user's current session
+/// generation so that a later password recovery ends it.
+async fn log_in(
+    request: &HttpRequest,
+    session: &Session,
+    user_id: &payloads::UserId,
+    pool: &PgPool,
+) -> Result<(), RouteError> {
+    let generation = store::get_session_generation(user_id, pool)
+        .await?
+        .ok_or(RouteError::Api(payloads::ApiError::UserNotFound))?;
+    Identity::login(&request.extensions(), user_id.to_string())
+        .map_err(|e| RouteError::UnexpectedError(e.into()))?;
+    SessionGeneration::insert(generation, session)
+        .map_err(RouteError::UnexpectedError)?;
+    Ok(())
+}
+
 #[post("/login_check")]
 pub async fn login_check(user: Identity) -> Result<HttpResponse, RouteError> {
     get_user_id(&user)?;
@@ -51,12 +78,80 @@ pub async fn login_check(user: Identity) -> Result<HttpResponse, RouteError> {
 }
 
 #[post("/logout")]
-pub async fn logout(user: Identity) -> Result<HttpResponse, RouteError> {
-    let _ = get_user_id(&user); // to instrument the user_id, if exists
+pub async fn logout(
+    user: Identity,
+    session: Session,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user); // to instrument the user_id, if exists
+    if let (Ok(user_id), Some(impersonation)) =
+        (user_id, Impersonation::get(&session))
+    {
+        store::stop_impersonation(
+            &impersonation.impersonator_id,
+            &user_id,
+            &pool,
+            &time_source,
+        )
+        .await?;
+    }
     user.logout();
     Ok(HttpResponse::Ok().finish())
 }
 
+/// Log in as another user for support, until stopped, logged out, or
+/// `IMPERSONATION_DURATION` passes. Superuser-only, and audited.
+#[post("/impersonate")]
+pub async fn impersonate(
+    request: HttpRequest,
+    user: Identity,
+    session: Session,
+    details: web::Json<payloads::requests::Impersonate>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let target_id = details.target_user_id;
+    let expires_at =
+        store::start_impersonation(&user_id, &target_id, &pool, &time_source)
+            .await?;
+    tracing::warn!(%target_id, "Impersonation started");
+    log_in(&request, &session, &target_id, &pool).await?;
+    Impersonation {
+        impersonator_id: user_id,
+        expires_at,
+    }
+    .insert(&session)
+    .map_err(RouteError::UnexpectedError)?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// End an impersonation and return the session to the superuser.
+#[post("/stop_impersonating")]
+pub async fn stop_impersonating(
+    request: HttpRequest,
+    user: Identity,
+    session: Session,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let Some(impersonation) = Impersonation::get(&session) else {
+        return Err(RouteError::Api(payloads::ApiError::NotImpersonating));
+    };
+    store::stop_impersonation(
+        &impersonation.impersonator_id,
+        &user_id,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Impersonation::remove(&session);
+    log_in(&request, &session, &impersonation.impersonator_id, &pool).await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
 #[post("/create_account")]
 pub async fn create_account(
     _request: HttpRequest,
@@ -64,21 +159,24 @@ pub async fn create_account(
     pool: web::Data<PgPool>,
     email_service: web::Data<crate::email::EmailService>,
     time_source: web::Data<TimeSource>,
+    id_source: web::Data<IdSource>,
     config: web::Data<AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
-    let user_id = create_user(new_user_details.0, &pool, &time_source).await?;
-
-    // Read the user back to get the full User struct
-    let user = store::read_user(&pool, &user_id).await?;
+    // Without verification, the account is usable right away.
+    if !config.require_email_verification {
+        create_user(new_user_details.0, true, &pool, &time_source, &id_source)
+            .await?;
+        return Ok(HttpResponse::Ok().finish());
+    }
 
-    // Create email verification token
-    let expires_at = time_source.now() + Span::new().hours(24);
-    let token_id = store::create_token(
-        &user.id,
-        TokenAction::EmailVerification,
+    // The user and their verification token are created together
+    let expires_at = time_source.expires_in(Span::new().hours(24));
+    let (user, token_id) = create_unverified_user(
+        new_user_details.0,
         expires_at,
         &pool,
         &time_source,
+        &id_source,
     )
     .await?;
 
@@ -99,12 +197,25 @@ pub async fn create_account(
     Ok(HttpResponse::Ok().finish())
 }
 
+/// Response for the verification routes on instances that don't require it.
+fn verification_not_required() -> HttpResponse {
+    HttpResponse::Ok().json(payloads::responses::SuccessMessage {
+        message: "Email verification is not required on this instance."
+            .to_string(),
+    })
+}
+
 #[post("/verify_email")]
 pub async fn verify_email(
     request: web::Json<payloads::requests::VerifyEmail>,
     pool: web::Data<PgPool>,
     time_source: web::Data<TimeSource>,
+    config: web::Data<AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
+    if !config.require_email_verification {
+        return Ok(verification_not_required());
+    }
+
     // Parse token
     let token_uuid = request
         .token
@@ -137,6 +248,7 @@ pub async fn forgot_password(
     pool: web::Data<PgPool>,
     email_service: web::Data<crate::email::EmailService>,
     time_source: web::Data<TimeSource>,
+    id_source: web::Data<IdSource>,
     config: web::Data<AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     // Always return success to prevent email enumeration
@@ -151,7 +263,7 @@ pub async fn forgot_password(
     let user = store::get_user_by_email(&request.email, &pool).await.ok();
 
     // Always create a token (even if we won't use it)
-    let expires_at = time_source.now() + Span::new().hours(1);
+    let expires_at = time_source.expires_in(Span::new().hours(1));
 
     // Create token using a dummy user ID if user doesn't exist
     let dummy_user_id = payloads::UserId(uuid::Uuid::new_v4());
@@ -163,6 +275,7 @@ pub async fn forgot_password(
         expires_at,
         &pool,
         &time_source,
+        &id_source,
     )
     .await;
 
@@ -206,11 +319,21 @@ pub async fn resend_verification_email(
     pool: web::Data<PgPool>,
     email_service: web::Data<crate::email::EmailService>,
     time_source: web::Data<TimeSource>,
+    id_source: web::Data<IdSource>,
     config: web::Data<AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&identity)?;
     let user = store::read_user(&pool, &user_id).await?;
 
+    // Accounts created before verification was turned off are verified on
+    // request instead.
+    if !config.require_email_verification {
+        if !user.email_verified {
+            store::verify_user_email(&user_id, &pool, &time_source).await?;
+        }
+        return Ok(verification_not_required());
+    }
+
     // If already verified, just return success
     if user.email_verified {
         return Ok(HttpResponse::Ok().json(
@@ -221,13 +344,14 @@ pub async fn resend_verification_email(
     }
 
     // Create new verification token
-    let expires_at = time_source.now() + Span::new().hours(24);
+    let expires_at = time_source.expires_in(Span::new().hours(24));
     let token_id = store::create_token(
         &user_id,
         TokenAction::EmailVerification,
         expires_at,
         &pool,
         &time_source,
+        &id_source,
     )
     .await?;
 
@@ -287,12 +411,27 @@ pub async fn reset_password(
         &mut request.password,
         SecretBox::new(Box::new(String::new())),
     );
-    change_password(user_id, password, &pool)
+    change_password(user_id, password, &pool, &time_source)
         .await
         .map_err(RouteError::UnexpectedError)?;
 
     tracing::info!("Password changed successfully for user {}", user_id);
 
+    // Queued rather than sent inline, so a slow or failing email provider
+    // doesn't hold up or fail the reset
+    let user = store::read_user(&pool, &user_id).await?;
+    if let Err(e) = store::outbox::enqueue_email(
+        &user.email,
+        "password_changed",
+        &crate::email::password_changed_email(&user.username),
+        &**pool,
+        &time_source,
+    )
+    .await
+    {
+        tracing::error!("Failed to queue password changed email: {:?}", e);
+    }
+
     let response = payloads::responses::SuccessMessage {
         message: "Password has been reset successfully.".to_string(),
     };
@@ -300,44 +439,172 @@ pub async fn reset_password(
     Ok(HttpResponse::Ok().json(response))
 }
 
+#[derive(serde::Deserialize)]
+pub struct GenerateRecoveryCodesRequest {
+    password: SecretBox<String>,
+}
+
+/// Replace the current user's recovery codes with a new set, returned only in
+/// this response. The current password is required, since the codes can reset
+/// it.
+#[post("/generate_recovery_codes")]
+pub async fn generate_recovery_codes(
+    user: Identity,
+    session: Session,
+    request: web::Json<GenerateRecoveryCodesRequest>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    Impersonation::refuse(&session)?;
+    let username = store::read_user(&pool, &user_id).await?.username;
+    let credentials = Credentials::new(username, request.into_inner().password);
+    match validate_credentials(credentials, &pool).await {
+        Ok(validated_id) if validated_id == user_id => {}
+        Ok(_) | Err(AuthError::InvalidCredentials(_)) => {
+            return Err(RouteError::Api(payloads::ApiError::IncorrectPassword));
+        }
+        Err(AuthError::UnexpectedError(e)) => {
+            return Err(RouteError::UnexpectedError(e));
+        }
+    }
+    let codes =
+        crate::password::generate_recovery_codes(&user_id, &pool, &time_source)
+            .await?;
+    Ok(HttpResponse::Ok().json(payloads::responses::RecoveryCodes { codes }))
+}
+
+#[derive(serde::Deserialize)]
+pub struct RecoverAccountRequest {
+    pub username: String,
+    recovery_code: SecretBox<String>,
+    new_password: SecretBox<String>,
+}
+
+/// Set a new password with a recovery code, without email. Every session of
+/// the account ends, including this one if it was logged in.
+#[post("/recover_account")]
+pub async fn recover_account(
+    request: web::Json<RecoverAccountRequest>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+    rate_limit: web::Data<RecoveryRateLimit>,
+) -> Result<HttpResponse, RouteError> {
+    // Before any hashing, and whether or not the username exists
+    if !rate_limit.try_claim(time_source.now()) {
+        return Err(RouteError::Api(
+            payloads::ApiError::AccountRecoveryRateLimited,
+        ));
+    }
+    let request = request.into_inner();
+    let user_id = crate::password::recover_account(
+        &request.username,
+        request.recovery_code,
+        request.new_password,
+        &pool,
+        &time_source,
+    )
+    .await?;
+
+    tracing::info!("Account recovered for user {}", user_id);
+
+    let user = store::read_user(&pool, &user_id).await?;
+    if let Err(e) = store::outbox::enqueue_email(
+        &user.email,
+        "password_changed",
+        &crate::email::password_changed_email(&user.username),
+        &**pool,
+        &time_source,
+    )
+    .await
+    {
+        tracing::error!("Failed to queue password changed email: {:?}", e);
+    }
+
+    Ok(
+        HttpResponse::Ok().json(payloads::responses::SuccessMessage {
+            message: "Password has been reset successfully.".to_string(),
+        }),
+    )
+}
+
 #[get("/user_profile")]
 pub async fn user_profile(
     user: Identity,
+    session: Session,
     pool: web::Data<PgPool>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
     let user_data = store::read_user(&pool, &user_id).await?;
+    let profile = profile_for_session(user_data, &session, &pool).await?;
+    Ok(HttpResponse::Ok().json(profile))
+}
 
-    let profile = payloads::responses::UserProfile {
-        user_id,
-        username: user_data.username,
-        email: user_data.email,
-        display_name: user_data.display_name,
-        email_verified: user_data.email_verified,
-    };
+/// Everything the UI shell needs on page load: the profile, the user's
+/// communities, and how many invites are waiting for them.
+#[get("/bootstrap")]
+pub async fn bootstrap(
+    user: Identity,
+    session: Session,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let user_data = store::read_user(&pool, &user_id).await?;
+    // Invites are only shown once the email they match against is verified
+    let grace = config.email_verification_grace;
+    let pending_invites =
+        if store::require_verified_email(&user_data, grace, &time_source)
+            .is_ok()
+        {
+            store::count_received_invites(&user_id, grace, &pool, &time_source)
+                .await?
+        } else {
+            0
+        };
+    let profile = profile_for_session(user_data, &session, &pool).await?;
+    let communities = store::get_communities(&user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(payloads::responses::Bootstrap {
+        profile,
+        communities,
+        pending_invites: u32::try_from(pending_invites).unwrap_or(u32::MAX),
+    }))
+}
 
-    Ok(HttpResponse::Ok().json(profile))
+/// The user's profile, noting who is behind the session if it's an
+/// impersonation.
+async fn profile_for_session(
+    user_data: store::User,
+    session: &Session,
+    pool: &PgPool,
+) -> Result<payloads::responses::UserProfile, RouteError> {
+    let remaining = store::count_recovery_codes(&user_data.id, pool).await?;
+    let mut profile = payloads::responses::UserProfile::from(user_data);
+    profile.recovery_codes_remaining =
+        u32::try_from(remaining).unwrap_or(u32::MAX);
+    if let Some(impersonation) = Impersonation::get(session) {
+        let impersonator =
+            store::read_user(pool, &impersonation.impersonator_id).await?;
+        profile.impersonated_by = Some(payloads::responses::Impersonator {
+            user_id: impersonator.id,
+            username: impersonator.username,
+            expires_at: impersonation.expires_at,
+        });
+    }
+    Ok(profile)
 }
 
 #[post("/update_profile")]
 pub async fn update_profile(
     user: Identity,
+    session: Session,
     request: web::Json<payloads::requests::UpdateProfile>,
     pool: web::Data<PgPool>,
     time_source: web::Data<TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-
-    // Validate display_name length if provided
-    if let Some(ref display_name) = request.display_name
-        && display_name.len() > payloads::requests::DISPLAY_NAME_MAX_LEN
-    {
-        return Err(RouteError::BadRequest(anyhow::anyhow!(
-            "Display name must not exceed {} characters",
-            payloads::requests::DISPLAY_NAME_MAX_LEN
-        )));
-    }
-
+    Impersonation::refuse(&session)?;
     let updated_user = store::update_user_profile(
         &user_id,
         &request.display_name,
@@ -345,25 +612,83 @@ pub async fn update_profile(
         &time_source,
     )
     .await?;
+    let profile = profile_for_session(updated_user, &session, &pool).await?;
+    Ok(HttpResponse::Ok().json(profile))
+}
 
-    let profile = payloads::responses::UserProfile {
-        user_id,
-        username: updated_user.username,
-        email: updated_user.email,
-        display_name: updated_user.display_name,
-        email_verified: updated_user.email_verified,
-    };
+#[post("/update_notification_preferences")]
+pub async fn update_notification_preferences(
+    user: Identity,
+    session: Session,
+    request: web::Json<payloads::requests::UpdateNotificationPreferences>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    Impersonation::refuse(&session)?;
+    let updated_user = store::update_notification_preferences(
+        &user_id,
+        request.email_possession_reminders,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    let profile = profile_for_session(updated_user, &session, &pool).await?;
+    Ok(HttpResponse::Ok().json(profile))
+}
 
+#[post("/update_locale")]
+pub async fn update_locale(
+    user: Identity,
+    session: Session,
+    request: web::Json<payloads::requests::UpdateLocale>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let updated_user =
+        store::update_locale(&user_id, request.locale, &pool, &time_source)
+            .await?;
+    let profile = profile_for_session(updated_user, &session, &pool).await?;
     Ok(HttpResponse::Ok().json(profile))
 }
 
+/// Download everything stored about the current user as a JSON attachment.
+#[post("/export_my_data")]
+pub async fn export_my_data(
+    user: Identity,
+    session: Session,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<TimeSource>,
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    Impersonation::refuse(&session)?;
+    let export = store::export::export_user_data(
+        &user_id,
+        config.possession_reminder_window,
+        &pool,
+        &time_source,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header((
+            "content-disposition",
+            "attachment; filename=\"tinylvt-export.json\"",
+        ))
+        .json(export))
+}
+
 #[post("/delete_user")]
 pub async fn delete_user(
     user: Identity,
+    session: Session,
     pool: web::Data<PgPool>,
     time_source: web::Data<TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
+    Impersonation::refuse(&session)?;
     store::delete_user(&pool, &user_id, &time_source).await?;
     user.logout();
     Ok(HttpResponse::Ok().finish())
@@ -1,7 +1,9 @@
+pub mod admin;
 pub mod auction;
 pub mod billing;
 pub mod community;
 pub mod currency;
+pub mod dev;
 pub mod login;
 pub mod proxy_bidding;
 pub mod site;
@@ -9,41 +11,88 @@ pub mod sse;
 
 use actix_identity::Identity;
 use actix_web::{
-    HttpResponse, Responder, ResponseError, body::BoxBody,
-    dev::HttpServiceFactory, get, http::StatusCode, web,
+    HttpRequest, HttpResponse, Responder, ResponseError,
+    body::BoxBody,
+    dev::HttpServiceFactory,
+    error::JsonPayloadError,
+    get,
+    http::{Method, StatusCode, header},
+    route, web,
 };
-use payloads::ApiError;
+use payloads::{ApiError, Locale};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::messages;
 use crate::store::{self, StoreError};
 
-pub fn api_services() -> impl HttpServiceFactory {
+/// JSON body config for API routes. A body that isn't JSON, or doesn't match
+/// the route's request type, is rejected with a typed [`ApiError`] like any
+/// other client error. Oversized bodies keep actix's 413.
+pub fn json_config(limit: usize) -> web::JsonConfig {
+    web::JsonConfig::default()
+        .limit(limit)
+        .error_handler(|err, _req| {
+            let api_error = match &err {
+                JsonPayloadError::ContentType => {
+                    ApiError::UnsupportedContentType
+                }
+                JsonPayloadError::Deserialize(e) => {
+                    ApiError::InvalidRequestBody(e.to_string())
+                }
+                _ => return err.into(),
+            };
+            RouteError::Api(api_error).into()
+        })
+}
+
+/// All API routes. `image_json_config` replaces the app-wide JSON body limit
+/// on routes that upload images, which are sent as JSON byte arrays.
+pub fn api_services(
+    image_json_config: web::JsonConfig,
+) -> impl HttpServiceFactory {
     web::scope("/api")
         .service(health_check)
+        .service(ready)
+        .service(admin::set_maintenance_mode)
+        .service(admin::token_stats)
         .service(platform_stats)
         .service(login::login)
         .service(login::login_check)
         .service(login::user_profile)
+        .service(login::bootstrap)
         .service(login::update_profile)
+        .service(login::update_notification_preferences)
+        .service(login::update_locale)
+        .service(login::export_my_data)
         .service(login::delete_user)
         .service(login::logout)
+        .service(login::impersonate)
+        .service(login::stop_impersonating)
         .service(login::create_account)
         .service(login::verify_email)
         .service(login::forgot_password)
         .service(login::reset_password)
+        .service(login::generate_recovery_codes)
+        .service(login::recover_account)
         .service(login::resend_verification_email)
         .service(community::create_community)
         .service(community::get_communities)
+        .service(community::list_my_roles)
+        .service(community::get_community_overview)
         .service(community::invite_community_member)
         .service(community::get_received_invites)
+        .service(community::count_received_invites)
         .service(community::get_issued_invites)
         .service(community::delete_invite)
         .service(community::get_invite_community_name)
+        .service(community::get_community_preview)
         .service(community::accept_invite)
         .service(community::get_members)
+        .service(community::get_member_profile)
         .service(community::set_membership_schedule)
         .service(community::get_membership_schedule)
+        .service(community::get_community_stats)
         .service(community::update_member_active_status)
         .service(community::bulk_activate_members)
         .service(community::remove_member)
@@ -53,44 +102,80 @@ pub fn api_services() -> impl HttpServiceFactory {
         .service(currency::resolve_orphaned_balance)
         .service(community::delete_community)
         .service(community::update_community_details)
+        .service(community::set_registration_open)
+        .service(community::set_invite_base_url)
+        .service(community::set_community_default_params)
+        .service(community::get_community_default_params)
+        .service(community::set_round_duration_bounds)
         .service(site::create_site)
         .service(site::get_site)
+        .service(site::resolve_open_hours)
         .service(site::update_site)
         .service(site::delete_site)
         .service(site::soft_delete_site)
         .service(site::restore_site)
         .service(site::list_sites)
-        .service(site::create_site_image)
+        .service(
+            web::resource("/create_site_image")
+                .app_data(image_json_config.clone())
+                .route(web::post().to(site::create_site_image)),
+        )
         .service(site::get_site_image)
         .service(site::get_site_image_bytes)
-        .service(site::update_site_image)
+        .service(
+            web::resource("/update_site_image")
+                .app_data(image_json_config)
+                .route(web::post().to(site::update_site_image)),
+        )
         .service(site::delete_site_image)
         .service(site::list_site_images)
         .service(site::create_space)
         .service(site::get_space)
+        .service(site::get_spaces)
         .service(site::update_space)
         .service(site::update_spaces)
+        .service(site::reorder_spaces)
         .service(site::delete_space)
         .service(site::soft_delete_space)
         .service(site::restore_space)
         .service(site::list_spaces)
+        .service(site::list_spaces_with_values)
         .service(auction::create_auction)
+        .service(auction::recreate_auction)
+        .service(auction::simulate_auction)
         .service(auction::get_auction)
         .service(auction::delete_auction)
         .service(auction::schedule_auction)
         .service(auction::cancel_auction)
+        .service(auction::update_auction_notes)
+        .service(auction::get_auction_report)
         .service(auction::list_auctions)
+        .service(auction::list_community_auctions)
         .service(auction::get_auction_round)
         .service(auction::list_auction_rounds)
+        .service(auction::list_auction_rounds_filtered)
+        .service(auction::list_auction_rounds_since)
+        .service(auction::list_round_thresholds)
+        .service(auction::my_standing_wins)
+        .service(auction::get_space_current_price)
         .service(auction::get_round_space_result)
         .service(auction::list_round_space_results_for_round)
+        .service(auction::get_round_prices)
         .service(auction::get_eligibility)
         .service(auction::list_eligibility)
+        .service(auction::list_round_eligibility)
         .service(auction::create_bid)
         .service(auction::get_bid)
         .service(auction::list_bids)
+        .service(auction::get_bid_history)
+        .service(auction::space_bid_log)
+        .service(auction::my_possessions)
+        .service(auction::possession_receipt)
+        .service(auction::verify_receipt)
         .service(auction::delete_bid)
+        .service(auction::clear_my_bids)
         .service(proxy_bidding::create_or_update_user_value)
+        .service(proxy_bidding::set_user_value_and_rebid)
         .service(proxy_bidding::get_user_value)
         .service(proxy_bidding::delete_user_value)
         .service(proxy_bidding::list_user_values)
@@ -114,11 +199,48 @@ pub fn api_services() -> impl HttpServiceFactory {
         .service(billing::create_portal_session)
         .service(billing::stripe_webhook)
         .service(sse::sse_auction)
+        .service(dev::email_preview_kinds)
+        .service(dev::email_preview)
+}
+
+/// Uptime monitors probe with HEAD, so the health endpoints accept it too and
+/// answer with the same status and an empty body.
+#[route("/health_check", method = "GET", method = "HEAD")]
+pub async fn health_check(req: HttpRequest) -> impl Responder {
+    probe_response(&req, StatusCode::OK, "healthy")
 }
 
-#[get("/health_check")]
-pub async fn health_check() -> impl Responder {
-    HttpResponse::Ok().body("healthy")
+/// Readiness, as opposed to the liveness that `health_check` reports:
+/// whether this instance can serve traffic, with the database reachable and
+/// fully migrated.
+#[route("/ready", method = "GET", method = "HEAD")]
+pub async fn ready(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+) -> impl Responder {
+    match store::latest_migration_applied(&pool).await {
+        Ok(true) => probe_response(&req, StatusCode::OK, "ready"),
+        Ok(false) => {
+            tracing::warn!("not ready: latest migration not applied");
+            probe_response(&req, StatusCode::SERVICE_UNAVAILABLE, "not ready")
+        }
+        Err(e) => {
+            tracing::warn!("not ready: {e:?}");
+            probe_response(&req, StatusCode::SERVICE_UNAVAILABLE, "not ready")
+        }
+    }
+}
+
+fn probe_response(
+    req: &HttpRequest,
+    status: StatusCode,
+    body: &'static str,
+) -> HttpResponse {
+    if req.method() == Method::HEAD {
+        HttpResponse::build(status).finish()
+    } else {
+        HttpResponse::build(status).body(body)
+    }
 }
 
 #[get("/platform_stats")]
@@ -148,11 +270,22 @@ pub enum RouteError {
 }
 
 /// Status code for a typed API error. `MemberNotFound` is an auth failure
-/// since membership is what authorizes access to community resources.
-/// Not-found variants map to 404; everything else is a client error.
+/// since membership is what authorizes access to community resources, and a
+/// wrong recovery code or password is a failed login.
+/// Not-found variants map to 404; `MaintenanceMode` to 503; rate limits to
+/// 429; actions the session may never take (as a non-holder, or under
+/// impersonation) to 403; conflicts needing a retry (a duplicate bid, an
+/// unconfirmed site deletion) to 409; everything else is a client error.
 fn api_error_status(e: &ApiError) -> StatusCode {
     match e {
-        ApiError::MemberNotFound => StatusCode::UNAUTHORIZED,
+        ApiError::MemberNotFound
+        | ApiError::InvalidRecoveryCode
+        | ApiError::IncorrectPassword => StatusCode::UNAUTHORIZED,
+        ApiError::MaintenanceMode => StatusCode::SERVICE_UNAVAILABLE,
+        ApiError::DataExportRateLimited
+        | ApiError::AccountRecoveryRateLimited => StatusCode::TOO_MANY_REQUESTS,
+        ApiError::NotPossessionHolder
+        | ApiError::NotAllowedWhileImpersonating => StatusCode::FORBIDDEN,
         ApiError::TokenNotFound
         | ApiError::UserNotFound
         | ApiError::CommunityNotFound
@@ -169,10 +302,29 @@ fn api_error_status(e: &ApiError) -> StatusCode {
         | ApiError::OpenHoursNotFound
         | ApiError::AuctionParamsNotFound
         | ApiError::AccountNotFound => StatusCode::NOT_FOUND,
+        ApiError::DuplicateBid | ApiError::SiteDeletionNotConfirmed(_) => {
+            StatusCode::CONFLICT
+        }
+        ApiError::UnsupportedContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
         _ => StatusCode::BAD_REQUEST,
     }
 }
 
+/// Seconds a client should wait before retrying a rate-limited request, sent
+/// as `Retry-After`. The export and recovery windows are upper bounds on the
+/// remaining wait.
+fn api_error_retry_after(e: &ApiError) -> Option<i64> {
+    match e {
+        ApiError::DataExportRateLimited => {
+            Some(store::export::DATA_EXPORT_INTERVAL.as_secs())
+        }
+        ApiError::AccountRecoveryRateLimited => {
+            Some(store::RECOVERY_ATTEMPT_WINDOW.as_secs())
+        }
+        _ => None,
+    }
+}
+
 impl ResponseError for RouteError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
         match self {
@@ -185,7 +337,14 @@ impl ResponseError for RouteError {
             Self::NotFound(e) => {
                 HttpResponse::NotFound().body(format!("{self}: {e}"))
             }
-            Self::Api(e) => HttpResponse::build(api_error_status(e)).json(e),
+            Self::Api(e) => {
+                let mut response = HttpResponse::build(api_error_status(e));
+                if let Some(secs) = api_error_retry_after(e) {
+                    response.insert_header((header::RETRY_AFTER, secs));
+                }
+                // In English; `LocaleMiddleware` translates the message
+                response.json(messages::ErrorBody::new(e, Locale::En))
+            }
             Self::UnexpectedError(e) => {
                 tracing::error!(error = ?e, "Internal server error");
                 HttpResponse::InternalServerError().body(self.to_string())
@@ -219,6 +378,21 @@ impl From<StoreError> for RouteError {
     }
 }
 
+/// `Last-Modified` and `ETag` headers for a resource whose `updated_at` every
+/// mutation bumps, so clients can tell a stale cached copy from a fresh one.
+fn cache_validators(
+    id: impl std::fmt::Display,
+    updated_at: jiff::Timestamp,
+) -> (header::LastModified, header::ETag) {
+    let last_modified =
+        header::LastModified(std::time::SystemTime::from(updated_at).into());
+    let etag = header::ETag(header::EntityTag::new_weak(format!(
+        "{id}-{}",
+        updated_at.as_microsecond()
+    )));
+    (last_modified, etag)
+}
+
 fn get_user_id(user: &Identity) -> Result<payloads::UserId, RouteError> {
     let id_str = user.id().map_err(|e| {
         RouteError::AuthError(
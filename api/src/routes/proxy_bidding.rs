@@ -19,6 +19,19 @@ pub async fn create_or_update_user_value(
     Ok(HttpResponse::Ok().finish())
 }
 
+#[post("/set_user_value_and_rebid")]
+pub async fn set_user_value_and_rebid(
+    user: Identity,
+    details: web::Json<payloads::requests::UserValue>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    store::set_user_value_and_rebid(&details, &user_id, &pool, &time_source)
+        .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
 #[post("/get_user_value")]
 pub async fn get_user_value(
     user: Identity,
@@ -105,8 +118,10 @@ pub async fn delete_proxy_bidding(
     user: Identity,
     auction_id: web::Json<AuctionId>,
     pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    store::delete_proxy_bidding(&auction_id, &user_id, &pool).await?;
+    store::delete_proxy_bidding(&auction_id, &user_id, &pool, &time_source)
+        .await?;
     Ok(HttpResponse::Ok().finish())
 }
@@ -1,24 +1,33 @@
 use actix_identity::Identity;
 use actix_web::{HttpResponse, get, post, web};
+use payloads::requests;
 use sqlx::PgPool;
 
 use crate::store;
 
-use super::{RouteError, get_user_id, get_validated_member};
+use super::{RouteError, cache_validators, get_user_id, get_validated_member};
 
 #[post("/create_site")]
 pub async fn create_site(
     user: Identity,
-    details: web::Json<payloads::Site>,
+    details: web::Json<requests::SiteDetails>,
     pool: web::Data<PgPool>,
     time_source: web::Data<crate::time::TimeSource>,
+    id_source: web::Data<crate::id_source::IdSource>,
+    config: web::Data<crate::AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
     let validated_member =
         get_validated_member(&user_id, &details.0.community_id, &pool).await?;
-    let site =
-        store::create_site(&details, &validated_member, &pool, &time_source)
-            .await?;
+    let site = store::create_site(
+        &details,
+        &validated_member,
+        &config.round_duration_bounds,
+        &pool,
+        &time_source,
+        &id_source,
+    )
+    .await?;
     // return the community id so we can start using for other things
     Ok(HttpResponse::Ok().json(site.id))
 }
@@ -27,14 +36,42 @@ pub async fn create_site(
 pub async fn get_site(
     user: Identity,
     site_id: web::Json<payloads::SiteId>,
+    options: web::Query<payloads::requests::ReadOptions>,
     pool: web::Data<PgPool>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
     let community_id = store::get_site_community_id(&site_id, &pool).await?;
-    get_validated_member(&user_id, &community_id, &pool).await?;
-    let site = store::get_site(&site_id, &pool).await?;
+    let actor = store::get_member_or_not_found(
+        &user_id,
+        &community_id,
+        payloads::ApiError::SiteNotFound,
+        &pool,
+    )
+    .await?;
+    let site = store::get_site_as(&site_id, &actor, &options, &pool).await?;
+    let (last_modified, etag) = cache_validators(site.site_id, site.updated_at);
     // return the community id so we can start using for other things
-    Ok(HttpResponse::Ok().json(site))
+    Ok(HttpResponse::Ok()
+        .insert_header(last_modified)
+        .insert_header(etag)
+        .json(site))
+}
+
+#[post("/resolve_open_hours")]
+pub async fn resolve_open_hours(
+    user: Identity,
+    details: web::Json<payloads::requests::ResolveOpenHours>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let windows = store::resolve_open_hours(
+        &details.site_id,
+        details.date,
+        &user_id,
+        &pool,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(windows))
 }
 
 #[post("/site")]
@@ -43,26 +80,35 @@ pub async fn update_site(
     details: web::Json<payloads::requests::UpdateSite>,
     pool: web::Data<PgPool>,
     time_source: web::Data<crate::time::TimeSource>,
+    config: web::Data<crate::AppConfig>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
     let community_id =
         store::get_site_community_id(&details.site_id, &pool).await?;
     let actor = get_validated_member(&user_id, &community_id, &pool).await?;
-    let site =
-        store::update_site(&details, &actor, &pool, &time_source).await?;
+    let site = store::update_site(
+        &details,
+        &actor,
+        &config.round_duration_bounds,
+        &pool,
+        &time_source,
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(site))
 }
 
 #[post("/delete_site")]
 pub async fn delete_site(
     user: Identity,
-    site_id: web::Json<payloads::SiteId>,
+    details: web::Json<requests::DeleteSite>,
     pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let community_id = store::get_site_community_id(&site_id, &pool).await?;
+    let community_id =
+        store::get_site_community_id(&details.site_id, &pool).await?;
     let actor = get_validated_member(&user_id, &community_id, &pool).await?;
-    store::delete_site(&site_id, &actor, &pool).await?;
+    store::delete_site(&details, &actor, &pool, &time_source).await?;
     Ok(HttpResponse::Ok().finish())
 }
 
@@ -99,15 +145,17 @@ pub async fn list_sites(
     user: Identity,
     community_id: web::Json<payloads::CommunityId>,
     pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    let sites = store::list_sites(&community_id, &user_id, &pool).await?;
+    let sites =
+        store::list_sites(&community_id, &user_id, &pool, &time_source).await?;
     Ok(HttpResponse::Ok().json(sites))
 }
 
 // Site Image Routes
 
-#[post("/create_site_image")]
+/// Registered in `api_services` with the image body limit.
 pub async fn create_site_image(
     user: Identity,
     details: web::Json<payloads::requests::CreateSiteImage>,
@@ -130,7 +178,12 @@ pub async fn get_site_image(
     let user_id = get_user_id(&user)?;
     let site_image =
         store::get_site_image(&site_image_id, &user_id, &pool).await?;
-    Ok(HttpResponse::Ok().json(site_image))
+    let (last_modified, etag) =
+        cache_validators(site_image.id, site_image.updated_at);
+    Ok(HttpResponse::Ok()
+        .insert_header(last_modified)
+        .insert_header(etag)
+        .json(site_image))
 }
 
 /// Returns raw image bytes for use in <img src> tags.
@@ -145,15 +198,19 @@ pub async fn get_site_image_bytes(
     let site_image_id = path.into_inner();
     let site_image =
         store::get_site_image(&site_image_id, &user_id, &pool).await?;
+    let (last_modified, etag) =
+        cache_validators(site_image.id, site_image.updated_at);
     Ok(HttpResponse::Ok()
         .content_type(site_image.mime_type.as_str())
+        .insert_header(last_modified)
+        .insert_header(etag)
         // Private caching ensures membership check happens per-user (CDNs won't
         // cache, but browsers will). Image data is immutable once created.
         .insert_header(("Cache-Control", "private, max-age=604800, immutable"))
         .body(site_image.image_data))
 }
 
-#[post("/update_site_image")]
+/// Registered in `api_services` with the image body limit.
 pub async fn update_site_image(
     user: Identity,
     details: web::Json<payloads::requests::UpdateSiteImage>,
@@ -218,6 +275,26 @@ pub async fn get_space(
     Ok(HttpResponse::Ok().json(space))
 }
 
+#[post("/get_spaces")]
+pub async fn get_spaces(
+    user: Identity,
+    details: web::Json<payloads::requests::GetSpaces>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+
+    if details.space_ids.len() > payloads::requests::MAX_GET_SPACES_IDS {
+        return Err(RouteError::BadRequest(anyhow::anyhow!(
+            "Too many space ids: {} exceeds the limit of {}",
+            details.space_ids.len(),
+            payloads::requests::MAX_GET_SPACES_IDS
+        )));
+    }
+
+    let spaces = store::get_spaces(&details.space_ids, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(spaces))
+}
+
 #[post("/space")]
 pub async fn update_space(
     user: Identity,
@@ -251,15 +328,59 @@ pub async fn update_spaces(
     Ok(HttpResponse::Ok().json(results))
 }
 
+#[post("/reorder_spaces")]
+pub async fn reorder_spaces(
+    user: Identity,
+    details: web::Json<payloads::requests::ReorderSpaces>,
+    pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    store::reorder_spaces(
+        &details.site_id,
+        &details.ordered_space_ids,
+        &user_id,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Delete a space, responding with whether it was archived instead.
 #[post("/delete_space")]
 pub async fn delete_space(
     user: Identity,
-    space_id: web::Json<payloads::SpaceId>,
+    details: web::Json<DeleteSpaceBody>,
     pool: web::Data<PgPool>,
+    time_source: web::Data<crate::time::TimeSource>,
 ) -> Result<HttpResponse, RouteError> {
     let user_id = get_user_id(&user)?;
-    store::delete_space(&space_id, &user_id, &pool).await?;
-    Ok(HttpResponse::Ok().finish())
+    let details = match details.into_inner() {
+        DeleteSpaceBody::SpaceId(space_id) => payloads::requests::DeleteSpace {
+            space_id,
+            force: false,
+        },
+        DeleteSpaceBody::Details(details) => details,
+    };
+    let archived = store::delete_space(
+        &details.space_id,
+        details.force,
+        &user_id,
+        &pool,
+        &time_source,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(archived))
+}
+
+/// The delete endpoint predates [`payloads::requests::DeleteSpace`], so it
+/// also takes the bare space id older clients send.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+pub enum DeleteSpaceBody {
+    SpaceId(payloads::SpaceId),
+    Details(payloads::requests::DeleteSpace),
 }
 
 #[post("/soft_delete_space")]
@@ -296,3 +417,15 @@ pub async fn list_spaces(
     let spaces = store::list_spaces(&site_id, &user_id, &pool).await?;
     Ok(HttpResponse::Ok().json(spaces))
 }
+
+#[post("/spaces_with_values")]
+pub async fn list_spaces_with_values(
+    user: Identity,
+    site_id: web::Json<payloads::SiteId>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, RouteError> {
+    let user_id = get_user_id(&user)?;
+    let spaces =
+        store::list_spaces_with_values(&site_id, &user_id, &pool).await?;
+    Ok(HttpResponse::Ok().json(spaces))
+}
@@ -33,7 +33,13 @@ pub async fn sse_auction(
     let user_id = get_user_id(&user)?;
     let auction_id = path.into_inner();
     // Same gate as POST /auction.
-    store::auction::read_auction(&auction_id, &user_id, &pool).await?;
+    store::auction::read_auction(
+        &auction_id,
+        &user_id,
+        &Default::default(),
+        &pool,
+    )
+    .await?;
 
     let rx = bus.subscribe();
     let stream = build_event_stream(rx, auction_id, user_id);
@@ -3,8 +3,8 @@
 //! E.g. on the proxy bidding lead time is reached and auto scheduling is
 //! enabled, the scheduler creates the auction row so proxy bids can start to be
 //! associated with it. Other scheduling tasks include, starting the auction,
-//! computing auction rounds, and updating members' is_active state based on the
-//! membership schedule.
+//! computing auction rounds, updating members' is_active state based on the
+//! membership schedule, and reminding winners that their possession ends soon.
 //!
 //! ```text
 //!          round_duration
@@ -37,18 +37,34 @@ use anyhow::Context;
 use jiff::tz::TimeZone;
 use jiff_sqlx::ToSqlx;
 use payloads::{ApiError, SpaceId};
+use rand_chacha::ChaCha8Rng;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
 use rust_decimal::Decimal;
 use sqlx::{Acquire, PgPool};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 use tokio::time;
 
-use crate::{pubsub, store, telemetry::log_error, time::TimeSource};
+use crate::{
+    email::EmailService, pubsub, store, telemetry::log_error, time::TimeSource,
+};
 
 pub struct Scheduler {
     pool: PgPool,
     time_source: TimeSource,
     tick_interval: Duration,
+    possession_reminders: Option<PossessionReminders>,
+    token_cleanup_interval: Option<jiff::SignedDuration>,
+    outbox: Option<EmailService>,
+}
+
+/// What the scheduler needs to email winners before their possession ends.
+/// The emails go out through the outbox.
+pub struct PossessionReminders {
+    /// Base URL for links in the email
+    pub base_url: String,
+    /// How long before a possession ends to send the reminder
+    pub window: jiff::SignedDuration,
 }
 
 impl Scheduler {
@@ -61,17 +77,238 @@ impl Scheduler {
             pool,
             time_source,
             tick_interval,
+            possession_reminders: None,
+            token_cleanup_interval: None,
+            outbox: None,
         }
     }
 
+    /// Also email winners shortly before their possession periods end.
+    pub fn with_possession_reminders(
+        mut self,
+        possession_reminders: PossessionReminders,
+    ) -> Self {
+        self.possession_reminders = Some(possession_reminders);
+        self
+    }
+
+    /// Also delete expired email verification and password reset tokens,
+    /// at most once per `interval`.
+    pub fn with_token_cleanup(
+        mut self,
+        interval: jiff::SignedDuration,
+    ) -> Self {
+        self.token_cleanup_interval = Some(interval);
+        self
+    }
+
+    /// Also send emails queued in the outbox.
+    pub fn with_outbox(mut self, email_service: EmailService) -> Self {
+        self.outbox = Some(email_service);
+        self
+    }
+
     pub async fn run(&self) {
+        // The first tick fires at once, so rounds that ended while the server
+        // was down are caught up at startup.
         let mut interval = time::interval(self.tick_interval);
+        let mut last_token_cleanup = None;
         loop {
             interval.tick().await;
             schedule_tick(&self.pool, &self.time_source).await;
+            if let Some(cleanup_interval) = self.token_cleanup_interval {
+                let _ = cleanup_expired_tokens_if_due(
+                    &self.pool,
+                    &self.time_source,
+                    cleanup_interval,
+                    &mut last_token_cleanup,
+                )
+                .await
+                .map_err(log_error);
+            }
+            if let Some(reminders) = &self.possession_reminders {
+                let _ = queue_possession_reminders(
+                    &self.pool,
+                    &self.time_source,
+                    reminders,
+                )
+                .await
+                .map_err(log_error);
+            }
+            if let Some(email_service) = &self.outbox {
+                let _ = send_outbox_emails(
+                    &self.pool,
+                    &self.time_source,
+                    email_service,
+                )
+                .await
+                .map_err(log_error);
+            }
         }
     }
 }
+
+/// Emails sent from the outbox per tick at most.
+const OUTBOX_BATCH_SIZE: usize = 50;
+
+/// Send the outbox emails that are due. Returns the number sent.
+///
+/// Each email is claimed in its own short statement, sent with no
+/// transaction open, then marked sent or failed, so concurrent scheduler
+/// instances don't send the same email and a failure is retried after a
+/// backoff.
+#[tracing::instrument(skip_all)]
+pub async fn send_outbox_emails(
+    pool: &PgPool,
+    time_source: &TimeSource,
+    email_service: &EmailService,
+) -> anyhow::Result<usize> {
+    let mut sent = 0;
+    for _ in 0..OUTBOX_BATCH_SIZE {
+        let Some(email) = store::outbox::claim_due_email(pool, time_source)
+            .await
+            .context("failed to claim an outbox email")?
+        else {
+            break;
+        };
+
+        match email_service
+            .send_email(&email.recipient, email.payload.0.clone())
+            .await
+        {
+            Ok(()) => {
+                store::outbox::mark_email_sent(&email.id, pool, time_source)
+                    .await?;
+                sent += 1;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    id = %email.id,
+                    template = %email.template,
+                    attempts = email.attempts + 1,
+                    "failed to send outbox email: {e:#}"
+                );
+                store::outbox::mark_email_failed(
+                    &email,
+                    &format!("{e:#}"),
+                    pool,
+                    time_source,
+                )
+                .await?;
+            }
+        }
+    }
+    Ok(sent)
+}
+
+/// Queue an email to each winner whose possession ends within the reminder
+/// window, once per possession. Returns the number of emails queued.
+///
+/// The `notifications_sent` row is claimed in the same transaction that
+/// queues the email in the outbox, so a reminder is queued exactly once and
+/// the outbox retries a send that fails. A reminder that fails to queue is
+/// logged and skipped, and tried again on a later tick.
+#[tracing::instrument(skip_all)]
+pub async fn queue_possession_reminders(
+    pool: &PgPool,
+    time_source: &TimeSource,
+    reminders: &PossessionReminders,
+) -> anyhow::Result<usize> {
+    let due = store::notification::list_due_possession_reminders(
+        reminders.window,
+        pool,
+        time_source,
+    )
+    .await
+    .context("failed to list due possession reminders")?;
+
+    let mut queued = 0;
+    for reminder in due {
+        match queue_possession_reminder(&reminder, reminders, pool, time_source)
+            .await
+        {
+            Ok(true) => queued += 1,
+            // Another scheduler instance got here first
+            Ok(false) => {}
+            Err(e) => tracing::error!(
+                user_id = %reminder.user_id,
+                "failed to queue possession reminder: {e:#}"
+            ),
+        }
+    }
+    Ok(queued)
+}
+
+/// Claim one reminder and queue its email, in one transaction. Returns false
+/// if it was already claimed.
+async fn queue_possession_reminder(
+    reminder: &store::notification::PossessionReminder,
+    reminders: &PossessionReminders,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> anyhow::Result<bool> {
+    let mut tx = pool.begin().await?;
+    let claimed = store::notification::claim_notification(
+        &reminder.user_id,
+        store::notification::NotificationKind::PossessionEnding,
+        reminder.reference_id(),
+        &mut *tx,
+        time_source,
+    )
+    .await?;
+    if !claimed {
+        return Ok(false);
+    }
+
+    let tz = reminder
+        .site_timezone
+        .as_deref()
+        .and_then(|tz| TimeZone::get(tz).ok())
+        .unwrap_or(TimeZone::UTC);
+    let ends_at = reminder
+        .possession_end_at
+        .to_zoned(tz)
+        .strftime("%Y-%m-%d %H:%M %Z")
+        .to_string();
+    store::outbox::enqueue_email(
+        &reminder.email,
+        "possession_ending",
+        &crate::email::possession_ending_email(
+            &reminder.username,
+            &reminder.space_name,
+            &reminder.site_name,
+            &ends_at,
+            &reminders.base_url,
+        ),
+        &mut *tx,
+        time_source,
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(true)
+}
+
+/// Delete expired tokens if `interval` has passed since `last_run`, as
+/// measured by the time source. Returns the number deleted, or `None` if the
+/// cleanup wasn't due yet.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn cleanup_expired_tokens_if_due(
+    pool: &PgPool,
+    time_source: &TimeSource,
+    interval: jiff::SignedDuration,
+    last_run: &mut Option<jiff::Timestamp>,
+) -> anyhow::Result<Option<u64>> {
+    let now = time_source.now();
+    if last_run.is_some_and(|last| now.duration_since(last) < interval) {
+        return Ok(None);
+    }
+    let deleted = store::cleanup_expired_tokens(pool, time_source)
+        .await
+        .context("failed to clean up expired tokens")?;
+    *last_run = Some(now);
+    Ok(Some(deleted))
+}
+
 /// Main scheduler tick function.
 /// Runs all periodic tasks and logs any errors without propagating them,
 /// ensuring one task failure doesn't prevent other tasks from running.
@@ -409,6 +646,15 @@ async fn cancel_runaway_auction(
     .await
     .context("failed to cancel runaway auction")?;
 
+    store::create_auction_report(
+        &auction.id,
+        payloads::responses::AuctionConclusion::Terminated,
+        tx,
+        time_source,
+    )
+    .await
+    .context("failed to create auction report")?;
+
     pubsub::emit(
         tx,
         &payloads::AuctionEvent::AuctionEnded {
@@ -427,6 +673,16 @@ async fn cancel_runaway_auction(
     Ok(())
 }
 
+/// PRNG for a round's randomized decisions, seeded by the auction's
+/// `random_seed` and the round number. Every round can be replayed on its own
+/// from the stored seed.
+fn round_rng(random_seed: i64, round_num: i32) -> ChaCha8Rng {
+    let mut seed = [0u8; 32];
+    seed[..8].copy_from_slice(&random_seed.to_le_bytes());
+    seed[8..12].copy_from_slice(&round_num.to_le_bytes());
+    ChaCha8Rng::from_seed(seed)
+}
+
 /// For rounds that have concluded (now > end_time), create an entry for each
 /// space for that round defining the current value of the space (0.0 by
 /// default), and the user_id of the current highest bidder.
@@ -434,13 +690,16 @@ async fn cancel_runaway_auction(
 /// Bids are just whether someone wants the space at the previous value plus
 /// the bid increment (from the auction params), and are defined in the bids
 /// table. When there are multiple bids for the same space, the winner is
-/// selected at random for this round.
+/// selected at random for this round, using [`round_rng`] so the selection can
+/// be replayed.
 ///
 /// Bids in the bids table are assumed to already have sufficient eligibility
 /// and are considered valid.
 ///
-/// If all space values remain the same in a new round, the auction is
-/// concluded by defining end_at in the auction table with the current time.
+/// If all space values remain the same in a new round and the auction has run
+/// at least its params' `min_rounds`, the auction is concluded by defining
+/// end_at in the auction table with the current time, after the auction's
+/// winner constraints have trimmed the final results.
 ///
 /// Returns whether the auction is still ongoing.
 #[tracing::instrument(skip(tx, time_source))]
@@ -450,28 +709,26 @@ async fn update_round_space_results_within_tx(
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
     time_source: &TimeSource,
 ) -> anyhow::Result<bool> {
-    // Get the auction params to know the bid increment
-    let auction_params = sqlx::query_as::<_, store::AuctionParams>(
-        "SELECT * FROM auction_params WHERE id = $1",
-    )
-    .bind(&auction.auction_params_id)
-    .fetch_one(&mut **tx)
-    .await
-    .context("failed to get auction params")?;
+    // A winning bid takes the space at the round's ask price
+    let ask_prices = store::round_ask_prices_tx(previous_round, tx)
+        .await
+        .context("failed to get round ask prices")?;
 
     // Get all spaces for this auction's site
     let spaces = sqlx::query_as::<_, store::Space>(
-        "SELECT * FROM spaces WHERE site_id = $1 AND is_available = true AND deleted_at IS NULL",
+        "SELECT * FROM spaces WHERE site_id = $1 AND is_available = true AND deleted_at IS NULL ORDER BY id",
     )
     .bind(auction.site_id)
     .fetch_all(&mut **tx)
     .await
     .context("failed to get available spaces for site")?;
 
+    // Spaces are visited in id order, so the same bids draw the same numbers.
+    let mut rng = round_rng(auction.random_seed, previous_round.round_num);
+
     let mut any_bids = false;
-    // Collect winner payments for settlement (user_id -> total amount owed)
-    let mut winner_payments: HashMap<payloads::UserId, Decimal> =
-        HashMap::new();
+    // This round's results, which become final if the auction concludes
+    let mut round_wins = Vec::new();
 
     for space in &spaces {
         // Check how many bids exist for this space in the concluded round
@@ -515,25 +772,24 @@ async fn update_round_space_results_within_tx(
         })?;
 
         let (new_value, winning_user_id) = if bid_count > 0 {
-            // With any bids, increase the value if there was a previous value
-            // In mock-time mode, use deterministic ordering for reproducible
-            // tests. Need to use username since ids are nondeterministic.
+            // With any bids, increase the value if there was a previous value.
+            // Bidders are listed in a stable order for the seeded draw. In
+            // mock-time mode, order by username for reproducible tests, since
+            // ids are nondeterministic.
             #[cfg(feature = "mock-time")]
             let query = "SELECT b.user_id FROM bids b
                 JOIN users u ON b.user_id = u.id
                 WHERE b.space_id = $1 AND b.round_id = $2
-                ORDER BY u.username
-                LIMIT 1";
+                ORDER BY u.username";
             #[cfg(not(feature = "mock-time"))]
             let query = "SELECT user_id FROM bids
                 WHERE space_id = $1 AND round_id = $2
-                ORDER BY random()
-                LIMIT 1";
+                ORDER BY user_id";
 
-            let winner = sqlx::query_scalar::<_, payloads::UserId>(query)
+            let bidders = sqlx::query_scalar::<_, payloads::UserId>(query)
                 .bind(space.id)
                 .bind(previous_round.id)
-                .fetch_one(&mut **tx)
+                .fetch_all(&mut **tx)
                 .await
                 .with_context(|| {
                     format!(
@@ -541,12 +797,17 @@ async fn update_round_space_results_within_tx(
                         space.id
                     )
                 })?;
+            let draw = (rng.next_u64() % bidders.len() as u64) as usize;
+            // Under mock time an auction's seed is 0 unless a test sets one.
+            // Those auctions keep the first bidder by username, as before
+            // seeding, so tests written against that rule still hold.
+            #[cfg(feature = "mock-time")]
+            let draw = if auction.random_seed == 0 { 0 } else { draw };
+            let winner = bidders[draw];
 
-            let new_value = payloads::next_bid_amount(
-                prev_result.as_ref().map(|p| p.value),
-                auction_params.bid_increment,
-                space.reserve_price,
-            );
+            let new_value = *ask_prices.get(&space.id).with_context(|| {
+                format!("no ask price for space {}", space.id)
+            })?;
 
             (new_value, winner)
         } else {
@@ -578,10 +839,12 @@ async fn update_round_space_results_within_tx(
             format!("failed to create space round entry for space {}", space.id)
         })?;
 
-        // Accumulate payment owed by this winner
-        *winner_payments
-            .entry(winning_user_id)
-            .or_insert(Decimal::ZERO) += new_value;
+        round_wins.push(ProvisionalWin {
+            space_id: space.id,
+            user_id: winning_user_id,
+            value: new_value,
+            eligibility_points: space.eligibility_points,
+        });
     }
 
     // The previous round is now fully concluded — its round_space_results have
@@ -596,15 +859,42 @@ async fn update_round_space_results_within_tx(
     )
     .await?;
 
-    // Conclude the auction if there are no more bids
-    if !any_bids {
+    // Conclude the auction if there are no more bids, unless it hasn't yet
+    // run its minimum number of rounds. Round numbers start at 0.
+    let min_rounds: Option<i32> = sqlx::query_scalar(
+        "SELECT min_rounds FROM auction_params WHERE id = $1",
+    )
+    .bind(&auction.auction_params_id)
+    .fetch_one(&mut **tx)
+    .await
+    .context("failed to get auction min_rounds")?;
+    let min_rounds_elapsed =
+        min_rounds.is_none_or(|min| previous_round.round_num + 1 >= min);
+    if !any_bids && min_rounds_elapsed {
+        let community_id: payloads::CommunityId =
+            sqlx::query_scalar("SELECT community_id FROM sites WHERE id = $1")
+                .bind(auction.site_id)
+                .fetch_one(&mut **tx)
+                .await
+                .context("failed to get community_id for auction")?;
+
+        let final_wins = enforce_winner_constraints(
+            auction,
+            previous_round,
+            &community_id,
+            round_wins,
+            tx,
+        )
+        .await?;
+
         sqlx::query(
             "UPDATE auctions
-            SET end_at = $1
+            SET end_at = $1, updated_at = $3
             WHERE id = $2",
         )
         .bind(previous_round.end_at.to_sqlx())
         .bind(auction.id)
+        .bind(time_source.now().to_sqlx())
         .execute(&mut **tx)
         .await
         .with_context(|| {
@@ -619,13 +909,28 @@ async fn update_round_space_results_within_tx(
         )
         .await?;
 
-        // Get community_id from site for settlement
-        let community_id: payloads::CommunityId =
-            sqlx::query_scalar("SELECT community_id FROM sites WHERE id = $1")
-                .bind(auction.site_id)
-                .fetch_one(&mut **tx)
-                .await
-                .context("failed to get community_id for auction settlement")?;
+        // Winner constraints have already trimmed the final round's results
+        store::create_auction_report(
+            &auction.id,
+            payloads::responses::AuctionConclusion::Ended,
+            tx,
+            time_source,
+        )
+        .await
+        .context("failed to create auction report")?;
+
+        // Sandbox auctions end here: results stand, but no money moves.
+        if auction.sandbox {
+            return Ok(false);
+        }
+
+        // Total owed by each winner
+        let mut winner_payments: HashMap<payloads::UserId, Decimal> =
+            HashMap::new();
+        for win in &final_wins {
+            *winner_payments.entry(win.user_id).or_insert(Decimal::ZERO) +=
+                win.value;
+        }
 
         // Create auction settlement journal entry
         store::currency::create_auction_settlement_entry(
@@ -637,9 +942,120 @@ async fn update_round_space_results_within_tx(
         )
         .await
         .context("failed to create auction settlement journal entry")?;
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// A space's result in the concluding round, before winner constraints apply.
+#[derive(Debug, Clone, PartialEq)]
+struct ProvisionalWin {
+    space_id: SpaceId,
+    user_id: payloads::UserId,
+    value: Decimal,
+    eligibility_points: f64,
+}
+
+/// Apply the auction's [`payloads::WinnerConstraint`]s to the concluding
+/// round's results, deleting the results of dropped wins so those spaces end
+/// up unawarded. Returns the wins that stand.
+async fn enforce_winner_constraints(
+    auction: &store::Auction,
+    final_round: &store::AuctionRound,
+    community_id: &payloads::CommunityId,
+    wins: Vec<ProvisionalWin>,
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Vec<ProvisionalWin>> {
+    let constraints = &auction.winner_constraints.0;
+    if constraints.is_empty() {
+        return Ok(wins);
+    }
+
+    let active_members: HashSet<payloads::UserId> = if constraints
+        .contains(&payloads::WinnerConstraint::MustBeActiveMember)
+    {
+        store::list_active_member_ids(community_id, &mut **tx)
+            .await
+            .context("failed to get active members")?
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let (kept, dropped) =
+        apply_winner_constraints(wins, constraints, &active_members);
+    if dropped.is_empty() {
+        return Ok(kept);
     }
 
-    Ok(any_bids)
+    let dropped_space_ids: Vec<SpaceId> =
+        dropped.iter().map(|win| win.space_id).collect();
+    sqlx::query(
+        "DELETE FROM round_space_results
+        WHERE round_id = $1 AND space_id = ANY($2)",
+    )
+    .bind(final_round.id)
+    .bind(&dropped_space_ids)
+    .execute(&mut **tx)
+    .await
+    .context("failed to drop constrained wins")?;
+
+    tracing::info!(
+        auction_id = %auction.id,
+        dropped = dropped.len(),
+        "Winner constraints left spaces unawarded"
+    );
+
+    Ok(kept)
+}
+
+/// Split wins into those that stand and those breaking a constraint. Each
+/// member's wins are taken from the highest price down (space id breaks ties),
+/// so a member over a limit keeps their most valuable spaces. A win that
+/// doesn't fit is skipped and later, cheaper wins are still considered, which
+/// matters for point limits.
+fn apply_winner_constraints(
+    mut wins: Vec<ProvisionalWin>,
+    constraints: &[payloads::WinnerConstraint],
+    active_members: &HashSet<payloads::UserId>,
+) -> (Vec<ProvisionalWin>, Vec<ProvisionalWin>) {
+    use payloads::WinnerConstraint;
+
+    wins.sort_by(|a, b| {
+        b.value
+            .cmp(&a.value)
+            .then_with(|| a.space_id.0.cmp(&b.space_id.0))
+    });
+
+    // user_id -> (spaces kept, eligibility points kept)
+    let mut totals: HashMap<payloads::UserId, (u32, f64)> = HashMap::new();
+    let mut kept = Vec::new();
+    let mut dropped = Vec::new();
+    for win in wins {
+        let (count, points) =
+            totals.get(&win.user_id).copied().unwrap_or((0, 0.0));
+        let allowed = constraints.iter().all(|constraint| match *constraint {
+            WinnerConstraint::MaxWinsPerUser(max) => count < max,
+            WinnerConstraint::MaxPointsPerUser(max) => {
+                points + win.eligibility_points <= max
+            }
+            WinnerConstraint::MustBeActiveMember => {
+                active_members.contains(&win.user_id)
+            }
+        });
+        if allowed {
+            totals.insert(
+                win.user_id,
+                (count + 1, points + win.eligibility_points),
+            );
+            kept.push(win);
+        } else {
+            dropped.push(win);
+        }
+    }
+    (kept, dropped)
 }
 
 /// For an in-progress auction, create the next auction round as needed.
@@ -672,34 +1088,53 @@ pub async fn add_subsequent_rounds_for_auction(
         .start_at
         .context("auction has no start time; cannot create rounds")?;
 
-    let start_time_ts = previous_round
+    let round_num: i32 = previous_round
+        .as_ref()
+        .map(|r| r.round_num + 1)
+        .unwrap_or(0);
+
+    let mut start_time_ts = previous_round
         .as_ref()
         .map(|r| r.end_at)
         .unwrap_or(auction_start);
 
-    // use DST-aware datetime math in case the round duration is days or
-    // larger
-    let zoned_start_time = match start_time_ts
+    // Round durations are validated to hours and smaller, so this is a fixed
+    // length of time in any timezone. Params stored before that validation
+    // may still use days, which this zoned math adds in the site's local time.
+    let mut zoned_start_time = match start_time_ts
         .in_tz(&timezone.unwrap_or("UTC".into()))
-        .context("converting to timezone; falling back to DST-naive")
+        .context("converting to timezone; falling back to UTC")
     {
         Ok(t) => t,
         Err(e) => {
             tracing::warn!("{e:#}");
-            auction_start.to_zoned(TimeZone::UTC)
+            start_time_ts.to_zoned(TimeZone::UTC)
         }
     };
 
-    let zoned_end_time = zoned_start_time
+    let mut zoned_end_time = zoned_start_time
         .checked_add(auction_params.round_duration)
         .context("computing round end time; skipping")?;
 
-    let round_num: i32 = previous_round
-        .as_ref()
-        .map(|r| r.round_num + 1)
-        .unwrap_or(0);
+    // If the scheduler was down long enough that this round would already be
+    // over, start it now instead. Otherwise nobody could bid in it, and the
+    // auction would conclude on the missed rounds.
+    let now = time_source.now();
+    if zoned_end_time.timestamp() <= now {
+        tracing::warn!(
+            "auction {} round {round_num} would have ended at {}; starting it \
+             now instead",
+            auction.id,
+            zoned_end_time.timestamp(),
+        );
+        start_time_ts = now;
+        zoned_start_time = now.to_zoned(zoned_start_time.time_zone().clone());
+        zoned_end_time = zoned_start_time
+            .checked_add(auction_params.round_duration)
+            .context("computing round end time; skipping")?;
+    }
 
-    let eligibility_threshold = get_eligibility_for_round_num(
+    let eligibility_threshold = payloads::eligibility_for_round_num(
         round_num,
         &auction_params.activity_rule_params.eligibility_progression,
     );
@@ -726,6 +1161,12 @@ pub async fn add_subsequent_rounds_for_auction(
     .await
     .context("inserting round into database")?;
 
+    // Fix the round's ask prices now that the previous round's results are
+    // written
+    store::round_ask_prices_tx(&new_round, tx)
+        .await
+        .context("writing round ask prices")?;
+
     pubsub::emit(
         tx,
         &payloads::AuctionEvent::RoundCreated {
@@ -771,7 +1212,9 @@ pub async fn add_subsequent_rounds_for_auction(
 /// The eligibility calculation takes the total eligibility points from these
 /// spaces and divides by the eligibility threshold. For example, if the
 /// threshold is 0.5 (50%), and a user has activity on spaces worth 10 points,
-/// their eligibility is set to 20 points (10 / 0.5).
+/// their eligibility is set to 20 points (10 / 0.5). The result is rounded
+/// to whole points by the auction's `eligibility_rounding` policy, so 6
+/// points at a 0.8 threshold (7.5) gives 7 when flooring or 8 when rounding.
 ///
 /// After the first round, eligibility cannot increase. For example, if a user
 /// has 20 points of eligibility after round 1:
@@ -794,6 +1237,14 @@ pub async fn update_user_eligibilities(
         return Ok(());
     }
 
+    let rounding = sqlx::query_scalar::<_, payloads::RoundingPolicy>(
+        "SELECT eligibility_rounding FROM auction_params WHERE id = $1",
+    )
+    .bind(&auction.auction_params_id)
+    .fetch_one(&mut **tx)
+    .await
+    .context("failed to get auction eligibility rounding")?;
+
     // Get all spaces for this auction's site to calculate eligibility points
     let spaces = sqlx::query_as::<_, store::Space>(
         "SELECT * FROM spaces WHERE site_id = $1 AND is_available = true AND deleted_at IS NULL",
@@ -858,7 +1309,7 @@ pub async fn update_user_eligibilities(
 
         // Calculate new eligibility by dividing by threshold
         let mut new_eligibility =
-            total_points / previous_round.eligibility_threshold;
+            rounding.apply(total_points / previous_round.eligibility_threshold);
 
         // If not first round (round_num > 0), get previous eligibility and
         // ensure no increase
@@ -905,24 +1356,6 @@ pub async fn update_user_eligibilities(
     Ok(())
 }
 
-fn get_eligibility_for_round_num(
-    round_num: i32,
-    progression: &[(i32, f64)],
-) -> f64 {
-    // binary_search_by returns either the index of an exact match, or the
-    // insert location where round_num would go. The eligibility progression
-    // defines the threshold for a breakpoint's round onwards, so on a miss we
-    // want the breakpoint just before the insert location (idx - 1).
-    match progression.binary_search_by(|(round, _)| round.cmp(&round_num)) {
-        Ok(idx) => progression[idx].1,
-        // Before the first breakpoint (insert location 0): no breakpoint
-        // applies yet, so eligibility is unconstrained (0.0). This also
-        // covers an empty progression, whose only insert location is 0.
-        Err(0) => 0.0,
-        Err(idx) => progression[idx - 1].1,
-    }
-}
-
 /// A due (round, user) proxy work item, as listed by the lock-free selector.
 #[derive(Debug, sqlx::FromRow)]
 struct ProxyWorkItem {
@@ -941,13 +1374,32 @@ async fn process_due_proxy_items(
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> anyhow::Result<()> {
-    let items = list_due_proxy_items(pool, time_source).await?;
+    let items = list_due_proxy_items(None, pool, time_source).await?;
+    process_proxy_items(&items, pool, time_source).await
+}
+
+/// Process the user's due proxy work items right away instead of waiting
+/// for the next tick, so a changed value takes effect in the current round.
+pub async fn process_due_proxy_items_for_user(
+    user_id: &payloads::UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> anyhow::Result<()> {
+    let items = list_due_proxy_items(Some(user_id), pool, time_source).await?;
+    process_proxy_items(&items, pool, time_source).await
+}
+
+async fn process_proxy_items(
+    items: &[ProxyWorkItem],
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> anyhow::Result<()> {
     if items.is_empty() {
         return Ok(());
     }
     tracing::debug!("Found {} due proxy work items", items.len());
 
-    for item in &items {
+    for item in items {
         // Per-item failures are recorded on the item's marker (backoff) and
         // must not stop the pass — that isolation is the point.
         if let Err(e) = process_proxy_item(item, pool, time_source).await {
@@ -967,8 +1419,10 @@ async fn process_due_proxy_items(
 /// is due when its active round has no marker row (per-round baseline), its
 /// settings row is flagged dirty (mid-round change — this arm ignores
 /// backoff, making a member change during backoff a fresh-input retry), or
-/// its marker records failures and the backoff has expired.
+/// its marker records failures and the backoff has expired. `user_id`
+/// restricts the listing to one user's items.
 async fn list_due_proxy_items(
+    user_id: Option<&payloads::UserId>,
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> anyhow::Result<Vec<ProxyWorkItem>> {
@@ -991,6 +1445,7 @@ async fn list_due_proxy_items(
             ON prp.round_id = ar.id AND prp.user_id = upb.user_id
         WHERE $1 >= ar.start_at
             AND $1 < ar.end_at
+            AND ($2::uuid IS NULL OR upb.user_id = $2)
             AND (
                 prp.round_id IS NULL
                 OR upb.needs_processing
@@ -1004,6 +1459,7 @@ async fn list_due_proxy_items(
         backoff = backoff_interval_sql("prp.failure_count"),
     ))
     .bind(time_source.now().to_sqlx())
+    .bind(user_id)
     .fetch_all(pool)
     .await
     .map_err(Into::into)
@@ -1192,22 +1648,11 @@ async fn run_proxy_item_work(
 ) -> anyhow::Result<()> {
     // Plan reads: the auction-level inputs for this item. (The settings row
     // always denotes a current member: proxy bidding rows are deleted when
-    // a member leaves a community.)
-    let spaces = sqlx::query_as::<_, store::Space>(
-        "SELECT s.* FROM spaces s
-        JOIN sites si ON s.site_id = si.id
-        JOIN auctions a ON si.id = a.site_id
-        WHERE a.id = $1 AND s.is_available = true AND s.deleted_at IS NULL",
-    )
-    .bind(round.auction_id)
-    .fetch_all(&mut **tx)
-    .await
-    .context("failed to get auction spaces")?;
-
-    // Index spaces by id for cheap reserve price lookups when computing
-    // surpluses.
-    let spaces: HashMap<SpaceId, store::Space> =
-        spaces.into_iter().map(|s| (s.id, s)).collect();
+    // a member leaves a community.) First, what a bid on each of the
+    // auction's available spaces costs this round.
+    let ask_prices = store::round_ask_prices_tx(round, tx)
+        .await
+        .context("failed to get round ask prices")?;
 
     // Prices as of the previous round; does not exist for round 0.
     let prev_round_space_results =
@@ -1224,18 +1669,6 @@ async fn run_proxy_item_work(
         .await
         .context("failed to get round results")?;
 
-    // Get the auction params for the bid increment
-    let auction_params = sqlx::query_as::<_, store::AuctionParams>(
-        "SELECT * FROM auction_params ap
-        JOIN auctions a on ap.id = a.auction_params_id
-        WHERE a.id = $1",
-    )
-    .bind(round.auction_id)
-    .fetch_one(&mut **tx)
-    .await
-    .context("failed to get auction params")?;
-    let bid_increment = auction_params.bid_increment;
-
     // Clear any existing bids for this user in this round before reprocessing.
     // This ensures that if proxy bidding settings or user values were updated
     // mid-round, we start fresh with the new settings.
@@ -1269,7 +1702,7 @@ async fn run_proxy_item_work(
 
     let user_values = sqlx::query_as::<_, store::UserValue>(user_values_query)
         .bind(settings.user_id)
-        .bind(spaces.keys().copied().collect::<Vec<_>>())
+        .bind(ask_prices.keys().copied().collect::<Vec<_>>())
         .fetch_all(&mut **tx)
         .await
         .with_context(|| {
@@ -1278,42 +1711,35 @@ async fn run_proxy_item_work(
 
     tracing::info!("Found {} space values", user_values.len(),);
 
-    // Count the number of spaces the user is already the high bidder for
-    let num_spaces_already_winning = prev_round_space_results
+    // Count the number of spaces the user is already the high bidder for,
+    // and what those standing wins currently cost
+    let standing_wins = prev_round_space_results
         .iter()
-        .filter(|rsr| rsr.winning_user_id == settings.user_id)
-        .count();
+        .filter(|rsr| rsr.winning_user_id == settings.user_id);
+    let num_spaces_already_winning = standing_wins.clone().count();
+    let mut committed_total: Decimal = standing_wins.map(|rsr| rsr.value).sum();
 
     // Calculate surpluses for spaces where user has set values
     // user_values is already ordered by space name in mock-time mode
-    // Tuples: (space_id, surplus, value)
-    let mut space_surpluses: Vec<(SpaceId, Decimal, Decimal)> = Vec::new();
+    // Tuples: (space_id, surplus, value, next_bid)
+    let mut space_surpluses: Vec<(SpaceId, Decimal, Decimal, Decimal)> =
+        Vec::new();
     for user_value_entry in &user_values {
         let space_id = &user_value_entry.space_id;
-        // The user_values query filters by `space_id = ANY(spaces.keys())`,
-        // so every entry's space should be in the spaces map. Skip with a
+        // The user_values query filters by `space_id = ANY(ask_prices.keys())`,
+        // so every entry's space should have an ask price. Skip with a
         // warning if not, rather than silently falling back.
-        let Some(space) = spaces.get(space_id) else {
+        let Some(&next_bid) = ask_prices.get(space_id) else {
             tracing::warn!(
                 space_id = ?space_id,
                 user_id = ?settings.user_id,
-                "proxy bidding: user_value references a space not in the \
-                 spaces map; skipping",
+                "proxy bidding: user_value references a space without an \
+                 ask price; skipping",
             );
             continue;
         };
-        // Compute what the next bid on this space would cost, then
-        // compare to the user's stated value. Surplus < 0 means the user
-        // wouldn't bid here.
-        let prev_value = prev_round_space_results
-            .iter()
-            .find(|r| r.space_id == *space_id)
-            .map(|r| r.value);
-        let next_bid = payloads::next_bid_amount(
-            prev_value,
-            bid_increment,
-            space.reserve_price,
-        );
+        // Compare what the next bid on this space would cost to the user's
+        // stated value. Surplus < 0 means the user wouldn't bid here.
 
         let surplus = user_value_entry.value - next_bid;
         tracing::info!(
@@ -1329,6 +1755,7 @@ async fn run_proxy_item_work(
                 user_value_entry.space_id,
                 surplus,
                 user_value_entry.value,
+                next_bid,
             ));
         }
     }
@@ -1341,14 +1768,28 @@ async fn run_proxy_item_work(
     // Sort by surplus descending, then value descending to break ties
     space_surpluses.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.2.cmp(&a.2)));
 
-    // Try bidding on spaces in surplus order until we hit max_items
+    // Try bidding on spaces in surplus order until we hit max_items. With a
+    // budget cap, a space whose price would push the committed total over
+    // max_total is skipped, so the lowest-surplus spaces are the first to go
+    // as prices rise.
     let mut successful_bids = 0;
-    for (space_id, surplus, _value) in space_surpluses {
+    for (space_id, surplus, _value, next_bid) in space_surpluses {
         if successful_bids + num_spaces_already_winning
             >= settings.max_items as usize
         {
             break;
         }
+        if let Some(max_total) = settings.max_total
+            && committed_total + next_bid > max_total
+        {
+            tracing::info!(
+                "Skipping {:?}: bid of {} would exceed budget of {}",
+                space_id,
+                next_bid,
+                max_total
+            );
+            continue;
+        }
 
         tracing::info!(
             "Attempting to bid on {:?} with surplus {}",
@@ -1368,11 +1809,12 @@ async fn run_proxy_item_work(
         {
             Ok(_) => {
                 successful_bids += 1;
+                committed_total += next_bid;
                 tracing::info!("Successfully placed bid on {:?}", space_id);
             }
             Err(store::StoreError::Api(
                 ApiError::ExceedsEligibility { .. }
-                | ApiError::AlreadyWinningSpace,
+                | ApiError::AlreadyWinningSpace { .. },
             )) => {
                 // Expected errors - try next space
                 tracing::info!(
@@ -1418,27 +1860,67 @@ async fn run_proxy_item_work(
 
 #[cfg(test)]
 mod tests {
+    use super::{ProvisionalWin, apply_winner_constraints};
+    use payloads::{SpaceId, UserId, WinnerConstraint};
+    use rust_decimal::Decimal;
+    use std::collections::HashSet;
+    use uuid::Uuid;
+
+    fn win(user: u128, space: u128, value: i64, points: f64) -> ProvisionalWin {
+        ProvisionalWin {
+            space_id: SpaceId(Uuid::from_u128(space)),
+            user_id: UserId(Uuid::from_u128(user)),
+            value: Decimal::from(value),
+            eligibility_points: points,
+        }
+    }
+
+    fn kept_spaces(
+        wins: Vec<ProvisionalWin>,
+        constraints: &[WinnerConstraint],
+        active: &HashSet<UserId>,
+    ) -> Vec<u128> {
+        let (kept, _) = apply_winner_constraints(wins, constraints, active);
+        kept.iter().map(|w| w.space_id.0.as_u128()).collect()
+    }
+
     #[test]
-    fn test_get_eligibility_for_round_num() {
-        use super::get_eligibility_for_round_num;
-        let progression: &[(i32, f64)] =
-            &[(0, 0.5), (10, 0.75), (20, 0.9), (30, 1.0)];
-        let f = get_eligibility_for_round_num;
-        assert_eq!(f(0, &progression[..1]), 0.5);
-        assert_eq!(f(0, progression), 0.5);
-        assert_eq!(f(1, progression), 0.5);
-        assert_eq!(f(10, progression), 0.75);
-        assert_eq!(f(11, progression), 0.75);
-        assert_eq!(f(31, progression), 1.0);
-        assert_eq!(f(0, &[]), 0.0);
-        assert_eq!(f(0, &[(5, 0.5)]), 0.0);
-        assert_eq!(f(5, &[(5, 0.5)]), 0.5);
-        // Multiple breakpoints all in the future: rounds before the first
-        // breakpoint are unconstrained, not a panic from index underflow.
-        assert_eq!(f(2, &[(5, 0.5), (10, 0.75)]), 0.0);
-        assert_eq!(f(0, &[(5, 0.5), (10, 0.75)]), 0.0);
-        assert_eq!(f(5, &[(5, 0.5), (10, 0.75)]), 0.5);
-        assert_eq!(f(7, &[(5, 0.5), (10, 0.75)]), 0.5);
-        assert_eq!(f(10, &[(5, 0.5), (10, 0.75)]), 0.75);
+    fn test_apply_winner_constraints() {
+        let wins = vec![
+            win(1, 10, 3, 5.0),
+            win(1, 11, 7, 5.0),
+            win(1, 12, 1, 2.0),
+            win(2, 13, 2, 5.0),
+        ];
+        let none = HashSet::new();
+
+        // No constraints keeps everything, highest price first
+        assert_eq!(kept_spaces(wins.clone(), &[], &none), [11, 10, 13, 12]);
+
+        assert_eq!(
+            kept_spaces(
+                wins.clone(),
+                &[WinnerConstraint::MaxWinsPerUser(1)],
+                &none
+            ),
+            [11, 13]
+        );
+
+        // A win over the point limit is skipped, but a cheaper one that fits
+        // is still kept
+        assert_eq!(
+            kept_spaces(
+                wins.clone(),
+                &[WinnerConstraint::MaxPointsPerUser(7.0)],
+                &none
+            ),
+            [11, 13, 12]
+        );
+
+        let active = HashSet::from([UserId(Uuid::from_u128(2))]);
+        assert_eq!(
+            kept_spaces(wins, &[WinnerConstraint::MustBeActiveMember], &active),
+            [13]
+        );
     }
 }
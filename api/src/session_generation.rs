@@ -0,0 +1,118 @@
+//! Ending every session of one user. Sessions live entirely in signed
+//! cookies, so they can't be deleted server-side. Instead each user has a
+//! session generation, recorded in the session at login, and a session whose
+//! recorded generation is behind the user's current one is purged. Bumping
+//! the generation logs the user out everywhere.
+
+use actix_identity::IdentityExt;
+use actix_session::{Session, SessionExt};
+use actix_web::{
+    Error,
+    dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready},
+    web,
+};
+use payloads::UserId;
+use sqlx::PgPool;
+use std::{
+    future::{Ready, ready},
+    rc::Rc,
+};
+
+use crate::{LocalBoxFuture, store};
+
+const SESSION_KEY: &str = "session_generation";
+
+/// The generation a session logged in at.
+pub struct SessionGeneration;
+
+impl SessionGeneration {
+    /// Sessions from before generations were recorded count as the first
+    /// generation.
+    pub fn get(session: &Session) -> i32 {
+        session.get(SESSION_KEY).ok().flatten().unwrap_or(0)
+    }
+
+    pub fn insert(generation: i32, session: &Session) -> anyhow::Result<()> {
+        Ok(session.insert(SESSION_KEY, generation)?)
+    }
+}
+
+/// Logs out sessions from an earlier generation than their user's current
+/// one, or whose user is gone. Must sit inside the identity middleware.
+///
+/// If the generation can't be read the request is let through, so a
+/// database hiccup doesn't log everyone out.
+pub struct SessionGenerationMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for SessionGenerationMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = SessionGenerationMiddlewareService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(SessionGenerationMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct SessionGenerationMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for SessionGenerationMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>
+        + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let user_id = req
+            .get_identity()
+            .ok()
+            .and_then(|identity| identity.id().ok())
+            .and_then(|id| id.parse::<uuid::Uuid>().ok())
+            .map(UserId);
+        let pool = req.app_data::<web::Data<PgPool>>().cloned();
+        let service = self.service.clone();
+
+        Box::pin(async move {
+            if let (Some(user_id), Some(pool)) = (user_id, pool) {
+                let session = req.get_session();
+                match store::get_session_generation(&user_id, &pool).await {
+                    Ok(current)
+                        if current
+                            == Some(SessionGeneration::get(&session)) => {}
+                    Ok(_) => {
+                        tracing::info!(
+                            %user_id,
+                            "Session generation is stale; logging out"
+                        );
+                        session.purge();
+                    }
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to check session generation: {e:?}"
+                        );
+                    }
+                }
+            }
+            service.call(req).await
+        })
+    }
+}
@@ -0,0 +1,92 @@
+//! Optionally serve the built UI (`ui/dist/`) from the API server, for
+//! deployments without a separate static host.
+//!
+//! Mirrors the nginx config: paths that don't match a file fall back to
+//! `index.html` so the UI's router can handle deep links like
+//! `/communities/<id>`. Paths with a file extension are missing assets and
+//! still get a real 404, as do unmatched `/api` paths.
+
+use actix_files::{Files, NamedFile};
+use actix_web::{
+    Error, HttpResponse,
+    body::MessageBody,
+    dev::{HttpServiceFactory, ServiceRequest, ServiceResponse, fn_service},
+    http::{
+        Method, StatusCode,
+        header::{CACHE_CONTROL, HeaderValue},
+    },
+    middleware::{Next, from_fn},
+    web,
+};
+use std::path::Path;
+
+const INDEX_FILE: &str = "index.html";
+
+/// Serve `dir` at the root. Register after the API routes so they take
+/// precedence.
+pub fn static_files_service(dir: &Path) -> impl HttpServiceFactory + use<> {
+    let index = dir.join(INDEX_FILE);
+    web::scope("").wrap(from_fn(cache_headers)).service(
+        Files::new("/", dir).index_file(INDEX_FILE).default_handler(
+            fn_service(move |req: ServiceRequest| {
+                let index = index.clone();
+                async move { spa_fallback(req, &index).await }
+            }),
+        ),
+    )
+}
+
+/// Serve `index.html` for client-side routes, otherwise 404.
+async fn spa_fallback(
+    req: ServiceRequest,
+    index: &Path,
+) -> Result<ServiceResponse, Error> {
+    let (req, _) = req.into_parts();
+    if !is_client_route(req.method(), req.path()) {
+        return Ok(ServiceResponse::new(
+            req,
+            HttpResponse::NotFound().finish(),
+        ));
+    }
+    let mut res = NamedFile::open_async(index).await?.into_response(&req);
+    res.headers_mut()
+        .insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    Ok(ServiceResponse::new(req, res))
+}
+
+fn is_client_route(method: &Method, path: &str) -> bool {
+    (method == Method::GET || method == Method::HEAD)
+        && !path.starts_with("/api")
+        && extension(path).is_none()
+}
+
+/// Trunk content-hashes js, css, and wasm file names, so they can be cached
+/// forever. Pages must be revalidated to pick up new hashes.
+///
+/// `Files` and `NamedFile` already answer `If-None-Match` and
+/// `If-Modified-Since` with 304. Those responses repeat the policy, since a
+/// cache refreshes its stored headers from them.
+async fn cache_headers(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let policy = match extension(req.path()) {
+        Some("js" | "css" | "wasm") => "public, max-age=31536000, immutable",
+        None | Some("html") => "no-cache",
+        Some(_) => "public, max-age=300",
+    };
+    let mut res = next.call(req).await?;
+    let cacheable =
+        res.status().is_success() || res.status() == StatusCode::NOT_MODIFIED;
+    if cacheable && !res.headers().contains_key(CACHE_CONTROL) {
+        res.headers_mut()
+            .insert(CACHE_CONTROL, HeaderValue::from_static(policy));
+    }
+    Ok(res)
+}
+
+/// Extension of the last path segment, if any.
+fn extension(path: &str) -> Option<&str> {
+    let segment = path.rsplit('/').next().unwrap_or_default();
+    segment.rsplit_once('.').map(|(_, ext)| ext)
+}
@@ -2,11 +2,13 @@ use super::*;
 use jiff_sqlx::ToSqlx;
 use payloads::{
     ApiError, AuctionId, AuctionRoundId, Bid, PermissionLevel, SiteId, SpaceId,
-    UserId,
+    UserId, responses::AuctionConclusion,
 };
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use std::collections::HashMap;
 
+use crate::id_source::IdSource;
 use crate::time::TimeSource;
 
 /// Calculate the total eligibility points required for a set of spaces
@@ -101,11 +103,28 @@ pub async fn get_eligibility(
 
 /// List a user's eligibility for every round in an auction, in round order.
 /// The returned vec aligns 1:1 with the rounds: index 0 is round 0.
+/// Superseded by [`list_round_eligibility`], which labels each entry with its
+/// round.
 pub async fn list_eligibility(
     auction_id: &AuctionId,
     user_id: &UserId,
     pool: &PgPool,
 ) -> Result<Vec<payloads::Eligibility>, StoreError> {
+    Ok(list_round_eligibility(auction_id, user_id, pool)
+        .await?
+        .into_iter()
+        .map(|round| round.eligibility)
+        .collect())
+}
+
+/// The user's eligibility in every round of the auction, in round order.
+/// Rounds where the user has no eligibility record are included, as
+/// `Finite(0.0)` when the prior round was constrained.
+pub async fn list_round_eligibility(
+    auction_id: &AuctionId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::RoundEligibility>, StoreError> {
     // Validate user has access to this auction's community
     let auction =
         sqlx::query_as::<_, Auction>("SELECT * FROM auctions WHERE id = $1")
@@ -129,8 +148,12 @@ pub async fn list_eligibility(
     let mut eligibilities = Vec::with_capacity(rounds.len());
 
     // Round 0 has no prior round, so it is always unconstrained.
-    if !rounds.is_empty() {
-        eligibilities.push(payloads::Eligibility::Unlimited);
+    if let Some(first) = rounds.first() {
+        eligibilities.push(payloads::responses::RoundEligibility {
+            round_id: first.id,
+            round_num: first.round_num,
+            eligibility: payloads::Eligibility::Unlimited,
+        });
     }
 
     // Each subsequent round is interpreted against its predecessor's
@@ -139,14 +162,19 @@ pub async fn list_eligibility(
     for pair in rounds.windows(2) {
         let prior_threshold = pair[0].eligibility_threshold;
         let round = &pair[1];
-        eligibilities.push(
+        let eligibility =
             user_eligibility(pool, &round.id, user_id, Some(prior_threshold))
-                .await?,
-        );
+                .await?;
+        eligibilities.push(payloads::responses::RoundEligibility {
+            round_id: round.id,
+            round_num: round.round_num,
+            eligibility,
+        });
     }
 
     Ok(eligibilities)
 }
+
 /// Get an auction and validate that the user has the required permission
 /// level in the site's community. Returns both the auction and the
 /// validated member if successful.
@@ -184,8 +212,10 @@ pub(super) async fn get_validated_auction(
 pub async fn create_auction(
     details: &payloads::Auction,
     user_id: &UserId,
+    default_bounds: &payloads::RoundDurationBounds,
     pool: &PgPool,
     time_source: &TimeSource,
+    id_source: &IdSource,
 ) -> Result<payloads::AuctionId, StoreError> {
     // Get the site and validate user permissions
     let community_id = get_site_community_id(&details.site_id, pool).await?;
@@ -212,6 +242,9 @@ pub async fn create_auction(
         return Err(ApiError::InvalidPossessionPeriod.into());
     }
 
+    payloads::validate_winner_constraints(&details.winner_constraints)
+        .map_err(ApiError::InvalidWinnerConstraints)?;
+
     // A start time more than one round in the past would create round 0 already
     // ended, so nobody (human or proxy) could ever bid and the auction would
     // immediately self-conclude with no allocations. Starting exactly at now is
@@ -265,6 +298,13 @@ pub async fn create_auction(
     let mut tx = pool.begin().await?;
 
     // Create auction params first
+    check_round_duration_bounds(
+        details.auction_params.round_duration,
+        &community_id,
+        default_bounds,
+        &mut tx,
+    )
+    .await?;
     let auction_params_id = create_auction_params(
         &details.auction_params,
         &community_id,
@@ -275,20 +315,28 @@ pub async fn create_auction(
 
     let auction_id = sqlx::query_as::<_, Auction>(
         "INSERT INTO auctions (
+            id,
             site_id,
             possession_start_at,
             possession_end_at,
             start_at,
             auction_params_id,
+            random_seed,
+            sandbox,
+            winner_constraints,
             created_at,
             updated_at
-        ) VALUES ($1, $2, $3, $4, $5, $6, $6) RETURNING *",
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $10) RETURNING *",
     )
+    .bind(id_source.new_id())
     .bind(details.site_id)
     .bind(details.possession_start_at.to_sqlx())
     .bind(details.possession_end_at.to_sqlx())
     .bind(details.start_at.map(|t| t.to_sqlx()))
     .bind(auction_params_id)
+    .bind(new_random_seed())
+    .bind(details.sandbox)
+    .bind(Json(details.winner_constraints.clone()))
     .bind(time_source.now().to_sqlx())
     .fetch_one(&mut *tx)
     .await?
@@ -299,12 +347,181 @@ pub async fn create_auction(
     Ok(auction_id)
 }
 
+/// Create a copy of an auction with new times, through the same checks as
+/// [`create_auction`]. The source may have concluded or been canceled.
+pub async fn recreate_auction(
+    details: &payloads::requests::RecreateAuction,
+    user_id: &UserId,
+    default_bounds: &payloads::RoundDurationBounds,
+    pool: &PgPool,
+    time_source: &TimeSource,
+    id_source: &IdSource,
+) -> Result<payloads::responses::Auction, StoreError> {
+    let (source, _) = get_validated_auction(
+        &details.source_auction_id,
+        user_id,
+        PermissionLevel::Coleader,
+        pool,
+    )
+    .await?;
+
+    let (possession_start_at, possession_end_at, start_at) = match details
+        .shift_by
+    {
+        Some(span) => {
+            if details.possession_start_at.is_some()
+                || details.possession_end_at.is_some()
+                || details.start_at.is_some()
+            {
+                return Err(ApiError::InvalidRequestBody(
+                    "shift_by can't be combined with explicit times".into(),
+                )
+                .into());
+            }
+            let timezone: Option<String> =
+                sqlx::query_scalar("SELECT timezone FROM sites WHERE id = $1")
+                    .bind(source.site_id)
+                    .fetch_one(pool)
+                    .await?;
+            let tz = timezone
+                .as_deref()
+                .and_then(|tz| jiff::tz::TimeZone::get(tz).ok())
+                .unwrap_or(jiff::tz::TimeZone::UTC);
+            let shift = |t: jiff::Timestamp| {
+                t.to_zoned(tz.clone())
+                    .checked_add(span)
+                    .map(|z| z.timestamp())
+                    .map_err(|e| ApiError::InvalidRequestBody(e.to_string()))
+            };
+            (
+                shift(source.possession_start_at)?,
+                shift(source.possession_end_at)?,
+                source.start_at.map(shift).transpose()?,
+            )
+        }
+        None => {
+            let (Some(start), Some(end)) =
+                (details.possession_start_at, details.possession_end_at)
+            else {
+                return Err(ApiError::InvalidRequestBody(
+                    "possession times are required without shift_by".into(),
+                )
+                .into());
+            };
+            (start, end, details.start_at)
+        }
+    };
+
+    let auction_params = sqlx::query_as::<_, AuctionParams>(
+        "SELECT * FROM auction_params WHERE id = $1",
+    )
+    .bind(&source.auction_params_id)
+    .fetch_one(pool)
+    .await?;
+
+    let auction = payloads::Auction {
+        site_id: source.site_id,
+        possession_start_at,
+        possession_end_at,
+        start_at,
+        auction_params: auction_params.into(),
+        sandbox: source.sandbox,
+        winner_constraints: source.winner_constraints.0,
+    };
+    let auction_id = create_auction(
+        &auction,
+        user_id,
+        default_bounds,
+        pool,
+        time_source,
+        id_source,
+    )
+    .await?;
+    read_auction(&auction_id, user_id, &Default::default(), pool).await
+}
+
+/// Seed for a new auction's tie-breaks. Fixed at 0 under mock time, which the
+/// scheduler treats as unseeded: ties go to the first bidder by username, so
+/// tests that depend on tie-break outcomes are reproducible.
+fn new_random_seed() -> i64 {
+    #[cfg(feature = "mock-time")]
+    {
+        0
+    }
+    #[cfg(not(feature = "mock-time"))]
+    {
+        use rand_core::RngCore;
+        rand_core::OsRng.next_u64() as i64
+    }
+}
+
+/// Predict the eligibility schedule `params` would produce on a site, without
+/// persisting anything. Thresholds follow the same breakpoint lookup the
+/// scheduler uses when it creates each round.
+pub async fn simulate_auction(
+    site_id: &SiteId,
+    params: &payloads::AuctionParams,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<payloads::responses::SimulationResult, StoreError> {
+    let community_id = get_site_community_id(site_id, pool).await?;
+    let actor = get_validated_member(user_id, &community_id, pool).await?;
+
+    if !PermissionLevel::Coleader.validate(actor.0.role) {
+        return Err(ApiError::InsufficientPermissions {
+            required: PermissionLevel::Coleader,
+        }
+        .into());
+    }
+
+    params.validate().map_err(ApiError::InvalidAuctionParams)?;
+
+    let total_eligibility_points: f64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(eligibility_points), 0) FROM spaces
+        WHERE site_id = $1 AND is_available AND deleted_at IS NULL",
+    )
+    .bind(site_id)
+    .fetch_one(pool)
+    .await?;
+
+    // Thresholds are constant after the last breakpoint. Breakpoints past the
+    // round cap can never apply, since the auction is cancelled first.
+    let progression = &params.activity_rule_params.eligibility_progression;
+    let last_round = progression
+        .last()
+        .map_or(0, |(round, _)| *round)
+        .min(payloads::MAX_AUCTION_ROUNDS - 1);
+    let rounds: Vec<_> = (0..=last_round)
+        .map(|round_num| {
+            let eligibility_threshold =
+                payloads::eligibility_for_round_num(round_num, progression);
+            payloads::responses::SimulatedRound {
+                round_num,
+                eligibility_threshold,
+                activity_for_all_spaces: eligibility_threshold
+                    * total_eligibility_points,
+            }
+        })
+        .collect();
+    let rounds_until_full_eligibility = rounds
+        .iter()
+        .find(|round| round.eligibility_threshold >= 1.0)
+        .map(|round| round.round_num);
+
+    Ok(payloads::responses::SimulationResult {
+        total_eligibility_points,
+        rounds_until_full_eligibility,
+        rounds,
+    })
+}
+
 pub async fn read_auction(
     auction_id: &AuctionId,
     user_id: &UserId,
+    options: &payloads::requests::ReadOptions,
     pool: &PgPool,
 ) -> Result<payloads::responses::Auction, StoreError> {
-    let (auction, _) = get_validated_auction(
+    let (auction, actor) = get_validated_auction(
         auction_id,
         user_id,
         PermissionLevel::Member,
@@ -319,7 +536,11 @@ pub async fn read_auction(
     .fetch_one(pool)
     .await?;
 
-    Ok(auction.with_params(auction_params))
+    let mut response = auction.with_params(auction_params, &actor);
+    if options.includes(payloads::requests::Include::CommunityRole) {
+        response.community_role = Some(actor.community_role());
+    }
+    Ok(response)
 }
 
 pub async fn delete_auction(
@@ -513,6 +734,14 @@ pub async fn cancel_auction(
     )
     .await?;
 
+    create_auction_report(
+        auction_id,
+        AuctionConclusion::Canceled,
+        &mut tx,
+        time_source,
+    )
+    .await?;
+
     tx.commit().await?;
 
     tracing::info!(%auction_id, "auction canceled");
@@ -520,6 +749,130 @@ pub async fn cancel_auction(
     Ok(())
 }
 
+/// Record the report of an auction concluding in `tx`, after its final
+/// results are settled. Only an [`AuctionConclusion::Ended`] auction awards
+/// its final round's results. A second report for the same auction is
+/// ignored.
+pub async fn create_auction_report(
+    auction_id: &AuctionId,
+    conclusion: AuctionConclusion,
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    sqlx::query(
+        "INSERT INTO auction_reports (
+            auction_id, conclusion, total_revenue, awarded_spaces,
+            unawarded_spaces, distinct_winners, round_count, created_at
+        )
+        SELECT a.id, $2, COALESCE(w.revenue, 0), w.awarded,
+            GREATEST(s.available - w.awarded, 0), w.winners,
+            (SELECT COUNT(*)::INTEGER FROM auction_rounds
+                WHERE auction_id = a.id),
+            $4
+        FROM auctions a
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*)::INTEGER AS available FROM spaces
+            WHERE site_id = a.site_id
+                AND is_available = true
+                AND deleted_at IS NULL
+        ) s
+        CROSS JOIN LATERAL (
+            SELECT SUM(r.value) AS revenue,
+                COUNT(*)::INTEGER AS awarded,
+                COUNT(DISTINCT r.winning_user_id)::INTEGER AS winners
+            FROM round_space_results r
+            WHERE $3 AND r.round_id = (
+                SELECT id FROM auction_rounds
+                WHERE auction_id = a.id
+                ORDER BY round_num DESC
+                LIMIT 1
+            )
+        ) w
+        WHERE a.id = $1
+        ON CONFLICT (auction_id) DO NOTHING",
+    )
+    .bind(auction_id)
+    .bind(conclusion)
+    .bind(conclusion == AuctionConclusion::Ended)
+    .bind(time_source.now().to_sqlx())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// An auction's report, or None if it hasn't concluded. Visible to all
+/// members.
+pub async fn get_auction_report(
+    auction_id: &AuctionId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Option<payloads::responses::AuctionReport>, StoreError> {
+    let (_, _) = get_validated_auction(
+        auction_id,
+        user_id,
+        PermissionLevel::Member,
+        pool,
+    )
+    .await?;
+
+    Ok(sqlx::query_as::<_, payloads::responses::AuctionReport>(
+        "SELECT * FROM auction_reports WHERE auction_id = $1",
+    )
+    .bind(auction_id)
+    .fetch_optional(pool)
+    .await?)
+}
+
+/// Set the moderators' notes and internal status on an auction. Either can
+/// be changed at any point in the auction's life.
+pub async fn update_auction_notes(
+    details: &payloads::requests::UpdateAuctionNotes,
+    user_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    get_validated_auction(
+        &details.auction_id,
+        user_id,
+        PermissionLevel::Moderator,
+        pool,
+    )
+    .await?;
+
+    if let Some(notes) = &details.moderator_notes
+        && notes.len() > payloads::MAX_AUCTION_MODERATOR_NOTES_LENGTH
+    {
+        return Err(ApiError::ModeratorNotesTooLong {
+            size: notes.len(),
+            max: payloads::MAX_AUCTION_MODERATOR_NOTES_LENGTH,
+        }
+        .into());
+    }
+    if let Some(status) = &details.internal_status
+        && status.len() > payloads::MAX_AUCTION_INTERNAL_STATUS_LENGTH
+    {
+        return Err(ApiError::InternalStatusTooLong {
+            size: status.len(),
+            max: payloads::MAX_AUCTION_INTERNAL_STATUS_LENGTH,
+        }
+        .into());
+    }
+
+    sqlx::query(
+        "UPDATE auctions
+        SET moderator_notes = $1, internal_status = $2, updated_at = $3
+        WHERE id = $4",
+    )
+    .bind(&details.moderator_notes)
+    .bind(&details.internal_status)
+    .bind(time_source.now().to_sqlx())
+    .bind(details.auction_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
 pub async fn list_auctions(
     site_id: &SiteId,
     user_id: &UserId,
@@ -531,7 +884,7 @@ pub async fn list_auctions(
         .fetch_one(pool)
         .await?;
 
-    let _ = get_validated_member(user_id, &site.community_id, pool).await?;
+    let actor = get_validated_member(user_id, &site.community_id, pool).await?;
 
     let auctions = sqlx::query_as::<_, Auction>(
         "SELECT * FROM auctions WHERE site_id = $1 ORDER BY start_at DESC",
@@ -550,7 +903,40 @@ pub async fn list_auctions(
         .fetch_one(pool)
         .await?;
 
-        responses.push(auction.with_params(auction_params));
+        responses.push(auction.with_params(auction_params, &actor));
+    }
+
+    Ok(responses)
+}
+
+/// Every auction on every site of a community, newest start first.
+pub async fn list_community_auctions(
+    community_id: &CommunityId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::Auction>, StoreError> {
+    let actor = get_validated_member(user_id, community_id, pool).await?;
+
+    let auctions = sqlx::query_as::<_, Auction>(
+        "SELECT a.* FROM auctions a
+        JOIN sites s ON a.site_id = s.id
+        WHERE s.community_id = $1
+        ORDER BY a.start_at DESC, a.id",
+    )
+    .bind(community_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut responses = Vec::new();
+    for auction in auctions {
+        let auction_params = sqlx::query_as::<_, AuctionParams>(
+            "SELECT * FROM auction_params WHERE id = $1",
+        )
+        .bind(&auction.auction_params_id)
+        .fetch_one(pool)
+        .await?;
+
+        responses.push(auction.with_params(auction_params, &actor));
     }
 
     Ok(responses)
@@ -565,8 +951,9 @@ pub async fn get_auction_round(
         "SELECT * FROM auction_rounds WHERE id = $1",
     )
     .bind(round_id)
-    .fetch_one(pool)
-    .await?;
+    .fetch_optional(pool)
+    .await?
+    .ok_or(ApiError::AuctionRoundNotFound)?;
 
     // Validate user has access to this auction's community
     let auction =
@@ -576,34 +963,206 @@ pub async fn get_auction_round(
             .await?;
 
     let community_id = get_site_community_id(&auction.site_id, pool).await?;
-    let _ = get_validated_member(user_id, &community_id, pool).await?;
+    get_member_or_not_found(
+        user_id,
+        &community_id,
+        ApiError::AuctionRoundNotFound,
+        pool,
+    )
+    .await?;
 
     Ok(round.into_response())
 }
 
 pub async fn list_auction_rounds(
-    auction_id: &AuctionId,
+    details: &payloads::requests::ListAuctionRounds,
     user_id: &UserId,
     pool: &PgPool,
 ) -> Result<Vec<payloads::responses::AuctionRound>, StoreError> {
     // First validate user has access to this auction's community
     let auction =
         sqlx::query_as::<_, Auction>("SELECT * FROM auctions WHERE id = $1")
-            .bind(auction_id)
+            .bind(details.auction_id)
             .fetch_one(pool)
             .await?;
 
     let community_id = get_site_community_id(&auction.site_id, pool).await?;
     let _ = get_validated_member(user_id, &community_id, pool).await?;
 
+    let limit = details
+        .limit
+        .map(|limit| limit.clamp(1, payloads::requests::MAX_PAGE_LIMIT));
+    // NULL bounds and a NULL limit leave the range open.
+    let rounds = sqlx::query_as::<_, AuctionRound>(
+        "SELECT * FROM auction_rounds
+        WHERE auction_id = $1
+            AND ($2::INTEGER IS NULL OR round_num >= $2)
+            AND ($3::INTEGER IS NULL OR round_num <= $3)
+        ORDER BY round_num, id
+        LIMIT $4",
+    )
+    .bind(details.auction_id)
+    .bind(details.from_round)
+    .bind(details.to_round)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rounds.into_iter().map(|r| r.into_response()).collect())
+}
+
+/// Rounds of an auction created or updated after `since` (all of them when
+/// None), along with the server time to pass as the next `since`. The time
+/// is read before the query, so a round written in between is returned
+/// again by the next poll rather than skipped.
+pub async fn list_auction_rounds_since(
+    auction_id: &AuctionId,
+    since: Option<jiff::Timestamp>,
+    user_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<payloads::responses::AuctionRoundsSince, StoreError> {
+    get_validated_auction(auction_id, user_id, PermissionLevel::Member, pool)
+        .await?;
+
+    let server_time = time_source.now();
     let rounds = sqlx::query_as::<_, AuctionRound>(
-        "SELECT * FROM auction_rounds WHERE auction_id = $1 ORDER BY round_num",
+        "SELECT * FROM auction_rounds
+        WHERE auction_id = $1
+            AND ($2::TIMESTAMPTZ IS NULL OR updated_at > $2)
+        ORDER BY round_num, id",
     )
     .bind(auction_id)
+    .bind(since.map(|since| since.to_sqlx()))
     .fetch_all(pool)
     .await?;
 
-    Ok(rounds.into_iter().map(|r| r.into_response()).collect())
+    Ok(payloads::responses::AuctionRoundsSince {
+        rounds: rounds.into_iter().map(|r| r.into_response()).collect(),
+        server_time,
+    })
+}
+
+/// Each round's eligibility threshold, in round order, for charting the
+/// progression as it actually played out.
+pub async fn list_round_thresholds(
+    auction_id: &AuctionId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::RoundThreshold>, StoreError> {
+    get_validated_auction(auction_id, user_id, PermissionLevel::Member, pool)
+        .await?;
+
+    let thresholds = sqlx::query_as::<_, payloads::responses::RoundThreshold>(
+        "SELECT round_num, eligibility_threshold FROM auction_rounds
+        WHERE auction_id = $1
+        ORDER BY round_num",
+    )
+    .bind(auction_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(thresholds)
+}
+
+/// Spaces the user is standing winner on in the auction's latest processed
+/// round. Results carry forward each round, so that round alone holds every
+/// standing win.
+pub async fn list_my_standing_wins(
+    auction_id: &AuctionId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::StandingWin>, StoreError> {
+    get_validated_auction(auction_id, user_id, PermissionLevel::Member, pool)
+        .await?;
+
+    let wins = sqlx::query_as::<_, payloads::responses::StandingWin>(
+        "SELECT rsr.space_id, sp.name AS space_name, latest.round_num,
+            rsr.value
+        FROM (
+            SELECT ar.id, ar.round_num FROM auction_rounds ar
+            WHERE ar.auction_id = $1
+            AND EXISTS (
+                SELECT 1 FROM round_space_results
+                WHERE round_id = ar.id
+            )
+            ORDER BY ar.round_num DESC
+            LIMIT 1
+        ) latest
+        JOIN round_space_results rsr ON rsr.round_id = latest.id
+        JOIN spaces sp ON sp.id = rsr.space_id
+        WHERE rsr.winning_user_id = $2
+        ORDER BY sp.name",
+    )
+    .bind(auction_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(wins)
+}
+
+/// Standing price of a space in its site's ongoing auction. Mid-round, a bid
+/// on the space means it will take the round's ask price; otherwise the
+/// price is the latest processed round's result. None if there's no ongoing
+/// auction or nobody has bid on the space yet.
+pub async fn get_space_current_price(
+    space_id: &SpaceId,
+    user_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Option<Decimal>, StoreError> {
+    let (space, _) =
+        get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
+            .await?;
+    let now = time_source.now().to_sqlx();
+
+    let current_round = sqlx::query_as::<_, AuctionRound>(
+        "SELECT ar.* FROM auction_rounds ar
+        JOIN auctions a ON ar.auction_id = a.id
+        WHERE a.site_id = $1
+        AND a.end_at IS NULL
+        AND $2 >= ar.start_at AND $2 < ar.end_at
+        ORDER BY ar.start_at DESC
+        LIMIT 1",
+    )
+    .bind(space.site_id)
+    .bind(now)
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(round) = &current_round {
+        let has_bid: bool = sqlx::query_scalar(
+            "SELECT EXISTS(
+                SELECT 1 FROM bids WHERE space_id = $1 AND round_id = $2
+            )",
+        )
+        .bind(space_id)
+        .bind(round.id)
+        .fetch_one(pool)
+        .await?;
+        if has_bid {
+            let mut tx = pool.begin().await?;
+            let prices = round_ask_prices_tx(round, &mut tx).await?;
+            tx.commit().await?;
+            return Ok(prices.get(space_id).copied());
+        }
+    }
+
+    Ok(sqlx::query_scalar::<_, Decimal>(
+        "SELECT rsr.value FROM round_space_results rsr
+        JOIN auction_rounds ar ON rsr.round_id = ar.id
+        JOIN auctions a ON ar.auction_id = a.id
+        WHERE rsr.space_id = $1
+        AND a.end_at IS NULL
+        AND a.start_at <= $2
+        ORDER BY ar.start_at DESC
+        LIMIT 1",
+    )
+    .bind(space_id)
+    .bind(now)
+    .fetch_optional(pool)
+    .await?)
 }
 
 pub async fn get_round_space_result(
@@ -613,7 +1172,7 @@ pub async fn get_round_space_result(
     pool: &PgPool,
 ) -> Result<payloads::RoundSpaceResult, StoreError> {
     // Verify user has access to the space
-    get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
+    validate_space_access(space_id, user_id, PermissionLevel::Member, pool)
         .await?;
 
     // Fetch the round_space_result
@@ -714,6 +1273,138 @@ pub async fn list_round_space_results_for_round(
     .await
 }
 
+/// The ask price of each biddable space in `round`, keyed by space.
+///
+/// Prices are written once per space and round: the previous round's value
+/// plus this round's bid increment, or the reserve price if no round has
+/// produced a value yet. The scheduler writes them when it creates the round;
+/// this fills in any that are missing (spaces made available mid-round, or
+/// rounds created before prices were stored), so every reader sees the same
+/// numbers.
+pub async fn round_ask_prices_tx(
+    round: &AuctionRound,
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<HashMap<SpaceId, Decimal>, StoreError> {
+    let unpriced = sqlx::query_as::<_, (SpaceId, payloads::ReservePrice)>(
+        "SELECT s.id, s.reserve_price FROM spaces s
+        JOIN auctions a ON s.site_id = a.site_id
+        WHERE a.id = $1
+        AND s.is_available = true
+        AND s.deleted_at IS NULL
+        AND NOT EXISTS (
+            SELECT 1 FROM round_space_prices
+            WHERE round_id = $2 AND space_id = s.id
+        )",
+    )
+    .bind(round.auction_id)
+    .bind(round.id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    if !unpriced.is_empty() {
+        let auction_params = sqlx::query_as::<_, AuctionParams>(
+            "SELECT ap.* FROM auction_params ap
+            JOIN auctions a ON ap.id = a.auction_params_id
+            WHERE a.id = $1",
+        )
+        .bind(round.auction_id)
+        .fetch_one(&mut **tx)
+        .await?;
+        let bid_increment =
+            auction_params.bid_increment_for_round(round.round_num);
+
+        let prev_values: HashMap<SpaceId, Decimal> =
+            sqlx::query_as::<_, (SpaceId, Decimal)>(
+                "SELECT rsr.space_id, rsr.value
+                FROM round_space_results rsr
+                JOIN auction_rounds ar ON rsr.round_id = ar.id
+                WHERE ar.auction_id = $1 AND ar.round_num = $2",
+            )
+            .bind(round.auction_id)
+            .bind(round.round_num - 1)
+            .fetch_all(&mut **tx)
+            .await?
+            .into_iter()
+            .collect();
+
+        let (space_ids, ask_prices): (Vec<SpaceId>, Vec<Decimal>) = unpriced
+            .into_iter()
+            .map(|(space_id, reserve_price)| {
+                let ask = payloads::next_bid_amount(
+                    prev_values.get(&space_id).copied(),
+                    bid_increment,
+                    reserve_price,
+                );
+                (space_id, ask)
+            })
+            .unzip();
+
+        sqlx::query(
+            "INSERT INTO round_space_prices (round_id, space_id, ask_price)
+            SELECT $1, * FROM UNNEST($2::uuid[], $3::numeric[])
+            ON CONFLICT (round_id, space_id) DO NOTHING",
+        )
+        .bind(round.id)
+        .bind(space_ids)
+        .bind(ask_prices)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(sqlx::query_as::<_, (SpaceId, Decimal)>(
+        "SELECT space_id, ask_price FROM round_space_prices
+        WHERE round_id = $1",
+    )
+    .bind(round.id)
+    .fetch_all(&mut **tx)
+    .await?
+    .into_iter()
+    .collect())
+}
+
+/// The full price sheet for a round: what a bid on each space costs.
+pub async fn get_round_prices(
+    round_id: &AuctionRoundId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::RoundSpacePrice>, StoreError> {
+    let auction_round = sqlx::query_as::<_, AuctionRound>(
+        "SELECT * FROM auction_rounds WHERE id = $1",
+    )
+    .bind(round_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => {
+            StoreError::Api(ApiError::AuctionRoundNotFound)
+        }
+        e => e.into(),
+    })?;
+
+    let auction =
+        sqlx::query_as::<_, Auction>("SELECT * FROM auctions WHERE id = $1")
+            .bind(auction_round.auction_id)
+            .fetch_one(pool)
+            .await?;
+
+    let community_id = get_site_community_id(&auction.site_id, pool).await?;
+    let _ = get_validated_member(user_id, &community_id, pool).await?;
+
+    let mut tx = pool.begin().await?;
+    let prices = round_ask_prices_tx(&auction_round, &mut tx).await?;
+    tx.commit().await?;
+
+    let mut prices: Vec<_> = prices
+        .into_iter()
+        .map(|(space_id, ask_price)| payloads::RoundSpacePrice {
+            space_id,
+            ask_price,
+        })
+        .collect();
+    prices.sort_by_key(|p| p.space_id.0);
+    Ok(prices)
+}
+
 pub async fn create_bid(
     space_id: &SpaceId,
     round_id: &AuctionRoundId,
@@ -736,8 +1427,8 @@ pub async fn create_bid_tx(
     time_source: &TimeSource,
     pool: &PgPool, // for get_validated_space
 ) -> Result<(), StoreError> {
-    // Get the space to validate user permissions and check availability
-    let (space, _) =
+    // Validate user permissions on the space and check availability
+    let (space, actor) =
         get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
             .await?;
 
@@ -752,12 +1443,14 @@ pub async fn create_bid_tx(
     }
 
     // Check if the site has been deleted
-    let site = sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
-        .bind(space.site_id)
-        .fetch_one(pool)
-        .await?;
+    let site_deleted = sqlx::query_scalar::<_, bool>(
+        "SELECT deleted_at IS NOT NULL FROM sites WHERE id = $1",
+    )
+    .bind(space.site_id)
+    .fetch_one(pool)
+    .await?;
 
-    if site.deleted_at.is_some() {
+    if site_deleted {
         return Err(ApiError::SiteDeleted.into());
     }
 
@@ -795,22 +1488,20 @@ pub async fn create_bid_tx(
 
         // Check if user is already the standing high bidder from the previous
         // round
-        let is_winning = sqlx::query_scalar::<_, bool>(
-            "SELECT EXISTS (
-                SELECT 1 FROM round_space_results
-                WHERE round_id = $1
-                AND space_id = $2
-                AND winning_user_id = $3
-            )",
+        let winning_price = sqlx::query_scalar::<_, Decimal>(
+            "SELECT value FROM round_space_results
+            WHERE round_id = $1
+            AND space_id = $2
+            AND winning_user_id = $3",
         )
         .bind(previous_round.id)
         .bind(space_id)
         .bind(user_id)
-        .fetch_one(&mut **tx)
+        .fetch_optional(&mut **tx)
         .await?;
 
-        if is_winning {
-            return Err(ApiError::AlreadyWinningSpace.into());
+        if let Some(price) = winning_price {
+            return Err(ApiError::AlreadyWinningSpace { price }.into());
         }
 
         // Resolve the user's eligibility for this round the same way the read
@@ -865,66 +1556,30 @@ pub async fn create_bid_tx(
         }
     }
 
-    // Get bid increment from auction params
     let auction =
         sqlx::query_as::<_, Auction>("SELECT * FROM auctions WHERE id = $1")
             .bind(round.auction_id)
             .fetch_one(&mut **tx)
             .await?;
 
-    let auction_params = sqlx::query_as::<_, AuctionParams>(
-        "SELECT * FROM auction_params WHERE id = $1",
-    )
-    .bind(auction.auction_params_id)
-    .fetch_one(&mut **tx)
-    .await?;
-
-    // Calculate the amount this bid will lock
-    // Get previous round's value for this space (if any)
-    let prev_value: Option<Decimal> = if round.round_num > 0 {
-        let prev_round_id: Option<payloads::AuctionRoundId> =
-            sqlx::query_scalar(
-                "SELECT id FROM auction_rounds
-                WHERE auction_id = $1 AND round_num = $2",
-            )
-            .bind(round.auction_id)
-            .bind(round.round_num - 1)
-            .fetch_optional(&mut **tx)
-            .await?;
-
-        if let Some(prev_id) = prev_round_id {
-            sqlx::query_scalar(
-                "SELECT value FROM round_space_results
-                WHERE round_id = $1 AND space_id = $2",
-            )
-            .bind(prev_id)
-            .bind(space_id)
-            .fetch_optional(&mut **tx)
-            .await?
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-
-    let bid_amount = payloads::next_bid_amount(
-        prev_value,
-        auction_params.bid_increment,
-        space.reserve_price,
-    );
+    // The amount this bid will lock is the space's ask price for the round
+    let bid_amount = round_ask_prices_tx(&round, tx)
+        .await?
+        .get(space_id)
+        .copied()
+        .ok_or(ApiError::SpaceNotAvailable)?;
 
     // Check if user has sufficient credit for this bid. Skip when the bid
     // amount is non-positive: a chore bid doesn't put the bidder on the
     // hook for anything (and the locked-balance computation similarly
     // clamps chore bids to zero rather than treating them as freed
-    // credit).
-    if bid_amount > Decimal::ZERO {
+    // credit). Sandbox auctions never settle, so there's nothing to cover.
+    if bid_amount > Decimal::ZERO && !auction.sandbox {
         // Lock the bidder's account row for the credit check; without it,
         // a settlement or transfer committing mid-check could tear the
         // balance/locked-balance read and overstate available credit.
         let mut locked = currency::lock_account_tx(
-            &site.community_id,
+            &actor.0.community_id,
             payloads::AccountOwner::Member(*user_id),
             tx,
         )
@@ -938,16 +1593,23 @@ pub async fn create_bid_tx(
         .await?;
     }
 
-    // Create the bid
-    sqlx::query(
-        "INSERT INTO bids (space_id, round_id, user_id, created_at, updated_at) VALUES ($1, $2, $3, $4, $4)",
+    // Create the bid. A double submit hits the (space, round, user) primary
+    // key; report it as a duplicate rather than a generic constraint error.
+    let inserted = sqlx::query(
+        "INSERT INTO bids (space_id, round_id, user_id, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $4)
+        ON CONFLICT (space_id, round_id, user_id) DO NOTHING",
     )
     .bind(space_id)
     .bind(round_id)
     .bind(user_id)
     .bind(time_source.now().to_sqlx())
     .execute(&mut **tx)
-    .await?;
+    .await?
+    .rows_affected();
+    if inserted == 0 {
+        return Err(ApiError::DuplicateBid.into());
+    }
 
     crate::pubsub::emit(
         tx,
@@ -968,10 +1630,9 @@ pub async fn get_bid(
     user_id: &UserId,
     pool: &PgPool,
 ) -> Result<Bid, StoreError> {
-    // Get the space to validate user permissions
-    let (_, _) =
-        get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
-            .await?;
+    // Validate user permissions on the space
+    validate_space_access(space_id, user_id, PermissionLevel::Member, pool)
+        .await?;
 
     let bid = sqlx::query_as::<_, Bid>(
         "SELECT * FROM bids WHERE space_id = $1 AND round_id = $2 AND user_id = $3",
@@ -1028,6 +1689,178 @@ pub async fn list_bids(
     Ok(bids)
 }
 
+/// The user's bids across every round of an auction, newest first, one
+/// keyset page at a time.
+pub async fn get_bid_history(
+    details: &payloads::requests::GetBidHistory,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<payloads::responses::BidHistoryPage, StoreError> {
+    let _ = get_validated_auction(
+        &details.auction_id,
+        user_id,
+        PermissionLevel::Member,
+        pool,
+    )
+    .await?;
+
+    #[derive(FromRow)]
+    struct BidRow {
+        id: Uuid,
+        #[sqlx(flatten)]
+        bid: Bid,
+    }
+
+    let limit = details.limit.clamp(1, payloads::requests::MAX_PAGE_LIMIT);
+    let (cursor_created_at, cursor_id) =
+        cursor_bounds(details.cursor.as_ref())?;
+    // Fetch one extra row to learn whether another page follows.
+    let mut rows = sqlx::query_as::<_, BidRow>(&format!(
+        "SELECT b.* FROM bids b
+        JOIN auction_rounds ar ON b.round_id = ar.id
+        WHERE ar.auction_id = $1
+            AND b.user_id = $2
+            AND {}
+        ORDER BY b.created_at DESC, b.id DESC
+        LIMIT $5",
+        keyset_after("b.created_at", "b.id", 3),
+    ))
+    .bind(details.auction_id)
+    .bind(user_id)
+    .bind(cursor_created_at)
+    .bind(cursor_id)
+    .bind(limit + 1)
+    .fetch_all(pool)
+    .await?;
+
+    let next_cursor = if rows.len() as i64 > limit {
+        rows.truncate(limit as usize);
+        rows.last()
+            .map(|row| payloads::Cursor::encode(row.bid.created_at, row.id))
+    } else {
+        None
+    };
+
+    Ok(payloads::responses::BidHistoryPage {
+        bids: rows.into_iter().map(|row| row.bid).collect(),
+        next_cursor,
+    })
+}
+
+/// Every bid on a space across the rounds of a concluded auction, withdrawn
+/// ones included, in the order they were placed. For moderators settling
+/// disputes, so only available once the bids can no longer change.
+pub async fn get_space_bid_log(
+    details: &payloads::requests::SpaceBidLog,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::SpaceBidLogEntry>, StoreError> {
+    let (auction, _) = get_validated_auction(
+        &details.auction_id,
+        user_id,
+        PermissionLevel::Moderator,
+        pool,
+    )
+    .await?;
+    if auction.end_at.is_none() {
+        return Err(ApiError::AuctionNotConcluded.into());
+    }
+
+    let space_site_id = sqlx::query_scalar::<_, SiteId>(
+        "SELECT site_id FROM spaces WHERE id = $1",
+    )
+    .bind(details.space_id)
+    .fetch_optional(pool)
+    .await?;
+    if space_site_id != Some(auction.site_id) {
+        return Err(ApiError::SpaceNotFound.into());
+    }
+
+    Ok(sqlx::query_as::<_, payloads::responses::SpaceBidLogEntry>(
+        "SELECT ar.round_num, u.username, b.created_at, false AS withdrawn
+        FROM bids b
+        JOIN auction_rounds ar ON b.round_id = ar.id
+        JOIN users u ON b.user_id = u.id
+        WHERE ar.auction_id = $1 AND b.space_id = $2
+        UNION ALL
+        SELECT ar.round_num, u.username, w.bid_created_at, true
+        FROM bid_withdrawals w
+        JOIN auction_rounds ar ON w.round_id = ar.id
+        JOIN users u ON w.user_id = u.id
+        WHERE ar.auction_id = $1 AND w.space_id = $2
+        ORDER BY created_at, round_num, username",
+    )
+    .bind(details.auction_id)
+    .bind(details.space_id)
+    .fetch_all(pool)
+    .await?)
+}
+
+/// The receipt for a space's winner in the final round of a concluded (not
+/// canceled, not sandbox) auction. Only the winner and moderators may get it.
+pub async fn get_possession_receipt(
+    details: &payloads::requests::PossessionReceipt,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<payloads::responses::PossessionReceipt, StoreError> {
+    let (auction, actor) = get_validated_auction(
+        &details.auction_id,
+        user_id,
+        PermissionLevel::Member,
+        pool,
+    )
+    .await?;
+    if auction.end_at.is_none() {
+        return Err(ApiError::AuctionNotConcluded.into());
+    }
+
+    #[derive(FromRow)]
+    struct ReceiptRow {
+        winning_user_id: UserId,
+        #[sqlx(flatten)]
+        receipt: payloads::responses::PossessionReceipt,
+    }
+
+    let row = sqlx::query_as::<_, ReceiptRow>(
+        "SELECT rsr.winning_user_id,
+            c.name AS community_name,
+            s.name AS site_name,
+            sp.name AS space_name,
+            COALESCE(NULLIF(TRIM(u.display_name), ''), u.username)
+                AS holder_name,
+            a.possession_start_at,
+            a.possession_end_at,
+            rsr.value AS price
+        FROM auctions a
+        JOIN LATERAL (
+            SELECT ar.id
+            FROM auction_rounds ar
+            WHERE ar.auction_id = a.id
+            ORDER BY ar.round_num DESC
+            LIMIT 1
+        ) final_round ON true
+        JOIN round_space_results rsr ON rsr.round_id = final_round.id
+        JOIN spaces sp ON sp.id = rsr.space_id
+        JOIN sites s ON s.id = a.site_id
+        JOIN communities c ON c.id = s.community_id
+        JOIN users u ON u.id = rsr.winning_user_id
+        WHERE a.id = $1
+            AND rsr.space_id = $2
+            AND NOT a.was_canceled
+            AND NOT a.sandbox",
+    )
+    .bind(details.auction_id)
+    .bind(details.space_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(ApiError::RoundSpaceResultNotFound)?;
+
+    if row.winning_user_id != *user_id && !actor.0.role.is_ge_moderator() {
+        return Err(ApiError::NotPossessionHolder.into());
+    }
+    Ok(row.receipt)
+}
+
 pub async fn delete_bid(
     space_id: &SpaceId,
     round_id: &AuctionRoundId,
@@ -1035,10 +1868,9 @@ pub async fn delete_bid(
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> Result<(), StoreError> {
-    // Get the space to validate user permissions
-    let (_, _) =
-        get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
-            .await?;
+    // Validate user permissions on the space
+    validate_space_access(space_id, user_id, PermissionLevel::Member, pool)
+        .await?;
 
     let mut tx = pool.begin().await?;
 
@@ -1064,15 +1896,30 @@ pub async fn delete_bid(
         return Err(ApiError::RoundEnded.into());
     }
 
-    // Delete the bid
-    sqlx::query(
-        "DELETE FROM bids WHERE space_id = $1 AND round_id = $2 AND user_id = $3",
+    // Delete the bid, keeping a record of it for the moderators' bid log
+    let bid_created_at = sqlx::query_scalar::<_, SqlxTs>(
+        "DELETE FROM bids WHERE space_id = $1 AND round_id = $2 AND user_id = $3
+        RETURNING created_at",
     )
     .bind(space_id)
     .bind(round_id)
     .bind(user_id)
-    .execute(&mut *tx)
+    .fetch_optional(&mut *tx)
     .await?;
+    if let Some(bid_created_at) = bid_created_at {
+        sqlx::query(
+            "INSERT INTO bid_withdrawals
+                (space_id, round_id, user_id, bid_created_at, withdrawn_at)
+            VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(space_id)
+        .bind(round_id)
+        .bind(user_id)
+        .bind(bid_created_at)
+        .bind(now.to_sqlx())
+        .execute(&mut *tx)
+        .await?;
+    }
 
     crate::pubsub::emit(
         &mut tx,
@@ -1089,11 +1936,133 @@ pub async fn delete_bid(
     Ok(())
 }
 
+/// Withdraw all of a user's bids in an ongoing round at once, recording each
+/// for the moderators' bid log as [`delete_bid`] does. Returns how many bids
+/// were withdrawn.
+pub async fn clear_my_bids(
+    round_id: &AuctionRoundId,
+    user_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<u64, StoreError> {
+    let mut tx = pool.begin().await?;
+
+    let round = sqlx::query_as::<_, AuctionRound>(
+        "SELECT * FROM auction_rounds WHERE id = $1",
+    )
+    .bind(round_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::AuctionRoundNotFound)?;
+    get_validated_auction(
+        &round.auction_id,
+        user_id,
+        PermissionLevel::Member,
+        pool,
+    )
+    .await?;
+
+    let now = time_source.now();
+    if now < round.start_at {
+        return Err(ApiError::RoundNotStarted.into());
+    }
+    if now >= round.end_at {
+        return Err(ApiError::RoundEnded.into());
+    }
+
+    let withdrawn = sqlx::query(
+        "WITH deleted AS (
+            DELETE FROM bids WHERE round_id = $1 AND user_id = $2
+            RETURNING space_id, created_at
+        )
+        INSERT INTO bid_withdrawals
+            (space_id, round_id, user_id, bid_created_at, withdrawn_at)
+        SELECT space_id, $1, $2, created_at, $3 FROM deleted",
+    )
+    .bind(round_id)
+    .bind(user_id)
+    .bind(now.to_sqlx())
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    if withdrawn > 0 {
+        crate::pubsub::emit(
+            &mut tx,
+            &payloads::AuctionEvent::BidsChanged {
+                auction_id: round.auction_id,
+                round_id: *round_id,
+                user_id: *user_id,
+            },
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(withdrawn)
+}
+
+/// Spaces won by a user in concluded auctions whose possession period hasn't
+/// ended yet, soonest-ending first. `ends_soon` is set when the possession
+/// ends within `reminder_window` of now.
+pub async fn list_user_possessions(
+    user_id: &UserId,
+    reminder_window: jiff::SignedDuration,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Vec<payloads::responses::Possession>, StoreError> {
+    let now = time_source.now();
+    let possessions =
+        sqlx::query_as::<_, payloads::responses::Possession>(&format!(
+            "SELECT * FROM ({POSSESSIONS_QUERY}) p
+            WHERE winning_user_id = $3
+            ORDER BY possession_end_at, space_name"
+        ))
+        .bind(now.to_sqlx())
+        .bind((now + reminder_window).to_sqlx())
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(possessions)
+}
+
+/// Current and upcoming possessions: each space's winner in the final round
+/// of a concluded (not canceled, not sandbox) auction whose possession period
+/// ends after `$1`. `ends_soon` compares against the reminder cutoff in `$2`.
+pub(super) const POSSESSIONS_QUERY: &str = "
+    SELECT a.id AS auction_id,
+        s.community_id,
+        s.id AS site_id,
+        s.name AS site_name,
+        s.timezone AS site_timezone,
+        sp.id AS space_id,
+        sp.name AS space_name,
+        a.possession_start_at,
+        a.possession_end_at,
+        a.possession_end_at <= $2 AS ends_soon,
+        rsr.winning_user_id
+    FROM auctions a
+    JOIN LATERAL (
+        SELECT ar.id
+        FROM auction_rounds ar
+        WHERE ar.auction_id = a.id
+        ORDER BY ar.round_num DESC
+        LIMIT 1
+    ) final_round ON true
+    JOIN round_space_results rsr ON rsr.round_id = final_round.id
+    JOIN spaces sp ON sp.id = rsr.space_id
+    JOIN sites s ON s.id = a.site_id
+    WHERE a.end_at IS NOT NULL
+        AND NOT a.was_canceled
+        AND NOT a.sandbox
+        AND a.possession_end_at > $1";
+
 pub async fn get_platform_stats(
     pool: &PgPool,
 ) -> Result<payloads::responses::PlatformStats, StoreError> {
     let auctions_held: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM auctions WHERE end_at IS NOT NULL",
+        "SELECT COUNT(*) FROM auctions WHERE end_at IS NOT NULL AND NOT sandbox",
     )
     .fetch_one(pool)
     .await?;
@@ -1105,7 +2074,7 @@ pub async fn get_platform_stats(
              SELECT DISTINCT ON (ar.auction_id) ar.id \
              FROM auction_rounds ar \
              INNER JOIN auctions a ON a.id = ar.auction_id \
-             WHERE a.end_at IS NOT NULL \
+             WHERE a.end_at IS NOT NULL AND NOT a.sandbox \
              ORDER BY ar.auction_id, ar.round_num DESC \
          )",
     )
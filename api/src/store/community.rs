@@ -1,35 +1,53 @@
 use super::*;
+use jiff::SignedDuration;
 use jiff_sqlx::ToSqlx;
-use payloads::{ApiError, CommunityId, InviteId, Role, UserId, requests};
+use payloads::{
+    ApiError, CommunityId, InviteId, Role, UserId, requests, sanitize,
+};
 use sqlx::{PgPool, Row};
 use tracing::Level;
 
+use crate::id_source::IdSource;
 use crate::time::TimeSource;
 
 /// Create a community and add the creating user as the leader.
+/// Create a community led by `user_id`, unless they already lead
+/// `max_led` communities.
 pub async fn create_community(
     details: &requests::CreateCommunity,
     user_id: UserId, // initial leader of community
+    max_led: Option<i64>,
     pool: &PgPool,
     time_source: &TimeSource,
+    id_source: &IdSource,
 ) -> Result<Community, StoreError> {
     let user = read_user(pool, &user_id).await?;
-    if !user.email_verified {
-        return Err(ApiError::UnverifiedEmail.into());
-    }
-    if details.name.len() > payloads::requests::COMMUNITY_NAME_MAX_LEN {
-        return Err(ApiError::FieldTooLong.into());
-    }
-    if let Some(desc) = &details.description
-        && desc.len() > payloads::MAX_COMMUNITY_DESCRIPTION_LENGTH
-    {
-        return Err(ApiError::CommunityDescriptionTooLong {
-            size: desc.len(),
-            max: payloads::MAX_COMMUNITY_DESCRIPTION_LENGTH,
+    require_verified_email(&user, SignedDuration::ZERO, time_source)?;
+    let (name, description) = sanitize_community_details(
+        &details.name,
+        details.description.as_deref(),
+    )?;
+    let mut tx = pool.begin().await?;
+
+    if let Some(max) = max_led {
+        // Serialize the user's community creation so concurrent requests
+        // can't both pass the cap.
+        sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR NO KEY UPDATE")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        let led = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM community_members
+            WHERE user_id = $1 AND role = $2",
+        )
+        .bind(user_id)
+        .bind(Role::Leader)
+        .fetch_one(&mut *tx)
+        .await?;
+        if led >= max {
+            return Err(ApiError::CommunityLimitReached { max }.into());
         }
-        .into());
     }
-    let mut tx = pool.begin().await?;
 
     currency::validate_currency_settings_quantization(&details.currency)?;
 
@@ -37,10 +55,10 @@ pub async fn create_community(
     // For IOU modes: if debts aren't callable, must have finite credit limit
     match &details.currency.mode_config {
         payloads::CurrencyModeConfig::DistributedClearing(cfg)
-        | payloads::CurrencyModeConfig::DeferredPayment(cfg) => {
-            if !cfg.debts_callable && cfg.default_credit_limit.is_none() {
-                return Err(StoreError::InvalidCurrencyConfiguration);
-            }
+        | payloads::CurrencyModeConfig::DeferredPayment(cfg)
+            if !cfg.debts_callable && cfg.default_credit_limit.is_none() =>
+        {
+            return Err(StoreError::InvalidCurrencyConfiguration);
         }
         // Temporarily blocked while the mode is extended with Stripe-backed
         // payments (docs/plans/stripe-auction-payments.md), so the schema
@@ -55,6 +73,7 @@ pub async fn create_community(
 
     let db_community = sqlx::query_as::<_, DbCommunity>(
         "INSERT INTO communities (
+            id,
             name,
             description,
             new_members_default_active,
@@ -70,11 +89,12 @@ pub async fn create_community(
             allowance_start,
             created_at,
             updated_at
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $14)
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $15)
         RETURNING *;",
     )
-    .bind(&details.name)
-    .bind(&details.description)
+    .bind(id_source.new_id())
+    .bind(&name)
+    .bind(&description)
     .bind(currency_db.new_members_default_active)
     .bind(currency_db.mode)
     .bind(currency_db.default_credit_limit)
@@ -147,6 +167,21 @@ pub async fn get_validated_member(
     Ok(ValidatedMember(member))
 }
 
+/// [`get_validated_member`] for reading a resource by id, where a non-member
+/// gets `not_found`: the same error as for an id that doesn't exist, so ids
+/// can't be probed for existence.
+pub async fn get_member_or_not_found(
+    user_id: &UserId,
+    community_id: &CommunityId,
+    not_found: ApiError,
+    pool: &PgPool,
+) -> Result<ValidatedMember, StoreError> {
+    match get_validated_member(user_id, community_id, pool).await {
+        Err(StoreError::Api(ApiError::MemberNotFound)) => Err(not_found.into()),
+        result => result,
+    }
+}
+
 /// Batch fetch user identities for a list of user IDs
 ///
 /// Returns a HashMap of user_id -> UserIdentity. This is useful for
@@ -224,29 +259,73 @@ where
         .collect()
 }
 
+/// Issue an invite, unless the community already has `max_pending`
+/// outstanding invites. Accepting a single-use invite or deleting any invite
+/// frees its place.
 pub async fn invite_community_member(
     actor: &ValidatedMember,
     new_member_email: &Option<String>,
     single_use: bool,
+    role: Option<Role>,
+    max_pending: i64,
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> Result<InviteId, StoreError> {
     if !actor.0.role.is_ge_moderator() {
         return Err(ApiError::RequiresModeratorPermissions.into());
     }
+    let role = role.unwrap_or(Role::Member);
+    if !actor.0.role.can_invite_role(&role) {
+        return Err(ApiError::CannotInviteRole.into());
+    }
+
+    let mut tx = pool.begin().await?;
+    // Serialize invites to the community so concurrent ones can't both pass
+    // the cap. NO KEY UPDATE doesn't block inserts referencing the row.
+    sqlx::query("SELECT 1 FROM communities WHERE id = $1 FOR NO KEY UPDATE")
+        .bind(actor.0.community_id)
+        .execute(&mut *tx)
+        .await?;
+    let count = count_pending_invites(&actor.0.community_id, &mut *tx).await?;
+    if count >= max_pending {
+        return Err(ApiError::TooManyPendingInvites {
+            count,
+            max: max_pending,
+        }
+        .into());
+    }
+
     let invite = sqlx::query_as::<_, CommunityInvite>(
-        "INSERT INTO community_invites (community_id, email, single_use, created_at)
-        VALUES ($1, $2, $3, $4) RETURNING *;",
+        "INSERT INTO community_invites (community_id, email, single_use, role, created_at)
+        VALUES ($1, $2, $3, $4, $5) RETURNING *;",
     )
     .bind(actor.0.community_id)
     .bind(new_member_email)
     .bind(single_use)
+    .bind(role)
     .bind(time_source.now().to_sqlx())
-    .fetch_one(pool)
+    .fetch_one(&mut *tx)
     .await?;
+    tx.commit().await?;
     Ok(invite.id)
 }
 
+/// Invites issued by the community that are still open to be accepted.
+async fn count_pending_invites<'e, E>(
+    community_id: &CommunityId,
+    executor: E,
+) -> Result<i64, StoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    Ok(sqlx::query_scalar(
+        "SELECT COUNT(*) FROM community_invites WHERE community_id = $1",
+    )
+    .bind(community_id)
+    .fetch_one(executor)
+    .await?)
+}
+
 pub async fn get_invite_community_name(
     invite_id: &payloads::InviteId,
     pool: &PgPool,
@@ -275,14 +354,19 @@ pub async fn accept_invite(
     time_source: &TimeSource,
 ) -> Result<(), StoreError> {
     let user = read_user(pool, user_id).await?;
-    if !user.email_verified {
-        return Err(ApiError::UnverifiedEmail.into());
-    }
+    require_verified_email(&user, SignedDuration::ZERO, time_source)?;
+
+    let mut tx = pool.begin().await?;
+
+    // Lock the invite row for the rest of the transaction. Two users racing
+    // on the same single-use link would otherwise both read it before either
+    // delete commits; with the lock, the loser blocks until the winner
+    // commits and then sees no row.
     let invite = sqlx::query_as::<_, CommunityInvite>(
-        "SELECT * FROM community_invites WHERE id = $1;",
+        "SELECT * FROM community_invites WHERE id = $1 FOR UPDATE;",
     )
     .bind(invite_id)
-    .fetch_optional(pool)
+    .fetch_optional(&mut *tx)
     .await?;
     let Some(invite) = invite else {
         return Err(ApiError::CommunityInviteNotFound.into());
@@ -297,9 +381,38 @@ pub async fn accept_invite(
 
     // Fetch community to get new_members_default_active setting
     let community = get_community_by_id(&invite.community_id, pool).await?;
+    if !community.registration_open {
+        return Err(ApiError::RegistrationClosed.into());
+    }
     let is_active = community.currency.new_members_default_active;
 
-    let mut tx = pool.begin().await?;
+    // The invite row lock only serializes accepts of the same invite. Lock
+    // the (community, user) pair too, so a user accepting two invites to the
+    // same community at once can't have both pass the membership and
+    // orphaned-account checks below.
+    sqlx::query(
+        "SELECT pg_advisory_xact_lock(hashtextextended(
+            'community_member:' || $1::text || ':' || $2::text, 0
+        ))",
+    )
+    .bind(invite.community_id)
+    .bind(user_id)
+    .execute(&mut *tx)
+    .await?;
+
+    let already_member: bool = sqlx::query_scalar(
+        "SELECT EXISTS(
+            SELECT 1 FROM community_members
+            WHERE community_id = $1 AND user_id = $2
+        )",
+    )
+    .bind(invite.community_id)
+    .bind(user_id)
+    .fetch_one(&mut *tx)
+    .await?;
+    if already_member {
+        return Err(ApiError::AlreadyMember.into());
+    }
 
     // Check if an orphaned account exists (user previously left)
     let orphaned_account_exists: bool = sqlx::query_scalar(
@@ -317,22 +430,24 @@ pub async fn accept_invite(
     .fetch_one(&mut *tx)
     .await?;
 
-    // Insert community_members row (new member or returning member)
-    let result = sqlx::query(
+    // Insert community_members row (new member or returning member). The
+    // unique constraint stays as a backstop for writers that don't take the
+    // pair lock.
+    sqlx::query(
         "INSERT INTO community_members (community_id, user_id, role, is_active, created_at, updated_at)
         VALUES ($1, $2, $3, $4, $5, $5);",
     )
     .bind(invite.community_id)
     .bind(user_id)
-    .bind(Role::Member)
+    .bind(invite.role)
     .bind(is_active)
     .bind(time_source.now().to_sqlx())
     .execute(&mut *tx)
-    .await;
-
-    if let Err(StoreError::NotUnique(_)) = result.map_err(StoreError::from) {
-        return Err(ApiError::AlreadyMember.into());
-    }
+    .await
+    .map_err(|e| match StoreError::from(e) {
+        StoreError::NotUnique(_) => ApiError::AlreadyMember.into(),
+        e => e,
+    })?;
 
     // Only create account if this is a new member (no orphaned account)
     if !orphaned_account_exists {
@@ -359,6 +474,38 @@ pub async fn accept_invite(
     Ok(())
 }
 
+/// Public, unauthenticated preview of the community an invite points to.
+/// The description is only included if the community has opted in.
+pub async fn get_community_preview(
+    invite_id: &payloads::InviteId,
+    pool: &PgPool,
+) -> Result<responses::CommunityPreview, StoreError> {
+    let row = sqlx::query(
+        "SELECT
+            c.name,
+            CASE WHEN c.description_public THEN c.description END
+                AS description,
+            (SELECT COUNT(*) FROM community_members m
+             WHERE m.community_id = c.id) AS member_count
+         FROM community_invites ci
+         JOIN communities c ON ci.community_id = c.id
+         WHERE ci.id = $1;",
+    )
+    .bind(invite_id)
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(row) = row else {
+        return Err(ApiError::CommunityInviteNotFound.into());
+    };
+
+    Ok(responses::CommunityPreview {
+        name: row.try_get("name")?,
+        member_count: row.try_get("member_count")?,
+        description: row.try_get("description")?,
+    })
+}
+
 pub async fn delete_invite(
     actor: &ValidatedMember,
     invite_id: &payloads::InviteId,
@@ -390,6 +537,31 @@ pub async fn delete_invite(
     Ok(())
 }
 
+/// The user's role in each of their communities, without the communities
+/// themselves, oldest membership first.
+pub async fn list_my_roles(
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::CommunityRole>, StoreError> {
+    let members = sqlx::query_as::<_, CommunityMember>(
+        "SELECT * FROM community_members
+        WHERE user_id = $1
+        ORDER BY created_at, community_id",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(members
+        .into_iter()
+        .map(|member| payloads::responses::CommunityRole {
+            community_id: member.community_id,
+            role: member.role,
+            is_active: member.is_active,
+        })
+        .collect())
+}
+
 pub async fn get_communities(
     user_id: &UserId,
     pool: &PgPool,
@@ -438,16 +610,64 @@ pub async fn get_community_by_id(
     db_community.try_into()
 }
 
-pub async fn get_received_invites(
+/// Everything the community detail page shows, assembled behind a single
+/// membership check. The pending invite count is only included for
+/// moderators and above, who are the ones able to see issued invites.
+pub async fn get_community_overview(
+    community_id: &CommunityId,
     user_id: &UserId,
     pool: &PgPool,
-) -> Result<Vec<responses::CommunityInviteReceived>, StoreError> {
+    time_source: &TimeSource,
+) -> Result<responses::CommunityOverview, StoreError> {
+    let actor = get_validated_member(user_id, community_id, pool).await?;
+    let community = get_community_by_id(community_id, pool).await?;
+    let member_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM community_members WHERE community_id = $1",
+    )
+    .bind(community_id)
+    .fetch_one(pool)
+    .await?;
+    let sites = list_sites_as(&actor, pool, time_source).await?;
+    let pending_invite_count = if actor.0.role.is_ge_moderator() {
+        Some(count_pending_invites(community_id, pool).await?)
+    } else {
+        None
+    };
+
+    Ok(responses::CommunityOverview {
+        community: responses::CommunityWithRole {
+            community,
+            user_role: actor.0.role,
+            user_is_active: actor.0.is_active,
+        },
+        member_count,
+        sites,
+        pending_invite_count,
+    })
+}
+
+/// The user's email address, which invites are matched against.
+async fn read_verified_email(
+    user_id: &UserId,
+    grace: SignedDuration,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<String, StoreError> {
     let user = read_user(pool, user_id).await?;
     // Need to make sure this user actually owns this email before showing them
-    // the invites they've received
-    if !user.email_verified {
-        return Err(ApiError::UnverifiedEmail.into());
-    }
+    // the invites they've received, unless the deployment allows a grace
+    // period. Accepting one still requires verification.
+    require_verified_email(&user, grace, time_source)?;
+    Ok(user.email)
+}
+
+pub async fn get_received_invites(
+    user_id: &UserId,
+    grace: SignedDuration,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Vec<responses::CommunityInviteReceived>, StoreError> {
+    let email = read_verified_email(user_id, grace, pool, time_source).await?;
     Ok(sqlx::query_as::<_, responses::CommunityInviteReceived>(
         "SELECT
             a.*,
@@ -456,24 +676,45 @@ pub async fn get_received_invites(
         JOIN communities b ON a.community_id = b.id
         WHERE a.email_normalized = lower($1)",
     )
-    .bind(user.email)
+    .bind(email)
     .fetch_all(pool)
     .await?)
 }
 
+/// Number of invites the user has received, for badging without fetching the
+/// invites themselves.
+pub async fn count_received_invites(
+    user_id: &UserId,
+    grace: SignedDuration,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<i64, StoreError> {
+    let email = read_verified_email(user_id, grace, pool, time_source).await?;
+    Ok(sqlx::query_scalar(
+        "SELECT COUNT(*) FROM community_invites WHERE email_normalized = lower($1)",
+    )
+    .bind(email)
+    .fetch_one(pool)
+    .await?)
+}
+
+/// The community's outstanding invites, newest first, and how many more it
+/// may issue.
 pub async fn get_issued_invites(
     actor: &ValidatedMember,
+    max_pending: i64,
     pool: &PgPool,
-) -> Result<Vec<responses::IssuedCommunityInvite>, StoreError> {
+) -> Result<responses::IssuedInvites, StoreError> {
     if !actor.0.role.is_ge_moderator() {
         return Err(ApiError::RequiresModeratorPermissions.into());
     }
 
-    Ok(sqlx::query_as::<_, responses::IssuedCommunityInvite>(
+    let invites = sqlx::query_as::<_, responses::IssuedCommunityInvite>(
         "SELECT
             id,
             email as new_member_email,
             single_use,
+            role,
             created_at
         FROM community_invites
         WHERE community_id = $1
@@ -481,11 +722,38 @@ pub async fn get_issued_invites(
     )
     .bind(actor.0.community_id)
     .fetch_all(pool)
+    .await?;
+    Ok(responses::IssuedInvites {
+        pending_invite_count: invites.len() as i64,
+        max_pending_invites: max_pending,
+        invites,
+    })
+}
+
+/// Ids of the community's active members. Inactive members keep their
+/// membership but are left out of distributions, active-only listings, and
+/// `MustBeActiveMember` winner constraints; this is the one definition all of
+/// those share.
+pub async fn list_active_member_ids<'e, E>(
+    community_id: &CommunityId,
+    executor: E,
+) -> Result<Vec<UserId>, StoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    Ok(sqlx::query_scalar(
+        "SELECT user_id FROM community_members
+        WHERE community_id = $1 AND is_active
+        ORDER BY user_id",
+    )
+    .bind(community_id)
+    .fetch_all(executor)
     .await?)
 }
 
 pub async fn get_members(
     actor: &ValidatedMember,
+    active_only: bool,
     pool: &PgPool,
 ) -> Result<Vec<responses::CommunityMember>, StoreError> {
     let should_include_balances = actor.0.role.is_ge_coleader()
@@ -506,7 +774,7 @@ pub async fn get_members(
         balance: Option<rust_decimal::Decimal>,
     }
 
-    let db_members: Vec<DbMember> = if should_include_balances {
+    let mut db_members: Vec<DbMember> = if should_include_balances {
         sqlx::query_as(
             "SELECT cm.user_id, cm.role, cm.is_active,
                     a.balance_cached AS balance
@@ -534,6 +802,15 @@ pub async fn get_members(
         .await?
     };
 
+    if active_only {
+        let active: std::collections::HashSet<UserId> =
+            list_active_member_ids(&actor.0.community_id, pool)
+                .await?
+                .into_iter()
+                .collect();
+        db_members.retain(|m| active.contains(&m.user_id));
+    }
+
     with_user_identities(
         db_members,
         |m| m.user_id,
@@ -551,6 +828,34 @@ pub async fn get_members(
     .await
 }
 
+/// Profile of another member of the actor's community, looked up by
+/// username. A user outside the community is indistinguishable from one that
+/// doesn't exist.
+pub async fn get_member_profile(
+    actor: &ValidatedMember,
+    username: &str,
+    pool: &PgPool,
+) -> Result<responses::MemberProfile, StoreError> {
+    sqlx::query_as::<_, responses::MemberProfile>(
+        "SELECT
+            u.username,
+            u.display_name,
+            cm.role,
+            cm.is_active,
+            cm.created_at AS member_since
+        FROM community_members cm
+        JOIN users u ON cm.user_id = u.id
+        WHERE cm.community_id = $1
+            AND u.username_normalized = lower($2)
+            AND u.deleted_at IS NULL",
+    )
+    .bind(actor.0.community_id)
+    .bind(username)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::UserNotFound.into())
+}
+
 pub async fn remove_member(
     actor: &ValidatedMember,
     member_user_id: &UserId,
@@ -746,6 +1051,35 @@ pub async fn set_membership_schedule(
 
     let mut tx = pool.begin().await?;
 
+    // The schedule sync only touches members in the schedule, so a member
+    // dropped from it would keep whatever is_active it last gave them.
+    // Reset them to the community's default for new members instead, as if
+    // they had just joined.
+    let emails: Vec<&str> =
+        schedule.iter().map(|elem| elem.email.as_str()).collect();
+    sqlx::query(
+        "UPDATE community_members m
+        SET is_active = c.new_members_default_active, updated_at = $3
+        FROM users u, communities c
+        WHERE
+            m.community_id = $1
+            AND c.id = m.community_id
+            AND u.id = m.user_id
+            AND u.email_normalized IN (
+                SELECT email_normalized FROM community_membership_schedule
+                WHERE community_id = $1
+            )
+            AND NOT EXISTS (
+                SELECT 1 FROM unnest($2::TEXT[]) AS e (email)
+                WHERE lower(e.email) = u.email_normalized
+            )",
+    )
+    .bind(actor.0.community_id)
+    .bind(&emails)
+    .bind(time_source.now().to_sqlx())
+    .execute(&mut *tx)
+    .await?;
+
     sqlx::query(
         "DELETE FROM community_membership_schedule
         WHERE community_id = $1",
@@ -931,6 +1265,47 @@ pub async fn get_membership_schedule(
     .await?)
 }
 
+/// Aggregate stats for the actor's community. Coleader+ only.
+pub async fn get_community_stats(
+    actor: &ValidatedMember,
+    pool: &PgPool,
+) -> Result<payloads::responses::CommunityStats, StoreError> {
+    if !actor.0.role.is_ge_coleader() {
+        return Err(ApiError::RequiresColeaderPermissions.into());
+    }
+
+    Ok(sqlx::query_as::<_, payloads::responses::CommunityStats>(
+        "SELECT
+            (SELECT COUNT(*) FROM community_members
+                WHERE community_id = $1) AS total_members,
+            (SELECT COUNT(*) FROM community_members
+                WHERE community_id = $1 AND is_active) AS active_members,
+            (SELECT COUNT(*) FROM sites
+                WHERE community_id = $1 AND deleted_at IS NULL) AS sites,
+            (SELECT COUNT(*) FROM spaces sp
+                JOIN sites s ON s.id = sp.site_id
+                WHERE s.community_id = $1
+                    AND s.deleted_at IS NULL
+                    AND sp.deleted_at IS NULL) AS spaces,
+            (SELECT COUNT(*) FROM auctions a
+                JOIN sites s ON s.id = a.site_id
+                WHERE s.community_id = $1
+                    AND a.end_at IS NOT NULL
+                    AND NOT a.was_canceled
+                    AND NOT a.sandbox) AS auctions_run,
+            (SELECT COALESCE(SUM(jl.amount), 0) FROM journal_lines jl
+                JOIN journal_entries je ON je.id = jl.entry_id
+                WHERE je.community_id = $1
+                    AND je.entry_type IN (
+                        'auction_settlement', 'transfer', 'treasury_transfer'
+                    )
+                    AND jl.amount > 0) AS total_value_transacted",
+    )
+    .bind(actor.0.community_id)
+    .fetch_one(pool)
+    .await?)
+}
+
 #[derive(Debug, Clone, FromRow)]
 struct MemberInSchedule {
     community_id: CommunityId,
@@ -940,7 +1315,8 @@ struct MemberInSchedule {
 
 #[tracing::instrument(skip(pool, time_source), err(level = Level::ERROR))]
 /// Update members' is_active status in all communities based on the schedule,
-/// if they are present in the schedule.
+/// if they are present in the schedule. Members removed from a schedule were
+/// already reset by [`set_membership_schedule`].
 pub async fn update_is_active_from_schedule(
     pool: &PgPool,
     time_source: &TimeSource,
@@ -1052,42 +1428,238 @@ pub async fn delete_community(
     Ok(())
 }
 
+/// Normalize a community's name and description for storage, or reject
+/// them.
+fn sanitize_community_details(
+    name: &str,
+    description: Option<&str>,
+) -> Result<(String, Option<String>), StoreError> {
+    let name = sanitized(
+        sanitize::name(name, payloads::requests::COMMUNITY_NAME_MAX_LEN),
+        "Community name",
+        |_, _| ApiError::FieldTooLong,
+    )?;
+    let description = description
+        .map(|description| {
+            sanitized(
+                sanitize::text(
+                    description,
+                    payloads::MAX_COMMUNITY_DESCRIPTION_LENGTH,
+                ),
+                "Community description",
+                |size, max| ApiError::CommunityDescriptionTooLong { size, max },
+            )
+        })
+        .transpose()?;
+    Ok((name, description))
+}
+
 /// Update community name and description (coleader+ only).
 pub async fn update_community_details(
     actor: &ValidatedMember,
     details: &requests::UpdateCommunityDetails,
     pool: &PgPool,
+    time_source: &TimeSource,
 ) -> Result<Community, StoreError> {
     if !actor.0.role.is_ge_coleader() {
         return Err(ApiError::RequiresColeaderPermissions.into());
     }
 
-    if details.name.len() > payloads::requests::COMMUNITY_NAME_MAX_LEN {
-        return Err(ApiError::FieldTooLong.into());
-    }
-
-    if let Some(desc) = &details.description
-        && desc.len() > payloads::MAX_COMMUNITY_DESCRIPTION_LENGTH
-    {
-        return Err(ApiError::CommunityDescriptionTooLong {
-            size: desc.len(),
-            max: payloads::MAX_COMMUNITY_DESCRIPTION_LENGTH,
-        }
-        .into());
-    }
+    let (name, description) = sanitize_community_details(
+        &details.name,
+        details.description.as_deref(),
+    )?;
 
     let db_community = sqlx::query_as::<_, DbCommunity>(
         "UPDATE communities
-         SET name = $1, description = $2
-         WHERE id = $3
+         SET name = $1, description = $2,
+             description_public = COALESCE($3, description_public),
+             updated_at = $5
+         WHERE id = $4
          RETURNING *",
     )
-    .bind(&details.name)
-    .bind(&details.description)
+    .bind(&name)
+    .bind(&description)
+    .bind(details.description_public)
     .bind(details.community_id)
+    .bind(time_source.now().to_sqlx())
     .fetch_optional(pool)
     .await?
     .ok_or(ApiError::CommunityNotFound)?;
 
     db_community.try_into()
 }
+
+/// Open or close the community to new members (leader only). Outstanding
+/// invites are kept either way.
+pub async fn set_registration_open(
+    actor: &ValidatedMember,
+    registration_open: bool,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    if !actor.0.role.is_leader() {
+        return Err(ApiError::RequiresLeaderPermissions.into());
+    }
+
+    sqlx::query(
+        "UPDATE communities SET registration_open = $1, updated_at = $2
+         WHERE id = $3",
+    )
+    .bind(registration_open)
+    .bind(time_source.now().to_sqlx())
+    .bind(actor.0.community_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Set or clear the base URL of the community's invite links (leader only).
+/// Stored without a trailing slash, so links join onto it the same way as
+/// onto the instance's base URL.
+pub async fn set_invite_base_url(
+    actor: &ValidatedMember,
+    invite_base_url: Option<&str>,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    if !actor.0.role.is_leader() {
+        return Err(ApiError::RequiresLeaderPermissions.into());
+    }
+    let invite_base_url =
+        invite_base_url.map(validate_invite_base_url).transpose()?;
+
+    sqlx::query(
+        "UPDATE communities SET invite_base_url = $1, updated_at = $2
+         WHERE id = $3",
+    )
+    .bind(invite_base_url)
+    .bind(time_source.now().to_sqlx())
+    .bind(actor.0.community_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Set the community's round duration bounds (leader only). Bounds left
+/// unset fall back to the instance's. Existing auctions keep their rounds.
+pub async fn set_round_duration_bounds(
+    actor: &ValidatedMember,
+    bounds: &payloads::RoundDurationBounds,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    if !actor.0.role.is_leader() {
+        return Err(ApiError::RequiresLeaderPermissions.into());
+    }
+    if !bounds.is_valid() {
+        return Err(ApiError::InvalidRoundDurationBounds.into());
+    }
+
+    sqlx::query(
+        "UPDATE communities
+         SET min_round_duration = $1, max_round_duration = $2, updated_at = $3
+         WHERE id = $4",
+    )
+    .bind(bounds.min.map(duration_to_interval).transpose()?)
+    .bind(bounds.max.map(duration_to_interval).transpose()?)
+    .bind(time_source.now().to_sqlx())
+    .bind(actor.0.community_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// An absolute http(s) URL with a host and nothing after the path, which
+/// invite paths are appended to.
+fn validate_invite_base_url(url: &str) -> Result<String, StoreError> {
+    let invalid = |reason: &str| -> StoreError {
+        ApiError::InvalidInviteBaseUrl(reason.into()).into()
+    };
+    let trimmed = url.trim().trim_end_matches('/');
+    let parsed =
+        reqwest::Url::parse(trimmed).map_err(|e| invalid(&e.to_string()))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid("must use http or https"));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("must include a host"));
+    }
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err(invalid("must not include a query or fragment"));
+    }
+    if trimmed.len() > 255 {
+        return Err(invalid("must be at most 255 characters"));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Set or clear the auction params that sites created in the community
+/// without their own inherit (coleader+ only).
+pub async fn set_community_default_params(
+    actor: &ValidatedMember,
+    params: Option<&payloads::AuctionParams>,
+    default_bounds: &payloads::RoundDurationBounds,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    if !actor.0.role.is_ge_coleader() {
+        return Err(ApiError::RequiresColeaderPermissions.into());
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let params_id = match params {
+        Some(params) => {
+            check_round_duration_bounds(
+                params.round_duration,
+                &actor.0.community_id,
+                default_bounds,
+                &mut tx,
+            )
+            .await?;
+            Some(
+                create_auction_params(
+                    params,
+                    &actor.0.community_id,
+                    &mut tx,
+                    time_source,
+                )
+                .await?,
+            )
+        }
+        None => None,
+    };
+
+    sqlx::query(
+        "UPDATE communities
+         SET community_default_auction_params_id = $1, updated_at = $2
+         WHERE id = $3",
+    )
+    .bind(params_id)
+    .bind(time_source.now().to_sqlx())
+    .bind(actor.0.community_id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    cleanup_unused_auction_params(pool).await;
+    Ok(())
+}
+
+/// The auction params new sites in the community inherit, if any.
+pub async fn get_community_default_params(
+    actor: &ValidatedMember,
+    pool: &PgPool,
+) -> Result<Option<payloads::AuctionParams>, StoreError> {
+    let params = sqlx::query_as::<_, AuctionParams>(
+        "SELECT p.* FROM auction_params p
+         JOIN communities c ON c.community_default_auction_params_id = p.id
+         WHERE c.id = $1",
+    )
+    .bind(actor.0.community_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(params.map(Into::into))
+}
@@ -206,8 +206,8 @@ pub(crate) async fn get_effective_credit_limit_tx(
 /// Get locked balance for an account (Rust-based implementation).
 ///
 /// The locked balance reduces the user's available credit. Sums the
-/// per-auction locked balance over all active auctions in the account's
-/// community.
+/// per-auction locked balance over all active, non-sandbox auctions in the
+/// account's community.
 ///
 /// Works within an active transaction and will see uncommitted changes made
 /// by the same transaction (e.g., bids inserted but not yet committed).
@@ -231,7 +231,9 @@ async fn get_locked_balance_tx(
         SELECT auc.id
         FROM auctions auc
         JOIN sites s ON auc.site_id = s.id
-        WHERE s.community_id = $1 AND auc.end_at IS NULL
+        WHERE s.community_id = $1
+            AND auc.end_at IS NULL
+            AND NOT auc.sandbox
         "#,
     )
     .bind(community_id)
@@ -252,26 +254,12 @@ async fn get_locked_balance_tx(
 ///
 /// Locked balance includes:
 /// - Winning bids: value from latest processed round_space_results
-/// - Outstanding bids: (prev round value + bid increment) for unprocessed
-///   rounds
+/// - Outstanding bids: the round's ask price for unprocessed rounds
 pub(crate) async fn get_auction_locked_balance_tx(
     user_id: &payloads::UserId,
     auction_id: &payloads::AuctionId,
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> Result<Decimal, StoreError> {
-    // Get bid increment for calculating bid amounts
-    let bid_increment: payloads::BidIncrement = sqlx::query_scalar(
-        r#"
-        SELECT ap.bid_increment
-        FROM auctions auc
-        JOIN auction_params ap ON auc.auction_params_id = ap.id
-        WHERE auc.id = $1
-        "#,
-    )
-    .bind(auction_id)
-    .fetch_one(&mut **tx)
-    .await?;
-
     // Get the latest processed round number (highest round with results)
     let max_processed_round: Option<i32> = sqlx::query_scalar(
         r#"
@@ -316,23 +304,18 @@ pub(crate) async fn get_auction_locked_balance_tx(
         }
     }
 
-    // Add locked balance from bids in unprocessed rounds
-    #[derive(sqlx::FromRow)]
-    struct UnprocessedBid {
-        space_id: payloads::SpaceId,
-        round_num: i32,
-        reserve_price: payloads::ReservePrice,
-    }
-
-    let unprocessed_bids: Vec<UnprocessedBid> = sqlx::query_as(
+    // Add locked balance from bids in unprocessed rounds, at each round's
+    // ask price
+    let unprocessed_rounds = sqlx::query_as::<_, super::AuctionRound>(
         r#"
-        SELECT b.space_id, ar.round_num, s.reserve_price
-        FROM bids b
-        JOIN auction_rounds ar ON b.round_id = ar.id
-        JOIN spaces s ON b.space_id = s.id
+        SELECT ar.*
+        FROM auction_rounds ar
         WHERE ar.auction_id = $1
           AND ar.round_num > $2
-          AND b.user_id = $3
+          AND EXISTS (
+              SELECT 1 FROM bids b
+              WHERE b.round_id = ar.id AND b.user_id = $3
+          )
         "#,
     )
     .bind(auction_id)
@@ -341,40 +324,27 @@ pub(crate) async fn get_auction_locked_balance_tx(
     .fetch_all(&mut **tx)
     .await?;
 
-    for bid in unprocessed_bids {
-        // Get the previous round's value for this space (if any)
-        let prev_round_value: Option<Decimal> = if bid.round_num > 0 {
-            sqlx::query_scalar(
-                r#"
-                SELECT rsr.value
-                FROM round_space_results rsr
-                JOIN auction_rounds ar ON rsr.round_id = ar.id
-                WHERE ar.auction_id = $1
-                  AND ar.round_num = $2
-                  AND rsr.space_id = $3
-                "#,
-            )
-            .bind(auction_id)
-            .bind(bid.round_num - 1)
-            .bind(bid.space_id)
-            .fetch_optional(&mut **tx)
-            .await?
-            .flatten()
-        } else {
-            None
-        };
+    for round in unprocessed_rounds {
+        let ask_prices = super::round_ask_prices_tx(&round, tx).await?;
+        let bid_space_ids: Vec<payloads::SpaceId> = sqlx::query_scalar(
+            "SELECT space_id FROM bids WHERE round_id = $1 AND user_id = $2",
+        )
+        .bind(round.id)
+        .bind(user_id)
+        .fetch_all(&mut **tx)
+        .await?;
 
         // Clamp negative bid amounts (chore bids) to zero: the bidder
         // isn't on the hook for anything until they actually win, and
         // a later round could displace them, so treating a negative
         // bid as freed credit would let them pre-spend money they may
-        // never receive.
-        let bid_amount = payloads::next_bid_amount(
-            prev_round_value,
-            bid_increment,
-            bid.reserve_price,
-        );
-        locked += bid_amount.max(Decimal::ZERO);
+        // never receive. Bids on spaces withdrawn from the auction have
+        // no ask price and aren't counted by the scheduler either.
+        for space_id in bid_space_ids {
+            let bid_amount =
+                ask_prices.get(&space_id).copied().unwrap_or_default();
+            locked += bid_amount.max(Decimal::ZERO);
+        }
     }
 
     Ok(locked)
@@ -1038,26 +1008,28 @@ async fn get_account_tx(
 
 /// Get account IDs for all active members in a community
 ///
-/// Returns a vector of account IDs for members where is_active = true,
-/// ordered by account ID for consistency.
+/// Returns a vector of account IDs for members in
+/// [`super::community::list_active_member_ids`], ordered by account ID for
+/// consistency.
 async fn get_active_member_account_ids_tx(
     community_id: &CommunityId,
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> Result<Vec<AccountId>, StoreError> {
+    let member_ids =
+        super::community::list_active_member_ids(community_id, &mut **tx)
+            .await?;
     let account_ids: Vec<AccountId> = sqlx::query_scalar(
         r#"
-        SELECT a.id
-        FROM accounts a
-        JOIN community_members cm
-            ON a.community_id = cm.community_id
-            AND a.owner_id = cm.user_id
-        WHERE a.community_id = $1
-          AND a.owner_type = 'member_main'
-          AND cm.is_active = true
-        ORDER BY a.id
+        SELECT id
+        FROM accounts
+        WHERE community_id = $1
+          AND owner_type = 'member_main'
+          AND owner_id = ANY($2)
+        ORDER BY id
         "#,
     )
     .bind(community_id)
+    .bind(&member_ids)
     .fetch_all(&mut **tx)
     .await?;
 
@@ -2462,10 +2434,10 @@ pub async fn update_currency_config(
     // must have finite credit limit
     match &currency.mode_config {
         payloads::CurrencyModeConfig::DistributedClearing(cfg)
-        | payloads::CurrencyModeConfig::DeferredPayment(cfg) => {
-            if !cfg.debts_callable && cfg.default_credit_limit.is_none() {
-                return Err(StoreError::InvalidCurrencyConfiguration);
-            }
+        | payloads::CurrencyModeConfig::DeferredPayment(cfg)
+            if !cfg.debts_callable && cfg.default_credit_limit.is_none() =>
+        {
+            return Err(StoreError::InvalidCurrencyConfiguration);
         }
         _ => {}
     }
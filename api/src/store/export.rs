@@ -0,0 +1,183 @@
+//! Takeout of a user's own data ahead of account deletion.
+//!
+//! Every query is scoped to rows owned by the user. Anything that could
+//! identify another member (counterparty accounts, notes written by someone
+//! else) is left out rather than redacted after the fact.
+
+use super::*;
+use jiff_sqlx::ToSqlx;
+use payloads::responses::{
+    DataExport, ExportedBid, ExportedMembership, ExportedProxyBidding,
+    ExportedTransaction, ExportedUserValue, Possession,
+};
+use sqlx::PgPool;
+
+use crate::time::TimeSource;
+
+/// Minimum time between two exports by the same user.
+pub const DATA_EXPORT_INTERVAL: jiff::SignedDuration =
+    jiff::SignedDuration::from_hours(1);
+
+/// Postgres' `serialization_failure`, raised when a repeatable-read
+/// transaction updates a row another transaction changed since its snapshot.
+const SERIALIZATION_FAILURE: &str = "40001";
+
+/// Assemble everything stored about `user_id`.
+///
+/// The sections are read in a single repeatable-read transaction so they're
+/// consistent with each other, and the rate limit is claimed at the end of
+/// that same transaction, so a failed read doesn't use up the user's export.
+/// Of concurrent requests, only the first to claim it succeeds; the rest fail
+/// to serialize and are rate limited too.
+pub async fn export_user_data(
+    user_id: &UserId,
+    reminder_window: jiff::SignedDuration,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<DataExport, StoreError> {
+    let now = time_source.now();
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+        .execute(&mut *tx)
+        .await?;
+
+    let user = sqlx::query_as::<_, User>(
+        "SELECT * FROM users WHERE id = $1 AND deleted_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ApiError::UserNotFound)?;
+    // Checked again when claimed; this just skips the reads
+    if user
+        .data_exported_at
+        .is_some_and(|at| at > now - DATA_EXPORT_INTERVAL)
+    {
+        return Err(ApiError::DataExportRateLimited.into());
+    }
+
+    let memberships = sqlx::query_as::<_, ExportedMembership>(
+        "SELECT c.id AS community_id,
+            c.name AS community_name,
+            cm.role,
+            cm.is_active,
+            cm.created_at AS joined_at
+        FROM community_members cm
+        JOIN communities c ON c.id = cm.community_id
+        WHERE cm.user_id = $1
+        ORDER BY cm.created_at",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let bids = sqlx::query_as::<_, ExportedBid>(
+        "SELECT ar.auction_id,
+            ar.round_num,
+            s.name AS site_name,
+            sp.name AS space_name,
+            b.created_at
+        FROM bids b
+        JOIN auction_rounds ar ON ar.id = b.round_id
+        JOIN spaces sp ON sp.id = b.space_id
+        JOIN sites s ON s.id = sp.site_id
+        WHERE b.user_id = $1
+        ORDER BY b.created_at, b.id",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let user_values = sqlx::query_as::<_, ExportedUserValue>(
+        "SELECT s.name AS site_name,
+            sp.name AS space_name,
+            uv.value,
+            uv.updated_at
+        FROM user_values uv
+        JOIN spaces sp ON sp.id = uv.space_id
+        JOIN sites s ON s.id = sp.site_id
+        WHERE uv.user_id = $1
+        ORDER BY s.name, sp.name",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let proxy_bidding = sqlx::query_as::<_, ExportedProxyBidding>(
+        "SELECT upb.auction_id,
+            s.name AS site_name,
+            upb.max_items,
+            upb.updated_at
+        FROM use_proxy_bidding upb
+        JOIN auctions a ON a.id = upb.auction_id
+        JOIN sites s ON s.id = a.site_id
+        WHERE upb.user_id = $1
+        ORDER BY upb.created_at",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let transactions = sqlx::query_as::<_, ExportedTransaction>(
+        "SELECT c.name AS community_name,
+            je.entry_type,
+            je.auction_id,
+            jl.amount,
+            CASE WHEN je.initiated_by_id = $1 THEN je.note END AS note,
+            je.created_at
+        FROM journal_lines jl
+        JOIN accounts acc ON acc.id = jl.account_id
+        JOIN journal_entries je ON je.id = jl.entry_id
+        JOIN communities c ON c.id = je.community_id
+        WHERE acc.owner_id = $1
+        ORDER BY je.created_at, je.id",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let possessions = sqlx::query_as::<_, Possession>(&format!(
+        "SELECT * FROM ({}) p
+        WHERE winning_user_id = $3
+        ORDER BY possession_end_at, space_name",
+        auction::POSSESSIONS_QUERY
+    ))
+    .bind(now.to_sqlx())
+    .bind((now + reminder_window).to_sqlx())
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let claimed = sqlx::query(
+        "UPDATE users SET data_exported_at = $2
+        WHERE id = $1
+            AND (data_exported_at IS NULL OR data_exported_at <= $3)",
+    )
+    .bind(user_id)
+    .bind(now.to_sqlx())
+    .bind((now - DATA_EXPORT_INTERVAL).to_sqlx())
+    .execute(&mut *tx)
+    .await;
+    match claimed {
+        Ok(result) if result.rows_affected() == 1 => {}
+        Ok(_) => return Err(ApiError::DataExportRateLimited.into()),
+        Err(sqlx::Error::Database(e))
+            if e.code().as_deref() == Some(SERIALIZATION_FAILURE) =>
+        {
+            return Err(ApiError::DataExportRateLimited.into());
+        }
+        Err(e) => return Err(e.into()),
+    }
+    tx.commit().await?;
+
+    Ok(DataExport {
+        exported_at: now,
+        profile: user.into(),
+        memberships,
+        bids,
+        user_values,
+        proxy_bidding,
+        transactions,
+        possessions,
+    })
+}
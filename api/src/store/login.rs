@@ -5,16 +5,80 @@ use jiff_sqlx::ToSqlx;
 use payloads::{ApiError, UserId};
 use sqlx::PgPool;
 
+use crate::id_source::IdSource;
 use crate::time::TimeSource;
 
 /// Create a new user as would happen during signup.
+/// Insert a new user. `email_verified` is only true on instances that don't
+/// require email verification.
 pub async fn create_user(
     pool: &PgPool,
     username: &str,
     email: &str,
     password_hash: &str,
+    email_verified: bool,
     time_source: &TimeSource,
+    id_source: &IdSource,
 ) -> Result<User, StoreError> {
+    insert_user(
+        pool,
+        username,
+        email,
+        password_hash,
+        email_verified,
+        time_source,
+        id_source,
+    )
+    .await
+}
+
+/// Insert an unverified user together with their email verification token,
+/// so signup never leaves a user behind with no way to verify.
+pub async fn create_account(
+    username: &str,
+    email: &str,
+    password_hash: &str,
+    verification_expires_at: Timestamp,
+    pool: &PgPool,
+    time_source: &TimeSource,
+    id_source: &IdSource,
+) -> Result<(User, TokenId), StoreError> {
+    let mut tx = pool.begin().await?;
+    let user = insert_user(
+        &mut *tx,
+        username,
+        email,
+        password_hash,
+        false,
+        time_source,
+        id_source,
+    )
+    .await?;
+    let token_id = insert_token(
+        &mut *tx,
+        &user.id,
+        TokenAction::EmailVerification,
+        verification_expires_at,
+        time_source,
+        id_source,
+    )
+    .await?;
+    tx.commit().await?;
+    Ok((user, token_id))
+}
+
+async fn insert_user<'e, E>(
+    executor: E,
+    username: &str,
+    email: &str,
+    password_hash: &str,
+    email_verified: bool,
+    time_source: &TimeSource,
+    id_source: &IdSource,
+) -> Result<User, StoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
     // Validate username format
     let validation = payloads::requests::validate_username(username);
     if let Some(error_message) = validation.error_message() {
@@ -27,20 +91,24 @@ pub async fn create_user(
     }
     let user = sqlx::query_as::<_, User>(
         "INSERT INTO users (
+                id,
                 username,
                 email,
                 password_hash,
+                email_verified,
                 created_at,
                 updated_at
             )
-            VALUES ($1, $2, $3, $4, $4)
+            VALUES ($1, $2, $3, $4, $5, $6, $6)
             RETURNING *;",
     )
+    .bind(id_source.new_id())
     .bind(username)
     .bind(email)
     .bind(password_hash)
+    .bind(email_verified)
     .bind(time_source.now().to_sqlx())
-    .fetch_one(pool)
+    .fetch_one(executor)
     .await
     .map_err(map_user_identifier_unique_error)?;
     Ok(user)
@@ -64,6 +132,25 @@ pub async fn update_user_profile(
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> Result<User, StoreError> {
+    // Stored normalized, and a blank name clears it
+    let display_name = display_name
+        .as_deref()
+        .map(|name| {
+            payloads::sanitize::name(
+                name,
+                payloads::requests::DISPLAY_NAME_MAX_LEN,
+            )
+            .map_err(|e| {
+                let validation =
+                    payloads::requests::DisplayNameValidation::from(e);
+                ApiError::InvalidDisplayName(
+                    validation.error_message().unwrap_or_default().to_string(),
+                )
+            })
+        })
+        .transpose()?
+        .filter(|name| !name.is_empty());
+
     let updated_user = sqlx::query_as::<_, User>(
         r#"
         UPDATE users SET display_name = $2, updated_at = $3
@@ -72,7 +159,7 @@ pub async fn update_user_profile(
         "#,
     )
     .bind(user_id.0)
-    .bind(display_name.as_ref())
+    .bind(display_name)
     .bind(time_source.now().to_sqlx())
     .fetch_one(pool)
     .await
@@ -84,6 +171,73 @@ pub async fn update_user_profile(
     Ok(updated_user)
 }
 
+pub async fn update_notification_preferences(
+    user_id: &UserId,
+    email_possession_reminders: bool,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<User, StoreError> {
+    let updated_user = sqlx::query_as::<_, User>(
+        r#"
+        UPDATE users SET email_possession_reminders = $2, updated_at = $3
+        WHERE id = $1 AND deleted_at IS NULL
+        RETURNING *
+        "#,
+    )
+    .bind(user_id.0)
+    .bind(email_possession_reminders)
+    .bind(time_source.now().to_sqlx())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => ApiError::UserNotFound.into(),
+        _ => StoreError::Database(e),
+    })?;
+
+    Ok(updated_user)
+}
+
+pub async fn update_locale(
+    user_id: &UserId,
+    locale: Option<payloads::Locale>,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<User, StoreError> {
+    let updated_user = sqlx::query_as::<_, User>(
+        r#"
+        UPDATE users SET locale = $2, updated_at = $3
+        WHERE id = $1 AND deleted_at IS NULL
+        RETURNING *
+        "#,
+    )
+    .bind(user_id.0)
+    .bind(locale)
+    .bind(time_source.now().to_sqlx())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => ApiError::UserNotFound.into(),
+        _ => StoreError::Database(e),
+    })?;
+
+    Ok(updated_user)
+}
+
+/// The language the user chose for messages, if any.
+pub async fn get_user_locale(
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Option<payloads::Locale>, StoreError> {
+    let locale = sqlx::query_scalar::<_, Option<payloads::Locale>>(
+        "SELECT locale FROM users WHERE id = $1 AND deleted_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .context("Failed to fetch user locale")?;
+    Ok(locale.flatten())
+}
+
 /// Delete a user account.
 ///
 /// Attempts a hard delete first. If that fails due to foreign key constraints
@@ -136,6 +290,7 @@ pub async fn delete_user(
             // FK violation means user has historical data that must be
             // preserved. This can happen via:
             // - bids.user_id → user placed auction bids
+            // - bid_withdrawals.user_id → user withdrew auction bids
             // - auction_results.winning_user_id → user won auction rounds
             // - entry_lines.account_id (via accounts cascade) → user has
             //   transaction history
@@ -184,6 +339,10 @@ pub async fn delete_user(
                 .bind(id)
                 .execute(&mut *tx)
                 .await?;
+            sqlx::query("DELETE FROM recovery_codes WHERE user_id = $1")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
 
             // Anonymize PII and mark as unverified to block community actions.
             //
@@ -220,31 +379,93 @@ pub async fn delete_user(
     }
 }
 
-/// Create a token for email verification or password reset
-#[tracing::instrument(skip(pool, time_source))]
+/// How many unused tokens of one action a user may have outstanding. Creating
+/// another deletes the oldest, so repeated resend requests can't grow the
+/// table without bound.
+pub const MAX_OUTSTANDING_TOKENS_PER_ACTION: i64 = 5;
+
+/// Create a token for email verification or password reset, evicting the
+/// user's oldest unused tokens of the same action beyond
+/// [`MAX_OUTSTANDING_TOKENS_PER_ACTION`].
+#[tracing::instrument(skip(pool, time_source, id_source))]
 pub async fn create_token(
     user_id: &UserId,
     action: TokenAction,
     expires_at: Timestamp,
     pool: &PgPool,
     time_source: &TimeSource,
+    id_source: &IdSource,
 ) -> Result<TokenId, StoreError> {
+    let mut tx = pool.begin().await.context("Failed to begin transaction")?;
+
+    let token_id = insert_token(
+        &mut *tx,
+        user_id,
+        action,
+        expires_at,
+        time_source,
+        id_source,
+    )
+    .await?;
+
+    let evicted = sqlx::query(
+        r#"
+        DELETE FROM tokens
+        WHERE id IN (
+            SELECT id FROM tokens
+            WHERE user_id = $1 AND action = $2 AND NOT used
+            ORDER BY created_at DESC, id DESC
+            OFFSET $3
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(action)
+    .bind(MAX_OUTSTANDING_TOKENS_PER_ACTION)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to evict outstanding tokens")?
+    .rows_affected();
+
+    tx.commit().await.context("Failed to commit transaction")?;
+
+    tracing::info!(
+        "Created {:?} token for user {} ({} evicted)",
+        action,
+        user_id.0,
+        evicted
+    );
+    Ok(token_id)
+}
+
+async fn insert_token<'e, E>(
+    executor: E,
+    user_id: &UserId,
+    action: TokenAction,
+    expires_at: Timestamp,
+    time_source: &TimeSource,
+    id_source: &IdSource,
+) -> Result<TokenId, StoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
     let token_id = sqlx::query_as::<_, TokenId>(
         r#"
-        INSERT INTO tokens (user_id, action, expires_at, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, $4)
+        INSERT INTO tokens (
+            id, user_id, action, expires_at, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $5)
         RETURNING id
         "#,
     )
+    .bind(id_source.new_id())
     .bind(user_id)
     .bind(action)
     .bind(expires_at.to_sqlx())
     .bind(time_source.now().to_sqlx())
-    .fetch_one(pool)
+    .fetch_one(executor)
     .await
     .context("Failed to create token")?;
-
-    tracing::info!("Created {:?} token for user {}", action, user_id.0);
     Ok(token_id)
 }
 
@@ -301,6 +522,26 @@ pub async fn consume_token(
     .await
     .context("Failed to mark token as used")?;
 
+    // Once a password is reset, earlier reset links must stop working too,
+    // in case one was requested by someone else. Expired ones already don't
+    // work, and stay counted as expired.
+    if expected_action == TokenAction::PasswordReset {
+        sqlx::query(
+            r#"
+            UPDATE tokens
+            SET used = true, updated_at = $3
+            WHERE user_id = $1 AND action = $2 AND NOT used
+                AND expires_at > $3
+            "#,
+        )
+        .bind(token.user_id)
+        .bind(TokenAction::PasswordReset)
+        .bind(time_source.now().to_sqlx())
+        .execute(&mut *tx)
+        .await
+        .context("Failed to invalidate other reset tokens")?;
+    }
+
     tx.commit().await.context("Failed to commit transaction")?;
 
     tracing::info!(
@@ -383,3 +624,388 @@ pub async fn cleanup_expired_tokens(
     tracing::info!("Cleaned up {} expired tokens", result.rows_affected());
     Ok(result.rows_affected())
 }
+
+/// Token counts by action for operators. Buckets are exclusive: used tokens
+/// count as used whether or not they've since expired.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn get_token_stats(
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<payloads::responses::TokenStats, StoreError> {
+    let rows = sqlx::query_as::<_, (TokenAction, i64, i64, i64)>(
+        r#"
+        SELECT
+            action,
+            COUNT(*) FILTER (WHERE used),
+            COUNT(*) FILTER (WHERE NOT used AND expires_at >= $1),
+            COUNT(*) FILTER (WHERE NOT used AND expires_at < $1)
+        FROM tokens
+        GROUP BY action
+        "#,
+    )
+    .bind(time_source.now().to_sqlx())
+    .fetch_all(pool)
+    .await
+    .context("Failed to count tokens")?;
+
+    let mut stats = payloads::responses::TokenStats::default();
+    for (action, used, unused, expired) in rows {
+        let counts = match action {
+            TokenAction::EmailVerification => &mut stats.email_verification,
+            TokenAction::PasswordReset => &mut stats.password_reset,
+        };
+        *counts = payloads::responses::TokenCounts {
+            used,
+            unused,
+            expired,
+        };
+    }
+    Ok(stats)
+}
+
+/// How many recovery codes are issued at a time.
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Recovery attempts allowed against one account per
+/// [`RECOVERY_ATTEMPT_WINDOW`], counting successes and failures alike.
+pub const MAX_RECOVERY_ATTEMPTS: i32 = 5;
+
+/// The window starts at an account's first recovery attempt and is reset
+/// once recovery succeeds.
+pub const RECOVERY_ATTEMPT_WINDOW: jiff::SignedDuration =
+    jiff::SignedDuration::from_hours(1);
+
+/// Replace the user's recovery codes, used or not, with `code_hashes`.
+#[tracing::instrument(skip(code_hashes, pool, time_source))]
+pub async fn replace_recovery_codes(
+    user_id: &UserId,
+    code_hashes: &[String],
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    let mut tx = pool.begin().await.context("Failed to begin transaction")?;
+    sqlx::query("DELETE FROM recovery_codes WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .context("Failed to delete old recovery codes")?;
+    sqlx::query(
+        r#"
+        INSERT INTO recovery_codes (user_id, code_hash, created_at)
+        SELECT $1, code_hash, $3
+        FROM unnest($2::text[]) AS t(code_hash)
+        "#,
+    )
+    .bind(user_id)
+    .bind(code_hashes)
+    .bind(time_source.now().to_sqlx())
+    .execute(&mut *tx)
+    .await
+    .context("Failed to insert recovery codes")?;
+    tx.commit().await.context("Failed to commit transaction")?;
+    Ok(())
+}
+
+/// How many of the user's recovery codes are still unused.
+pub async fn count_recovery_codes(
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<i64, StoreError> {
+    let count = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM recovery_codes
+        WHERE user_id = $1 AND used_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .context("Failed to count recovery codes")?;
+    Ok(count)
+}
+
+/// Count a recovery attempt against the account named `username`, returning
+/// its id, or `None` if no such account exists. Fails with
+/// `AccountRecoveryRateLimited` once [`MAX_RECOVERY_ATTEMPTS`] have been made
+/// in the current window.
+///
+/// The attempt is claimed in a single statement, so concurrent requests
+/// can't all slip under the limit.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn claim_recovery_attempt(
+    username: &str,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Option<UserId>, StoreError> {
+    let now = time_source.now();
+    let claimed = sqlx::query_as::<_, (UserId, bool)>(
+        r#"
+        UPDATE users SET
+            recovery_attempts = CASE
+                WHEN recovery_window_start IS NULL
+                    OR recovery_window_start <= $3
+                THEN 1 ELSE recovery_attempts + 1 END,
+            recovery_window_start = CASE
+                WHEN recovery_window_start IS NULL
+                    OR recovery_window_start <= $3
+                THEN $2 ELSE recovery_window_start END
+        WHERE username_normalized = lower($1) AND deleted_at IS NULL
+        RETURNING id, recovery_attempts <= $4
+        "#,
+    )
+    .bind(username)
+    .bind(now.to_sqlx())
+    .bind((now - RECOVERY_ATTEMPT_WINDOW).to_sqlx())
+    .bind(MAX_RECOVERY_ATTEMPTS)
+    .fetch_optional(pool)
+    .await
+    .context("Failed to claim recovery attempt")?;
+    match claimed {
+        Some((_, false)) => Err(ApiError::AccountRecoveryRateLimited.into()),
+        Some((user_id, true)) => Ok(Some(user_id)),
+        None => Ok(None),
+    }
+}
+
+/// The ids and hashes of the user's unused recovery codes.
+pub async fn unused_recovery_codes(
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<(Uuid, String)>, StoreError> {
+    let codes = sqlx::query_as::<_, (Uuid, String)>(
+        "SELECT id, code_hash FROM recovery_codes
+        WHERE user_id = $1 AND used_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .context("Failed to fetch recovery codes")?;
+    Ok(codes)
+}
+
+/// Consume a verified recovery code and set the user's new password. All of
+/// the user's sessions end, their outstanding password reset links stop
+/// working, and their recovery attempt count is cleared.
+///
+/// Fails with `InvalidRecoveryCode` if a concurrent request consumed the
+/// code first.
+#[tracing::instrument(skip(password_hash, pool, time_source))]
+pub async fn recover_account(
+    user_id: &UserId,
+    code_id: Uuid,
+    password_hash: &str,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    let now = time_source.now().to_sqlx();
+    let mut tx = pool.begin().await.context("Failed to begin transaction")?;
+
+    let consumed = sqlx::query(
+        "UPDATE recovery_codes SET used_at = $3
+        WHERE id = $1 AND user_id = $2 AND used_at IS NULL",
+    )
+    .bind(code_id)
+    .bind(user_id)
+    .bind(now)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to consume recovery code")?
+    .rows_affected();
+    if consumed == 0 {
+        return Err(ApiError::InvalidRecoveryCode.into());
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE users SET
+            password_hash = $2,
+            session_generation = session_generation + 1,
+            recovery_attempts = 0,
+            recovery_window_start = NULL,
+            updated_at = $3
+        WHERE id = $1
+        "#,
+    )
+    .bind(user_id)
+    .bind(password_hash)
+    .bind(now)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to set recovered password")?;
+
+    sqlx::query(
+        r#"
+        UPDATE tokens
+        SET used = true, updated_at = $3
+        WHERE user_id = $1 AND action = $2 AND NOT used
+            AND expires_at > $3
+        "#,
+    )
+    .bind(user_id)
+    .bind(TokenAction::PasswordReset)
+    .bind(now)
+    .execute(&mut *tx)
+    .await
+    .context("Failed to invalidate reset tokens")?;
+
+    tx.commit().await.context("Failed to commit transaction")?;
+    tracing::info!("Recovered account for user {}", user_id.0);
+    Ok(())
+}
+
+/// The user's current session generation, or `None` if the account is gone.
+/// Sessions record the generation they logged in at and end once it moves
+/// on.
+pub async fn get_session_generation(
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Option<i32>, StoreError> {
+    let generation = sqlx::query_scalar::<_, i32>(
+        "SELECT session_generation FROM users
+        WHERE id = $1 AND deleted_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .context("Failed to fetch session generation")?;
+    Ok(generation)
+}
+
+/// How long an impersonated session acts as its target before logging out.
+pub const IMPERSONATION_DURATION: jiff::SignedDuration =
+    jiff::SignedDuration::from_mins(30);
+
+/// Check that `actor_id` may impersonate `target_id` and audit the start.
+/// Only superusers may impersonate, and never another superuser. Returns when
+/// the impersonation expires.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn start_impersonation(
+    actor_id: &UserId,
+    target_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Timestamp, StoreError> {
+    let actor = read_user(pool, actor_id).await?;
+    if !actor.is_superuser {
+        return Err(ApiError::RequiresSuperuser.into());
+    }
+    let target = read_user(pool, target_id).await?;
+    if target.deleted_at.is_some() {
+        return Err(ApiError::UserNotFound.into());
+    }
+    if target.is_superuser {
+        return Err(ApiError::CannotImpersonateSuperuser.into());
+    }
+
+    let now = time_source.now();
+    let expires_at = now + IMPERSONATION_DURATION;
+    insert_impersonation_audit(
+        "impersonation_start",
+        actor_id,
+        target_id,
+        serde_json::json!({ "expires_at": expires_at }),
+        pool,
+        now,
+    )
+    .await?;
+    Ok(expires_at)
+}
+
+/// Audit the end of an impersonation, whether stopped explicitly or by
+/// logging out.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn stop_impersonation(
+    actor_id: &UserId,
+    target_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    insert_impersonation_audit(
+        "impersonation_stop",
+        actor_id,
+        target_id,
+        serde_json::json!({}),
+        pool,
+        time_source.now(),
+    )
+    .await
+}
+
+/// Audit a mutating request made under impersonation, before it runs.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn audit_impersonated_request(
+    actor_id: &UserId,
+    target_id: &UserId,
+    method: &str,
+    path: &str,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    insert_impersonation_audit(
+        "impersonated_request",
+        actor_id,
+        target_id,
+        serde_json::json!({ "method": method, "path": path }),
+        pool,
+        time_source.now(),
+    )
+    .await
+}
+
+async fn insert_impersonation_audit(
+    action: &str,
+    actor_id: &UserId,
+    target_id: &UserId,
+    details: serde_json::Value,
+    pool: &PgPool,
+    now: Timestamp,
+) -> Result<(), StoreError> {
+    sqlx::query(
+        "INSERT INTO audit_log (
+            actor_id, action, target_table, target_id, details, created_at
+        ) VALUES ($1, $2, 'users', $3, $4, $5)",
+    )
+    .bind(actor_id)
+    .bind(action)
+    .bind(target_id)
+    .bind(details)
+    .bind(now.to_sqlx())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Make the account with `email` a superuser, for bootstrapping a new
+/// deployment. Only a verified, non-deleted account is promoted; accounts are
+/// never created. Returns whether anything changed, so it's safe to run on
+/// every startup.
+#[tracing::instrument(skip(pool, time_source))]
+pub async fn ensure_superuser(
+    email: &str,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<bool, StoreError> {
+    let (user_id, is_superuser) = sqlx::query_as::<_, (UserId, bool)>(
+        "SELECT id, is_superuser FROM users
+        WHERE email_normalized = lower($1)
+            AND email_verified
+            AND deleted_at IS NULL",
+    )
+    .bind(email)
+    .fetch_optional(pool)
+    .await
+    .context("Failed to fetch user by email")?
+    .ok_or(ApiError::UserNotFound)?;
+    if is_superuser {
+        return Ok(false);
+    }
+
+    sqlx::query(
+        "UPDATE users SET is_superuser = true, updated_at = $2 WHERE id = $1",
+    )
+    .bind(user_id)
+    .bind(time_source.now().to_sqlx())
+    .execute(pool)
+    .await
+    .context("Failed to promote user to superuser")?;
+    tracing::info!(%user_id, "promoted bootstrap superuser");
+    Ok(true)
+}
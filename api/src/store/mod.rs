@@ -20,12 +20,10 @@
 //! - **Consistent time handling**: All time-sensitive operations use the same
 //!   `TimeSource` instance passed from the application routes.
 //!
-//! ### Database Triggers
-//! - **Auto-updated timestamps**: The database has triggers that automatically
-//!   update `updated_at` fields, so application code doesn't need to manually
-//!   set these values.
-//! - **Consistent audit trail**: All modifications are tracked at the database
-//!   level for reliability.
+//! ### Timestamps
+//! - **Explicit `updated_at`**: There are no database triggers. Every mutation
+//!   sets `updated_at` from the `TimeSource`, even when no other column
+//!   changes, so it doubles as a cache validator (`Last-Modified`/`ETag`).
 //!
 //! ### Type Safety
 //! - **TokenId with sqlx::Type**: TokenId implements sqlx::Type, so it can be
@@ -57,7 +55,10 @@ pub mod auction;
 pub mod billing;
 pub mod community;
 pub mod currency;
+pub mod export;
 pub mod login;
+pub mod notification;
+pub mod outbox;
 pub mod proxy_bidding;
 pub mod site;
 pub mod space;
@@ -96,6 +97,7 @@ impl From<Space> for payloads::responses::Space {
     fn from(space: Space) -> Self {
         Self {
             space_id: space.id,
+            display_order: space.display_order,
             created_at: space.created_at,
             updated_at: space.updated_at,
             deleted_at: space.deleted_at,
@@ -114,6 +116,11 @@ pub struct User {
     pub password_hash: String,
     pub display_name: Option<String>,
     pub email_verified: bool,
+    pub email_possession_reminders: bool,
+    #[sqlx(try_from = "OptionalTimestamp")]
+    pub data_exported_at: Option<Timestamp>,
+    pub locale: Option<payloads::Locale>,
+    pub is_superuser: bool,
     #[sqlx(try_from = "SqlxTs")]
     pub created_at: Timestamp,
     #[sqlx(try_from = "SqlxTs")]
@@ -122,6 +129,37 @@ pub struct User {
     pub deleted_at: Option<Timestamp>,
 }
 
+impl From<User> for payloads::responses::UserProfile {
+    fn from(user: User) -> Self {
+        Self {
+            user_id: user.id,
+            username: user.username,
+            email: user.email,
+            display_name: user.display_name,
+            email_verified: user.email_verified,
+            email_possession_reminders: user.email_possession_reminders,
+            locale: user.locale,
+            impersonated_by: None,
+            // Counted separately; see `count_recovery_codes`
+            recovery_codes_remaining: 0,
+        }
+    }
+}
+
+/// Fail with `UnverifiedEmail` unless the user has verified their email or
+/// signed up less than `grace` ago. Actions that change anything pass a zero
+/// grace period; see [`crate::Config::email_verification_grace`].
+pub fn require_verified_email(
+    user: &User,
+    grace: jiff::SignedDuration,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    if user.email_verified || time_source.now() < user.created_at + grace {
+        return Ok(());
+    }
+    Err(ApiError::UnverifiedEmail.into())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Display, sqlx::Type, FromRow)]
 #[sqlx(transparent)]
 pub struct TokenId(pub Uuid);
@@ -156,6 +194,18 @@ pub struct CommunityMember {
 /// validated to exist.
 pub struct ValidatedMember(CommunityMember);
 
+impl ValidatedMember {
+    /// The member's standing, for responses that expand
+    /// `include=community_role`.
+    fn community_role(&self) -> payloads::responses::CommunityRole {
+        payloads::responses::CommunityRole {
+            community_id: self.0.community_id,
+            role: self.0.role,
+            is_active: self.0.is_active,
+        }
+    }
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct CommunityInvite {
     pub id: InviteId,
@@ -163,6 +213,7 @@ pub struct CommunityInvite {
     pub email: Option<String>,
     pub email_normalized: Option<String>,
     pub single_use: bool,
+    pub role: Role,
     #[sqlx(try_from = "SqlxTs")]
     pub created_at: Timestamp,
 }
@@ -224,18 +275,38 @@ pub struct AuctionParams {
     pub round_duration: Span,
     pub bid_increment: payloads::BidIncrement,
     pub activity_rule_params: Json<payloads::ActivityRuleParams>,
+    pub increment_schedule: Json<Vec<(i32, Decimal)>>,
+    pub min_rounds: Option<i32>,
+    pub eligibility_rounding: payloads::RoundingPolicy,
     #[sqlx(try_from = "SqlxTs")]
     pub created_at: Timestamp,
     #[sqlx(try_from = "SqlxTs")]
     pub updated_at: Timestamp,
 }
 
+impl AuctionParams {
+    /// Bid increment for bids placed in `round_num`.
+    pub fn bid_increment_for_round(
+        &self,
+        round_num: i32,
+    ) -> payloads::BidIncrement {
+        payloads::bid_increment_for_round(
+            round_num,
+            &self.increment_schedule,
+            self.bid_increment,
+        )
+    }
+}
+
 impl From<AuctionParams> for payloads::AuctionParams {
     fn from(params: AuctionParams) -> Self {
         Self {
             round_duration: params.round_duration,
             bid_increment: params.bid_increment,
             activity_rule_params: params.activity_rule_params.0,
+            increment_schedule: params.increment_schedule.0,
+            min_rounds: params.min_rounds,
+            eligibility_rounding: params.eligibility_rounding,
         }
     }
 }
@@ -294,6 +365,7 @@ pub struct Space {
     pub is_available: bool,
     pub site_image_id: Option<SiteImageId>,
     pub reserve_price: payloads::ReservePrice,
+    pub display_order: i32,
     #[sqlx(try_from = "SqlxTs")]
     pub created_at: Timestamp,
     #[sqlx(try_from = "SqlxTs")]
@@ -319,31 +391,56 @@ pub struct Auction {
     pub scheduler_failure_count: i32,
     #[sqlx(try_from = "OptionalTimestamp")]
     pub scheduler_last_failed_at: Option<Timestamp>,
+    /// Seeds the PRNG for tie-breaks, together with the round number
+    pub random_seed: i64,
+    /// Practice auction with no balance effects
+    pub sandbox: bool,
+    pub winner_constraints: Json<Vec<payloads::WinnerConstraint>>,
     #[sqlx(try_from = "SqlxTs")]
     pub created_at: Timestamp,
     #[sqlx(try_from = "SqlxTs")]
     pub updated_at: Timestamp,
+    pub moderator_notes: Option<String>,
+    pub internal_status: Option<String>,
 }
 
 impl Auction {
-    // Helper to convert to response type with params
+    // Helper to convert to response type with params. The random seed is only
+    // shown to coleaders and above, and the moderator notes and internal
+    // status to moderators and above.
     pub fn with_params(
         self,
         params: AuctionParams,
+        viewer: &ValidatedMember,
     ) -> payloads::responses::Auction {
         payloads::responses::Auction {
             auction_id: self.id,
+            community_id: viewer.0.community_id,
             auction_details: payloads::Auction {
                 site_id: self.site_id,
                 possession_start_at: self.possession_start_at,
                 possession_end_at: self.possession_end_at,
                 start_at: self.start_at,
                 auction_params: params.into(),
+                sandbox: self.sandbox,
+                winner_constraints: self.winner_constraints.0,
             },
             created_at: self.created_at,
             updated_at: self.updated_at,
             end_at: self.end_at,
             was_canceled: self.was_canceled,
+            random_seed: viewer
+                .0
+                .role
+                .is_ge_coleader()
+                .then_some(self.random_seed),
+            community_role: None,
+            moderator_notes: self
+                .moderator_notes
+                .filter(|_| viewer.0.role.is_ge_moderator()),
+            internal_status: self
+                .internal_status
+                .filter(|_| viewer.0.role.is_ge_moderator()),
         }
     }
 
@@ -432,6 +529,7 @@ pub struct UseProxyBidding {
     pub user_id: UserId,
     pub auction_id: AuctionId,
     pub max_items: i32,
+    pub max_total: Option<Decimal>,
     pub needs_processing: bool,
     #[sqlx(try_from = "SqlxTs")]
     pub created_at: Timestamp,
@@ -444,6 +542,7 @@ impl From<UseProxyBidding> for payloads::responses::UseProxyBidding {
         Self {
             auction_id: value.auction_id,
             max_items: value.max_items,
+            max_total: value.max_total,
             created_at: value.created_at,
         }
     }
@@ -459,8 +558,15 @@ struct DbCommunity {
     id: CommunityId,
     name: String,
     description: Option<String>,
+    description_public: bool,
     community_image_id: Option<SiteImageId>,
     new_members_default_active: bool,
+    registration_open: bool,
+    invite_base_url: Option<String>,
+    #[sqlx(try_from = "payloads::OptionalSpan")]
+    min_round_duration: Option<jiff::Span>,
+    #[sqlx(try_from = "payloads::OptionalSpan")]
+    max_round_duration: Option<jiff::Span>,
     #[sqlx(try_from = "SqlxTs")]
     created_at: Timestamp,
     #[sqlx(try_from = "SqlxTs")]
@@ -503,6 +609,13 @@ impl TryFrom<DbCommunity> for Community {
             id: db.id,
             name: db.name,
             description: db.description,
+            description_public: db.description_public,
+            registration_open: db.registration_open,
+            invite_base_url: db.invite_base_url,
+            round_duration_bounds: payloads::RoundDurationBounds {
+                min: db.min_round_duration.and_then(duration_from_interval),
+                max: db.max_round_duration.and_then(duration_from_interval),
+            },
             community_image_id: db.community_image_id,
             created_at: db.created_at,
             updated_at: db.updated_at,
@@ -523,6 +636,24 @@ pub struct AuditLog {
     pub created_at: Timestamp,
 }
 
+/// Whether the database is reachable and has the newest embedded migration
+/// applied.
+pub async fn latest_migration_applied(
+    pool: &sqlx::PgPool,
+) -> Result<bool, StoreError> {
+    let Some(latest) = crate::MIGRATOR.iter().map(|m| m.version).max() else {
+        return Ok(true);
+    };
+    Ok(sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (
+            SELECT 1 FROM _sqlx_migrations WHERE version = $1 AND success
+        )",
+    )
+    .bind(latest)
+    .fetch_one(pool)
+    .await?)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum StoreError {
     /// A client-facing error, serialized as JSON in the response body.
@@ -551,6 +682,24 @@ pub enum StoreError {
     StripeError(String),
 }
 
+/// Turn a [`payloads::sanitize`] result into the normalized string or an API
+/// error. `too_long` builds the field's own length error from the normalized
+/// length and the limit.
+fn sanitized(
+    result: Result<String, payloads::sanitize::SanitizeError>,
+    field: &str,
+    too_long: impl FnOnce(usize, usize) -> ApiError,
+) -> Result<String, StoreError> {
+    use payloads::sanitize::SanitizeError;
+    result.map_err(|e| match e {
+        SanitizeError::TooLong { len, max } => too_long(len, max).into(),
+        SanitizeError::ControlCharacters => ApiError::ControlCharacters {
+            field: field.to_string(),
+        }
+        .into(),
+    })
+}
+
 /// Convert a space name unique constraint violation into a more specific error.
 /// If the error is a unique violation on the spaces_site_id_name_unique index,
 /// returns SpaceNameNotUnique. Otherwise returns the original error.
@@ -608,9 +757,31 @@ pub fn span_to_interval(span: &Span) -> Result<PgInterval, StoreError> {
         .ok_or_else(|| ApiError::SpanTooLarge(span.to_string()).into())
 }
 
+/// An interval holding an exact duration, for bounds compared against spans.
+pub(crate) fn duration_to_interval(
+    duration: jiff::SignedDuration,
+) -> Result<PgInterval, StoreError> {
+    let microseconds = i64::try_from(duration.as_micros())
+        .map_err(|_| ApiError::SpanTooLarge(format!("{duration:#}")))?;
+    Ok(PgInterval {
+        months: 0,
+        days: 0,
+        microseconds,
+    })
+}
+
+/// The exact duration of an interval read back as a span, counting days as
+/// 24 hours. None for intervals in months, which have no fixed length.
+pub(crate) fn duration_from_interval(
+    span: Span,
+) -> Option<jiff::SignedDuration> {
+    span.to_duration(jiff::SpanRelativeTo::days_are_24_hours())
+        .ok()
+}
+
 fn span_to_interval_opt(span: &Span) -> Option<PgInterval> {
     let microseconds = span
-        .get_milliseconds()
+        .get_microseconds()
         .checked_add(span.get_milliseconds().checked_mul(1_000)?)?
         .checked_add(span.get_seconds().checked_mul(1_000_000)?)?
         .checked_add(span.get_minutes().checked_mul(60 * 1_000_000)?)?
@@ -629,3 +800,32 @@ fn span_to_interval_opt(span: &Span) -> Option<PgInterval> {
         months,
     })
 }
+
+/// SQL predicate restricting a `created_at DESC, id DESC` listing to rows
+/// strictly after a keyset cursor. The cursor's timestamp and id must be bound
+/// at `$param` and `$param + 1` (see [`cursor_bounds`]); binding NULLs, for the
+/// first page, matches every row.
+pub(crate) fn keyset_after(
+    created_at_col: &str,
+    id_col: &str,
+    param: usize,
+) -> String {
+    let next = param + 1;
+    format!(
+        "(${param}::timestamptz IS NULL \
+         OR ({created_at_col}, {id_col}) < (${param}, ${next}::uuid))"
+    )
+}
+
+/// Bind values for [`keyset_after`].
+pub(crate) fn cursor_bounds(
+    cursor: Option<&payloads::Cursor>,
+) -> Result<(Option<SqlxTs>, Option<Uuid>), StoreError> {
+    use jiff_sqlx::ToSqlx;
+
+    let Some(cursor) = cursor else {
+        return Ok((None, None));
+    };
+    let (created_at, id) = cursor.decode()?;
+    Ok((Some(created_at.to_sqlx()), Some(id)))
+}
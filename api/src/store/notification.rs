@@ -0,0 +1,126 @@
+//! Bookkeeping for one-time notification emails.
+//!
+//! Each notification is keyed by `(user_id, kind, reference_id)` in
+//! `notifications_sent`. Claiming that row and queuing the email in the
+//! outbox happen in one transaction, so each notification is queued exactly
+//! once, and the outbox retries a send that fails.
+
+use super::*;
+use jiff_sqlx::ToSqlx;
+use payloads::{AuctionId, SpaceId};
+use sqlx::PgPool;
+
+use crate::time::TimeSource;
+
+/// Namespace for deterministic (UUIDv5) notification reference ids.
+const REFERENCE_ID_NAMESPACE: Uuid =
+    uuid::uuid!("4f0f3b8e-6f5d-4a49-9d8e-2b7c1e5a9c31");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(type_name = "notification_kind", rename_all = "snake_case")]
+pub enum NotificationKind {
+    PossessionEnding,
+}
+
+/// A possession ending within the reminder window whose winner hasn't been
+/// reminded yet and hasn't opted out.
+#[derive(Debug, Clone, FromRow)]
+pub struct PossessionReminder {
+    pub user_id: UserId,
+    pub email: String,
+    pub username: String,
+    pub auction_id: AuctionId,
+    pub space_id: SpaceId,
+    pub site_name: String,
+    pub site_timezone: Option<String>,
+    pub space_name: String,
+    #[sqlx(try_from = "SqlxTs")]
+    pub possession_end_at: Timestamp,
+}
+
+impl PossessionReminder {
+    /// Stable id for this possession, the same on every tick.
+    pub fn reference_id(&self) -> Uuid {
+        Uuid::new_v5(
+            &REFERENCE_ID_NAMESPACE,
+            format!("{}:{}", self.auction_id, self.space_id).as_bytes(),
+        )
+    }
+}
+
+pub async fn list_due_possession_reminders(
+    reminder_window: jiff::SignedDuration,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Vec<PossessionReminder>, StoreError> {
+    let now = time_source.now();
+    let reminders = sqlx::query_as::<_, PossessionReminder>(&format!(
+        "SELECT u.id AS user_id,
+            u.email,
+            u.username,
+            p.auction_id,
+            p.space_id,
+            p.site_name,
+            p.site_timezone,
+            p.space_name,
+            p.possession_end_at
+        FROM ({}) p
+        JOIN users u ON u.id = p.winning_user_id
+        WHERE p.ends_soon
+            AND u.deleted_at IS NULL
+            AND u.email_possession_reminders
+        ORDER BY p.possession_end_at",
+        auction::POSSESSIONS_QUERY
+    ))
+    .bind(now.to_sqlx())
+    .bind((now + reminder_window).to_sqlx())
+    .fetch_all(pool)
+    .await?;
+
+    // Reference ids are derived in Rust, so drop already-sent reminders
+    // after the fact rather than in the query above.
+    let reference_ids: Vec<Uuid> = reminders
+        .iter()
+        .map(PossessionReminder::reference_id)
+        .collect();
+    let sent: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT reference_id FROM notifications_sent
+        WHERE kind = $1 AND reference_id = ANY($2)",
+    )
+    .bind(NotificationKind::PossessionEnding)
+    .bind(&reference_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(reminders
+        .into_iter()
+        .filter(|r| !sent.contains(&r.reference_id()))
+        .collect())
+}
+
+/// Claim a notification. Returns false if it was already claimed, in which
+/// case the caller must not queue it again. Takes any executor so the claim
+/// can commit together with the queued email.
+pub async fn claim_notification<'e, E>(
+    user_id: &UserId,
+    kind: NotificationKind,
+    reference_id: Uuid,
+    executor: E,
+    time_source: &TimeSource,
+) -> Result<bool, StoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let result = sqlx::query(
+        "INSERT INTO notifications_sent
+            (user_id, kind, reference_id, created_at)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT DO NOTHING",
+    )
+    .bind(user_id)
+    .bind(kind)
+    .bind(reference_id)
+    .bind(time_source.now().to_sqlx())
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected() == 1)
+}
@@ -0,0 +1,145 @@
+//! Durable queue of outbound emails.
+//!
+//! Emails are rendered when enqueued and stored in `outbox_emails`, so a
+//! pending email survives a restart. The scheduler claims one due email at a
+//! time by pushing its next attempt back by [`CLAIM_LEASE`], sends it with no
+//! transaction open, then marks it sent or failed. A failure backs off
+//! exponentially before the next attempt, and after [`MAX_ATTEMPTS`] the
+//! email is left unsent.
+//!
+//! Delivery is at least once: a crash between a send and marking it sent
+//! sends that email again once its lease runs out.
+
+use super::*;
+use jiff_sqlx::ToSqlx;
+use sqlx::PgPool;
+
+use crate::email::EmailTemplate;
+use crate::time::TimeSource;
+
+/// Failed sends after which an email is no longer retried.
+pub const MAX_ATTEMPTS: i32 = 10;
+
+/// Wait before retrying after the first failure, doubling with each further
+/// failure.
+const INITIAL_BACKOFF: jiff::SignedDuration =
+    jiff::SignedDuration::from_secs(60);
+
+/// Longest wait between attempts.
+const MAX_BACKOFF: jiff::SignedDuration = jiff::SignedDuration::from_hours(6);
+
+/// How long a claimed email is held back from other scheduler instances
+/// while it's being sent.
+pub const CLAIM_LEASE: jiff::SignedDuration =
+    jiff::SignedDuration::from_mins(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, sqlx::Type, FromRow)]
+#[sqlx(transparent)]
+pub struct OutboxEmailId(pub Uuid);
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OutboxEmail {
+    pub id: OutboxEmailId,
+    pub recipient: String,
+    pub template: String,
+    pub payload: Json<EmailTemplate>,
+    pub attempts: i32,
+}
+
+/// Add an email to the outbox, to be sent on the next scheduler tick. Takes
+/// any executor so the email can be enqueued in the same transaction as the
+/// change it's about.
+pub async fn enqueue_email<'e, E>(
+    recipient: &str,
+    template: &str,
+    email: &EmailTemplate,
+    executor: E,
+    time_source: &TimeSource,
+) -> Result<OutboxEmailId, StoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    Ok(sqlx::query_scalar(
+        "INSERT INTO outbox_emails (
+            recipient, template, payload, next_attempt_at, created_at
+        ) VALUES ($1, $2, $3, $4, $4)
+        RETURNING id",
+    )
+    .bind(recipient)
+    .bind(template)
+    .bind(Json(email))
+    .bind(time_source.now().to_sqlx())
+    .fetch_one(executor)
+    .await?)
+}
+
+/// Claim the oldest email that is due to be sent, if any, by pushing its
+/// next attempt back by [`CLAIM_LEASE`]. Rows locked by another scheduler
+/// instance are skipped.
+pub async fn claim_due_email(
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Option<OutboxEmail>, StoreError> {
+    let now = time_source.now();
+    Ok(sqlx::query_as::<_, OutboxEmail>(
+        "UPDATE outbox_emails SET next_attempt_at = $3
+        WHERE id = (
+            SELECT id FROM outbox_emails
+            WHERE sent_at IS NULL
+                AND next_attempt_at <= $1
+                AND attempts < $2
+            ORDER BY next_attempt_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, recipient, template, payload, attempts",
+    )
+    .bind(now.to_sqlx())
+    .bind(MAX_ATTEMPTS)
+    .bind((now + CLAIM_LEASE).to_sqlx())
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn mark_email_sent(
+    id: &OutboxEmailId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    sqlx::query("UPDATE outbox_emails SET sent_at = $2 WHERE id = $1")
+        .bind(id)
+        .bind(time_source.now().to_sqlx())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Record a failed send and schedule the next attempt.
+pub async fn mark_email_failed(
+    email: &OutboxEmail,
+    error: &str,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    let next_attempt_at = time_source.now() + retry_backoff(email.attempts);
+    sqlx::query(
+        "UPDATE outbox_emails
+        SET attempts = attempts + 1, last_error = $2, next_attempt_at = $3
+        WHERE id = $1",
+    )
+    .bind(email.id)
+    .bind(error)
+    .bind(next_attempt_at.to_sqlx())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Wait before retrying an email that has failed `previous_attempts` times
+/// before this failure.
+fn retry_backoff(previous_attempts: i32) -> jiff::SignedDuration {
+    let factor = 1i32 << previous_attempts.clamp(0, 16);
+    INITIAL_BACKOFF
+        .checked_mul(factor)
+        .map_or(MAX_BACKOFF, |backoff| backoff.min(MAX_BACKOFF))
+}
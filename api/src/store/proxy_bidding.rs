@@ -12,7 +12,7 @@ pub async fn create_or_update_user_value(
     time_source: &TimeSource,
 ) -> Result<(), StoreError> {
     // Verify the space exists and user has access to it
-    let (_, _) = get_validated_space(
+    validate_space_access(
         &details.space_id,
         user_id,
         PermissionLevel::Member,
@@ -42,6 +42,26 @@ pub async fn create_or_update_user_value(
     Ok(())
 }
 
+/// Set the user's value for a space and immediately rerun their proxy
+/// bidding in any active round, so a raised value bids again without waiting
+/// for the next scheduler tick. Without proxy bidding enabled or an active
+/// round this is just a value update.
+pub async fn set_user_value_and_rebid(
+    details: &payloads::requests::UserValue,
+    user_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    create_or_update_user_value(details, user_id, pool, time_source).await?;
+    crate::scheduler::process_due_proxy_items_for_user(
+        user_id,
+        pool,
+        time_source,
+    )
+    .await?;
+    Ok(())
+}
+
 /// Mark the user's proxy rows dirty for open auctions of the space's site,
 /// in the same transaction as the value write, so the proxy processor
 /// re-selects the (round, user) item. Setting the flag in the writer's own
@@ -76,9 +96,8 @@ pub async fn get_user_value(
     pool: &PgPool,
 ) -> Result<payloads::responses::UserValue, StoreError> {
     // Verify the space exists and user has access to it
-    let (_, _) =
-        get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
-            .await?;
+    validate_space_access(space_id, user_id, PermissionLevel::Member, pool)
+        .await?;
 
     let value = sqlx::query_as::<_, UserValue>(
         "SELECT * FROM user_values WHERE space_id = $1 AND user_id = $2",
@@ -101,9 +120,8 @@ pub async fn delete_user_value(
     pool: &PgPool,
 ) -> Result<(), StoreError> {
     // Verify the space exists and user has access to it
-    let (_, _) =
-        get_validated_space(space_id, user_id, PermissionLevel::Member, pool)
-            .await?;
+    validate_space_access(space_id, user_id, PermissionLevel::Member, pool)
+        .await?;
 
     let mut tx = pool.begin().await?;
 
@@ -149,6 +167,44 @@ pub async fn list_user_values(
     Ok(values.into_iter().map(Into::into).collect())
 }
 
+/// When proxy bidding can be set up for an auction. It opens the site's
+/// proxy bidding lead time before the auction starts, so every registration
+/// is in before bidding begins, and closes when round 0 ends. Changes during
+/// round 0 are still picked up before any round concludes; after that they
+/// would shift outcomes mid-auction.
+pub async fn get_proxy_bidding_window(
+    auction_id: &AuctionId,
+    pool: &PgPool,
+) -> Result<payloads::responses::ProxyBiddingWindow, StoreError> {
+    #[derive(sqlx::FromRow)]
+    struct Window {
+        #[sqlx(try_from = "OptionalTimestamp")]
+        opens_at: Option<Timestamp>,
+        #[sqlx(try_from = "OptionalTimestamp")]
+        closes_at: Option<Timestamp>,
+    }
+
+    let window = sqlx::query_as::<_, Window>(
+        "SELECT
+            a.start_at - s.proxy_bidding_lead_time AS opens_at,
+            COALESCE(r.end_at, a.end_at) AS closes_at
+        FROM auctions a
+        JOIN sites s ON s.id = a.site_id
+        LEFT JOIN auction_rounds r
+            ON r.auction_id = a.id AND r.round_num = 0
+        WHERE a.id = $1",
+    )
+    .bind(auction_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(ApiError::AuctionNotFound)?;
+
+    Ok(payloads::responses::ProxyBiddingWindow {
+        opens_at: window.opens_at,
+        closes_at: window.closes_at,
+    })
+}
+
 pub async fn create_or_update_proxy_bidding(
     details: &payloads::requests::UseProxyBidding,
     user_id: &UserId,
@@ -156,7 +212,7 @@ pub async fn create_or_update_proxy_bidding(
     time_source: &TimeSource,
 ) -> Result<(), StoreError> {
     // Verify user has access to the auction
-    let (_, _) = get_validated_auction(
+    let (auction, actor) = get_validated_auction(
         &details.auction_id,
         user_id,
         PermissionLevel::Member,
@@ -164,20 +220,61 @@ pub async fn create_or_update_proxy_bidding(
     )
     .await?;
 
+    let window = get_proxy_bidding_window(&details.auction_id, pool).await?;
+    let now = time_source.now();
+    if !window.has_opened(now) {
+        return Err(ApiError::ProxyBiddingNotYetOpen {
+            // has_opened is only false with an opening time
+            opens_at: window.opens_at.unwrap_or(now),
+        }
+        .into());
+    }
+    if window.has_closed(now) {
+        return Err(ApiError::ProxyBiddingClosed.into());
+    }
+
+    // The proxy engine can't win more spaces than the auction has, so a
+    // larger limit is a mistake rather than "no limit".
+    let available: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM spaces
+        WHERE site_id = $1 AND is_available AND deleted_at IS NULL",
+    )
+    .bind(auction.site_id)
+    .fetch_one(pool)
+    .await?;
+    if details.max_items < 1 || i64::from(details.max_items) > available {
+        return Err(ApiError::InvalidMaxItems { available }.into());
+    }
+
+    if let Some(max_total) = details.max_total {
+        if max_total < Decimal::ZERO {
+            return Err(ApiError::AmountMustBeNonNegative.into());
+        }
+        let minor_units: i16 = sqlx::query_scalar(
+            "SELECT currency_minor_units FROM communities WHERE id = $1",
+        )
+        .bind(actor.0.community_id)
+        .fetch_one(pool)
+        .await?;
+        super::currency::check_amount_quantized(max_total, minor_units)?;
+    }
+
     // needs_processing = TRUE (the insert default, re-asserted on update)
     // marks the item dirty in this same statement, so the proxy processor
     // re-selects it even if this write straddles a processing pass.
     sqlx::query(
-        "INSERT INTO use_proxy_bidding (user_id, auction_id, max_items, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, $4)
+        "INSERT INTO use_proxy_bidding (user_id, auction_id, max_items, max_total, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $5)
         ON CONFLICT (user_id, auction_id)
         DO UPDATE SET max_items = EXCLUDED.max_items,
+            max_total = EXCLUDED.max_total,
             needs_processing = TRUE,
             updated_at = EXCLUDED.updated_at",
     )
     .bind(user_id)
     .bind(details.auction_id)
     .bind(details.max_items)
+    .bind(details.max_total)
     .bind(time_source.now().to_sqlx())
     .execute(pool)
     .await?;
@@ -189,7 +286,7 @@ pub async fn get_proxy_bidding(
     auction_id: &AuctionId,
     user_id: &UserId,
     pool: &PgPool,
-) -> Result<Option<payloads::responses::UseProxyBidding>, StoreError> {
+) -> Result<payloads::responses::ProxyBiddingStatus, StoreError> {
     // Verify user has access to the auction
     let (_, _) = get_validated_auction(
         auction_id,
@@ -207,7 +304,10 @@ pub async fn get_proxy_bidding(
     .fetch_optional(pool)
     .await?;
 
-    Ok(settings.map(|s| s.into()))
+    Ok(payloads::responses::ProxyBiddingStatus {
+        settings: settings.map(|s| s.into()),
+        window: get_proxy_bidding_window(auction_id, pool).await?,
+    })
 }
 
 /// Lists members who have enabled proxy bidding for an auction. Restricted
@@ -265,10 +365,13 @@ pub async fn list_proxy_bidding_participants(
     .await
 }
 
+/// Disable proxy bidding, allowed until the window closes like any other
+/// change.
 pub async fn delete_proxy_bidding(
     auction_id: &AuctionId,
     user_id: &UserId,
     pool: &PgPool,
+    time_source: &TimeSource,
 ) -> Result<(), StoreError> {
     // Verify user has access to the auction
     let (_, _) = get_validated_auction(
@@ -279,6 +382,11 @@ pub async fn delete_proxy_bidding(
     )
     .await?;
 
+    let window = get_proxy_bidding_window(auction_id, pool).await?;
+    if window.has_closed(time_source.now()) {
+        return Err(ApiError::ProxyBiddingClosed.into());
+    }
+
     sqlx::query(
         "DELETE FROM use_proxy_bidding WHERE auction_id = $1 AND user_id = $2",
     )
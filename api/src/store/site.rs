@@ -1,16 +1,45 @@
 use super::*;
 use anyhow::Context;
 use jiff_sqlx::ToSqlx;
-use payloads::{ApiError, PermissionLevel, SiteId};
+use payloads::{ApiError, PermissionLevel, SiteId, sanitize};
 use sqlx::{PgPool, Postgres, Transaction};
 
+use crate::id_source::IdSource;
 use crate::time::TimeSource;
 
+/// Normalize a site's name and description for storage, or reject them.
+fn sanitize_site(
+    mut details: payloads::Site,
+) -> Result<payloads::Site, StoreError> {
+    details.name = sanitized(
+        sanitize::name(&details.name, payloads::requests::SITE_NAME_MAX_LEN),
+        "Site name",
+        |size, max| ApiError::SiteNameTooLong { size, max },
+    )?;
+    details.description = details
+        .description
+        .map(|description| {
+            sanitized(
+                sanitize::text(
+                    &description,
+                    payloads::MAX_SITE_DESCRIPTION_LENGTH,
+                ),
+                "Site description",
+                |size, max| ApiError::SiteDescriptionTooLong { size, max },
+            )
+        })
+        .transpose()?
+        .filter(|description| !description.is_empty());
+    Ok(details)
+}
+
 pub async fn create_site(
-    details: &payloads::Site,
+    details: &payloads::requests::SiteDetails,
     actor: &ValidatedMember,
+    default_bounds: &payloads::RoundDurationBounds,
     pool: &PgPool,
     time_source: &TimeSource,
+    id_source: &IdSource,
 ) -> Result<Site, StoreError> {
     if !actor.0.role.is_ge_coleader() {
         return Err(ApiError::InsufficientPermissions {
@@ -19,25 +48,30 @@ pub async fn create_site(
         .into());
     }
 
-    // Validate name length
-    if details.name.len() > payloads::requests::SITE_NAME_MAX_LEN {
-        return Err(ApiError::SiteNameTooLong {
-            size: details.name.len(),
-            max: payloads::requests::SITE_NAME_MAX_LEN,
-        }
-        .into());
-    }
-
-    // Validate description length
-    if let Some(desc) = &details.description
-        && desc.len() > payloads::MAX_SITE_DESCRIPTION_LENGTH
-    {
-        return Err(ApiError::SiteDescriptionTooLong {
-            size: desc.len(),
-            max: payloads::MAX_SITE_DESCRIPTION_LENGTH,
-        }
-        .into());
-    }
+    // Omitted params are inherited from the community default. Auction
+    // params rows are never modified once written, so the site can share
+    // the community default's row.
+    let (details, inherited_params_id) =
+        match details.default_auction_params.clone() {
+            Some(params) => (details.clone().with_default_params(params), None),
+            None => {
+                let params = sqlx::query_as::<_, AuctionParams>(
+                    "SELECT p.* FROM auction_params p
+                     JOIN communities c
+                        ON c.community_default_auction_params_id = p.id
+                     WHERE c.id = $1",
+                )
+                .bind(actor.0.community_id)
+                .fetch_optional(pool)
+                .await?
+                .ok_or(ApiError::NoDefaultAuctionParams)?;
+                let id = params.id.clone();
+                (details.clone().with_default_params(params.into()), Some(id))
+            }
+        };
+
+    // Persist the canonical form, so the site reads back equal to itself
+    let details = &sanitize_site(details.normalized())?;
 
     // Validate timezone is a valid IANA timezone string
     if let Some(tz) = &details.timezone
@@ -48,6 +82,26 @@ pub async fn create_site(
         }
         .into());
     }
+    if let Some(field) = details.field_requiring_timezone() {
+        return Err(ApiError::TimezoneRequired {
+            field: field.to_string(),
+        }
+        .into());
+    }
+    if let Some((field, units)) = details.field_with_invalid_span_units() {
+        return Err(ApiError::InvalidSpanUnits {
+            field: field.to_string(),
+            units: units.to_string(),
+        }
+        .into());
+    }
+
+    validate_site_image_community(
+        details.site_image_id.as_ref(),
+        &actor.0.community_id,
+        pool,
+    )
+    .await?;
 
     // Check storage limit before creating
     super::billing::check_storage_limit(
@@ -64,16 +118,29 @@ pub async fn create_site(
         Some(hours) => Some(create_open_hours(hours, &mut tx).await?),
         None => None,
     };
-    let auction_params_id = create_auction_params(
-        &details.default_auction_params,
-        &details.community_id,
-        &mut tx,
-        time_source,
-    )
-    .await?;
+    let auction_params_id = match inherited_params_id {
+        Some(id) => id,
+        None => {
+            check_round_duration_bounds(
+                details.default_auction_params.round_duration,
+                &details.community_id,
+                default_bounds,
+                &mut tx,
+            )
+            .await?;
+            create_auction_params(
+                &details.default_auction_params,
+                &details.community_id,
+                &mut tx,
+                time_source,
+            )
+            .await?
+        }
+    };
 
     let site = sqlx::query_as::<_, Site>(
         "INSERT INTO sites (
+            id,
             community_id,
             name,
             description,
@@ -87,8 +154,10 @@ pub async fn create_site(
             site_image_id,
             created_at,
             updated_at
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $12) RETURNING *",
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $13)
+        RETURNING *",
     )
+    .bind(id_source.new_id())
     .bind(actor.0.community_id)
     .bind(&details.name)
     .bind(&details.description)
@@ -113,6 +182,8 @@ async fn create_open_hours(
     open_hours: &payloads::OpenHours,
     tx: &mut Transaction<'_, Postgres>,
 ) -> Result<OpenHoursId, StoreError> {
+    open_hours.validate().map_err(ApiError::InvalidOpenHours)?;
+
     let open_hours_id = sqlx::query_as::<_, OpenHoursId>(
         "INSERT INTO open_hours DEFAULT VALUES RETURNING id",
     )
@@ -120,6 +191,7 @@ async fn create_open_hours(
     .await?;
 
     insert_open_hours_weekdays(&open_hours_id, open_hours, tx).await?;
+    insert_open_hours_exceptions(&open_hours_id, open_hours, tx).await?;
     Ok(open_hours_id)
 }
 
@@ -147,6 +219,61 @@ async fn insert_open_hours_weekdays(
     Ok(())
 }
 
+async fn insert_open_hours_exceptions(
+    open_hours_id: &OpenHoursId,
+    open_hours: &payloads::OpenHours,
+    tx: &mut Transaction<'_, Postgres>,
+) -> Result<(), StoreError> {
+    for exception in &open_hours.exceptions {
+        sqlx::query(
+            "INSERT INTO open_hours_exceptions (
+                open_hours_id,
+                date,
+                closed,
+                open_time,
+                close_time
+            ) VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(open_hours_id)
+        .bind(exception.date.to_sqlx())
+        .bind(exception.closed)
+        .bind(exception.open_time.map(|t| t.to_sqlx()))
+        .bind(exception.close_time.map(|t| t.to_sqlx()))
+        .execute(&mut **tx)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Check a round duration against its community's bounds, falling back to
+/// the instance's `default_bounds` for any the community doesn't set.
+pub(super) async fn check_round_duration_bounds(
+    round_duration: Span,
+    community_id: &CommunityId,
+    default_bounds: &payloads::RoundDurationBounds,
+    tx: &mut Transaction<'_, Postgres>,
+) -> Result<(), StoreError> {
+    let (min, max) = sqlx::query_as::<_, (Option<SqlxSpan>, Option<SqlxSpan>)>(
+        "SELECT min_round_duration, max_round_duration
+         FROM communities WHERE id = $1",
+    )
+    .bind(community_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    let community_bounds = payloads::RoundDurationBounds {
+        min: min.and_then(|s| duration_from_interval(s.to_jiff())),
+        max: max.and_then(|s| duration_from_interval(s.to_jiff())),
+    };
+    let bounds = community_bounds.or(*default_bounds);
+    if !bounds.contains(round_duration) {
+        return Err(ApiError::InvalidAuctionParams(
+            payloads::AuctionParamsError::RoundDurationOutOfBounds(bounds),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 pub(super) async fn create_auction_params(
     params: &payloads::AuctionParams,
     community_id: &CommunityId,
@@ -160,8 +287,8 @@ pub(super) async fn create_auction_params(
     // relative to the scheduler tick.
     params.validate().map_err(ApiError::InvalidAuctionParams)?;
 
-    // The bid increment feeds every bid value and thus every settlement
-    // line; it must land on the community's minor-unit grain.
+    // The bid increments feed every bid value and thus every settlement
+    // line; they must land on the community's minor-unit grain.
     let minor_units: i16 = sqlx::query_scalar(
         "SELECT currency_minor_units FROM communities WHERE id = $1",
     )
@@ -169,19 +296,28 @@ pub(super) async fn create_auction_params(
     .fetch_one(&mut **tx)
     .await?;
     currency::check_amount_quantized(params.bid_increment.0, minor_units)?;
+    for &(_, increment) in &params.increment_schedule {
+        currency::check_amount_quantized(increment, minor_units)?;
+    }
 
     Ok(sqlx::query_as::<_, AuctionParamsId>(
         "INSERT INTO auction_params (
                 round_duration,
                 bid_increment,
                 activity_rule_params,
+                increment_schedule,
+                min_rounds,
+                eligibility_rounding,
                 created_at,
                 updated_at
-            ) VALUES ($1, $2, $3, $4, $4) RETURNING id",
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $7) RETURNING id",
     )
     .bind(span_to_interval(&params.round_duration)?)
     .bind(params.bid_increment)
     .bind(Json(params.activity_rule_params.clone()))
+    .bind(Json(params.increment_schedule.clone()))
+    .bind(params.min_rounds)
+    .bind(params.eligibility_rounding)
     .bind(time_source.now().to_sqlx())
     .fetch_one(&mut **tx)
     .await?)
@@ -203,6 +339,29 @@ pub async fn get_site_community_id(
     })
 }
 
+/// Validate that a referenced site image belongs to the given community, so
+/// a site or space can't display another community's image.
+pub(super) async fn validate_site_image_community(
+    site_image_id: Option<&payloads::SiteImageId>,
+    community_id: &CommunityId,
+    pool: &PgPool,
+) -> Result<(), StoreError> {
+    let Some(site_image_id) = site_image_id else {
+        return Ok(());
+    };
+    let image_community_id = sqlx::query_scalar::<_, CommunityId>(
+        "SELECT community_id FROM site_images WHERE id = $1",
+    )
+    .bind(site_image_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(ApiError::SiteImageNotFound)?;
+    if image_community_id != *community_id {
+        return Err(ApiError::SiteImageCommunityMismatch.into());
+    }
+    Ok(())
+}
+
 pub async fn get_site(
     site_id: &SiteId,
     pool: &PgPool,
@@ -223,7 +382,18 @@ pub async fn get_site(
             .bind(open_hours_id)
             .fetch_all(pool)
             .await?;
-            Some(payloads::OpenHours { days_of_week })
+            let exceptions = sqlx::query_as::<_, payloads::OpenHoursException>(
+                "SELECT * FROM open_hours_exceptions
+                WHERE open_hours_id = $1
+                ORDER BY date",
+            )
+            .bind(open_hours_id)
+            .fetch_all(pool)
+            .await?;
+            Some(payloads::OpenHours {
+                days_of_week,
+                exceptions,
+            })
         }
         None => None,
     };
@@ -237,6 +407,8 @@ pub async fn get_site(
         sqlx::Error::RowNotFound => ApiError::AuctionParamsNotFound.into(),
         e => StoreError::Database(e),
     })?;
+    // Normalized like the input was, so spans and open hours come back in
+    // the same form however Postgres returned them
     let site_details = payloads::Site {
         community_id: site.community_id,
         name: site.name,
@@ -249,19 +421,74 @@ pub async fn get_site(
         auto_schedule: site.auto_schedule,
         timezone: site.timezone,
         site_image_id: site.site_image_id,
-    };
+    }
+    .normalized();
+    let configuration_warnings = site_details
+        .field_requiring_timezone()
+        .map(|field| format!("{field} is set but the site has no timezone"))
+        .into_iter()
+        .collect();
     Ok(payloads::responses::Site {
         site_id: site.id,
         site_details,
         created_at: site.created_at,
         updated_at: site.updated_at,
         deleted_at: site.deleted_at,
+        configuration_warnings,
+        community_role: None,
+        next_auction: None,
+        active_auction_id: None,
     })
 }
 
+/// Fetch a site as seen by `viewer`: configuration warnings are only shown to
+/// moderators and above, and the viewer's community role is attached when
+/// requested.
+pub async fn get_site_as(
+    site_id: &SiteId,
+    viewer: &ValidatedMember,
+    options: &payloads::requests::ReadOptions,
+    pool: &PgPool,
+) -> Result<payloads::responses::Site, StoreError> {
+    let mut site = get_site(site_id, pool).await?;
+    if !viewer.0.role.is_ge_moderator() {
+        site.configuration_warnings.clear();
+    }
+    if options.includes(payloads::requests::Include::CommunityRole) {
+        site.community_role = Some(viewer.community_role());
+    }
+    Ok(site)
+}
+
+/// The site's open hours on `date`, in its timezone, as UTC windows. Empty
+/// if the site has no open hours or is closed that day. Sites saved without
+/// a timezone before one was required are read as UTC.
+pub async fn resolve_open_hours(
+    site_id: &SiteId,
+    date: jiff::civil::Date,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<(jiff::Timestamp, jiff::Timestamp)>, StoreError> {
+    let community_id = get_site_community_id(site_id, pool).await?;
+    get_validated_member(user_id, &community_id, pool).await?;
+    let site = get_site(site_id, pool).await?.site_details;
+    let Some(open_hours) = site.open_hours else {
+        return Ok(vec![]);
+    };
+    let tz = site
+        .timezone
+        .as_deref()
+        .and_then(|tz| jiff::tz::TimeZone::get(tz).ok())
+        .unwrap_or(jiff::tz::TimeZone::UTC);
+    Ok(open_hours
+        .windows_on(date, &tz)
+        .map_err(anyhow::Error::from)?)
+}
+
 pub async fn update_site(
     update_site: &payloads::requests::UpdateSite,
     actor: &ValidatedMember,
+    default_bounds: &payloads::RoundDurationBounds,
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> Result<responses::Site, StoreError> {
@@ -269,27 +496,28 @@ pub async fn update_site(
         return Err(ApiError::RequiresColeaderPermissions.into());
     }
 
-    let details = &update_site.site_details;
-
-    // Validate name length
-    if details.name.len() > payloads::requests::SITE_NAME_MAX_LEN {
-        return Err(ApiError::SiteNameTooLong {
-            size: details.name.len(),
-            max: payloads::requests::SITE_NAME_MAX_LEN,
-        }
-        .into());
-    }
-
-    // Validate description length
-    if let Some(desc) = &details.description
-        && desc.len() > payloads::MAX_SITE_DESCRIPTION_LENGTH
-    {
-        return Err(ApiError::SiteDescriptionTooLong {
-            size: desc.len(),
-            max: payloads::MAX_SITE_DESCRIPTION_LENGTH,
-        }
-        .into());
-    }
+    let existing_site =
+        sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
+            .bind(update_site.site_id)
+            .fetch_one(pool)
+            .await?;
+    let existing_params = sqlx::query_as::<_, AuctionParams>(
+        "SELECT * FROM auction_params WHERE id = $1",
+    )
+    .bind(&existing_site.default_auction_params_id)
+    .fetch_one(pool)
+    .await?;
+    let keeps_params =
+        update_site.site_details.default_auction_params.is_none();
+
+    // Persist the canonical form, so the site reads back equal to itself
+    let details = &sanitize_site(
+        update_site
+            .site_details
+            .clone()
+            .with_default_params(existing_params.clone().into())
+            .normalized(),
+    )?;
 
     // Validate timezone is a valid IANA timezone string
     if let Some(tz) = &details.timezone
@@ -300,12 +528,26 @@ pub async fn update_site(
         }
         .into());
     }
+    if let Some(field) = details.field_requiring_timezone() {
+        return Err(ApiError::TimezoneRequired {
+            field: field.to_string(),
+        }
+        .into());
+    }
+    if let Some((field, units)) = details.field_with_invalid_span_units() {
+        return Err(ApiError::InvalidSpanUnits {
+            field: field.to_string(),
+            units: units.to_string(),
+        }
+        .into());
+    }
 
-    let existing_site =
-        sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
-            .bind(update_site.site_id)
-            .fetch_one(pool)
-            .await?;
+    validate_site_image_community(
+        details.site_image_id.as_ref(),
+        &existing_site.community_id,
+        pool,
+    )
+    .await?;
 
     let mut tx = pool.begin().await?;
 
@@ -316,13 +558,31 @@ pub async fn update_site(
     )
     .await?;
 
-    let new_auction_params_id = create_auction_params(
-        &details.default_auction_params,
-        &existing_site.community_id,
-        &mut tx,
-        time_source,
-    )
-    .await?;
+    let new_auction_params_id = if keeps_params {
+        existing_site.default_auction_params_id
+    } else {
+        // Bounds set after the site was saved don't stop it being saved
+        // again with the round duration it already has
+        let params = &details.default_auction_params;
+        if payloads::normalize_span(existing_params.round_duration).fieldwise()
+            != payloads::normalize_span(params.round_duration).fieldwise()
+        {
+            check_round_duration_bounds(
+                params.round_duration,
+                &existing_site.community_id,
+                default_bounds,
+                &mut tx,
+            )
+            .await?;
+        }
+        create_auction_params(
+            params,
+            &existing_site.community_id,
+            &mut tx,
+            time_source,
+        )
+        .await?
+    };
 
     sqlx::query(
         "UPDATE sites SET
@@ -368,6 +628,9 @@ pub(super) async fn cleanup_unused_auction_params(pool: &PgPool) {
         WHERE NOT EXISTS (
             SELECT FROM sites
             WHERE default_auction_params_id = p.id
+        ) AND NOT EXISTS (
+            SELECT FROM communities
+            WHERE community_default_auction_params_id = p.id
         ) AND NOT EXISTS (
             SELECT FROM auctions
             WHERE auction_params_id = p.id
@@ -403,28 +666,57 @@ async fn update_open_hours(
     }
 }
 
+/// Permanently delete a site and, through cascades, its spaces and auctions.
+/// Refused while one of its auctions is active. Unless the request is
+/// confirmed, nothing is deleted and the error reports what would be.
 pub async fn delete_site(
-    site_id: &payloads::SiteId,
+    details: &payloads::requests::DeleteSite,
     actor: &ValidatedMember,
     pool: &PgPool,
+    time_source: &TimeSource,
 ) -> Result<(), StoreError> {
     if !actor.0.role.is_ge_coleader() {
         return Err(ApiError::RequiresColeaderPermissions.into());
     }
 
-    let existing_site =
-        sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
-            .bind(site_id)
-            .fetch_one(pool)
-            .await?;
-
     let mut tx = pool.begin().await?;
 
+    // Lock the site so the report matches what gets deleted
+    let existing_site = sqlx::query_as::<_, Site>(
+        "SELECT * FROM sites WHERE id = $1 FOR UPDATE",
+    )
+    .bind(details.site_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let now = time_source.now().to_sqlx();
+    let has_active_auction: bool = sqlx::query_scalar(
+        "SELECT EXISTS (
+            SELECT 1 FROM auctions
+            WHERE site_id = $1
+                AND start_at <= $2
+                AND (end_at IS NULL OR end_at > $2)
+        )",
+    )
+    .bind(details.site_id)
+    .bind(now)
+    .fetch_one(&mut *tx)
+    .await?;
+    if has_active_auction {
+        return Err(ApiError::SiteHasActiveAuction.into());
+    }
+
+    if !details.confirm {
+        let dependencies =
+            get_site_dependencies(&details.site_id, now, &mut tx).await?;
+        return Err(ApiError::SiteDeletionNotConfirmed(dependencies).into());
+    }
+
     // Remove any remaining open hours
     update_open_hours(&existing_site.open_hours_id, &None, &mut tx).await?;
 
     let delete_result = sqlx::query("DELETE FROM sites WHERE id = $1")
-        .bind(site_id)
+        .bind(details.site_id)
         .execute(&mut *tx)
         .await;
 
@@ -446,6 +738,44 @@ pub async fn delete_site(
     }
 }
 
+/// Count what deleting a site would take with it. Auctions count as
+/// concluded once their end time has passed.
+async fn get_site_dependencies(
+    site_id: &payloads::SiteId,
+    now: jiff_sqlx::Timestamp,
+    tx: &mut Transaction<'_, Postgres>,
+) -> Result<payloads::responses::SiteDependencies, StoreError> {
+    let (spaces, concluded_auctions, pending_auctions, images, user_values) =
+        sqlx::query_as::<_, (i64, i64, i64, i64, i64)>(
+            "SELECT
+                (SELECT COUNT(*) FROM spaces WHERE site_id = $1),
+                (SELECT COUNT(*) FROM auctions
+                    WHERE site_id = $1 AND end_at <= $2),
+                (SELECT COUNT(*) FROM auctions
+                    WHERE site_id = $1 AND (end_at IS NULL OR end_at > $2)),
+                (SELECT COUNT(DISTINCT image_id) FROM (
+                    SELECT site_image_id AS image_id FROM sites WHERE id = $1
+                    UNION ALL
+                    SELECT site_image_id FROM spaces WHERE site_id = $1
+                ) images),
+                (SELECT COUNT(*) FROM user_values v
+                    JOIN spaces s ON v.space_id = s.id
+                    WHERE s.site_id = $1)",
+        )
+        .bind(site_id)
+        .bind(now)
+        .fetch_one(&mut **tx)
+        .await?;
+
+    Ok(payloads::responses::SiteDependencies {
+        spaces,
+        concluded_auctions,
+        pending_auctions,
+        images,
+        user_values,
+    })
+}
+
 pub async fn soft_delete_site(
     site_id: &payloads::SiteId,
     actor: &ValidatedMember,
@@ -481,6 +811,13 @@ pub async fn soft_delete_site(
             &payloads::AuctionEvent::AuctionEnded { auction_id },
         )
         .await?;
+        super::create_auction_report(
+            &auction_id,
+            payloads::responses::AuctionConclusion::Terminated,
+            &mut tx,
+            time_source,
+        )
+        .await?;
     }
 
     // Soft delete the site
@@ -532,21 +869,76 @@ pub async fn list_sites(
     community_id: &payloads::CommunityId,
     user_id: &UserId,
     pool: &PgPool,
+    time_source: &TimeSource,
 ) -> Result<Vec<payloads::responses::Site>, StoreError> {
     // Validate user is a member of the community
-    let _ = get_validated_member(user_id, community_id, pool).await?;
+    let actor = get_validated_member(user_id, community_id, pool).await?;
+    list_sites_as(&actor, pool, time_source).await
+}
+
+/// A listed site's id and the auctions announced on it.
+#[derive(FromRow)]
+struct ListedSite {
+    id: SiteId,
+    next_auction_id: Option<payloads::AuctionId>,
+    #[sqlx(try_from = "OptionalTimestamp")]
+    next_auction_start_at: Option<Timestamp>,
+    active_auction_id: Option<payloads::AuctionId>,
+}
 
-    let sites = sqlx::query_as::<_, Site>(
-        "SELECT * FROM sites WHERE community_id = $1 ORDER BY name",
+/// Sites of the actor's community, as seen by the actor, each with its next
+/// and currently running auction. Canceled auctions have `end_at` set, so
+/// they're neither; sandbox auctions are left out.
+pub async fn list_sites_as(
+    actor: &ValidatedMember,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<Vec<payloads::responses::Site>, StoreError> {
+    let sites = sqlx::query_as::<_, ListedSite>(
+        "SELECT s.id,
+            next.id AS next_auction_id,
+            next.start_at AS next_auction_start_at,
+            active.id AS active_auction_id
+        FROM sites s
+        LEFT JOIN LATERAL (
+            SELECT a.id, a.start_at FROM auctions a
+            WHERE a.site_id = s.id
+                AND a.start_at > $2
+                AND a.end_at IS NULL
+                AND NOT a.sandbox
+            ORDER BY a.start_at, a.id
+            LIMIT 1
+        ) next ON true
+        LEFT JOIN LATERAL (
+            SELECT a.id FROM auctions a
+            WHERE a.site_id = s.id
+                AND a.start_at <= $2
+                AND a.end_at IS NULL
+                AND NOT a.sandbox
+            ORDER BY a.start_at DESC, a.id
+            LIMIT 1
+        ) active ON true
+        WHERE s.community_id = $1
+        ORDER BY s.name",
     )
-    .bind(community_id)
+    .bind(actor.0.community_id)
+    .bind(time_source.now().to_sqlx())
     .fetch_all(pool)
     .await?;
 
     // Convert to response format
     let mut site_responses = Vec::new();
     for site in sites {
-        let site_response = get_site(&site.id, pool).await?;
+        let mut site_response =
+            get_site_as(&site.id, actor, &Default::default(), pool).await?;
+        site_response.next_auction =
+            site.next_auction_id.zip(site.next_auction_start_at).map(
+                |(auction_id, start_at)| payloads::responses::UpcomingAuction {
+                    auction_id,
+                    start_at,
+                },
+            );
+        site_response.active_auction_id = site.active_auction_id;
         site_responses.push(site_response);
     }
 
@@ -1,19 +1,34 @@
 use super::*;
 use jiff_sqlx::ToSqlx;
-use payloads::{ApiError, PermissionLevel, SiteId, SpaceId, UserId};
+use payloads::{ApiError, PermissionLevel, SiteId, SpaceId, UserId, sanitize};
 use sqlx::PgPool;
 
 use crate::time::TimeSource;
 
-/// Get a space and validate that the user has the required permission
-/// level in the site's community. Returns both the space and the
-/// validated member if successful.
+#[cfg(feature = "query-counts")]
+thread_local! {
+    static FULL_SPACE_FETCHES: std::cell::Cell<u64> =
+        const { std::cell::Cell::new(0) };
+}
+
+/// Space rows loaded by [`get_validated_space`] on this thread, so tests can
+/// check that access checks which don't need the row skip loading it.
+#[cfg(feature = "query-counts")]
+pub fn full_space_fetches() -> u64 {
+    FULL_SPACE_FETCHES.get()
+}
+
+/// Load a space and check that the user may act on it at the given level.
+/// Callers that don't need the space should use [`validate_space_access`].
 pub(super) async fn get_validated_space(
     space_id: &SpaceId,
     user_id: &UserId,
     required_permission: PermissionLevel,
     pool: &PgPool,
 ) -> Result<(Space, ValidatedMember), StoreError> {
+    #[cfg(feature = "query-counts")]
+    FULL_SPACE_FETCHES.set(FULL_SPACE_FETCHES.get() + 1);
+
     let space =
         sqlx::query_as::<_, Space>("SELECT * FROM spaces WHERE id = $1")
             .bind(space_id)
@@ -24,16 +39,58 @@ pub(super) async fn get_validated_space(
                 e => StoreError::Database(e),
             })?;
 
-    let site = sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
-        .bind(space.site_id)
-        .fetch_one(pool)
-        .await
-        .map_err(|e| match e {
-            sqlx::Error::RowNotFound => ApiError::SiteNotFound.into(),
-            e => StoreError::Database(e),
-        })?;
+    let community_id = get_site_community_id(&space.site_id, pool).await?;
+    let actor = get_validated_member_with_permission(
+        user_id,
+        &community_id,
+        required_permission,
+        pool,
+    )
+    .await?;
 
-    let actor = get_validated_member(user_id, &site.community_id, pool).await?;
+    Ok((space, actor))
+}
+
+/// Check that the user may act on a space at the given level, reading only
+/// the community it belongs to.
+pub(super) async fn validate_space_access(
+    space_id: &SpaceId,
+    user_id: &UserId,
+    required_permission: PermissionLevel,
+    pool: &PgPool,
+) -> Result<ValidatedMember, StoreError> {
+    let community_id = get_space_community_id(space_id, pool).await?;
+    get_validated_member_with_permission(
+        user_id,
+        &community_id,
+        required_permission,
+        pool,
+    )
+    .await
+}
+
+pub async fn get_space_community_id(
+    space_id: &SpaceId,
+    pool: &PgPool,
+) -> Result<CommunityId, StoreError> {
+    sqlx::query_as::<_, CommunityId>(
+        "SELECT b.community_id FROM spaces a
+        JOIN sites b ON a.site_id = b.id
+        WHERE a.id = $1",
+    )
+    .bind(space_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::SpaceNotFound.into())
+}
+
+async fn get_validated_member_with_permission(
+    user_id: &UserId,
+    community_id: &CommunityId,
+    required_permission: PermissionLevel,
+    pool: &PgPool,
+) -> Result<ValidatedMember, StoreError> {
+    let actor = get_validated_member(user_id, community_id, pool).await?;
 
     if !required_permission.validate(actor.0.role) {
         return Err(ApiError::InsufficientPermissions {
@@ -42,7 +99,7 @@ pub(super) async fn get_validated_space(
         .into());
     }
 
-    Ok((space, actor))
+    Ok(actor)
 }
 
 /// Validate a space's eligibility points. The DB has a `>= 0.0` CHECK, but
@@ -59,33 +116,47 @@ fn validate_eligibility_points(
     Ok(())
 }
 
+/// Normalize a space's name and description for storage, or reject them.
+fn sanitize_space(
+    details: &payloads::Space,
+) -> Result<payloads::Space, StoreError> {
+    let name = sanitized(
+        sanitize::name(&details.name, payloads::requests::SPACE_NAME_MAX_LEN),
+        "Space name",
+        |size, max| ApiError::SpaceNameTooLong { size, max },
+    )?;
+    let description = details
+        .description
+        .as_deref()
+        .map(|description| {
+            sanitized(
+                sanitize::text(
+                    description,
+                    payloads::MAX_SPACE_DESCRIPTION_LENGTH,
+                ),
+                "Space description",
+                |size, max| ApiError::SpaceDescriptionTooLong { size, max },
+            )
+        })
+        .transpose()?;
+    Ok(payloads::Space {
+        name,
+        description,
+        ..details.clone()
+    })
+}
+
 /// Internal transaction-aware space creation function.
 /// Caller is responsible for managing the transaction and validating
-/// permissions.
+/// permissions. A space created without a `display_order` goes after the
+/// site's others.
 async fn create_space_tx(
     details: &payloads::Space,
+    display_order: Option<i32>,
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
     time_source: &TimeSource,
 ) -> Result<Space, StoreError> {
-    // Validate name length
-    if details.name.len() > payloads::requests::SPACE_NAME_MAX_LEN {
-        return Err(ApiError::SpaceNameTooLong {
-            size: details.name.len(),
-            max: payloads::requests::SPACE_NAME_MAX_LEN,
-        }
-        .into());
-    }
-
-    // Validate description length
-    if let Some(desc) = &details.description
-        && desc.len() > payloads::MAX_SPACE_DESCRIPTION_LENGTH
-    {
-        return Err(ApiError::SpaceDescriptionTooLong {
-            size: desc.len(),
-            max: payloads::MAX_SPACE_DESCRIPTION_LENGTH,
-        }
-        .into());
-    }
+    let details = &sanitize_space(details)?;
 
     validate_eligibility_points(details)?;
 
@@ -98,9 +169,17 @@ async fn create_space_tx(
             is_available,
             site_image_id,
             reserve_price,
+            display_order,
             created_at,
             updated_at
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $8) RETURNING *",
+        ) VALUES (
+            $1, $2, $3, $4, $5, $6, $7,
+            COALESCE($9, (
+                SELECT COALESCE(MAX(display_order), 0) + 1
+                FROM spaces WHERE site_id = $1
+            )),
+            $8, $8
+        ) RETURNING *",
     )
     .bind(details.site_id)
     .bind(&details.name)
@@ -110,6 +189,7 @@ async fn create_space_tx(
     .bind(details.site_image_id)
     .bind(details.reserve_price)
     .bind(time_source.now().to_sqlx())
+    .bind(display_order)
     .fetch_one(&mut **tx)
     .await
     .map_err(|e| map_space_name_unique_error(e, &details.name))?;
@@ -139,6 +219,12 @@ pub async fn create_space(
     }
 
     validate_reserve_price_quantized(&site.community_id, details, pool).await?;
+    site::validate_site_image_community(
+        details.site_image_id.as_ref(),
+        &site.community_id,
+        pool,
+    )
+    .await?;
 
     // Check storage limit before creating
     super::billing::check_storage_limit(
@@ -150,7 +236,7 @@ pub async fn create_space(
     .await?;
 
     let mut tx = pool.begin().await?;
-    let space = create_space_tx(details, &mut tx, time_source).await?;
+    let space = create_space_tx(details, None, &mut tx, time_source).await?;
     tx.commit().await?;
 
     Ok(space)
@@ -168,6 +254,29 @@ pub async fn get_space(
     Ok(space.into())
 }
 
+/// Get the spaces among `space_ids` that the user can see, in one query.
+/// Ids that don't exist or are in another community are omitted, so the
+/// result doesn't reveal which of them exist.
+pub async fn get_spaces(
+    space_ids: &[SpaceId],
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::Space>, StoreError> {
+    let spaces = sqlx::query_as::<_, Space>(
+        "SELECT a.* FROM spaces a
+        JOIN sites b ON a.site_id = b.id
+        JOIN community_members c ON c.community_id = b.community_id
+        WHERE a.id = ANY($1) AND c.user_id = $2
+        ORDER BY a.name, a.id",
+    )
+    .bind(space_ids)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(spaces.into_iter().map(Into::into).collect())
+}
+
 /// Validate that a space's reserve price lands on the community's minor-unit
 /// grain. Reserve prices seed bid values and thus settlement journal lines.
 async fn validate_reserve_price_quantized(
@@ -229,25 +338,7 @@ async fn update_space_tx(
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> Result<payloads::responses::UpdateSpaceResult, StoreError> {
-    // Validate name length
-    if details.name.len() > payloads::requests::SPACE_NAME_MAX_LEN {
-        return Err(ApiError::SpaceNameTooLong {
-            size: details.name.len(),
-            max: payloads::requests::SPACE_NAME_MAX_LEN,
-        }
-        .into());
-    }
-
-    // Validate description length
-    if let Some(desc) = &details.description
-        && desc.len() > payloads::MAX_SPACE_DESCRIPTION_LENGTH
-    {
-        return Err(ApiError::SpaceDescriptionTooLong {
-            size: desc.len(),
-            max: payloads::MAX_SPACE_DESCRIPTION_LENGTH,
-        }
-        .into());
-    }
+    let details = &sanitize_space(details)?;
 
     // Validate up front so an invalid value is rejected before the
     // copy-on-write path soft-deletes the old space.
@@ -259,6 +350,12 @@ async fn update_space_tx(
 
     let community_id = get_site_community_id(&old_space.site_id, pool).await?;
     validate_reserve_price_quantized(&community_id, details, pool).await?;
+    site::validate_site_image_community(
+        details.site_image_id.as_ref(),
+        &community_id,
+        pool,
+    )
+    .await?;
 
     // Check for auction history and nontrivial changes
     let has_history = space_has_auction_history(space_id, tx).await?;
@@ -279,7 +376,14 @@ async fn update_space_tx(
         .execute(&mut **tx)
         .await?;
 
-        let new_space = create_space_tx(details, tx, time_source).await?;
+        // The copy takes the old space's place in the site's order.
+        let new_space = create_space_tx(
+            details,
+            Some(old_space.display_order),
+            tx,
+            time_source,
+        )
+        .await?;
 
         return Ok(payloads::responses::UpdateSpaceResult {
             space: new_space.into(),
@@ -370,14 +474,18 @@ pub async fn update_spaces(
     Ok(results)
 }
 
+/// Delete a space that has never been auctioned. A space with auction
+/// history is refused, or with `force` archived by soft-deleting it, so the
+/// history stays intact. Returns whether the space was archived.
 pub async fn delete_space(
     space_id: &SpaceId,
+    force: bool,
     user_id: &UserId,
     pool: &PgPool,
-) -> Result<(), StoreError> {
-    let (_, _) =
-        get_validated_space(space_id, user_id, PermissionLevel::Coleader, pool)
-            .await?;
+    time_source: &TimeSource,
+) -> Result<bool, StoreError> {
+    validate_space_access(space_id, user_id, PermissionLevel::Coleader, pool)
+        .await?;
 
     // Only delete if no auction history references this space.
     // This preserves auction data integrity while allowing CASCADE for bulk
@@ -393,10 +501,14 @@ pub async fn delete_space(
 
     if result.rows_affected() == 0 {
         // Space exists but wasn't deleted due to auction history
-        return Err(ApiError::SpaceHasAuctionHistory.into());
+        if !force {
+            return Err(ApiError::SpaceHasAuctionHistory.into());
+        }
+        soft_delete_space(space_id, user_id, pool, time_source).await?;
+        return Ok(true);
     }
 
-    Ok(())
+    Ok(false)
 }
 
 pub async fn soft_delete_space(
@@ -405,9 +517,8 @@ pub async fn soft_delete_space(
     pool: &PgPool,
     time_source: &TimeSource,
 ) -> Result<(), StoreError> {
-    let (_, _) =
-        get_validated_space(space_id, user_id, PermissionLevel::Coleader, pool)
-            .await?;
+    validate_space_access(space_id, user_id, PermissionLevel::Coleader, pool)
+        .await?;
 
     let now = time_source.now().to_sqlx();
 
@@ -468,7 +579,7 @@ pub async fn list_spaces(
     let _ = get_validated_member(user_id, &site.community_id, pool).await?;
 
     let spaces = sqlx::query_as::<_, Space>(
-        "SELECT * FROM spaces WHERE site_id = $1 ORDER BY name",
+        "SELECT * FROM spaces WHERE site_id = $1 ORDER BY display_order, name",
     )
     .bind(site_id)
     .fetch_all(pool)
@@ -476,3 +587,97 @@ pub async fn list_spaces(
 
     Ok(spaces.into_iter().map(Into::into).collect())
 }
+
+/// Set the display order of a site's spaces. `ordered_ids` must list each of
+/// the site's current spaces exactly once.
+pub async fn reorder_spaces(
+    site_id: &SiteId,
+    ordered_ids: &[SpaceId],
+    user_id: &UserId,
+    pool: &PgPool,
+    time_source: &TimeSource,
+) -> Result<(), StoreError> {
+    let community_id = get_site_community_id(site_id, pool).await?;
+    get_validated_member_with_permission(
+        user_id,
+        &community_id,
+        PermissionLevel::Coleader,
+        pool,
+    )
+    .await?;
+
+    let mut tx = pool.begin().await?;
+
+    // Lock the site's spaces so one created or deleted concurrently can't be
+    // left out of the order.
+    let mut current = sqlx::query_scalar::<_, SpaceId>(
+        "SELECT id FROM spaces
+        WHERE site_id = $1 AND deleted_at IS NULL
+        FOR UPDATE",
+    )
+    .bind(site_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let mut requested = ordered_ids.to_vec();
+    current.sort_by_key(|id| id.0);
+    requested.sort_by_key(|id| id.0);
+    if current != requested {
+        return Err(ApiError::InvalidSpaceOrder.into());
+    }
+
+    sqlx::query(
+        "UPDATE spaces
+        SET display_order = o.ordinality::INTEGER, updated_at = $2
+        FROM unnest($1::UUID[]) WITH ORDINALITY AS o (id, ordinality)
+        WHERE spaces.id = o.id",
+    )
+    .bind(ordered_ids)
+    .bind(time_source.now().to_sqlx())
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// The site's spaces, as in [`list_spaces`], each with the value the user
+/// has declared for it, if any.
+pub async fn list_spaces_with_values(
+    site_id: &SiteId,
+    user_id: &UserId,
+    pool: &PgPool,
+) -> Result<Vec<payloads::responses::SpaceWithValue>, StoreError> {
+    let site = sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
+        .bind(site_id)
+        .fetch_one(pool)
+        .await?;
+
+    let _ = get_validated_member(user_id, &site.community_id, pool).await?;
+
+    #[derive(sqlx::FromRow)]
+    struct SpaceWithValue {
+        #[sqlx(flatten)]
+        space: Space,
+        user_value: Option<Decimal>,
+    }
+
+    let spaces = sqlx::query_as::<_, SpaceWithValue>(
+        "SELECT s.*, uv.value AS user_value FROM spaces s
+        LEFT JOIN user_values uv ON uv.space_id = s.id AND uv.user_id = $2
+        WHERE s.site_id = $1
+        ORDER BY s.display_order, s.name",
+    )
+    .bind(site_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(spaces
+        .into_iter()
+        .map(|row| payloads::responses::SpaceWithValue {
+            space: row.space.into(),
+            value: row.user_value,
+        })
+        .collect())
+}
@@ -32,6 +32,13 @@ impl TimeSource {
         *self.time.lock().unwrap()
     }
 
+    /// When something created now and lasting `span` expires, e.g. a token.
+    /// `span` can't have units of days or longer, which a timestamp has no
+    /// calendar for.
+    pub fn expires_in(&self, span: jiff::Span) -> Timestamp {
+        self.now() + span
+    }
+
     #[cfg(feature = "mock-time")]
     pub fn advance(&self, duration: jiff::Span) {
         *self.time.lock().unwrap() += duration;
@@ -42,3 +49,25 @@ impl TimeSource {
         *self.time.lock().unwrap() = time;
     }
 }
+
+#[cfg(all(test, feature = "mock-time"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_in_follows_mocked_now() {
+        let start: Timestamp = "2025-01-01T00:00:00Z".parse().unwrap();
+        let time_source = TimeSource::new(start);
+        let hour = jiff::Span::new().hours(1);
+        assert_eq!(
+            time_source.expires_in(hour),
+            "2025-01-01T01:00:00Z".parse().unwrap()
+        );
+
+        time_source.advance(jiff::Span::new().hours(24));
+        assert_eq!(
+            time_source.expires_in(hour),
+            "2025-01-02T01:00:00Z".parse().unwrap()
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use api::scheduler;
 use api::time::TimeSource;
+use jiff::SignedDuration;
 use jiff::Timestamp;
 use jiff::{Span, Zoned};
 use payloads::{
     ApiError, AuctionParamsError, EligibilityProgressionError, PermissionLevel,
-    requests,
+    RoundDurationBounds, requests,
 };
 use test_helpers::{self, spawn_app};
 
@@ -57,6 +58,178 @@ async fn test_auction_crud() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn recreate_auction_shifted_by_a_month() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let source = app.create_test_auction(&site.site_id).await?;
+
+    let recreated = app
+        .client
+        .recreate_auction(&requests::RecreateAuction {
+            source_auction_id: source.auction_id,
+            possession_start_at: None,
+            possession_end_at: None,
+            start_at: None,
+            shift_by: Some(Span::new().months(1)),
+        })
+        .await?;
+    assert_ne!(recreated.auction_id, source.auction_id);
+
+    let (old, new) = (&source.auction_details, &recreated.auction_details);
+    assert_eq!(new.site_id, old.site_id);
+    assert_eq!(new.auction_params, old.auction_params);
+    assert_eq!(new.sandbox, old.sandbox);
+    assert_eq!(new.winner_constraints, old.winner_constraints);
+
+    // Shifted in the site's timezone
+    let shifted = |t: Timestamp| -> anyhow::Result<Timestamp> {
+        Ok(t.in_tz("America/Los_Angeles")?
+            .checked_add(Span::new().months(1))?
+            .timestamp())
+    };
+    assert_eq!(new.possession_start_at, shifted(old.possession_start_at)?);
+    assert_eq!(new.possession_end_at, shifted(old.possession_end_at)?);
+    assert_eq!(new.start_at, Some(shifted(old.start_at.unwrap())?));
+
+    // Absolute times and a shift can't be mixed
+    assert_api_error(
+        app.client
+            .recreate_auction(&requests::RecreateAuction {
+                source_auction_id: source.auction_id,
+                possession_start_at: Some(new.possession_start_at),
+                possession_end_at: None,
+                start_at: None,
+                shift_by: Some(Span::new().months(1)),
+            })
+            .await,
+        ApiError::InvalidRequestBody(
+            "shift_by can't be combined with explicit times".into(),
+        ),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn possession_period_spans_dst() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let mut site = app.create_test_site(&community_id).await?;
+    site.site_details.timezone = Some("America/Los_Angeles".to_string());
+    site.site_details.possession_period = Span::new().weeks(1);
+    app.client
+        .update_site(&requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site.site_details.into(),
+        })
+        .await?;
+    let possession_period = app
+        .client
+        .get_site(&site.site_id)
+        .await?
+        .site_details
+        .possession_period;
+
+    // A week of possession from noon on March 6, ahead of the March 10
+    // switch to daylight time
+    let start: Zoned =
+        "2024-03-06T12:00:00-08:00[America/Los_Angeles]".parse()?;
+    app.time_source
+        .set(start.timestamp() - Span::new().hours(1));
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.possession_start_at = start.timestamp();
+    auction_details.possession_end_at =
+        start.checked_add(possession_period)?.timestamp();
+    auction_details.start_at = None;
+    let source_id = app.client.create_auction(&auction_details).await?;
+
+    // The next period starts and ends at local noon, with the week it spans
+    // an hour short
+    let next = app
+        .client
+        .recreate_auction(&requests::RecreateAuction {
+            source_auction_id: source_id,
+            possession_start_at: None,
+            possession_end_at: None,
+            start_at: None,
+            shift_by: Some(possession_period),
+        })
+        .await?
+        .auction_details;
+    let local = |t: Timestamp| t.in_tz("America/Los_Angeles");
+    assert_eq!(
+        local(next.possession_start_at)?,
+        "2024-03-13T12:00:00-07:00[America/Los_Angeles]".parse()?
+    );
+    assert_eq!(
+        local(next.possession_end_at)?,
+        "2024-03-20T12:00:00-07:00[America/Los_Angeles]".parse()?
+    );
+    assert_eq!(
+        auction_details
+            .possession_end_at
+            .duration_since(auction_details.possession_start_at),
+        jiff::SignedDuration::from_hours(7 * 24 - 1)
+    );
+
+    // Lead times are fixed lengths, so days aren't accepted
+    let mut site = app.client.get_site(&site.site_id).await?;
+    site.site_details.proxy_bidding_lead_time = Span::new().days(1);
+    assert_api_error(
+        app.client
+            .update_site(&requests::UpdateSite {
+                site_id: site.site_id,
+                site_details: site.site_details.into(),
+            })
+            .await,
+        ApiError::InvalidSpanUnits {
+            field: "proxy_bidding_lead_time".into(),
+            units: "days, weeks, months, or years".into(),
+        },
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn recreate_canceled_auction() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let source = app.create_test_auction(&site.site_id).await?;
+    app.client.cancel_auction(&source.auction_id).await?;
+
+    let now = app.time_source.now();
+    let possession_start_at = now + Span::new().hours(3);
+    let possession_end_at = now + Span::new().hours(4);
+    let recreated = app
+        .client
+        .recreate_auction(&requests::RecreateAuction {
+            source_auction_id: source.auction_id,
+            possession_start_at: Some(possession_start_at),
+            possession_end_at: Some(possession_end_at),
+            start_at: None,
+            shift_by: None,
+        })
+        .await?;
+
+    let details = &recreated.auction_details;
+    assert_eq!(details.possession_start_at, possession_start_at);
+    assert_eq!(details.possession_end_at, possession_end_at);
+    assert_eq!(details.start_at, None);
+    assert_eq!(
+        details.auction_params,
+        source.auction_details.auction_params
+    );
+    assert!(!recreated.was_canceled);
+    assert_eq!(recreated.end_at, None);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_auction_unauthorized() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -105,6 +278,28 @@ async fn test_auction_unauthorized() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn list_community_auctions_spans_sites() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site_a = app.create_test_site(&community_id).await?;
+    let site_b_id = app
+        .client
+        .create_site(&test_helpers::site_details_b(community_id).into())
+        .await?;
+
+    let auction_a = app.create_test_auction(&site_a.site_id).await?;
+    app.time_source.advance(Span::new().minutes(1));
+    let auction_b = app.create_test_auction(&site_b_id).await?;
+
+    // Newest start first
+    let auctions = app.client.list_community_auctions(&community_id).await?;
+    let ids: Vec<_> = auctions.iter().map(|a| a.auction_id).collect();
+    assert_eq!(ids, vec![auction_b.auction_id, auction_a.auction_id]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_auction_round_creation() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -175,6 +370,103 @@ async fn test_immediate_auction_round_creation() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_list_auction_rounds_since() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // First poll: everything so far
+    let first = app
+        .client
+        .list_auction_rounds_since(&requests::ListAuctionRoundsSince {
+            auction_id,
+            since: None,
+        })
+        .await?;
+    assert_eq!(first.rounds.len(), 1);
+    assert_eq!(first.rounds[0].round_details.round_num, 0);
+    assert_eq!(first.server_time, app.time_source.now());
+
+    // Keep the auction going into round 1
+    app.client
+        .create_bid(&space.space_id, &first.rounds[0].round_id)
+        .await?;
+    app.time_source.set(first.rounds[0].round_details.end_at);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Second poll: only the round created since the first
+    let second = app
+        .client
+        .list_auction_rounds_since(&requests::ListAuctionRoundsSince {
+            auction_id,
+            since: Some(first.server_time),
+        })
+        .await?;
+    assert_eq!(second.rounds.len(), 1);
+    assert_eq!(second.rounds[0].round_details.round_num, 1);
+    assert_eq!(second.server_time, app.time_source.now());
+
+    // Nothing new since the second poll
+    let third = app
+        .client
+        .list_auction_rounds_since(&requests::ListAuctionRoundsSince {
+            auction_id,
+            since: Some(second.server_time),
+        })
+        .await?;
+    assert!(third.rounds.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_catch_up_missed_rounds() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space.space_id, &rounds[0].round_id)
+        .await?;
+
+    // The server is down for several rounds' worth of time
+    let round_duration = auction_details.auction_params.round_duration;
+    app.time_source.advance(round_duration.checked_mul(5)?);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 0's bid counted, and round 1 is open for a full round from now
+    // rather than having lapsed while the server was down
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    assert_eq!(rounds.len(), 2);
+    let results = app
+        .client
+        .list_round_space_results_for_round(&rounds[0].round_id)
+        .await?;
+    assert_eq!(results[0].winner.username, "alice");
+    let now = app.time_source.now();
+    assert_eq!(rounds[1].round_details.start_at, now);
+    assert_eq!(rounds[1].round_details.end_at, now + round_duration);
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_none());
+
+    // Nothing more to catch up
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    assert_eq!(app.client.list_auction_rounds(&auction_id).await?.len(), 2);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_auction_time_validation() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -239,16 +531,46 @@ async fn test_create_auction_eligibility_validation() -> anyhow::Result<()> {
     auction_details
         .auction_params
         .activity_rule_params
-        .eligibility_progression = vec![(5, 0.5), (3, 0.75)];
+        .eligibility_progression = vec![(0, 0.5), (5, 0.75), (3, 1.0)];
     assert_api_error(
         app.client.create_auction(&auction_details).await,
         ApiError::InvalidAuctionParams(
             AuctionParamsError::EligibilityProgression(
-                EligibilityProgressionError::RoundsNotAscending { index: 1 },
+                EligibilityProgressionError::RoundsNotAscending { index: 2 },
             ),
         ),
     );
 
+    // The progression can't be empty, must start at round 0, and must end
+    // by requiring full eligibility.
+    for (progression, expected) in [
+        (vec![], EligibilityProgressionError::Empty),
+        (
+            vec![(1, 0.5), (3, 1.0)],
+            EligibilityProgressionError::FirstRoundNotZero { round: 1 },
+        ),
+        (
+            vec![(0, 0.5), (3, 0.75)],
+            EligibilityProgressionError::FinalThresholdNotFull {
+                index: 1,
+                round: 3,
+            },
+        ),
+    ] {
+        let mut auction_details =
+            test_helpers::auction_details_a(site.site_id, &app.time_source);
+        auction_details
+            .auction_params
+            .activity_rule_params
+            .eligibility_progression = progression;
+        assert_api_error(
+            app.client.create_auction(&auction_details).await,
+            ApiError::InvalidAuctionParams(
+                AuctionParamsError::EligibilityProgression(expected),
+            ),
+        );
+    }
+
     // A round 0 breakpoint is valid: it sets eligibility going into round 1
     // without constraining round 0's own bids.
     let mut auction_details =
@@ -256,7 +578,153 @@ async fn test_create_auction_eligibility_validation() -> anyhow::Result<()> {
     auction_details
         .auction_params
         .activity_rule_params
-        .eligibility_progression = vec![(0, 0.5), (3, 0.75)];
+        .eligibility_progression = vec![(0, 0.5), (3, 0.75), (6, 1.0)];
+    app.client.create_auction(&auction_details).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_community_round_duration_bounds() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    let bounds = RoundDurationBounds {
+        min: Some(SignedDuration::from_mins(1)),
+        max: Some(SignedDuration::from_hours(1)),
+    };
+    app.client
+        .set_round_duration_bounds(&requests::SetRoundDurationBounds {
+            community_id,
+            bounds,
+        })
+        .await?;
+    let community = app
+        .client
+        .get_communities()
+        .await?
+        .into_iter()
+        .find(|c| c.id == community_id)
+        .unwrap();
+    assert_eq!(community.round_duration_bounds, bounds);
+
+    // Rounds shorter or longer than the bounds are rejected
+    for round_duration in [Span::new().seconds(1), Span::new().hours(2)] {
+        let mut auction_details =
+            test_helpers::auction_details_a(site.site_id, &app.time_source);
+        auction_details.auction_params.round_duration = round_duration;
+        assert_api_error(
+            app.client.create_auction(&auction_details).await,
+            ApiError::InvalidAuctionParams(
+                AuctionParamsError::RoundDurationOutOfBounds(bounds),
+            ),
+        );
+    }
+
+    // Rounds within them, including at the bounds, are accepted
+    for round_duration in [Span::new().minutes(1), Span::new().hours(1)] {
+        let mut auction_details =
+            test_helpers::auction_details_a(site.site_id, &app.time_source);
+        auction_details.auction_params.round_duration = round_duration;
+        app.client.create_auction(&auction_details).await?;
+    }
+
+    // Sites are held to the bounds too
+    let mut site_details = site.site_details.clone();
+    site_details.default_auction_params.round_duration =
+        Span::new().seconds(30);
+    assert_api_error(
+        app.client
+            .update_site(&requests::UpdateSite {
+                site_id: site.site_id,
+                site_details: site_details.into(),
+            })
+            .await,
+        ApiError::InvalidAuctionParams(
+            AuctionParamsError::RoundDurationOutOfBounds(bounds),
+        ),
+    );
+
+    // A minimum above the maximum can't be met
+    assert_api_error(
+        app.client
+            .set_round_duration_bounds(&requests::SetRoundDurationBounds {
+                community_id,
+                bounds: RoundDurationBounds {
+                    min: Some(SignedDuration::from_hours(2)),
+                    max: Some(SignedDuration::from_hours(1)),
+                },
+            })
+            .await,
+        ApiError::InvalidRoundDurationBounds,
+    );
+
+    // Only the leader sets them
+    app.login_bob().await?;
+    assert_api_error(
+        app.client
+            .set_round_duration_bounds(&requests::SetRoundDurationBounds {
+                community_id,
+                bounds: RoundDurationBounds::default(),
+            })
+            .await,
+        ApiError::RequiresLeaderPermissions,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_instance_round_duration_bounds() -> anyhow::Result<()> {
+    let instance_bounds = RoundDurationBounds {
+        min: Some(SignedDuration::from_mins(1)),
+        max: None,
+    };
+    let app =
+        test_helpers::spawn_app_with_round_duration_bounds(instance_bounds)
+            .await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.auction_params.round_duration = Span::new().seconds(30);
+
+    // A community without bounds uses the instance's
+    assert_api_error(
+        app.client.create_auction(&auction_details).await,
+        ApiError::InvalidAuctionParams(
+            AuctionParamsError::RoundDurationOutOfBounds(instance_bounds),
+        ),
+    );
+
+    // A saved site keeps its round duration even once it's out of bounds
+    app.client
+        .set_round_duration_bounds(&requests::SetRoundDurationBounds {
+            community_id,
+            bounds: RoundDurationBounds {
+                min: Some(SignedDuration::from_mins(5)),
+                max: None,
+            },
+        })
+        .await?;
+    app.client
+        .update_site(&requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site.site_details.clone().into(),
+        })
+        .await?;
+
+    // The community's own bound replaces the instance's
+    app.client
+        .set_round_duration_bounds(&requests::SetRoundDurationBounds {
+            community_id,
+            bounds: RoundDurationBounds {
+                min: Some(SignedDuration::from_secs(10)),
+                max: None,
+            },
+        })
+        .await?;
     app.client.create_auction(&auction_details).await?;
 
     Ok(())
@@ -491,49 +959,149 @@ async fn test_cancel_mid_auction_blocks_settlement() -> anyhow::Result<()> {
 }
 
 #[tokio::test]
-async fn test_auction_rounds_dst() -> anyhow::Result<()> {
+async fn test_sandbox_auction_has_no_balance_effects() -> anyhow::Result<()> {
     let app = spawn_app().await;
     let community_id = app.create_two_person_community().await?;
-    let mut site = app.create_test_site(&community_id).await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
 
-    // Set timezone to Los Angeles
-    site.site_details.timezone = Some("America/Los_Angeles".to_string());
-    app.client
-        .update_site(&requests::UpdateSite {
-            site_id: site.site_id,
-            site_details: site.site_details,
-        })
-        .await?;
+    // Nobody has any credit, so nobody could outbid anyone for real
+    for member in app.client.get_members(&community_id).await? {
+        app.client
+            .update_credit_limit_override(
+                &requests::UpdateCreditLimitOverride {
+                    community_id,
+                    member_user_id: member.user.user_id,
+                    credit_limit_override: Some(rust_decimal::Decimal::ZERO),
+                },
+            )
+            .await?;
+    }
 
-    // Create an auction starting March 8, 2024 at noon PST
-    // This will span the DST transition on March 10 at 2am
     let mut auction_details =
         test_helpers::auction_details_a(site.site_id, &app.time_source);
-
-    // Set start time just before DST transition (March 10, 2024 1:59 AM PST)
-    let start_time: Zoned =
-        "2024-03-10T01:59:00-08:00[America/Los_Angeles]".parse()?;
-    let start_time = start_time.timestamp();
-    app.time_source.set(start_time);
-    auction_details.start_at = Some(start_time);
-
-    // Set round duration to one day
-    auction_details.auction_params.round_duration = jiff::Span::new().days(1);
-
-    // Create the auction
+    auction_details.sandbox = true;
     let auction_id = app.client.create_auction(&auction_details).await?;
+    assert!(
+        app.client
+            .get_auction(&auction_id)
+            .await?
+            .auction_details
+            .sandbox
+    );
 
-    // Start the auction to create initial round
+    // Round 0: both bid at the (zero) reserve price
     scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
-
-    // Verify initial round
     let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    assert_eq!(rounds.len(), 1);
-    let round0 = &rounds[0];
-    assert_eq!(round0.round_details.round_num, 0);
+    app.client
+        .create_bid(&space.space_id, &rounds[0].round_id)
+        .await?;
+    app.login_bob().await?;
+    app.client
+        .create_bid(&space.space_id, &rounds[0].round_id)
+        .await?;
+    app.time_source
+        .set(rounds[0].round_details.end_at + Span::new().seconds(1));
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 1: the tie-break loser outbids the winner without any credit
+    let winner = app
+        .client
+        .list_round_space_results_for_round(&rounds[0].round_id)
+        .await?[0]
+        .winner
+        .username
+        .clone();
+    let loser = if winner == "bob" { "alice" } else { "bob" };
+    app.client
+        .login(&test_helpers::login_credentials(loser))
+        .await?;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space.space_id, &rounds[1].round_id)
+        .await?;
+    app.time_source
+        .set(rounds[1].round_details.end_at + Span::new().seconds(1));
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 2: no bids, so the auction concludes with a priced win
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.time_source
+        .set(rounds[2].round_details.end_at + Span::new().seconds(1));
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_some());
+    let result = &app
+        .client
+        .list_round_space_results_for_round(&rounds[1].round_id)
+        .await?[0];
+    assert_eq!(result.winner.username, loser);
+    assert!(result.value > rust_decimal::Decimal::ZERO);
+
+    // No journal entries of any kind, and the win isn't a possession
+    let entry_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM journal_entries WHERE community_id = $1",
+    )
+    .bind(community_id)
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert_eq!(entry_count, 0);
+    assert!(app.client.my_possessions().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_auction_rounds_dst() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let mut site = app.create_test_site(&community_id).await?;
+
+    // Set timezone to Los Angeles
+    site.site_details.timezone = Some("America/Los_Angeles".to_string());
+    app.client
+        .update_site(&requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site.site_details.into(),
+        })
+        .await?;
+
+    // Create an auction starting March 8, 2024 at noon PST
+    // This will span the DST transition on March 10 at 2am
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+
+    // Set start time just before DST transition (March 10, 2024 1:59 AM PST)
+    let start_time: Zoned =
+        "2024-03-10T01:59:00-08:00[America/Los_Angeles]".parse()?;
+    let start_time = start_time.timestamp();
+    app.time_source.set(start_time);
+    auction_details.start_at = Some(start_time);
+
+    // A day isn't a fixed length across the transition, so it's rejected
+    auction_details.auction_params.round_duration = jiff::Span::new().days(1);
+    assert_api_error(
+        app.client.create_auction(&auction_details).await,
+        ApiError::InvalidAuctionParams(
+            payloads::AuctionParamsError::RoundDurationNotAbsolute,
+        ),
+    );
 
+    // Create the auction with 24 hour rounds
+    auction_details.auction_params.round_duration = jiff::Span::new().hours(24);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    // Start the auction to create initial round
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Verify initial round
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    assert_eq!(rounds.len(), 1);
+    let round0 = &rounds[0];
+    assert_eq!(round0.round_details.round_num, 0);
+
+    // 24 elapsed hours, an hour later on the clock after springing forward
     let expected_round_end_time: Zoned =
-        "2024-03-11T01:59:00-07:00[America/Los_Angeles]".parse()?;
+        "2024-03-11T02:59:00-07:00[America/Los_Angeles]".parse()?;
 
     assert_eq!(
         round0.round_details.end_at,
@@ -600,6 +1168,197 @@ async fn test_bid_crud() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn clear_my_bids_withdraws_all_in_round() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space_a = app.create_test_space(&site.site_id).await?;
+    let space_b = app
+        .client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round = &app.client.list_auction_rounds(&auction.auction_id).await?[0];
+
+    // Bob's bid is left alone
+    app.login_bob().await?;
+    app.client
+        .create_bid(&space_a.space_id, &round.round_id)
+        .await?;
+    app.login_alice().await?;
+    app.client
+        .create_bid(&space_a.space_id, &round.round_id)
+        .await?;
+    app.client.create_bid(&space_b, &round.round_id).await?;
+    assert_eq!(app.client.list_bids(&round.round_id).await?.len(), 2);
+
+    assert_eq!(app.client.clear_my_bids(&round.round_id).await?, 2);
+    assert!(app.client.list_bids(&round.round_id).await?.is_empty());
+    assert_eq!(app.client.clear_my_bids(&round.round_id).await?, 0);
+    app.login_bob().await?;
+    assert_eq!(app.client.list_bids(&round.round_id).await?.len(), 1);
+
+    app.time_source
+        .set(round.round_details.end_at + Span::new().seconds(1));
+    assert_api_error(
+        app.client.clear_my_bids(&round.round_id).await,
+        ApiError::RoundEnded,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bid_history_cursor_stable_under_inserts() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut space_ids = Vec::new();
+    for name in ["one", "two", "three", "four", "five"] {
+        let mut details = test_helpers::space_details_a(site.site_id);
+        details.name = name.into();
+        space_ids.push(app.client.create_space(&details).await?);
+    }
+
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round_id =
+        app.client.list_auction_rounds(&auction_id).await?[0].round_id;
+
+    // Three bids, two of them sharing a timestamp so the id tiebreaker matters
+    app.client.create_bid(&space_ids[0], &round_id).await?;
+    app.time_source.advance(Span::new().seconds(1));
+    app.client.create_bid(&space_ids[1], &round_id).await?;
+    app.client.create_bid(&space_ids[2], &round_id).await?;
+
+    let page = |cursor| requests::GetBidHistory {
+        auction_id,
+        cursor,
+        limit: 2,
+    };
+    let first = app.client.get_bid_history(&page(None)).await?;
+    assert_eq!(first.bids.len(), 2);
+    assert!(first.next_cursor.is_some());
+
+    // New bids land between page fetches, both at the boundary timestamp and
+    // later. With offset pagination these would shift the second page.
+    app.client.create_bid(&space_ids[3], &round_id).await?;
+    app.time_source.advance(Span::new().seconds(1));
+    app.client.create_bid(&space_ids[4], &round_id).await?;
+
+    let mut seen: Vec<_> = first.bids.iter().map(|b| b.space_id).collect();
+    let mut cursor = first.next_cursor;
+    while let Some(next) = cursor {
+        let page = app.client.get_bid_history(&page(Some(next))).await?;
+        seen.extend(page.bids.iter().map(|b| b.space_id));
+        cursor = page.next_cursor;
+    }
+
+    // No duplicates, and every bid present at the first fetch was reached
+    let unique: std::collections::HashSet<_> = seen.iter().collect();
+    assert_eq!(unique.len(), seen.len());
+    for space_id in &space_ids[..3] {
+        assert!(seen.contains(space_id));
+    }
+    // The later insert sorts ahead of the cursor, so it's never paged into
+    assert!(!seen.contains(&space_ids[4]));
+
+    // A fresh listing starts with the newest bid
+    let fresh = app.client.get_bid_history(&page(None)).await?;
+    assert_eq!(fresh.bids[0].space_id, space_ids[4]);
+
+    let result = app
+        .client
+        .get_bid_history(&page(Some(serde_json::from_str("\"bogus\"")?)))
+        .await;
+    assert_api_error(result, ApiError::InvalidCursor);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn space_bid_log_after_conclusion() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round = &app.client.list_auction_rounds(&auction.auction_id).await?[0];
+    let log = requests::SpaceBidLog {
+        auction_id: auction.auction_id,
+        space_id: space.space_id,
+    };
+
+    // Alice bids, then Bob bids, withdraws, and bids again
+    let alice_at = app.time_source.now();
+    app.client
+        .create_bid(&space.space_id, &round.round_id)
+        .await?;
+    app.login_bob().await?;
+    app.time_source.advance(Span::new().seconds(1));
+    let bob_withdrawn_at = app.time_source.now();
+    app.client
+        .create_bid(&space.space_id, &round.round_id)
+        .await?;
+    app.time_source.advance(Span::new().seconds(1));
+    app.client
+        .delete_bid(&space.space_id, &round.round_id)
+        .await?;
+    app.time_source.advance(Span::new().seconds(1));
+    let bob_at = app.time_source.now();
+    app.client
+        .create_bid(&space.space_id, &round.round_id)
+        .await?;
+    app.login_alice().await?;
+
+    assert_api_error(
+        app.client.space_bid_log(&log).await,
+        ApiError::AuctionNotConcluded,
+    );
+
+    // Round 1 is quiet, which concludes the auction
+    for round_num in 0..2 {
+        let rounds =
+            app.client.list_auction_rounds(&auction.auction_id).await?;
+        app.time_source.set(
+            rounds[round_num].round_details.end_at + Span::new().seconds(1),
+        );
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+    assert!(
+        app.client
+            .get_auction(&auction.auction_id)
+            .await?
+            .end_at
+            .is_some()
+    );
+
+    let entries: Vec<_> = app
+        .client
+        .space_bid_log(&log)
+        .await?
+        .into_iter()
+        .map(|e| (e.round_num, e.username, e.created_at, e.withdrawn))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (0, "alice".to_string(), alice_at, false),
+            (0, "bob".to_string(), bob_withdrawn_at, true),
+            (0, "bob".to_string(), bob_at, false),
+        ]
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_bid_after_round_end() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -646,6 +1405,81 @@ async fn test_bid_after_round_end() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn bid_access_checks_skip_space_row() -> anyhow::Result<()> {
+    use api::store::{self, StoreError, full_space_fetches};
+
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round_id =
+        app.client.list_auction_rounds(&auction_id).await?[0].round_id;
+    app.create_charlie_user().await?;
+
+    let user_id_for = |username: &'static str| {
+        sqlx::query_scalar::<_, payloads::UserId>(
+            "SELECT id FROM users WHERE username = $1",
+        )
+        .bind(username)
+        .fetch_one(&app.db_pool)
+    };
+    let alice_id = user_id_for("alice").await?;
+    let charlie_id = user_id_for("charlie").await?;
+    let pool = &app.db_pool;
+
+    // Placing a bid needs the space row to check availability
+    let before = full_space_fetches();
+    store::create_bid(
+        &space.space_id,
+        &round_id,
+        &alice_id,
+        pool,
+        &app.time_source,
+    )
+    .await?;
+    assert_eq!(full_space_fetches(), before + 1);
+
+    // Reading and deleting it only check access, without loading the row
+    let before = full_space_fetches();
+    let bid =
+        store::get_bid(&space.space_id, &round_id, &alice_id, pool).await?;
+    assert_eq!(bid.space_id, space.space_id);
+    assert!(matches!(
+        store::get_bid(&space.space_id, &round_id, &charlie_id, pool).await,
+        Err(StoreError::Api(ApiError::MemberNotFound))
+    ));
+    assert!(matches!(
+        store::delete_bid(
+            &payloads::SpaceId(uuid::Uuid::new_v4()),
+            &round_id,
+            &alice_id,
+            pool,
+            &app.time_source,
+        )
+        .await,
+        Err(StoreError::Api(ApiError::SpaceNotFound))
+    ));
+    store::delete_bid(
+        &space.space_id,
+        &round_id,
+        &alice_id,
+        pool,
+        &app.time_source,
+    )
+    .await?;
+    assert_eq!(full_space_fetches(), before);
+
+    assert!(app.client.list_bids(&round_id).await?.is_empty());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_continued_bidding() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -758,66 +1592,467 @@ async fn test_continued_bidding() -> anyhow::Result<()> {
 }
 
 #[tokio::test]
-async fn test_bid_eligibility() -> anyhow::Result<()> {
+async fn test_increment_schedule_escalates_prices() -> anyhow::Result<()> {
+    use rust_decimal::Decimal;
+
     let app = spawn_app().await;
     let community_id = app.create_two_person_community().await?;
     let site = app.create_test_site(&community_id).await?;
-
-    // Create two spaces with different eligibility points
-    let space_a = app.create_test_space(&site.site_id).await?; // 10 points
-    let space_b = app
+    let space = app.create_test_space(&site.site_id).await?;
+    let space_b_id = app
         .client
-        .create_space(&payloads::Space {
-            site_id: site.site_id,
-            name: "test space b".into(),
-            description: None,
-            eligibility_points: 15.0, // Higher points than space_a
-            is_available: true,
-            site_image_id: None,
-            reserve_price: payloads::ReservePrice(rust_decimal::Decimal::ZERO),
-        })
+        .create_space(&test_helpers::space_details_b(site.site_id))
         .await?;
-    let space_b = app.client.get_space(&space_b).await?;
 
-    // Create an auction that starts now
-    let start_time = app.time_source.now();
     let mut auction_details =
         test_helpers::auction_details_a(site.site_id, &app.time_source);
-    auction_details.start_at = Some(start_time);
+    auction_details.auction_params.increment_schedule =
+        vec![(0, Decimal::new(10, 1)), (5, Decimal::new(50, 1))];
     let auction_id = app.client.create_auction(&auction_details).await?;
+    assert_eq!(
+        app.client
+            .get_auction(&auction_id)
+            .await?
+            .auction_details
+            .auction_params
+            .increment_schedule,
+        auction_details.auction_params.increment_schedule
+    );
 
     scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
-
-    // Round 0 - no eligibility constraints
+    app.login_bob().await?;
     let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    let round_0 = &rounds[0];
-
-    // Alice bids only for space_a, Bob bids only for space_b
-    app.login_alice().await?;
     app.client
-        .create_bid(&space_a.space_id, &round_0.round_id)
-        .await?; // Alice bids on space_a
+        .create_bid(&space_b_id, &rounds[0].round_id)
+        .await?;
 
-    app.login_bob().await?;
-    app.client
-        .create_bid(&space_b.space_id, &round_0.round_id)
-        .await?; // Bob bids on space_b
+    // Alice and bob take turns outbidding each other on the same space.
+    // Rounds 1-4 step by 1, then rounds 5 onwards by 5.
+    let expected = [0, 1, 2, 3, 4, 9, 14];
+    for (round_num, expected) in expected.into_iter().enumerate() {
+        let username = if round_num % 2 == 0 { "alice" } else { "bob" };
+        app.client
+            .login(&test_helpers::login_credentials(username))
+            .await?;
+        let round =
+            &app.client.list_auction_rounds(&auction_id).await?[round_num];
+        app.client
+            .create_bid(&space.space_id, &round.round_id)
+            .await?;
+        app.time_source
+            .set(round.round_details.end_at + Span::new().seconds(1));
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
 
-    // Advance time to end round 0
-    app.time_source
-        .advance(auction_details.auction_params.round_duration);
-    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+        let result = app
+            .client
+            .get_round_space_result(&space.space_id, &round.round_id)
+            .await?;
+        assert_eq!(result.value, Decimal::from(expected), "round {round_num}");
+    }
 
-    // Round 1 - eligibility is based on round 0 results
-    let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    let round_1 = &rounds[1];
+    Ok(())
+}
 
-    // Get round 0 results - Alice should have won space_a and Bob should have
-    // won space_b
-    let results = app
-        .client
-        .list_round_space_results_for_round(&round_0.round_id)
-        .await?;
+#[tokio::test]
+async fn test_min_rounds_delays_conclusion() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.auction_params.min_rounds = Some(0);
+    assert_api_error(
+        app.client.create_auction(&auction_details).await,
+        ApiError::InvalidAuctionParams(AuctionParamsError::MinRoundsOutOfRange),
+    );
+    auction_details.auction_params.min_rounds = Some(3);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    // Alice's round 0 bid stands unchallenged, so round 1 is quiet and would
+    // normally end the auction
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space.space_id, &rounds[0].round_id)
+        .await?;
+    for round_num in 0..2 {
+        let rounds = app.client.list_auction_rounds(&auction_id).await?;
+        app.time_source.set(
+            rounds[round_num].round_details.end_at + Span::new().seconds(1),
+        );
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_none());
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    assert_eq!(rounds.len(), 3);
+
+    // The third quiet round concludes it with the standing result
+    app.time_source
+        .set(rounds[2].round_details.end_at + Span::new().seconds(1));
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_some());
+    assert_eq!(app.client.list_auction_rounds(&auction_id).await?.len(), 3);
+    let result = app
+        .client
+        .get_round_space_result(&space.space_id, &rounds[2].round_id)
+        .await?;
+    assert_eq!(result.winner.username, "alice");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_round_ask_prices() -> anyhow::Result<()> {
+    use rust_decimal::Decimal;
+    use std::collections::HashMap;
+
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space_a = app.create_test_space(&site.site_id).await?.space_id;
+    let mut space_b_details = test_helpers::space_details_b(site.site_id);
+    space_b_details.reserve_price = payloads::ReservePrice(Decimal::from(5));
+    let space_b = app.client.create_space(&space_b_details).await?;
+
+    // Increments step by 1 in round 0, then by 3
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.auction_params.increment_schedule =
+        vec![(1, Decimal::from(3))];
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    // Bob's proxy keeps bidding on B while it costs at most 20
+    app.login_bob().await?;
+    app.client
+        .create_or_update_user_value(&requests::UserValue {
+            space_id: space_b,
+            value: Decimal::from(20),
+        })
+        .await?;
+    app.client
+        .create_or_update_proxy_bidding(&requests::UseProxyBidding {
+            auction_id,
+            max_items: 1,
+            max_total: None,
+        })
+        .await?;
+    app.login_alice().await?;
+
+    let ask_prices = |round_num: usize| {
+        let app = &app;
+        async move {
+            let round =
+                &app.client.list_auction_rounds(&auction_id).await?[round_num];
+            let prices = app.client.get_round_prices(&round.round_id).await?;
+            anyhow::Ok(
+                prices
+                    .into_iter()
+                    .map(|p| (p.space_id, p.ask_price))
+                    .collect::<HashMap<_, _>>(),
+            )
+        }
+    };
+    let end_round = |round_num: usize| {
+        let app = &app;
+        async move {
+            let round = app.client.list_auction_rounds(&auction_id).await?
+                [round_num]
+                .clone();
+            app.time_source
+                .set(round.round_details.end_at + Span::new().seconds(1));
+            scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+            let mut values = HashMap::new();
+            for space_id in [space_a, space_b] {
+                let result = app
+                    .client
+                    .get_round_space_result(&space_id, &round.round_id)
+                    .await?;
+                values.insert(space_id, result.value);
+            }
+            anyhow::Ok(values)
+        }
+    };
+
+    // Round 0 opens at the reserve prices. Alice bids on A and Bob's proxy
+    // on B, each winning at the ask.
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round_0 = ask_prices(0).await?;
+    assert_eq!(
+        round_0,
+        HashMap::from([(space_a, Decimal::ZERO), (space_b, Decimal::from(5))])
+    );
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client.create_bid(&space_a, &rounds[0].round_id).await?;
+    assert_eq!(end_round(0).await?, round_0);
+
+    // Round 1 asks are the results plus the scheduled increment. Alice's
+    // bid on B locks its ask on top of her standing win on A.
+    let round_1 = ask_prices(1).await?;
+    assert_eq!(
+        round_1,
+        HashMap::from([
+            (space_a, Decimal::from(3)),
+            (space_b, Decimal::from(8))
+        ])
+    );
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client.create_bid(&space_b, &rounds[1].round_id).await?;
+    let currency_info = app
+        .client
+        .get_member_currency_info(&requests::GetMemberCurrencyInfo {
+            community_id,
+            member_user_id: None,
+        })
+        .await?;
+    assert_eq!(currency_info.locked_balance, round_1[&space_b]);
+    assert_eq!(end_round(1).await?[&space_b], round_1[&space_b]);
+
+    // Bob's proxy takes B back at the round 2 ask
+    let round_2 = ask_prices(2).await?;
+    assert_eq!(round_2[&space_b], Decimal::from(11));
+    assert_eq!(end_round(2).await?[&space_b], round_2[&space_b]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_wins_constraint_keeps_highest_priced_win()
+-> anyhow::Result<()> {
+    use rust_decimal::Decimal;
+
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let cheap_space = app.create_test_space(&site.site_id).await?;
+    let mut pricey_details = test_helpers::space_details_b(site.site_id);
+    pricey_details.reserve_price = payloads::ReservePrice(Decimal::from(5));
+    let pricey_space_id = app.client.create_space(&pricey_details).await?;
+
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.winner_constraints =
+        vec![payloads::WinnerConstraint::MaxWinsPerUser(0)];
+    let result = app.client.create_auction(&auction_details).await;
+    assert_api_error(
+        result,
+        ApiError::InvalidWinnerConstraints(
+            payloads::WinnerConstraintError::MaxWinsZero { index: 0 },
+        ),
+    );
+
+    auction_details.winner_constraints =
+        vec![payloads::WinnerConstraint::MaxWinsPerUser(1)];
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    assert_eq!(
+        app.client
+            .get_auction(&auction_id)
+            .await?
+            .auction_details
+            .winner_constraints,
+        auction_details.winner_constraints
+    );
+
+    // Alice leads on both spaces after round 0; round 1 has no bids and
+    // concludes the auction
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&cheap_space.space_id, &rounds[0].round_id)
+        .await?;
+    app.client
+        .create_bid(&pricey_space_id, &rounds[0].round_id)
+        .await?;
+    for round_num in 0..2 {
+        let rounds = app.client.list_auction_rounds(&auction_id).await?;
+        app.time_source.set(
+            rounds[round_num].round_details.end_at + Span::new().seconds(1),
+        );
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_some());
+
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    assert_eq!(
+        app.client
+            .list_round_space_results_for_round(&rounds[0].round_id)
+            .await?
+            .len(),
+        2
+    );
+    let final_results = app
+        .client
+        .list_round_space_results_for_round(&rounds[1].round_id)
+        .await?;
+    assert_eq!(final_results.len(), 1);
+    assert_eq!(final_results[0].space_id, pricey_space_id);
+    assert_eq!(final_results[0].winner.username, "alice");
+    assert_eq!(final_results[0].value, Decimal::from(5));
+
+    Ok(())
+}
+
+/// Run an auction on `site_id` where all three members bid on every space in
+/// round 0, with its seed overridden to `seed`. Returns each space's winner
+/// and value, ordered by space.
+async fn run_tied_auction(
+    app: &test_helpers::TestApp,
+    site_id: payloads::SiteId,
+    seed: i64,
+) -> anyhow::Result<Vec<(payloads::SpaceId, String, rust_decimal::Decimal)>> {
+    app.login_alice().await?;
+    let mut auction_details =
+        test_helpers::auction_details_a(site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    sqlx::query("UPDATE auctions SET random_seed = $1 WHERE id = $2")
+        .bind(seed)
+        .bind(auction_id)
+        .execute(&app.db_pool)
+        .await?;
+
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let spaces = app.client.list_spaces(&site_id).await?;
+    for username in ["alice", "bob", "charlie"] {
+        app.client
+            .login(&test_helpers::login_credentials(username))
+            .await?;
+        for space in &spaces {
+            app.client
+                .create_bid(&space.space_id, &rounds[0].round_id)
+                .await?;
+        }
+    }
+
+    // Round 0 resolves the ties; round 1 has no bids and concludes
+    for round_num in 0..2 {
+        let rounds = app.client.list_auction_rounds(&auction_id).await?;
+        app.time_source.set(
+            rounds[round_num].round_details.end_at + Span::new().seconds(1),
+        );
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_some());
+
+    let mut results = app
+        .client
+        .list_round_space_results_for_round(&rounds[0].round_id)
+        .await?
+        .into_iter()
+        .map(|r| (r.space_id, r.winner.username, r.value))
+        .collect::<Vec<_>>();
+    results.sort_by_key(|(space_id, ..)| space_id.0);
+    Ok(results)
+}
+
+#[tokio::test]
+async fn test_seeded_auction_replays_identically() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_three_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+
+    let first = run_tied_auction(&app, site.site_id, 42).await?;
+    let second = run_tied_auction(&app, site.site_id, 42).await?;
+    assert_eq!(first.len(), 2);
+    assert_eq!(first, second);
+
+    // The seed is only visible to coleaders and above
+    app.login_alice().await?;
+    let auctions = app.client.list_auctions(&site.site_id).await?;
+    assert!(auctions.iter().all(|a| a.random_seed == Some(42)));
+    app.login_bob().await?;
+    let auctions = app.client.list_auctions(&site.site_id).await?;
+    assert!(auctions.iter().all(|a| a.random_seed.is_none()));
+
+    Ok(())
+}
+
+/// Under mock time, auctions are unseeded unless a test sets a seed, and ties
+/// go to the first bidder by username.
+#[tokio::test]
+async fn test_unseeded_ties_go_to_first_username() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_three_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+
+    let results = run_tied_auction(&app, site.site_id, 0).await?;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|(_, winner, _)| winner == "alice"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bid_eligibility() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    // Create two spaces with different eligibility points
+    let space_a = app.create_test_space(&site.site_id).await?; // 10 points
+    let space_b = app
+        .client
+        .create_space(&payloads::Space {
+            site_id: site.site_id,
+            name: "test space b".into(),
+            description: None,
+            eligibility_points: 15.0, // Higher points than space_a
+            is_available: true,
+            site_image_id: None,
+            reserve_price: payloads::ReservePrice(rust_decimal::Decimal::ZERO),
+        })
+        .await?;
+    let space_b = app.client.get_space(&space_b).await?;
+
+    // Create an auction that starts now
+    let start_time = app.time_source.now();
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(start_time);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 0 - no eligibility constraints
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let round_0 = &rounds[0];
+
+    // Alice bids only for space_a, Bob bids only for space_b
+    app.login_alice().await?;
+    app.client
+        .create_bid(&space_a.space_id, &round_0.round_id)
+        .await?; // Alice bids on space_a
+
+    app.login_bob().await?;
+    app.client
+        .create_bid(&space_b.space_id, &round_0.round_id)
+        .await?; // Bob bids on space_b
+
+    // Advance time to end round 0
+    app.time_source
+        .advance(auction_details.auction_params.round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 1 - eligibility is based on round 0 results
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let round_1 = &rounds[1];
+
+    // Get round 0 results - Alice should have won space_a and Bob should have
+    // won space_b
+    let results = app
+        .client
+        .list_round_space_results_for_round(&round_0.round_id)
+        .await?;
     let space_a_result = results
         .iter()
         .find(|r| r.space_id == space_a.space_id)
@@ -836,7 +2071,12 @@ async fn test_bid_eligibility() -> anyhow::Result<()> {
         .client
         .create_bid(&space_a.space_id, &round_1.round_id)
         .await;
-    assert_api_error(result, ApiError::AlreadyWinningSpace);
+    assert_api_error(
+        result,
+        ApiError::AlreadyWinningSpace {
+            price: space_a_result.value,
+        },
+    );
 
     // But she can bid on space_b (though it will fail due to insufficient
     // eligibility: her standing 10-point win on space_a plus the 15-point
@@ -859,7 +2099,12 @@ async fn test_bid_eligibility() -> anyhow::Result<()> {
         .client
         .create_bid(&space_b.space_id, &round_1.round_id)
         .await;
-    assert_api_error(result, ApiError::AlreadyWinningSpace);
+    assert_api_error(
+        result,
+        ApiError::AlreadyWinningSpace {
+            price: space_b_result.value,
+        },
+    );
 
     // But he can bid on space_a since he has enough eligibility (15 points * 2
     // = 30 points > 25 points needed)
@@ -902,11 +2147,12 @@ async fn test_eligibility_disabled_at_zero() -> anyhow::Result<()> {
     let mut auction_details =
         test_helpers::auction_details_a(site.site_id, &app.time_source);
     auction_details.start_at = Some(start_time);
-    // 0% eligibility required from the outset.
+    // 0% eligibility required from the outset, until long after this test's
+    // rounds.
     auction_details
         .auction_params
         .activity_rule_params
-        .eligibility_progression = vec![(0, 0.0)];
+        .eligibility_progression = vec![(0, 0.0), (100, 1.0)];
     let auction_id = app.client.create_auction(&auction_details).await?;
 
     scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
@@ -948,43 +2194,151 @@ async fn test_eligibility_disabled_at_zero() -> anyhow::Result<()> {
     Ok(())
 }
 
-// When the prior round imposed a nonzero threshold, a user who sat out that
-// round has no eligibility row, so their eligibility is a finite 0 (a
-// Finite(0.0) budget): they cannot bid on a positive-point space, but they
-// can still bid on a zero-point space (bidding it adds nothing to their
-// activity).
 #[tokio::test]
-async fn test_eligibility_required_when_nonzero() -> anyhow::Result<()> {
+async fn test_my_standing_wins() -> anyhow::Result<()> {
+    use rust_decimal::Decimal;
+
     let app = spawn_app().await;
     let community_id = app.create_two_person_community().await?;
     let site = app.create_test_site(&community_id).await?;
-    let space = app.create_test_space(&site.site_id).await?; // 10 points
-    // A zero-point space that a zero-budget (Finite(0.0)) user is still
-    // allowed to bid.
-    let free_space = app
-        .client
-        .create_space(&payloads::Space {
-            site_id: site.site_id,
-            name: "free space".into(),
-            description: None,
-            eligibility_points: 0.0,
-            is_available: true,
-            site_image_id: None,
-            reserve_price: payloads::ReservePrice(rust_decimal::Decimal::ZERO),
-        })
-        .await?;
-    let free_space = app.client.get_space(&free_space).await?;
-
-    let start_time = app.time_source.now();
-    let mut auction_details =
+    let mut space_details = test_helpers::space_details_a(site.site_id);
+    space_details.reserve_price = payloads::ReservePrice(Decimal::from(3));
+    let space_id = app.client.create_space(&space_details).await?;
+    let auction_details =
         test_helpers::auction_details_a(site.site_id, &app.time_source);
-    auction_details.start_at = Some(start_time);
-    // Nonzero threshold from the outset (the default already is, but make it
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    let details = requests::MyStandingWins { auction_id };
+
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Nothing is standing before any round is processed
+    assert!(app.client.my_standing_wins(&details).await?.is_empty());
+
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space_id, &rounds[0].round_id)
+        .await?;
+    app.time_source
+        .advance(auction_details.auction_params.round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // In round 1, Alice stands on the space at round 0's price
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let result = app
+        .client
+        .get_round_space_result(&space_id, &rounds[0].round_id)
+        .await?;
+    let wins = app.client.my_standing_wins(&details).await?;
+    assert_eq!(
+        wins,
+        vec![payloads::responses::StandingWin {
+            space_id,
+            space_name: space_details.name.clone(),
+            round_num: 0,
+            value: result.value,
+        }]
+    );
+    let result = app.client.create_bid(&space_id, &rounds[1].round_id).await;
+    assert_api_error(
+        result,
+        ApiError::AlreadyWinningSpace {
+            price: wins[0].value,
+        },
+    );
+
+    app.login_bob().await?;
+    assert!(app.client.my_standing_wins(&details).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_space_current_price() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space_id = app.create_test_space(&site.site_id).await?.space_id;
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // No standing price until someone bids
+    assert_eq!(app.client.get_space_current_price(&space_id).await?, None);
+
+    // Mid-round, a bid stands at the round's ask price
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space_id, &rounds[0].round_id)
+        .await?;
+    let ask_price = app
+        .client
+        .get_round_prices(&rounds[0].round_id)
+        .await?
+        .into_iter()
+        .find(|p| p.space_id == space_id)
+        .unwrap()
+        .ask_price;
+    assert_eq!(
+        app.client.get_space_current_price(&space_id).await?,
+        Some(ask_price)
+    );
+
+    // Once the round is processed, the result carries the price
+    app.time_source
+        .advance(auction_details.auction_params.round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let result = app
+        .client
+        .get_round_space_result(&space_id, &rounds[0].round_id)
+        .await?;
+    assert_eq!(result.value, ask_price);
+    app.login_bob().await?;
+    assert_eq!(
+        app.client.get_space_current_price(&space_id).await?,
+        Some(result.value)
+    );
+
+    Ok(())
+}
+
+// When the prior round imposed a nonzero threshold, a user who sat out that
+// round has no eligibility row, so their eligibility is a finite 0 (a
+// Finite(0.0) budget): they cannot bid on a positive-point space, but they
+// can still bid on a zero-point space (bidding it adds nothing to their
+// activity).
+#[tokio::test]
+async fn test_eligibility_required_when_nonzero() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?; // 10 points
+    // A zero-point space that a zero-budget (Finite(0.0)) user is still
+    // allowed to bid.
+    let free_space = app
+        .client
+        .create_space(&payloads::Space {
+            site_id: site.site_id,
+            name: "free space".into(),
+            description: None,
+            eligibility_points: 0.0,
+            is_available: true,
+            site_image_id: None,
+            reserve_price: payloads::ReservePrice(rust_decimal::Decimal::ZERO),
+        })
+        .await?;
+    let free_space = app.client.get_space(&free_space).await?;
+
+    let start_time = app.time_source.now();
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(start_time);
+    // Nonzero threshold from the outset (the default already is, but make it
     // explicit for this test's intent).
     auction_details
         .auction_params
         .activity_rule_params
-        .eligibility_progression = vec![(0, 0.5)];
+        .eligibility_progression = vec![(0, 0.5), (100, 1.0)];
     let auction_id = app.client.create_auction(&auction_details).await?;
 
     scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
@@ -1037,144 +2391,482 @@ async fn test_eligibility_required_when_nonzero() -> anyhow::Result<()> {
     Ok(())
 }
 
-// A progression that starts at 0% and switches to a nonzero threshold partway
-// through should leave early rounds unconstrained, then activate the
-// constraint once the prior round's threshold becomes nonzero.
+// A progression that starts at 0% and switches to a nonzero threshold partway
+// through should leave early rounds unconstrained, then activate the
+// constraint once the prior round's threshold becomes nonzero.
+#[tokio::test]
+async fn test_eligibility_rounding_policy() -> anyhow::Result<()> {
+    // 6 points of activity at a 0.8 threshold is 7.5 points of eligibility
+    assert_eq!(
+        eligibility_after_first_round(payloads::RoundingPolicy::Floor).await?,
+        payloads::Eligibility::Finite(7.0),
+    );
+    assert_eq!(
+        eligibility_after_first_round(payloads::RoundingPolicy::Round).await?,
+        payloads::Eligibility::Finite(8.0),
+    );
+    Ok(())
+}
+
+/// Alice bids on a 6-point space in round 0 of an auction with a 0.8
+/// threshold; returns her eligibility for round 1.
+async fn eligibility_after_first_round(
+    rounding: payloads::RoundingPolicy,
+) -> anyhow::Result<payloads::Eligibility> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut space = test_helpers::space_details_a(site.site_id);
+    space.eligibility_points = 6.0;
+    let space_id = app.client.create_space(&space).await?;
+
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    auction_details
+        .auction_params
+        .activity_rule_params
+        .eligibility_progression = vec![(0, 0.8), (10, 1.0)];
+    auction_details.auction_params.eligibility_rounding = rounding;
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    let auction = app.client.get_auction(&auction_id).await?;
+    assert_eq!(
+        auction.auction_details.auction_params.eligibility_rounding,
+        rounding
+    );
+
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space_id, &rounds[0].round_id)
+        .await?;
+
+    app.time_source
+        .advance(auction_details.auction_params.round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    Ok(app.client.get_eligibility(&rounds[1].round_id).await?)
+}
+
+#[tokio::test]
+async fn test_eligibility_progression_activates_midway() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    let space_a = app.create_test_space(&site.site_id).await?; // 10 points
+    let space_b = app
+        .client
+        .create_space(&payloads::Space {
+            site_id: site.site_id,
+            name: "test space b".into(),
+            description: None,
+            eligibility_points: 15.0,
+            is_available: true,
+            site_image_id: None,
+            reserve_price: payloads::ReservePrice(rust_decimal::Decimal::ZERO),
+        })
+        .await?;
+    let space_b = app.client.get_space(&space_b).await?;
+
+    let start_time = app.time_source.now();
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(start_time);
+    // Rounds 0 and 1 are unconstrained (threshold 0.0); round 2 onward uses a
+    // 50% threshold. Since a round's threshold governs the *following*
+    // round's bids, the constraint first applies to round-3 bids.
+    auction_details
+        .auction_params
+        .activity_rule_params
+        .eligibility_progression = vec![(0, 0.0), (2, 0.5), (100, 1.0)];
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    let round_duration = auction_details.auction_params.round_duration;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Alice and Bob alternate outbidding each other on the 10-point space_a so
+    // that every round has a new bid, keeping the auction from concluding for
+    // lack of demand. Whoever isn't the standing winner bids each round.
+
+    // Round 0: Alice bids and becomes the standing winner.
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.login_alice().await?;
+    app.client
+        .create_bid(&space_a.space_id, &rounds[0].round_id)
+        .await?;
+
+    app.time_source.advance(round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 1 (prior threshold 0.0 -> unconstrained): Unlimited eligibility,
+    // and Bob can outbid freely.
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    assert_eq!(
+        app.client.get_eligibility(&rounds[1].round_id).await?,
+        payloads::Eligibility::Unlimited,
+        "round 1 should be Unlimited (prior threshold 0.0)"
+    );
+    app.login_bob().await?;
+    app.client
+        .create_bid(&space_a.space_id, &rounds[1].round_id)
+        .await?;
+
+    app.time_source.advance(round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 2 (prior threshold 0.0 -> still unconstrained): still Unlimited.
+    // Alice retakes the lead.
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    assert_eq!(
+        app.client.get_eligibility(&rounds[2].round_id).await?,
+        payloads::Eligibility::Unlimited,
+        "round 2 should still be Unlimited (prior threshold 0.0)"
+    );
+    app.login_alice().await?;
+    app.client
+        .create_bid(&space_a.space_id, &rounds[2].round_id)
+        .await?;
+
+    app.time_source.advance(round_duration);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 3: round 2's threshold was 50%, so the constraint now applies.
+    // Both Alice and Bob have 10 points of activity going into round 2 (Alice
+    // bid space_a in round 2; Bob holds the standing win from round 1), so
+    // each gets an eligibility of 10 / 0.5 = 20 for round 3. This also
+    // confirms the value is finite (not the inf/NaN that x/0.0 would produce).
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let round_3 = &rounds[3];
+
+    app.login_alice().await?;
+    assert_eq!(
+        app.client.get_eligibility(&round_3.round_id).await?,
+        payloads::Eligibility::Finite(20.0),
+        "Alice's round-3 eligibility should be 10 / 0.5 = 20"
+    );
+    app.login_bob().await?;
+    assert_eq!(
+        app.client.get_eligibility(&round_3.round_id).await?,
+        payloads::Eligibility::Finite(20.0),
+        "Bob's round-3 eligibility should be 10 / 0.5 = 20"
+    );
+
+    // Alice is the standing high bidder of space_a from round 2, so that
+    // 10-point standing win already counts toward her activity. Adding the
+    // 15-point space_b would total 25 > 20, so she cannot bid on space_b.
+    app.login_alice().await?;
+    assert_api_error(
+        app.client
+            .create_bid(&space_b.space_id, &round_3.round_id)
+            .await,
+        ApiError::ExceedsEligibility {
+            available: 20.0,
+            required: 25.0,
+        },
+    );
+
+    // Bob holds no standing win in round 3, so only his round-3 bids count. He
+    // can take either space alone (10 <= 20, 15 <= 20), but not both: after
+    // bidding space_a, adding space_b totals 10 + 15 = 25 > 20.
+    app.login_bob().await?;
+    app.client
+        .create_bid(&space_a.space_id, &round_3.round_id)
+        .await?;
+    assert_api_error(
+        app.client
+            .create_bid(&space_b.space_id, &round_3.round_id)
+            .await,
+        ApiError::ExceedsEligibility {
+            available: 20.0,
+            required: 25.0,
+        },
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_round_thresholds_follow_progression() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let space_b_id = app
+        .client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+
+    let progression = vec![(0, 0.5), (1, 0.75), (3, 1.0)];
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details
+        .auction_params
+        .activity_rule_params
+        .eligibility_progression = progression;
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    app.login_bob().await?;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space_b_id, &rounds[0].round_id)
+        .await?;
+
+    // Alternate bids so the auction keeps going for a few rounds
+    for round_num in 0..4 {
+        let username = if round_num % 2 == 0 { "alice" } else { "bob" };
+        app.client
+            .login(&test_helpers::login_credentials(username))
+            .await?;
+        let round =
+            &app.client.list_auction_rounds(&auction_id).await?[round_num];
+        app.client
+            .create_bid(&space.space_id, &round.round_id)
+            .await?;
+        app.time_source
+            .set(round.round_details.end_at + Span::new().seconds(1));
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+
+    let thresholds = app.client.list_round_thresholds(&auction_id).await?;
+    let thresholds: Vec<(i32, f64)> = thresholds
+        .into_iter()
+        .map(|t| (t.round_num, t.eligibility_threshold))
+        .collect();
+    assert_eq!(
+        thresholds,
+        vec![(0, 0.5), (1, 0.75), (2, 0.75), (3, 1.0), (4, 1.0)]
+    );
+
+    // Members only
+    app.create_charlie_user().await?;
+    app.login_charlie().await?;
+    assert_api_error(
+        app.client.list_round_thresholds(&auction_id).await,
+        ApiError::MemberNotFound,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simulate_auction() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+
+    let mut auction_params =
+        test_helpers::auction_details_a(site.site_id, &app.time_source)
+            .auction_params;
+    auction_params.activity_rule_params.eligibility_progression =
+        vec![(0, 0.5), (2, 0.75), (5, 1.0)];
+    let details = requests::SimulateAuction {
+        site_id: site.site_id,
+        auction_params,
+    };
+    let result = app.client.simulate_auction(&details).await?;
+
+    assert_eq!(result.total_eligibility_points, 20.0);
+    assert_eq!(result.rounds_until_full_eligibility, Some(5));
+    let thresholds: Vec<(i32, f64)> = result
+        .rounds
+        .iter()
+        .map(|r| (r.round_num, r.eligibility_threshold))
+        .collect();
+    assert_eq!(
+        thresholds,
+        vec![
+            (0, 0.5),
+            (1, 0.5),
+            (2, 0.75),
+            (3, 0.75),
+            (4, 0.75),
+            (5, 1.0)
+        ]
+    );
+    assert_eq!(result.rounds[2].activity_for_all_spaces, 15.0);
+
+    // Nothing was persisted
+    assert!(app.client.list_auctions(&site.site_id).await?.is_empty());
+
+    // Coleader or above only
+    app.login_bob().await?;
+    assert_api_error(
+        app.client.simulate_auction(&details).await,
+        ApiError::InsufficientPermissions {
+            required: PermissionLevel::Coleader,
+        },
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
-async fn test_eligibility_progression_activates_midway() -> anyhow::Result<()> {
+async fn test_list_auction_rounds_filtered() -> anyhow::Result<()> {
     let app = spawn_app().await;
     let community_id = app.create_two_person_community().await?;
     let site = app.create_test_site(&community_id).await?;
-
-    let space_a = app.create_test_space(&site.site_id).await?; // 10 points
-    let space_b = app
+    let space = app.create_test_space(&site.site_id).await?;
+    let space_b_id = app
         .client
-        .create_space(&payloads::Space {
-            site_id: site.site_id,
-            name: "test space b".into(),
-            description: None,
-            eligibility_points: 15.0,
-            is_available: true,
-            site_image_id: None,
-            reserve_price: payloads::ReservePrice(rust_decimal::Decimal::ZERO),
-        })
+        .create_space(&test_helpers::space_details_b(site.site_id))
         .await?;
-    let space_b = app.client.get_space(&space_b).await?;
 
-    let start_time = app.time_source.now();
-    let mut auction_details =
+    let auction_details =
         test_helpers::auction_details_a(site.site_id, &app.time_source);
-    auction_details.start_at = Some(start_time);
-    // Rounds 0 and 1 are unconstrained (threshold 0.0); round 2 onward uses a
-    // 50% threshold. Since a round's threshold governs the *following*
-    // round's bids, the constraint first applies to round-3 bids.
-    auction_details
-        .auction_params
-        .activity_rule_params
-        .eligibility_progression = vec![(0, 0.0), (2, 0.5)];
     let auction_id = app.client.create_auction(&auction_details).await?;
 
-    let round_duration = auction_details.auction_params.round_duration;
     scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
-
-    // Alice and Bob alternate outbidding each other on the 10-point space_a so
-    // that every round has a new bid, keeping the auction from concluding for
-    // lack of demand. Whoever isn't the standing winner bids each round.
-
-    // Round 0: Alice bids and becomes the standing winner.
+    app.login_bob().await?;
     let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    app.login_alice().await?;
     app.client
-        .create_bid(&space_a.space_id, &rounds[0].round_id)
+        .create_bid(&space_b_id, &rounds[0].round_id)
         .await?;
 
-    app.time_source.advance(round_duration);
-    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    // Alternate bids so the auction runs to round 5
+    for round_num in 0..5 {
+        let username = if round_num % 2 == 0 { "alice" } else { "bob" };
+        app.client
+            .login(&test_helpers::login_credentials(username))
+            .await?;
+        let round =
+            &app.client.list_auction_rounds(&auction_id).await?[round_num];
+        app.client
+            .create_bid(&space.space_id, &round.round_id)
+            .await?;
+        app.time_source
+            .set(round.round_details.end_at + Span::new().seconds(1));
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+    assert_eq!(app.client.list_auction_rounds(&auction_id).await?.len(), 6);
 
-    // Round 1 (prior threshold 0.0 -> unconstrained): Unlimited eligibility,
-    // and Bob can outbid freely.
-    let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    assert_eq!(
-        app.client.get_eligibility(&rounds[1].round_id).await?,
-        payloads::Eligibility::Unlimited,
-        "round 1 should be Unlimited (prior threshold 0.0)"
-    );
-    app.login_bob().await?;
-    app.client
-        .create_bid(&space_a.space_id, &rounds[1].round_id)
+    let round_nums = |rounds: Vec<payloads::responses::AuctionRound>| {
+        rounds
+            .into_iter()
+            .map(|r| r.round_details.round_num)
+            .collect::<Vec<_>>()
+    };
+    let rounds = app
+        .client
+        .list_auction_rounds_filtered(&requests::ListAuctionRounds {
+            auction_id,
+            from_round: Some(2),
+            to_round: Some(4),
+            limit: None,
+        })
         .await?;
+    assert_eq!(round_nums(rounds), vec![2, 3, 4]);
 
-    app.time_source.advance(round_duration);
+    let rounds = app
+        .client
+        .list_auction_rounds_filtered(&requests::ListAuctionRounds {
+            auction_id,
+            from_round: Some(2),
+            to_round: None,
+            limit: Some(2),
+        })
+        .await?;
+    assert_eq!(round_nums(rounds), vec![2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_round_eligibility_after_skipped_round() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+
+    // The minimum round count keeps quiet rounds from ending the auction
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.auction_params.min_rounds = Some(3);
+    let auction_id = app.client.create_auction(&auction_details).await?;
     scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
 
-    // Round 2 (prior threshold 0.0 -> still unconstrained): still Unlimited.
-    // Alice retakes the lead.
+    // Alice bids in round 0 only, then sits out round 1
     let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    assert_eq!(
-        app.client.get_eligibility(&rounds[2].round_id).await?,
-        payloads::Eligibility::Unlimited,
-        "round 2 should still be Unlimited (prior threshold 0.0)"
-    );
-    app.login_alice().await?;
     app.client
-        .create_bid(&space_a.space_id, &rounds[2].round_id)
+        .create_bid(&space.space_id, &rounds[0].round_id)
         .await?;
+    for round_num in 0..2 {
+        let rounds = app.client.list_auction_rounds(&auction_id).await?;
+        app.time_source.set(
+            rounds[round_num].round_details.end_at + Span::new().seconds(1),
+        );
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
 
-    app.time_source.advance(round_duration);
-    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
-
-    // Round 3: round 2's threshold was 50%, so the constraint now applies.
-    // Both Alice and Bob have 10 points of activity going into round 2 (Alice
-    // bid space_a in round 2; Bob holds the standing win from round 1), so
-    // each gets an eligibility of 10 / 0.5 = 20 for round 3. This also
-    // confirms the value is finite (not the inf/NaN that x/0.0 would produce).
     let rounds = app.client.list_auction_rounds(&auction_id).await?;
-    let round_3 = &rounds[3];
-
-    app.login_alice().await?;
+    let eligibilities = app.client.list_round_eligibility(&auction_id).await?;
+    assert_eq!(eligibilities.len(), 3);
+    for (round, entry) in rounds.iter().zip(&eligibilities) {
+        assert_eq!(entry.round_id, round.round_id);
+        assert_eq!(entry.round_num, round.round_details.round_num);
+        if entry.round_num > 0 {
+            assert_eq!(
+                entry.eligibility,
+                app.client.get_eligibility(&round.round_id).await?,
+                "round {}",
+                entry.round_num
+            );
+        }
+    }
     assert_eq!(
-        app.client.get_eligibility(&round_3.round_id).await?,
-        payloads::Eligibility::Finite(20.0),
-        "Alice's round-3 eligibility should be 10 / 0.5 = 20"
+        eligibilities[0].eligibility,
+        payloads::Eligibility::Unlimited
     );
-    app.login_bob().await?;
+
+    // The index-aligned listing still matches
     assert_eq!(
-        app.client.get_eligibility(&round_3.round_id).await?,
-        payloads::Eligibility::Finite(20.0),
-        "Bob's round-3 eligibility should be 10 / 0.5 = 20"
+        app.client.list_eligibility(&auction_id).await?,
+        eligibilities
+            .iter()
+            .map(|entry| entry.eligibility)
+            .collect::<Vec<_>>()
     );
 
-    // Alice is the standing high bidder of space_a from round 2, so that
-    // 10-point standing win already counts toward her activity. Adding the
-    // 15-point space_b would total 25 > 20, so she cannot bid on space_b.
-    app.login_alice().await?;
-    assert_api_error(
-        app.client
-            .create_bid(&space_b.space_id, &round_3.round_id)
-            .await,
-        ApiError::ExceedsEligibility {
-            available: 20.0,
-            required: 25.0,
-        },
-    );
+    Ok(())
+}
 
-    // Bob holds no standing win in round 3, so only his round-3 bids count. He
-    // can take either space alone (10 <= 20, 15 <= 20), but not both: after
-    // bidding space_a, adding space_b totals 10 + 15 = 25 > 20.
-    app.login_bob().await?;
-    app.client
-        .create_bid(&space_a.space_id, &round_3.round_id)
-        .await?;
+#[tokio::test]
+async fn outsiders_cannot_tell_rounds_exist() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round = app.client.list_auction_rounds(&auction_id).await?.remove(0);
+    assert_eq!(app.client.get_auction_round(&round.round_id).await?, round);
+
+    // An unknown round is a 404, not a database error
+    let missing = payloads::AuctionRoundId(uuid::Uuid::new_v4());
     assert_api_error(
-        app.client
-            .create_bid(&space_b.space_id, &round_3.round_id)
-            .await,
-        ApiError::ExceedsEligibility {
-            available: 20.0,
-            required: 25.0,
-        },
+        app.client.get_auction_round(&missing).await,
+        ApiError::AuctionRoundNotFound,
     );
 
+    // Charlie shares no community with Alice, so her round looks the same
+    // to him as one that doesn't exist
+    app.create_charlie_user().await?;
+    app.login_charlie().await?;
+    for round_id in [round.round_id, missing] {
+        assert_api_error(
+            app.client.get_auction_round(&round_id).await,
+            ApiError::AuctionRoundNotFound,
+        );
+    }
+
     Ok(())
 }
 
@@ -1300,3 +2992,241 @@ async fn test_bid_unavailable_space() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_duplicate_bid_is_a_conflict() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction.auction_id).await?;
+    let round_id = rounds[0].round_id;
+
+    app.client.create_bid(&space.space_id, &round_id).await?;
+
+    // A double submit is a typed 409, not a generic constraint failure.
+    let result = app.client.create_bid(&space.space_id, &round_id).await;
+    test_helpers::assert_status_code(result, reqwest::StatusCode::CONFLICT);
+    let result = app.client.create_bid(&space.space_id, &round_id).await;
+    assert_api_error(result, ApiError::DuplicateBid);
+
+    // The original bid is untouched.
+    assert_eq!(app.client.list_bids(&round_id).await?.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_with_community_role() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+    let options = requests::ReadOptions {
+        include: Some(requests::Include::CommunityRole),
+    };
+
+    // Without the expansion, only the community id is returned.
+    let plain = app.client.get_auction(&auction.auction_id).await?;
+    assert_eq!(plain.community_id, community_id);
+    assert_eq!(plain.community_role, None);
+
+    for (username, role) in [
+        ("alice", payloads::Role::Leader),
+        ("bob", payloads::Role::Member),
+    ] {
+        app.client
+            .login(&test_helpers::login_credentials(username))
+            .await?;
+        let expected = Some(payloads::responses::CommunityRole {
+            community_id,
+            role,
+            is_active: true,
+        });
+
+        let auction = app
+            .client
+            .get_auction_with(&auction.auction_id, &options)
+            .await?;
+        assert_eq!(auction.community_id, community_id);
+        assert_eq!(auction.community_role, expected, "{username}");
+
+        let site = app.client.get_site_with(&site.site_id, &options).await?;
+        assert_eq!(site.community_role, expected, "{username}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moderator_notes_hidden_from_members() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+
+    let notes = requests::UpdateAuctionNotes {
+        auction_id: auction.auction_id,
+        moderator_notes: Some("Waiting on treasurer sign-off".into()),
+        internal_status: Some("blocked".into()),
+    };
+    app.client.update_auction_notes(&notes).await?;
+    let read = app.client.get_auction(&auction.auction_id).await?;
+    assert_eq!(read.moderator_notes, notes.moderator_notes);
+    assert_eq!(read.internal_status, notes.internal_status);
+    let listed = app.client.list_auctions(&site.site_id).await?;
+    assert_eq!(listed[0].moderator_notes, notes.moderator_notes);
+
+    // Bob is a plain member: the notes are stored but never sent to him, and
+    // he can't change them
+    app.login_bob().await?;
+    let read = app.client.get_auction(&auction.auction_id).await?;
+    assert_eq!(read.moderator_notes, None);
+    assert_eq!(read.internal_status, None);
+    let listed = app.client.list_auctions(&site.site_id).await?;
+    assert_eq!(listed[0].moderator_notes, None);
+    assert_eq!(listed[0].internal_status, None);
+    let stored: Option<String> = sqlx::query_scalar(
+        "SELECT moderator_notes FROM auctions WHERE id = $1",
+    )
+    .bind(auction.auction_id)
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert_eq!(stored, notes.moderator_notes);
+    assert_api_error(
+        app.client.update_auction_notes(&notes).await,
+        ApiError::InsufficientPermissions {
+            required: PermissionLevel::Moderator,
+        },
+    );
+
+    // Length caps, and clearing
+    app.login_alice().await?;
+    let too_long = "x".repeat(payloads::MAX_AUCTION_MODERATOR_NOTES_LENGTH + 1);
+    assert_api_error(
+        app.client
+            .update_auction_notes(&requests::UpdateAuctionNotes {
+                auction_id: auction.auction_id,
+                moderator_notes: Some(too_long.clone()),
+                internal_status: None,
+            })
+            .await,
+        ApiError::ModeratorNotesTooLong {
+            size: too_long.len(),
+            max: payloads::MAX_AUCTION_MODERATOR_NOTES_LENGTH,
+        },
+    );
+    let too_long = "x".repeat(payloads::MAX_AUCTION_INTERNAL_STATUS_LENGTH + 1);
+    assert_api_error(
+        app.client
+            .update_auction_notes(&requests::UpdateAuctionNotes {
+                auction_id: auction.auction_id,
+                moderator_notes: None,
+                internal_status: Some(too_long.clone()),
+            })
+            .await,
+        ApiError::InternalStatusTooLong {
+            size: too_long.len(),
+            max: payloads::MAX_AUCTION_INTERNAL_STATUS_LENGTH,
+        },
+    );
+    app.client
+        .update_auction_notes(&requests::UpdateAuctionNotes {
+            auction_id: auction.auction_id,
+            moderator_notes: None,
+            internal_status: None,
+        })
+        .await?;
+    let read = app.client.get_auction(&auction.auction_id).await?;
+    assert_eq!(read.moderator_notes, None);
+    assert_eq!(read.internal_status, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn auction_report_recorded_at_conclusion() -> anyhow::Result<()> {
+    use payloads::responses::AuctionConclusion;
+    use rust_decimal::Decimal;
+
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut space_a = test_helpers::space_details_a(site.site_id);
+    space_a.reserve_price = payloads::ReservePrice(Decimal::from(2));
+    let space_a = app.client.create_space(&space_a).await?;
+    let mut space_b = test_helpers::space_details_b(site.site_id);
+    space_b.reserve_price = payloads::ReservePrice(Decimal::from(5));
+    let space_b = app.client.create_space(&space_b).await?;
+    let mut space_c = test_helpers::space_details_b(site.site_id);
+    space_c.name = "Unbid space".into();
+    app.client.create_space(&space_c).await?;
+
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    assert_eq!(app.client.get_auction_report(&auction_id).await?, None);
+
+    // Alice takes A at 2 and Bob takes B at 5 in round 0; round 1 has no
+    // bids and concludes the auction, leaving C unawarded
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client.create_bid(&space_a, &rounds[0].round_id).await?;
+    app.login_bob().await?;
+    app.client.create_bid(&space_b, &rounds[0].round_id).await?;
+    for round_num in 0..2 {
+        let rounds = app.client.list_auction_rounds(&auction_id).await?;
+        app.time_source.set(
+            rounds[round_num].round_details.end_at + Span::new().seconds(1),
+        );
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    }
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_some());
+
+    // Visible to members
+    let report = app.client.get_auction_report(&auction_id).await?.unwrap();
+    assert_eq!(report.auction_id, auction_id);
+    assert_eq!(report.conclusion, AuctionConclusion::Ended);
+    assert_eq!(report.total_revenue, Decimal::from(7));
+    assert_eq!(report.awarded_spaces, 2);
+    assert_eq!(report.unawarded_spaces, 1);
+    assert_eq!(report.distinct_winners, 2);
+    assert_eq!(report.round_count, 2);
+
+    // The report doesn't depend on the rows it was computed from
+    sqlx::query(
+        "DELETE FROM round_space_results WHERE round_id IN (
+            SELECT id FROM auction_rounds WHERE auction_id = $1
+        )",
+    )
+    .bind(auction_id)
+    .execute(&app.db_pool)
+    .await?;
+    sqlx::query("DELETE FROM bids WHERE space_id = $1")
+        .bind(space_b)
+        .execute(&app.db_pool)
+        .await?;
+    assert_eq!(
+        app.client.get_auction_report(&auction_id).await?,
+        Some(report)
+    );
+
+    // A canceled auction awards nothing
+    app.login_alice().await?;
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    app.client.cancel_auction(&auction_id).await?;
+    let report = app.client.get_auction_report(&auction_id).await?.unwrap();
+    assert_eq!(report.conclusion, AuctionConclusion::Canceled);
+    assert_eq!(report.total_revenue, Decimal::ZERO);
+    assert_eq!(report.awarded_spaces, 0);
+    assert_eq!(report.unawarded_spaces, 3);
+    assert_eq!(report.distinct_winners, 0);
+    assert_eq!(report.round_count, 0);
+
+    Ok(())
+}
@@ -58,6 +58,7 @@ async fn test_simulation_matches_full_system() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
     app.login_bob().await?;
@@ -65,6 +66,7 @@ async fn test_simulation_matches_full_system() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
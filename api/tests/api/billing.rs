@@ -246,7 +246,7 @@ async fn test_storage_enforcement_blocks_site_creation() -> anyhow::Result<()> {
 
     // Try to create a site - should be blocked by storage limit
     let site_details = test_helpers::site_details_b(community_id);
-    let result = app.client.create_site(&site_details).await;
+    let result = app.client.create_site(&site_details.into()).await;
 
     // Verify it's blocked specifically for storage, not some other error
     assert!(
@@ -1,5 +1,6 @@
 use payloads::{AccountOwner, ApiError, requests};
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 use test_helpers::{assert_api_error, spawn_app};
 
@@ -35,6 +36,48 @@ async fn long_community_name_rejected() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn non_json_body_rejected() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let url = format!("{}/api/create_community", app.client.address);
+
+    // Valid JSON, but not labeled as such
+    let response = app
+        .client
+        .inner_client
+        .post(&url)
+        .header(reqwest::header::CONTENT_TYPE, "text/plain")
+        .body(r#"{"name": "Test community"}"#)
+        .send()
+        .await?;
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+    assert_eq!(
+        response.json::<ApiError>().await?,
+        ApiError::UnsupportedContentType
+    );
+
+    // Labeled as JSON, but not a CreateCommunity
+    let response = app
+        .client
+        .inner_client
+        .post(&url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body("not json")
+        .send()
+        .await?;
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    assert!(matches!(
+        response.json::<ApiError>().await?,
+        ApiError::InvalidRequestBody(_)
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn community_invite_flow() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -48,6 +91,623 @@ async fn community_invite_flow() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn count_received_invites() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let first = app.create_test_community().await?;
+    let second = app.create_test_community().await?;
+    for community_id in [first, second] {
+        app.client
+            .invite_member(&requests::InviteCommunityMember {
+                community_id,
+                new_member_email: Some(test_helpers::bob_credentials().email),
+                single_use: false,
+                role: None,
+            })
+            .await?;
+    }
+    app.create_bob_user().await?;
+    app.login_bob().await?;
+    assert_eq!(app.client.count_received_invites().await?, 2);
+
+    for invite in app.client.get_received_invites().await? {
+        app.client.accept_invite(&invite.id).await?;
+    }
+    assert_eq!(app.client.count_received_invites().await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn communities_per_leader_are_capped() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_with_max_communities_per_leader(2).await;
+    app.create_two_person_community().await?;
+    app.create_test_community().await?;
+
+    assert_api_error(
+        app.client
+            .create_community(&requests::CreateCommunity {
+                name: "One too many".into(),
+                description: None,
+                currency: payloads::CurrencySettings {
+                    mode_config: test_helpers::default_currency_config(),
+                    name: "dollars".into(),
+                    symbol: "$".into(),
+                    minor_units: 2,
+                    balances_visible_to_members: true,
+                    new_members_default_active: true,
+                },
+            })
+            .await,
+        ApiError::CommunityLimitReached { max: 2 },
+    );
+    assert_eq!(app.client.get_communities().await?.len(), 2);
+
+    // Only communities led count: Bob is a member of Alice's, and still
+    // has his full allowance
+    app.login_bob().await?;
+    app.create_test_community().await?;
+    app.create_test_community().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pending_invites_are_capped() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_with_max_pending_invites(2).await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+
+    let first = app.create_link_invite().await?;
+    app.create_link_invite().await?;
+    assert_api_error(
+        app.client
+            .invite_member(&requests::InviteCommunityMember {
+                community_id,
+                new_member_email: None,
+                single_use: true,
+                role: None,
+            })
+            .await,
+        ApiError::TooManyPendingInvites { count: 2, max: 2 },
+    );
+    let issued = app.client.get_issued_invites(&community_id).await?;
+    assert_eq!(issued.pending_invite_count, 2);
+    assert_eq!(issued.pending_invite_count, issued.invites.len() as i64);
+    assert_eq!(issued.max_pending_invites, 2);
+
+    // Deleting an invite frees its place right away
+    app.client
+        .delete_invite(&requests::DeleteInvite {
+            community_id,
+            invite_id: first,
+        })
+        .await?;
+    let issued = app.client.get_issued_invites(&community_id).await?;
+    assert_eq!(issued.pending_invite_count, 1);
+    let invite_id = app.create_link_invite().await?;
+
+    // So does accepting a single-use one
+    app.create_bob_user().await?;
+    app.login_bob().await?;
+    app.client.accept_invite(&invite_id).await?;
+    app.login_alice().await?;
+    let issued = app.client.get_issued_invites(&community_id).await?;
+    assert_eq!(issued.pending_invite_count, 1);
+    app.create_link_invite().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unverified_email_grace_period() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_with_email_verification_grace(
+        jiff::SignedDuration::from_hours(1),
+    )
+    .await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    app.client
+        .invite_member(&requests::InviteCommunityMember {
+            community_id,
+            new_member_email: Some(test_helpers::bob_credentials().email),
+            single_use: false,
+            role: None,
+        })
+        .await?;
+
+    let bob = test_helpers::bob_credentials();
+    app.create_unverified_user(&bob).await?;
+    app.client
+        .login(&test_helpers::to_login_credentials(&bob))
+        .await?;
+
+    // Within the grace period bob can see the invite but not act on it
+    let invites = app.client.get_received_invites().await?;
+    assert_eq!(invites.len(), 1);
+    assert_eq!(app.client.bootstrap().await?.pending_invites, 1);
+    assert_api_error(
+        app.client.accept_invite(&invites[0].id).await,
+        ApiError::UnverifiedEmail,
+    );
+
+    app.time_source.advance(jiff::Span::new().hours(1));
+    assert_api_error(
+        app.client.get_received_invites().await,
+        ApiError::UnverifiedEmail,
+    );
+    assert_eq!(app.client.bootstrap().await?.pending_invites, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invite_grants_role_below_inviter() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let invite = |email: String, role| requests::InviteCommunityMember {
+        community_id,
+        new_member_email: Some(email),
+        single_use: false,
+        role: Some(role),
+    };
+
+    app.client
+        .invite_member(&invite(
+            test_helpers::bob_credentials().email,
+            payloads::Role::Moderator,
+        ))
+        .await?;
+    let issued = app.client.get_issued_invites(&community_id).await?;
+    assert_eq!(issued.invites[0].role, payloads::Role::Moderator);
+
+    // Bob sees the role before accepting, and joins with it
+    app.create_bob_user().await?;
+    app.login_bob().await?;
+    let received = app.client.get_received_invites().await?;
+    assert_eq!(received[0].role, payloads::Role::Moderator);
+    app.accept_invite().await?;
+    let communities = app.client.get_communities().await?;
+    assert_eq!(communities[0].user_role, payloads::Role::Moderator);
+
+    // A moderator can only invite members
+    let charlie_email = test_helpers::charlie_credentials().email;
+    for role in [payloads::Role::Coleader, payloads::Role::Moderator] {
+        let result = app
+            .client
+            .invite_member(&invite(charlie_email.clone(), role))
+            .await;
+        assert_api_error(result, ApiError::CannotInviteRole);
+    }
+    app.client
+        .invite_member(&invite(charlie_email, payloads::Role::Member))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn accept_invite_respects_registration_open() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    app.invite_bob().await?;
+    app.create_bob_user().await?;
+    let community = &app.client.get_communities().await?[0];
+    assert!(community.registration_open);
+
+    let close = requests::SetRegistrationOpen {
+        community_id,
+        registration_open: false,
+    };
+    app.client.set_registration_open(&close).await?;
+
+    // The invite is still listed, but can't be accepted.
+    app.login_bob().await?;
+    let invites = app.client.get_received_invites().await?;
+    assert_eq!(invites.len(), 1);
+    let result = app.client.accept_invite(&invites[0].id).await;
+    assert_api_error(result, ApiError::RegistrationClosed);
+    assert!(app.client.get_communities().await?.is_empty());
+
+    // Reopening lets the same invite through.
+    app.login_alice().await?;
+    app.client
+        .set_registration_open(&requests::SetRegistrationOpen {
+            community_id,
+            registration_open: true,
+        })
+        .await?;
+    app.login_bob().await?;
+    app.accept_invite().await?;
+
+    // Now a member, Bob still can't change the setting.
+    let result = app.client.set_registration_open(&close).await;
+    assert_api_error(result, ApiError::RequiresLeaderPermissions);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invite_email_uses_community_base_url() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let invite = |email: &str| requests::InviteCommunityMember {
+        community_id,
+        new_member_email: Some(email.into()),
+        single_use: false,
+        role: None,
+    };
+    let email_to = |to: &str| {
+        let sent = app.email_service.sent_emails();
+        let (_, email) = sent.into_iter().rfind(|(r, _)| r == to).unwrap();
+        email.text_body
+    };
+
+    // Without an override, links use the instance's base URL
+    let invite_id = app.client.invite_member(&invite("b@example.com")).await?;
+    assert!(
+        email_to("b@example.com").contains(&format!(
+            "http://localhost:8080/accept-invite/{invite_id}"
+        ))
+    );
+
+    // Only well-formed http(s) URLs are accepted
+    for invalid in ["not a url", "ftp://lvt.example.org", "https://a.org/?x=1"]
+    {
+        let result = app
+            .client
+            .set_invite_base_url(&requests::SetInviteBaseUrl {
+                community_id,
+                invite_base_url: Some(invalid.into()),
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(payloads::ClientError::Api(
+                _,
+                ApiError::InvalidInviteBaseUrl(_)
+            ))
+        ));
+    }
+
+    app.client
+        .set_invite_base_url(&requests::SetInviteBaseUrl {
+            community_id,
+            invite_base_url: Some("https://lvt.example.org/".into()),
+        })
+        .await?;
+    let community = &app.client.get_communities().await?[0];
+    assert_eq!(
+        community.invite_base_url.as_deref(),
+        Some("https://lvt.example.org")
+    );
+    let invite_id = app.client.invite_member(&invite("c@example.com")).await?;
+    assert!(email_to("c@example.com").contains(&format!(
+        "https://lvt.example.org/accept-invite/{invite_id}"
+    )));
+
+    // Clearing it falls back again
+    app.client
+        .set_invite_base_url(&requests::SetInviteBaseUrl {
+            community_id,
+            invite_base_url: None,
+        })
+        .await?;
+    let invite_id = app.client.invite_member(&invite("d@example.com")).await?;
+    assert!(
+        email_to("d@example.com").contains(&format!(
+            "http://localhost:8080/accept-invite/{invite_id}"
+        ))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn single_use_invite_accepted_once_under_race() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let invite_id = app.create_link_invite().await?;
+    app.create_bob_user().await?;
+    app.create_charlie_user().await?;
+
+    let user_id_for = |username: &'static str| {
+        sqlx::query_scalar::<_, payloads::UserId>(
+            "SELECT id FROM users WHERE username = $1",
+        )
+        .bind(username)
+        .fetch_one(&app.db_pool)
+    };
+    let bob_id = user_id_for("bob").await?;
+    let charlie_id = user_id_for("charlie").await?;
+
+    // Race both acceptances directly against the store so neither goes
+    // through a serializing HTTP session.
+    let (bob_result, charlie_result) = tokio::join!(
+        api::store::accept_invite(
+            &bob_id,
+            &invite_id,
+            &app.db_pool,
+            &app.time_source
+        ),
+        api::store::accept_invite(
+            &charlie_id,
+            &invite_id,
+            &app.db_pool,
+            &app.time_source
+        ),
+    );
+
+    let (winners, losers): (Vec<_>, Vec<_>) = [bob_result, charlie_result]
+        .into_iter()
+        .partition(Result::is_ok);
+    assert_eq!(winners.len(), 1);
+    assert!(matches!(
+        losers[0],
+        Err(api::store::StoreError::Api(
+            ApiError::CommunityInviteNotFound
+        ))
+    ));
+
+    // Alice plus exactly one of bob or charlie
+    let member_count: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM community_members WHERE community_id = $1",
+    )
+    .bind(community_id)
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert_eq!(member_count, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_accepts_join_once() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let link_invite = || requests::InviteCommunityMember {
+        community_id,
+        new_member_email: None,
+        single_use: false,
+        role: None,
+    };
+    let first = app.client.invite_member(&link_invite()).await?;
+    let second = app.client.invite_member(&link_invite()).await?;
+    app.create_bob_user().await?;
+    let bob_id = sqlx::query_scalar::<_, payloads::UserId>(
+        "SELECT id FROM users WHERE username = 'bob'",
+    )
+    .fetch_one(&app.db_pool)
+    .await?;
+
+    // Two multi-use invites don't share an invite row lock, so only the
+    // (community, user) lock keeps these from both joining.
+    let (first_result, second_result) = tokio::join!(
+        api::store::accept_invite(
+            &bob_id,
+            &first,
+            &app.db_pool,
+            &app.time_source
+        ),
+        api::store::accept_invite(
+            &bob_id,
+            &second,
+            &app.db_pool,
+            &app.time_source
+        ),
+    );
+
+    let (winners, losers): (Vec<_>, Vec<_>) = [first_result, second_result]
+        .into_iter()
+        .partition(Result::is_ok);
+    assert_eq!(winners.len(), 1);
+    assert!(matches!(
+        losers[0],
+        Err(api::store::StoreError::Api(ApiError::AlreadyMember))
+    ));
+
+    let membership_count: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM community_members
+        WHERE community_id = $1 AND user_id = $2",
+    )
+    .bind(community_id)
+    .bind(bob_id)
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert_eq!(membership_count, 1);
+    let account_count: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM accounts
+        WHERE community_id = $1 AND owner_id = $2",
+    )
+    .bind(community_id)
+    .bind(bob_id)
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert_eq!(account_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn community_preview_works_without_auth() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    app.client
+        .update_community_details(&requests::UpdateCommunityDetails {
+            community_id,
+            name: "Test community".into(),
+            description: Some("Members only notes".into()),
+            description_public: Some(false),
+        })
+        .await?;
+    let invite_id = app.create_link_invite().await?;
+    app.client.logout().await?;
+
+    let preview = app.client.get_community_preview(&invite_id).await?;
+    assert_eq!(preview.name, "Test community");
+    assert_eq!(preview.member_count, 1);
+    // Description is private until the community opts in
+    assert_eq!(preview.description, None);
+
+    // Only the preview fields go over the wire: no id, currency, etc.
+    let url =
+        format!("{}/api/community_preview/{invite_id}", app.client.address);
+    let body: serde_json::Value = app
+        .client
+        .inner_client
+        .get(&url)
+        .send()
+        .await?
+        .json()
+        .await?;
+    let mut keys: Vec<_> = body.as_object().unwrap().keys().collect();
+    keys.sort();
+    assert_eq!(keys, ["description", "member_count", "name"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn community_preview_includes_opted_in_description() -> anyhow::Result<()>
+{
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    app.login_alice().await?;
+    app.client
+        .update_community_details(&requests::UpdateCommunityDetails {
+            community_id,
+            name: "Test community".into(),
+            description: Some("A shared garden".into()),
+            description_public: Some(true),
+        })
+        .await?;
+    let invite_id = app.create_link_invite().await?;
+    app.client.logout().await?;
+
+    let preview = app.client.get_community_preview(&invite_id).await?;
+    assert_eq!(preview.member_count, 2);
+    assert_eq!(preview.description.as_deref(), Some("A shared garden"));
+
+    let result = app
+        .client
+        .get_community_preview(&payloads::InviteId(uuid::Uuid::new_v4()))
+        .await;
+    assert_api_error(result, ApiError::CommunityInviteNotFound);
+
+    Ok(())
+}
+
+/// Clients from before `description_public` existed leave it out, which
+/// keeps the community's current setting.
+#[tokio::test]
+async fn update_without_description_public_keeps_it() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    app.client
+        .update_community_details(&requests::UpdateCommunityDetails {
+            community_id,
+            name: "Test community".into(),
+            description: Some("A shared garden".into()),
+            description_public: Some(true),
+        })
+        .await?;
+
+    let url = format!("{}/api/update_community_details", app.client.address);
+    let response = app
+        .client
+        .inner_client
+        .post(&url)
+        .json(&serde_json::json!({
+            "community_id": community_id,
+            "name": "Renamed community",
+            "description": "A shared orchard",
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let community: payloads::responses::Community = response.json().await?;
+    assert_eq!(community.name, "Renamed community");
+    assert!(community.description_public);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn member_profile_visible_within_shared_community() -> anyhow::Result<()>
+{
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+
+    app.login_bob().await?;
+    let details = requests::GetMemberProfile {
+        community_id,
+        username: "alice".into(),
+    };
+    let profile = app.client.get_member_profile(&details).await?;
+    assert_eq!(profile.username, "alice");
+    assert_eq!(profile.role, payloads::Role::Leader);
+    assert!(profile.is_active);
+
+    // Private account details never go over the wire
+    let url = format!("{}/api/member_profile", app.client.address);
+    let body: serde_json::Value = app
+        .client
+        .inner_client
+        .post(&url)
+        .json(&details)
+        .send()
+        .await?
+        .json()
+        .await?;
+    let body = body.as_object().unwrap();
+    assert!(!body.contains_key("email"));
+    assert!(!body.contains_key("balance"));
+
+    // Charlie exists but isn't in the community
+    app.create_charlie_user().await?;
+    app.login_bob().await?;
+    let result = app
+        .client
+        .get_member_profile(&requests::GetMemberProfile {
+            community_id,
+            username: "charlie".into(),
+        })
+        .await;
+    assert_api_error(result, ApiError::UserNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn member_profile_hidden_from_other_communities() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+
+    // Charlie leads a separate community
+    app.create_charlie_user().await?;
+    app.login_charlie().await?;
+    app.create_test_community().await?;
+
+    let result = app
+        .client
+        .get_member_profile(&requests::GetMemberProfile {
+            community_id,
+            username: "alice".into(),
+        })
+        .await;
+    assert_api_error(result, ApiError::MemberNotFound);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn membership_schedule_set_read_update() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -79,6 +739,118 @@ async fn membership_schedule_set_read_update() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn clearing_schedule_resets_activity() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    // The schedule leaves Bob inactive
+    app.create_schedule(&community_id).await?;
+    api::store::update_is_active_from_schedule(&app.db_pool, &app.time_source)
+        .await?;
+    let is_active = |members: &[payloads::responses::CommunityMember]| {
+        members
+            .iter()
+            .map(|m| (m.user.username.clone(), m.is_active))
+            .collect::<Vec<_>>()
+    };
+    let members = app.client.get_members(&community_id).await?;
+    assert_eq!(
+        is_active(&members),
+        [("alice".to_string(), true), ("bob".to_string(), false)]
+    );
+
+    app.client
+        .set_membership_schedule(&requests::SetMembershipSchedule {
+            community_id,
+            schedule: vec![],
+        })
+        .await?;
+
+    // Bob is back to the default for new members, which is active, and a
+    // later sync leaves him there
+    for _ in 0..2 {
+        let members = app.client.get_members(&community_id).await?;
+        assert_eq!(
+            is_active(&members),
+            [("alice".to_string(), true), ("bob".to_string(), true)]
+        );
+        api::store::update_is_active_from_schedule(
+            &app.db_pool,
+            &app.time_source,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_members_active_only() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    // The schedule leaves Bob inactive
+    app.create_schedule(&community_id).await?;
+    api::store::update_is_active_from_schedule(&app.db_pool, &app.time_source)
+        .await?;
+
+    let usernames = |members: Vec<payloads::responses::CommunityMember>| {
+        members
+            .into_iter()
+            .map(|m| m.user.username)
+            .collect::<Vec<_>>()
+    };
+    let active = app
+        .client
+        .list_members(&requests::GetMembers {
+            community_id,
+            active_only: true,
+        })
+        .await?;
+    assert_eq!(usernames(active), ["alice"]);
+
+    let all = app
+        .client
+        .list_members(&requests::GetMembers {
+            community_id,
+            active_only: false,
+        })
+        .await?;
+    assert_eq!(usernames(all), ["alice", "bob"]);
+
+    // The bare community id still lists everyone
+    let all = app.client.get_members(&community_id).await?;
+    assert_eq!(usernames(all), ["alice", "bob"]);
+
+    let active_ids =
+        api::store::list_active_member_ids(&community_id, &app.db_pool).await?;
+    assert_eq!(active_ids.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_my_roles_across_communities() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let alice_community = app.create_two_person_community().await?;
+    app.login_bob().await?;
+    let bob_community = app.create_test_community().await?;
+
+    let roles = app.client.list_my_roles().await?;
+    let roles: HashMap<_, _> = roles
+        .into_iter()
+        .map(|role| (role.community_id, role.role))
+        .collect();
+    assert_eq!(
+        roles,
+        HashMap::from([
+            (alice_community, payloads::Role::Member),
+            (bob_community, payloads::Role::Leader),
+        ])
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn community_role_information_returned() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -196,3 +968,103 @@ async fn delete_community_with_financial_history() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn community_overview_matches_individual_endpoints() -> anyhow::Result<()>
+{
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    app.create_test_site(&community_id).await?;
+    app.create_link_invite().await?;
+
+    let overview = app.client.get_community_overview(&community_id).await?;
+    let communities = app.client.get_communities().await?;
+    assert_eq!(overview.community, communities[0]);
+    assert_eq!(
+        overview.member_count,
+        app.client.get_members(&community_id).await?.len() as i64
+    );
+    assert_eq!(overview.sites, app.client.list_sites(&community_id).await?);
+    assert_eq!(overview.sites.len(), 1);
+    assert_eq!(
+        overview.pending_invite_count,
+        Some(
+            app.client
+                .get_issued_invites(&community_id)
+                .await?
+                .pending_invite_count
+        )
+    );
+    // Bob's email invite was used up when he joined, leaving the link
+    assert_eq!(overview.pending_invite_count, Some(1));
+
+    // Members get the same overview without the invite count
+    app.login_bob().await?;
+    let overview = app.client.get_community_overview(&community_id).await?;
+    assert_eq!(overview.community, app.client.get_communities().await?[0]);
+    assert_eq!(overview.community.user_role, payloads::Role::Member);
+    assert_eq!(overview.member_count, 2);
+    assert_eq!(overview.sites, app.client.list_sites(&community_id).await?);
+    assert_eq!(overview.pending_invite_count, None);
+
+    // Non-members get nothing
+    app.create_charlie_user().await?;
+    app.login_charlie().await?;
+    assert_api_error(
+        app.client.get_community_overview(&community_id).await,
+        ApiError::MemberNotFound,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn community_stats_count_members_and_sites() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_three_person_community().await?;
+    let members = app.client.get_members(&community_id).await?;
+    let bob_id = members
+        .iter()
+        .find(|m| m.user.username == "bob")
+        .unwrap()
+        .user
+        .user_id;
+
+    app.client
+        .update_member_active_status(&requests::UpdateMemberActiveStatus {
+            community_id,
+            member_user_id: bob_id,
+            is_active: false,
+        })
+        .await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+    app.client
+        .create_transfer(&requests::CreateTransfer {
+            community_id,
+            to: AccountOwner::Member(bob_id),
+            amount: Decimal::new(5000, 2),
+            note: None,
+            idempotency_key: requests::ClientIdempotencyKey::new(),
+        })
+        .await?;
+
+    let stats = app.client.get_community_stats(&community_id).await?;
+    assert_eq!(stats.total_members, 3);
+    assert_eq!(stats.active_members, 2);
+    assert_eq!(stats.sites, 1);
+    assert_eq!(stats.spaces, 2);
+    assert_eq!(stats.auctions_run, 0);
+    assert_eq!(stats.total_value_transacted, Decimal::new(5000, 2));
+
+    app.login_bob().await?;
+    assert_api_error(
+        app.client.get_community_stats(&community_id).await,
+        ApiError::RequiresColeaderPermissions,
+    );
+
+    Ok(())
+}
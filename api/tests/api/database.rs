@@ -19,7 +19,7 @@ use api::store::{
 };
 use payloads::{ApiError, CommunityId, SiteId};
 
-use test_helpers::spawn_app;
+use test_helpers::{Isolation, spawn_app};
 
 const HOUR_MICROSECONDS: i64 = 60 * 60 * 1_000_000;
 
@@ -39,7 +39,9 @@ async fn test_populate() -> Result<(), StoreError> {
         "leader_user",
         "leader@example.com",
         "hashed_pw",
+        false,
         &app.time_source,
+        &app.id_source,
     )
     .await?;
 
@@ -63,8 +65,10 @@ async fn test_populate() -> Result<(), StoreError> {
             },
         },
         user.id,
+        None,
         conn,
         &app.time_source,
+        &app.id_source,
     )
     .await?;
 
@@ -76,11 +80,15 @@ async fn test_populate() -> Result<(), StoreError> {
 
     assert_eq!(community, community_retrieved);
     println!("1");
-    let _users = populate_users(conn, &community.id, &app.time_source).await?;
+    let _users =
+        populate_users(conn, &community.id, &app.time_source, &app.id_source)
+            .await?;
     println!("2");
     // check that we get a unique constraint error if attempting to populate
     // the same usernames
-    let result = populate_users(conn, &community.id, &app.time_source).await;
+    let result =
+        populate_users(conn, &community.id, &app.time_source, &app.id_source)
+            .await;
     assert!(matches!(
         result,
         Err(StoreError::Api(ApiError::UsernameTaken))
@@ -101,10 +109,49 @@ async fn test_populate() -> Result<(), StoreError> {
     Ok(())
 }
 
+/// Apps under schema isolation share a database but not their tables.
+#[tokio::test]
+async fn schema_isolation() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_with_isolation(Isolation::Schema).await;
+    let other = test_helpers::spawn_app_with_isolation(Isolation::Schema).await;
+
+    app.create_alice_user().await?;
+    assert!(app.client.login_check().await?);
+    app.client.logout().await?;
+    assert!(!app.client.login_check().await?);
+    app.client
+        .login(&test_helpers::login_credentials("alice"))
+        .await?;
+    assert_eq!(app.client.user_profile().await?.username, "alice");
+
+    let current_schema = "SELECT current_schema()::text";
+    let app_schema: String = sqlx::query_scalar(current_schema)
+        .fetch_one(&app.db_pool)
+        .await?;
+    let other_schema: String = sqlx::query_scalar(current_schema)
+        .fetch_one(&other.db_pool)
+        .await?;
+    assert!(app_schema.starts_with("test_"));
+    assert_ne!(app_schema, other_schema);
+
+    let user_count = "SELECT COUNT(*) FROM users";
+    let count: i64 = sqlx::query_scalar(user_count)
+        .fetch_one(&app.db_pool)
+        .await?;
+    assert_eq!(count, 1);
+    let count: i64 = sqlx::query_scalar(user_count)
+        .fetch_one(&other.db_pool)
+        .await?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
 async fn populate_users(
     conn: &PgPool,
     community_id: &CommunityId,
     time_source: &api::time::TimeSource,
+    id_source: &api::id_source::IdSource,
 ) -> Result<Vec<User>, StoreError> {
     use payloads::Role;
     // Skip Leader since the community creator is already the leader
@@ -116,7 +163,9 @@ async fn populate_users(
             &format!("{role}_user"),
             &format!("{role}@example.com"),
             &format!("hashed_pw_{i}"),
+            false,
             time_source,
+            id_source,
         )
         .await?;
         sqlx::query(
@@ -1,7 +1,11 @@
+use api::{scheduler, store::MAX_OUTSTANDING_TOKENS_PER_ACTION};
 use jiff_sqlx::ToSqlx;
-use payloads::{ApiError, requests};
+use payloads::{ApiError, requests, responses};
 use reqwest::StatusCode;
-use test_helpers::{assert_api_error, assert_status_code, spawn_app};
+use test_helpers::{
+    ADMIN_TOKEN, TestApp, assert_api_error, assert_status_code, spawn_app,
+    spawn_app_with_email_preview,
+};
 
 #[tokio::test]
 async fn test_email_verification_flow() {
@@ -232,3 +236,299 @@ async fn test_expired_token_handling() {
     let result = app.client.verify_email(&verify_request).await;
     assert_api_error(result, ApiError::TokenExpired);
 }
+
+#[tokio::test]
+async fn test_email_verification_not_required() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_without_email_verification().await;
+
+    // Accounts are usable as soon as they're created
+    app.client
+        .create_account(&test_helpers::alice_credentials())
+        .await?;
+    app.client
+        .login(&test_helpers::alice_login_credentials())
+        .await?;
+    assert!(app.client.user_profile().await?.email_verified);
+    app.create_test_community().await?;
+    app.invite_bob().await?;
+
+    app.client
+        .create_account(&test_helpers::bob_credentials())
+        .await?;
+    app.client
+        .login(&test_helpers::bob_login_credentials())
+        .await?;
+    app.accept_invite().await?;
+
+    // Verification routes succeed without doing anything
+    let resend = app.client.resend_verification_email().await?;
+    assert_eq!(
+        resend.message,
+        "Email verification is not required on this instance."
+    );
+    let verify = app
+        .client
+        .verify_email(&requests::VerifyEmail {
+            token: uuid::Uuid::new_v4().to_string(),
+        })
+        .await?;
+    assert_eq!(verify.message, resend.message);
+
+    let token_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tokens")
+        .fetch_one(&app.db_pool)
+        .await?;
+    assert_eq!(token_count, 0);
+
+    Ok(())
+}
+
+async fn request_password_reset(app: &TestApp) -> anyhow::Result<()> {
+    app.client
+        .forgot_password(&requests::ForgotPassword {
+            email: test_helpers::alice_credentials().email,
+        })
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_password_reset_invalidates_other_tokens() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let email = test_helpers::alice_credentials().email;
+
+    request_password_reset(&app).await?;
+    let earlier = app.get_password_reset_token_from_db(&email).await?;
+    app.time_source.advance(jiff::Span::new().seconds(1));
+    request_password_reset(&app).await?;
+    let later = app.get_password_reset_token_from_db(&email).await?;
+    assert!(app.is_token_valid(&earlier).await?);
+
+    let reset = |token: &str| requests::ResetPassword {
+        token: token.to_string(),
+        password: "newpassword456".to_string(),
+    };
+    app.client.reset_password(&reset(&later)).await?;
+
+    // The earlier link stopped working with the reset; other kinds of token
+    // are untouched
+    assert!(!app.is_token_valid(&earlier).await?);
+    assert_api_error(
+        app.client.reset_password(&reset(&earlier)).await,
+        ApiError::TokenAlreadyUsed,
+    );
+    let verification = app.get_verification_token_from_db(&email).await?;
+    assert!(app.is_token_valid(&verification).await?);
+
+    // The change notification goes out through the outbox
+    let sent_before = app.email_service.sent_emails().len();
+    scheduler::send_outbox_emails(
+        &app.db_pool,
+        &app.time_source,
+        &app.email_service,
+    )
+    .await?;
+    let sent = app.email_service.sent_emails();
+    assert_eq!(sent.len(), sent_before + 1);
+    let (recipient, template) = sent.last().unwrap();
+    assert_eq!(recipient, &email);
+    assert_eq!(template.subject, "Your password was changed");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_outstanding_token_cap_evicts_oldest() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let email = test_helpers::alice_credentials().email;
+
+    let mut tokens = Vec::new();
+    for _ in 0..MAX_OUTSTANDING_TOKENS_PER_ACTION + 2 {
+        request_password_reset(&app).await?;
+        tokens.push(app.get_password_reset_token_from_db(&email).await?);
+        app.time_source.advance(jiff::Span::new().seconds(1));
+    }
+
+    // The two oldest were evicted; the newest ones still work
+    let (evicted, kept) = tokens.split_at(2);
+    for token in evicted {
+        assert!(!app.is_token_valid(token).await?);
+    }
+    for token in kept {
+        assert!(app.is_token_valid(token).await?);
+    }
+
+    // Other actions aren't affected by the password reset cap
+    app.get_verification_token_from_db(&email).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scheduler_cleans_up_expired_tokens() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    request_password_reset(&app).await?;
+    let count_tokens = || async {
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM tokens")
+            .fetch_one(&app.db_pool)
+            .await
+    };
+    assert_eq!(count_tokens().await?, 2);
+
+    let interval = jiff::SignedDuration::from_hours(1);
+    let mut last_run = None;
+    let cleaned = scheduler::cleanup_expired_tokens_if_due(
+        &app.db_pool,
+        &app.time_source,
+        interval,
+        &mut last_run,
+    )
+    .await?;
+    assert_eq!(cleaned, Some(0));
+
+    // The password reset token expires after an hour; verification lasts a
+    // day
+    app.time_source.advance(jiff::Span::new().hours(2));
+    let cleaned = scheduler::cleanup_expired_tokens_if_due(
+        &app.db_pool,
+        &app.time_source,
+        interval,
+        &mut last_run,
+    )
+    .await?;
+    assert_eq!(cleaned, Some(1));
+    assert_eq!(count_tokens().await?, 1);
+
+    // Not due again until another interval has passed
+    let cleaned = scheduler::cleanup_expired_tokens_if_due(
+        &app.db_pool,
+        &app.time_source,
+        interval,
+        &mut last_run,
+    )
+    .await?;
+    assert_eq!(cleaned, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_stats() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let email = test_helpers::alice_credentials().email;
+
+    // Two password reset tokens expire, one is used, one is outstanding
+    request_password_reset(&app).await?;
+    request_password_reset(&app).await?;
+    app.time_source.advance(jiff::Span::new().hours(2));
+    request_password_reset(&app).await?;
+    let token = app.get_password_reset_token_from_db(&email).await?;
+    app.client
+        .reset_password(&requests::ResetPassword {
+            token,
+            password: "newpassword123".to_string(),
+        })
+        .await?;
+    request_password_reset(&app).await?;
+
+    let url = format!("{}/api/admin/token_stats", app.client.address);
+    let response = app
+        .client
+        .inner_client
+        .get(&url)
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let stats: responses::TokenStats = response.json().await?;
+    assert_eq!(
+        stats,
+        responses::TokenStats {
+            // Alice was verified directly, leaving her token unused
+            email_verification: responses::TokenCounts {
+                used: 0,
+                unused: 1,
+                expired: 0,
+            },
+            password_reset: responses::TokenCounts {
+                used: 1,
+                unused: 1,
+                expired: 2,
+            },
+        }
+    );
+
+    let response = app
+        .client
+        .inner_client
+        .get(&url)
+        .bearer_auth("wrong-token")
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_email_preview_renders_every_kind() -> anyhow::Result<()> {
+    let app = spawn_app_with_email_preview().await;
+    let base = format!("{}/api/dev/email_preview", app.client.address);
+
+    let kinds: Vec<String> = app
+        .client
+        .inner_client
+        .get(&base)
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(kinds.len(), api::email::EmailKind::ALL.len());
+
+    for kind in &kinds {
+        let response = app
+            .client
+            .inner_client
+            .get(format!("{base}/{kind}"))
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK, "{kind}");
+        let html = response.text().await?;
+        assert!(html.contains("<h2>"), "{kind}");
+        // The password changed email has no link
+        if kind != "password_changed" {
+            assert!(html.contains("http://localhost:8080/"), "{kind}");
+        }
+    }
+
+    let response = app
+        .client
+        .inner_client
+        .get(format!("{base}/no_such_kind"))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_email_preview_disabled_by_default() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let base = format!("{}/api/dev/email_preview", app.client.address);
+
+    let response = app.client.inner_client.get(&base).send().await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = app
+        .client
+        .inner_client
+        .get(format!("{base}/verification"))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use payloads::{ApiError, Locale, requests};
+use serde_json::Value;
+use test_helpers::{TestApp, spawn_app};
+
+/// Create a space whose name is one character too long, sending
+/// `Accept-Language` if given, and return the error body.
+async fn create_long_named_space(
+    app: &TestApp,
+    site_id: payloads::SiteId,
+    accept_language: Option<&str>,
+) -> anyhow::Result<Value> {
+    let mut details = test_helpers::space_details_a(site_id);
+    details.name = "x".repeat(requests::SPACE_NAME_MAX_LEN + 1);
+    let mut request = app
+        .client
+        .inner_client
+        .post(format!("{}/api/create_space", app.client.address))
+        .json(&details);
+    if let Some(accept_language) = accept_language {
+        request = request.header("Accept-Language", accept_language);
+    }
+    let response = request.send().await?;
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    Ok(response.json().await?)
+}
+
+#[tokio::test]
+async fn error_messages_follow_accept_language() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let expected = ApiError::SpaceNameTooLong {
+        size: requests::SPACE_NAME_MAX_LEN + 1,
+        max: requests::SPACE_NAME_MAX_LEN,
+    };
+
+    let body =
+        create_long_named_space(&app, site.site_id, Some("es-MX, en;q=0.5"))
+            .await?;
+    assert_eq!(body["code"], "SpaceNameTooLong");
+    assert_eq!(body["details"]["size"], 256);
+    assert_eq!(
+        body["message"],
+        "Nombre del espacio demasiado largo. El máximo es 255 caracteres; \
+         se recibieron 256"
+    );
+    // The extra field doesn't stop clients reading the error
+    assert_eq!(serde_json::from_value::<ApiError>(body)?, expected);
+
+    // Unknown locales, and no header at all, get English
+    for accept_language in [Some("fr, de;q=0.5"), None] {
+        let body = create_long_named_space(&app, site.site_id, accept_language)
+            .await?;
+        assert_eq!(body["message"], expected.to_string());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chosen_locale_overrides_accept_language() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    let profile = app
+        .client
+        .update_locale(&requests::UpdateLocale {
+            locale: Some(Locale::Es),
+        })
+        .await?;
+    assert_eq!(profile.locale, Some(Locale::Es));
+
+    let body = create_long_named_space(&app, site.site_id, Some("en")).await?;
+    assert!(
+        body["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Nombre del espacio demasiado largo")
+    );
+
+    // Clearing the choice follows the browser again
+    app.client
+        .update_locale(&requests::UpdateLocale { locale: None })
+        .await?;
+    let body = create_long_named_space(&app, site.site_id, Some("en")).await?;
+    assert!(
+        body["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Space name too long")
+    );
+
+    Ok(())
+}
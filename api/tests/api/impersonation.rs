@@ -0,0 +1,323 @@
+use jiff::Span;
+use jiff_sqlx::ToSqlx;
+use payloads::{ApiError, UserId, requests};
+use test_helpers::{TestApp, assert_api_error, spawn_app};
+
+async fn user_id(app: &TestApp, username: &str) -> anyhow::Result<UserId> {
+    Ok(
+        sqlx::query_scalar("SELECT id FROM users WHERE username = $1")
+            .bind(username)
+            .fetch_one(&app.db_pool)
+            .await?,
+    )
+}
+
+async fn make_superuser(app: &TestApp, username: &str) -> anyhow::Result<()> {
+    sqlx::query("UPDATE users SET is_superuser = true WHERE username = $1")
+        .bind(username)
+        .execute(&app.db_pool)
+        .await?;
+    Ok(())
+}
+
+/// (action, actor_id, target_id) of every audit log entry, oldest first.
+async fn audit_entries(
+    app: &TestApp,
+) -> anyhow::Result<Vec<(String, Option<UserId>, Option<UserId>)>> {
+    Ok(sqlx::query_as(
+        "SELECT action, actor_id, target_id FROM audit_log
+        ORDER BY created_at",
+    )
+    .fetch_all(&app.db_pool)
+    .await?)
+}
+
+#[tokio::test]
+async fn impersonation_is_audited() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    make_superuser(&app, "alice").await?;
+    let alice_id = user_id(&app, "alice").await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    app.client
+        .impersonate(&requests::Impersonate {
+            target_user_id: bob_id,
+        })
+        .await?;
+    let profile = app.client.user_profile().await?;
+    assert_eq!(profile.username, "bob");
+    let impersonator = profile.impersonated_by.unwrap();
+    assert_eq!(impersonator.user_id, alice_id);
+    assert_eq!(impersonator.username, "alice");
+    assert_eq!(
+        impersonator.expires_at,
+        app.time_source.now() + api::store::IMPERSONATION_DURATION
+    );
+
+    app.time_source.advance(Span::new().minutes(1));
+    app.client.stop_impersonating().await?;
+    let profile = app.client.user_profile().await?;
+    assert_eq!(profile.username, "alice");
+    assert!(profile.impersonated_by.is_none());
+
+    assert_eq!(
+        audit_entries(&app).await?,
+        vec![
+            ("impersonation_start".into(), Some(alice_id), Some(bob_id)),
+            ("impersonation_stop".into(), Some(alice_id), Some(bob_id)),
+        ]
+    );
+
+    // Not impersonating anymore
+    assert_api_error(
+        app.client.stop_impersonating().await,
+        ApiError::NotImpersonating,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn impersonator_cannot_act_on_the_account() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    make_superuser(&app, "alice").await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    app.client
+        .impersonate(&requests::Impersonate {
+            target_user_id: bob_id,
+        })
+        .await?;
+    assert_api_error(
+        app.client.delete_user().await,
+        ApiError::NotAllowedWhileImpersonating,
+    );
+    assert_api_error(
+        app.client
+            .generate_recovery_codes(&requests::GenerateRecoveryCodes {
+                password: "password_bob".into(),
+            })
+            .await,
+        ApiError::NotAllowedWhileImpersonating,
+    );
+
+    // Still impersonating bob, whose account is untouched
+    let profile = app.client.user_profile().await?;
+    assert_eq!(profile.username, "bob");
+    assert_eq!(profile.recovery_codes_remaining, 0);
+    let deleted: bool = sqlx::query_scalar(
+        "SELECT deleted_at IS NOT NULL FROM users WHERE id = $1",
+    )
+    .bind(bob_id)
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert!(!deleted);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn impersonated_requests_are_audited() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    make_superuser(&app, "alice").await?;
+    let alice_id = user_id(&app, "alice").await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    app.client
+        .impersonate(&requests::Impersonate {
+            target_user_id: bob_id,
+        })
+        .await?;
+    app.time_source.advance(Span::new().minutes(1));
+    // Reads aren't audited
+    app.client.user_profile().await?;
+    app.client
+        .update_locale(&requests::UpdateLocale {
+            locale: Some(payloads::Locale::Es),
+        })
+        .await?;
+    app.time_source.advance(Span::new().minutes(1));
+    app.client.stop_impersonating().await?;
+
+    assert_eq!(
+        audit_entries(&app).await?,
+        vec![
+            ("impersonation_start".into(), Some(alice_id), Some(bob_id)),
+            ("impersonated_request".into(), Some(alice_id), Some(bob_id)),
+            ("impersonation_stop".into(), Some(alice_id), Some(bob_id)),
+        ]
+    );
+    let path: String = sqlx::query_scalar(
+        "SELECT details->>'path' FROM audit_log
+        WHERE action = 'impersonated_request'",
+    )
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert_eq!(path, "/api/update_locale");
+
+    // Not audited once back to the superuser's own session
+    app.client
+        .update_locale(&requests::UpdateLocale { locale: None })
+        .await?;
+    assert_eq!(audit_entries(&app).await?.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn impersonation_expires() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    make_superuser(&app, "alice").await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    app.client
+        .impersonate(&requests::Impersonate {
+            target_user_id: bob_id,
+        })
+        .await?;
+    app.time_source.advance(Span::new().minutes(29));
+    assert!(app.client.login_check().await?);
+
+    app.time_source.advance(Span::new().minutes(1));
+    assert!(!app.client.login_check().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn logout_stops_impersonation() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    make_superuser(&app, "alice").await?;
+    let alice_id = user_id(&app, "alice").await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    app.client
+        .impersonate(&requests::Impersonate {
+            target_user_id: bob_id,
+        })
+        .await?;
+    app.time_source.advance(Span::new().minutes(1));
+    app.client.logout().await?;
+    assert!(!app.client.login_check().await?);
+
+    let entries = audit_entries(&app).await?;
+    assert_eq!(
+        entries.last().unwrap(),
+        &("impersonation_stop".into(), Some(alice_id), Some(bob_id))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn impersonation_requires_superuser() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    let result = app
+        .client
+        .impersonate(&requests::Impersonate {
+            target_user_id: bob_id,
+        })
+        .await;
+    assert_api_error(result, ApiError::RequiresSuperuser);
+    assert_eq!(app.client.user_profile().await?.username, "alice");
+    assert!(audit_entries(&app).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cannot_impersonate_superuser() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+    make_superuser(&app, "alice").await?;
+    make_superuser(&app, "bob").await?;
+    let alice_id = user_id(&app, "alice").await?;
+    let bob_id = user_id(&app, "bob").await?;
+
+    for target_user_id in [bob_id, alice_id] {
+        let result = app
+            .client
+            .impersonate(&requests::Impersonate { target_user_id })
+            .await;
+        assert_api_error(result, ApiError::CannotImpersonateSuperuser);
+    }
+    assert_eq!(app.client.user_profile().await?.username, "alice");
+    assert!(audit_entries(&app).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bootstrap_superuser_is_idempotent() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.client
+        .create_account(&test_helpers::credentials("bob"))
+        .await?;
+    let is_superuser = |username: &'static str| {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT is_superuser FROM users WHERE username = $1",
+        )
+        .bind(username)
+        .fetch_one(&app.db_pool)
+    };
+
+    // Matched case-insensitively, and only changed the first time
+    app.time_source.advance(Span::new().hours(1));
+    assert!(
+        api::store::ensure_superuser(
+            "Alice@Example.com",
+            &app.db_pool,
+            &app.time_source
+        )
+        .await?
+    );
+    assert!(is_superuser("alice").await?);
+    let touched: bool = sqlx::query_scalar(
+        "SELECT updated_at = $1 FROM users WHERE username = 'alice'",
+    )
+    .bind(app.time_source.now().to_sqlx())
+    .fetch_one(&app.db_pool)
+    .await?;
+    assert!(touched);
+    assert!(
+        !api::store::ensure_superuser(
+            "alice@example.com",
+            &app.db_pool,
+            &app.time_source
+        )
+        .await?
+    );
+    assert!(is_superuser("alice").await?);
+
+    // Unverified and unknown emails aren't promoted or created
+    for email in ["bob@example.com", "nobody@example.com"] {
+        assert!(matches!(
+            api::store::ensure_superuser(email, &app.db_pool, &app.time_source)
+                .await,
+            Err(api::store::StoreError::Api(ApiError::UserNotFound))
+        ));
+    }
+    assert!(!is_superuser("bob").await?);
+    let users: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&app.db_pool)
+        .await?;
+    assert_eq!(users, 2);
+
+    Ok(())
+}
@@ -17,8 +17,8 @@ async fn login_refused() -> anyhow::Result<()> {
     let result = app.client.login(&body).await;
 
     match result {
-        Err(payloads::ClientError::APIError(code, text)) => {
-            assert_eq!(code, StatusCode::UNAUTHORIZED);
+        Err(payloads::ClientError::APIError(meta, text)) => {
+            assert_eq!(meta.status, StatusCode::UNAUTHORIZED);
             assert_eq!(text, "Authentication failed: Invalid credentials");
         }
         _ => {
@@ -33,6 +33,50 @@ async fn login_refused() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn login_failure_does_not_reveal_username_exists() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.client.logout().await?;
+
+    let url = format!("{}/api/login", app.client.address);
+    let attempt = |username: &str| {
+        app.client
+            .inner_client
+            .post(&url)
+            .json(&requests::LoginCredentials {
+                username: username.into(),
+                password: "wrong-password".into(),
+            })
+            .send()
+    };
+    let unknown_user = attempt("nosuchuser").await?;
+    let wrong_password = attempt("alice").await?;
+    assert_eq!(unknown_user.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(unknown_user.status(), wrong_password.status());
+    assert_eq!(unknown_user.bytes().await?, wrong_password.bytes().await?);
+
+    // Both paths do the same argon2 work, so timing doesn't differ either
+    let validate = |username: &str| {
+        let credentials = serde_json::from_value(serde_json::json!({
+            "username": username,
+            "password": "wrong-password",
+        }))
+        .unwrap();
+        api::password::count_password_verifications(
+            api::password::validate_credentials(credentials, &app.db_pool),
+        )
+    };
+    let (result, verifications) = validate("nosuchuser").await;
+    assert!(result.is_err());
+    assert_eq!(verifications, 1);
+    let (result, verifications) = validate("alice").await;
+    assert!(result.is_err());
+    assert_eq!(verifications, 1);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn create_account() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -46,6 +90,63 @@ async fn create_account() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn create_account_is_atomic() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let expires_at = app.time_source.now() + jiff::Span::new().hours(24);
+    let create = || {
+        store::create_account(
+            "alice",
+            "alice@example.com",
+            "not-a-real-hash",
+            expires_at,
+            &app.db_pool,
+            &app.time_source,
+            &app.id_source,
+        )
+    };
+    let count = |table: &'static str| {
+        let query = format!("SELECT COUNT(*) FROM {table}");
+        let pool = app.db_pool.clone();
+        async move { sqlx::query_scalar::<_, i64>(&query).fetch_one(&pool).await }
+    };
+
+    // Force the token insert to fail after the user insert succeeds
+    sqlx::query(
+        "CREATE FUNCTION reject_tokens() RETURNS trigger AS $$
+        BEGIN RAISE EXCEPTION 'forced failure'; END $$ LANGUAGE plpgsql",
+    )
+    .execute(&app.db_pool)
+    .await?;
+    sqlx::query(
+        "CREATE TRIGGER reject_tokens BEFORE INSERT ON tokens
+        FOR EACH ROW EXECUTE FUNCTION reject_tokens()",
+    )
+    .execute(&app.db_pool)
+    .await?;
+
+    assert!(create().await.is_err());
+    assert_eq!(count("users").await?, 0);
+    assert_eq!(count("tokens").await?, 0);
+
+    sqlx::query("DROP TRIGGER reject_tokens ON tokens")
+        .execute(&app.db_pool)
+        .await?;
+
+    let (user, token_id) = create().await?;
+    assert!(!user.email_verified);
+    let (user_id, action): (payloads::UserId, store::TokenAction) =
+        sqlx::query_as("SELECT user_id, action FROM tokens WHERE id = $1")
+            .bind(token_id)
+            .fetch_one(&app.db_pool)
+            .await?;
+    assert_eq!(user_id, user.id);
+    assert_eq!(action, store::TokenAction::EmailVerification);
+    assert_eq!(count("users").await?, 1);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn username_email_collisions_are_case_insensitive() -> anyhow::Result<()>
 {
@@ -221,7 +322,47 @@ async fn update_profile_display_name_too_long() -> anyhow::Result<()> {
     };
 
     let result = app.client.update_profile(&update_request).await;
-    assert_status_code(result, StatusCode::BAD_REQUEST);
+    assert_api_error(
+        result,
+        ApiError::InvalidDisplayName(
+            "Display name must be at most 255 characters".into(),
+        ),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_profile_display_name_sanitized() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+
+    // Surrounding whitespace is trimmed
+    let update_request = requests::UpdateProfile {
+        display_name: Some("  Alice Smith\t".into()),
+    };
+    let profile = app.client.update_profile(&update_request).await?;
+    assert_eq!(profile.display_name.as_deref(), Some("Alice Smith"));
+
+    // Control characters inside the name are rejected
+    let update_request = requests::UpdateProfile {
+        display_name: Some("Alice\nSmith".into()),
+    };
+    let result = app.client.update_profile(&update_request).await;
+    assert_api_error(
+        result,
+        ApiError::InvalidDisplayName(
+            "Display name cannot contain control characters".into(),
+        ),
+    );
+
+    // A blank name clears it, falling back to the username
+    let update_request = requests::UpdateProfile {
+        display_name: Some("   ".into()),
+    };
+    let profile = app.client.update_profile(&update_request).await?;
+    assert_eq!(profile.display_name, None);
+    assert_eq!(profile.effective_name(), "alice");
 
     Ok(())
 }
@@ -489,3 +630,376 @@ async fn delete_user_with_transaction_history() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn export_my_data_excludes_other_users() -> anyhow::Result<()> {
+    use api::scheduler;
+
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round = &app.client.list_auction_rounds(&auction.auction_id).await?[0];
+
+    // Both bid on the same space, so bob's data sits right next to alice's
+    app.client
+        .create_bid(&space.space_id, &round.round_id)
+        .await?;
+    app.login_bob().await?;
+    app.client
+        .create_bid(&space.space_id, &round.round_id)
+        .await?;
+    app.login_alice().await?;
+
+    let export = app.client.export_my_data().await?;
+    assert_eq!(export.profile.username, "alice");
+    assert_eq!(export.memberships.len(), 1);
+    assert_eq!(export.memberships[0].community_id, community_id);
+    assert_eq!(export.bids.len(), 1);
+    assert_eq!(export.bids[0].auction_id, auction.auction_id);
+    assert_eq!(export.bids[0].round_num, round.round_details.round_num);
+
+    let text = serde_json::to_string(&export)?;
+    let bob = test_helpers::bob_credentials();
+    assert!(!text.contains(&bob.username));
+    assert!(!text.contains(&bob.email));
+
+    // Once per hour
+    assert_api_error(
+        app.client.export_my_data().await,
+        ApiError::DataExportRateLimited,
+    );
+    app.time_source.advance(jiff::Span::new().hours(1));
+    app.client.export_my_data().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_exports_claim_the_limit_once() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+
+    let (a, b) =
+        tokio::join!(app.client.export_my_data(), app.client.export_my_data());
+    let (ok, limited) = match (a, b) {
+        (Ok(export), limited) | (limited, Ok(export)) => (export, limited),
+        (a, b) => panic!("neither export succeeded: {a:?}, {b:?}"),
+    };
+    assert_eq!(ok.profile.username, "alice");
+    assert_api_error(limited, ApiError::DataExportRateLimited);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bootstrap_matches_individual_endpoints() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_two_person_community().await?;
+    // Leave bob an invite to a second community
+    let community_id = app.create_test_community().await?;
+    app.client
+        .invite_member(&requests::InviteCommunityMember {
+            community_id,
+            new_member_email: Some(test_helpers::bob_credentials().email),
+            single_use: false,
+            role: None,
+        })
+        .await?;
+
+    let check = async || -> anyhow::Result<()> {
+        let bootstrap = app.client.bootstrap().await?;
+        assert_eq!(bootstrap.profile, app.client.user_profile().await?);
+        assert_eq!(bootstrap.communities, app.client.get_communities().await?);
+        assert_eq!(
+            i64::from(bootstrap.pending_invites),
+            app.client.count_received_invites().await?
+        );
+        Ok(())
+    };
+    check().await?;
+    app.login_bob().await?;
+    assert_eq!(app.client.bootstrap().await?.pending_invites, 1);
+    check().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bootstrap_requires_authentication() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    assert_status_code(app.client.bootstrap().await, StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+// Session limits are enforced against the wall clock, not the app's mock
+// time, so these wait for real.
+
+// Session timestamps are stored in whole seconds, so the limits here are
+// whole seconds with a second of slack either side.
+
+#[tokio::test]
+async fn idle_session_ends() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_with_session_limits(
+        None,
+        Some(jiff::SignedDuration::from_secs(2)),
+    )
+    .await;
+    app.create_alice_user().await?;
+    app.login_alice().await?;
+
+    // Each request restarts the idle clock, so activity outlasts the timeout
+    for _ in 0..4 {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        assert!(app.client.login_check().await?);
+    }
+
+    tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+    assert!(!app.client.login_check().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_ends_at_max_age_despite_activity() -> anyhow::Result<()> {
+    let app = test_helpers::spawn_app_with_session_limits(
+        Some(jiff::SignedDuration::from_secs(2)),
+        None,
+    )
+    .await;
+    app.create_alice_user().await?;
+    app.login_alice().await?;
+
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert!(app.client.login_check().await?);
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    assert!(!app.client.login_check().await?);
+
+    Ok(())
+}
+
+fn confirm_password(password: &str) -> requests::GenerateRecoveryCodes {
+    requests::GenerateRecoveryCodes {
+        password: password.into(),
+    }
+}
+
+fn recover_alice(code: &str) -> requests::RecoverAccount {
+    requests::RecoverAccount {
+        username: "alice".into(),
+        recovery_code: code.into(),
+        new_password: "recovered-password".into(),
+    }
+}
+
+#[tokio::test]
+async fn recovery_code_works_once() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    assert_eq!(app.client.user_profile().await?.recovery_codes_remaining, 0);
+    let codes = app
+        .client
+        .generate_recovery_codes(&confirm_password("password_alice"))
+        .await?
+        .codes;
+    assert_eq!(codes.len(), 10);
+    assert_eq!(
+        app.client.user_profile().await?.recovery_codes_remaining,
+        10
+    );
+
+    // A second session, and a reset link, both outstanding
+    let other_session = payloads::APIClient::new(
+        app.client.address.clone(),
+        reqwest::Client::builder().cookie_store(true).build()?,
+    );
+    other_session
+        .login(&test_helpers::alice_login_credentials())
+        .await?;
+    app.client
+        .forgot_password(&requests::ForgotPassword {
+            email: "alice@example.com".into(),
+        })
+        .await?;
+    let reset_token = app
+        .get_password_reset_token_from_db("alice@example.com")
+        .await?;
+
+    // Case and separators don't matter
+    let typed = codes[0].replace('-', " ").to_lowercase();
+    app.client.recover_account(&recover_alice(&typed)).await?;
+
+    assert!(!app.client.login_check().await?);
+    assert!(!other_session.login_check().await?);
+    assert!(!app.is_token_valid(&reset_token).await?);
+    assert!(
+        app.client
+            .login(&test_helpers::alice_login_credentials())
+            .await
+            .is_err()
+    );
+    app.client
+        .login(&requests::LoginCredentials {
+            username: "alice".into(),
+            password: "recovered-password".into(),
+        })
+        .await?;
+    assert_eq!(app.client.user_profile().await?.recovery_codes_remaining, 9);
+
+    // Consumed and fabricated codes fail alike
+    assert_api_error(
+        app.client.recover_account(&recover_alice(&codes[0])).await,
+        ApiError::InvalidRecoveryCode,
+    );
+    assert_api_error(
+        app.client
+            .recover_account(&recover_alice("AAAAA-AAAAA"))
+            .await,
+        ApiError::InvalidRecoveryCode,
+    );
+    assert_api_error(
+        app.client
+            .recover_account(&requests::RecoverAccount {
+                username: "nosuchuser".into(),
+                ..recover_alice(&codes[1])
+            })
+            .await,
+        ApiError::InvalidRecoveryCode,
+    );
+
+    // Regenerating invalidates the rest of the old set
+    app.client
+        .generate_recovery_codes(&confirm_password("recovered-password"))
+        .await?;
+    assert_eq!(
+        app.client.user_profile().await?.recovery_codes_remaining,
+        10
+    );
+    assert_api_error(
+        app.client.recover_account(&recover_alice(&codes[1])).await,
+        ApiError::InvalidRecoveryCode,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn generating_recovery_codes_requires_the_password() -> anyhow::Result<()>
+{
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.create_bob_user().await?;
+
+    // Neither a wrong password nor another account's is accepted
+    for password in ["wrong-password", "password_bob"] {
+        assert_api_error(
+            app.client
+                .generate_recovery_codes(&confirm_password(password))
+                .await,
+            ApiError::IncorrectPassword,
+        );
+    }
+    assert_eq!(app.client.user_profile().await?.recovery_codes_remaining, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_recovery_is_rate_limited() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let codes = app
+        .client
+        .generate_recovery_codes(&confirm_password("password_alice"))
+        .await?
+        .codes;
+
+    for _ in 0..store::MAX_RECOVERY_ATTEMPTS {
+        assert_api_error(
+            app.client
+                .recover_account(&recover_alice("AAAAA-AAAAA"))
+                .await,
+            ApiError::InvalidRecoveryCode,
+        );
+    }
+    // Even a valid code is refused until the window passes
+    assert_api_error(
+        app.client.recover_account(&recover_alice(&codes[0])).await,
+        ApiError::AccountRecoveryRateLimited,
+    );
+
+    app.time_source.advance(jiff::Span::new().hours(1));
+    app.client
+        .recover_account(&recover_alice(&codes[0]))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_recovery_is_rate_limited_across_accounts() -> anyhow::Result<()>
+{
+    let app = test_helpers::spawn_app_with_max_recovery_attempts(2).await;
+    app.create_alice_user().await?;
+    let codes = app
+        .client
+        .generate_recovery_codes(&confirm_password("password_alice"))
+        .await?
+        .codes;
+
+    // Unknown usernames draw from the same limit
+    for username in ["nosuchuser", "nobody"] {
+        assert_api_error(
+            app.client
+                .recover_account(&requests::RecoverAccount {
+                    username: username.into(),
+                    ..recover_alice("AAAAA-AAAAA")
+                })
+                .await,
+            ApiError::InvalidRecoveryCode,
+        );
+    }
+    assert_api_error(
+        app.client.recover_account(&recover_alice(&codes[0])).await,
+        ApiError::AccountRecoveryRateLimited,
+    );
+
+    // Refilled one attempt at a time over the minute
+    app.time_source.advance(jiff::Span::new().seconds(30));
+    app.client
+        .recover_account(&recover_alice(&codes[0]))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn recovery_costs_the_same_for_unknown_users() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.client
+        .generate_recovery_codes(&confirm_password("password_alice"))
+        .await?;
+
+    let recover = |username: &'static str| {
+        api::password::count_password_verifications(
+            api::password::recover_account(
+                username,
+                secrecy::SecretBox::new(Box::new("AAAAA-AAAAA".into())),
+                secrecy::SecretBox::new(Box::new("new-password".into())),
+                &app.db_pool,
+                &app.time_source,
+            ),
+        )
+    };
+    let (result, unknown) = recover("nosuchuser").await;
+    assert!(result.is_err());
+    let (result, known) = recover("alice").await;
+    assert!(result.is_err());
+    assert_eq!(unknown, store::RECOVERY_CODE_COUNT);
+    assert_eq!(known, unknown);
+
+    Ok(())
+}
@@ -6,14 +6,24 @@ mod community;
 mod currency;
 mod database;
 mod email;
+mod error_messages;
+mod impersonation;
 mod login;
+mod maintenance;
 mod member_removal;
+mod outbox;
+mod permissions;
+mod possession;
 mod proxy_bidding;
 mod pubsub;
+mod query_plans;
 mod reserve_pricing;
+mod response_meta;
 mod schema_reference;
 mod security_headers;
 mod site;
+mod snapshot;
+mod static_files;
 mod stripe_sandbox;
 
 use test_helpers::spawn_app;
@@ -26,3 +36,69 @@ async fn health_check() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn health_check_head() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+
+    for path in ["/api/health_check", "/api/ready"] {
+        let response = app
+            .client
+            .inner_client
+            .head(format!("{}{path}", app.client.address))
+            .send()
+            .await?;
+        assert_eq!(response.status(), reqwest::StatusCode::OK, "{path}");
+        assert_eq!(response.headers()[reqwest::header::CONTENT_LENGTH], "0");
+        assert!(response.bytes().await?.is_empty());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ready_requires_latest_migration() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+
+    app.client.ready().await?;
+
+    // Losing the newest migration leaves the server live but not ready
+    sqlx::query(
+        "DELETE FROM _sqlx_migrations
+        WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)",
+    )
+    .execute(&app.db_pool)
+    .await?;
+    test_helpers::assert_status_code(
+        app.client.ready().await,
+        reqwest::StatusCode::SERVICE_UNAVAILABLE,
+    );
+    app.client.health_check().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_body_is_a_deserialization_error() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+
+    // The health check answers with plain text, so reading it as JSON
+    // simulates a backend contract mismatch on a successful response.
+    let response = app
+        .client
+        .inner_client
+        .get(format!("{}/api/health_check", app.client.address))
+        .send()
+        .await?;
+    match payloads::ok_body::<String>(response).await {
+        Err(payloads::ClientError::Deserialization {
+            endpoint, body, ..
+        }) => {
+            assert_eq!(endpoint, "/api/health_check");
+            assert_eq!(body, "healthy");
+        }
+        other => panic!("expected a deserialization error, got {other:?}"),
+    }
+
+    Ok(())
+}
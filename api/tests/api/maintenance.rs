@@ -0,0 +1,59 @@
+use payloads::{ApiError, requests};
+use reqwest::StatusCode;
+use test_helpers::{ADMIN_TOKEN, TestApp, assert_api_error, spawn_app};
+
+async fn set_maintenance_mode(
+    app: &TestApp,
+    token: &str,
+    enabled: bool,
+) -> anyhow::Result<reqwest::Response> {
+    let url = format!("{}/api/admin/maintenance", app.client.address);
+    Ok(app
+        .client
+        .inner_client
+        .post(&url)
+        .bearer_auth(token)
+        .json(&requests::SetMaintenanceMode { enabled })
+        .send()
+        .await?)
+}
+
+#[tokio::test]
+async fn maintenance_mode_blocks_api_except_health() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+
+    let response = set_maintenance_mode(&app, ADMIN_TOKEN, true).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Protected endpoints are short-circuited with a retry hint
+    let url = format!("{}/api/communities", app.client.address);
+    let response = app.client.inner_client.get(&url).send().await?;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(response.headers().contains_key("retry-after"));
+    assert_api_error(
+        app.client.get_communities().await,
+        ApiError::MaintenanceMode,
+    );
+
+    // Health checks still pass so the instance isn't restarted
+    app.client.health_check().await?;
+
+    // Turning it off restores service
+    set_maintenance_mode(&app, ADMIN_TOKEN, false).await?;
+    app.client.get_communities().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn maintenance_mode_requires_admin_token() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+
+    let response = set_maintenance_mode(&app, "wrong-token", true).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    app.client.health_check().await?;
+    app.client.platform_stats().await?;
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use api::email::{EmailService, EmailTemplate};
+use api::scheduler;
+use api::store::outbox;
+use jiff::Span;
+use test_helpers::spawn_app;
+
+fn email_service() -> EmailService {
+    EmailService::new(
+        secrecy::SecretBox::new(Box::new("test-api-key".into())),
+        "test@example.com".into(),
+    )
+}
+
+#[tokio::test]
+async fn outbox_email_survives_restart() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let template = EmailTemplate {
+        subject: "Hello".into(),
+        html_body: "<p>Hello</p>".into(),
+        text_body: "Hello".into(),
+    };
+    outbox::enqueue_email(
+        "someone@example.com",
+        "greeting",
+        &template,
+        &app.db_pool,
+        &app.time_source,
+    )
+    .await?;
+
+    // The first instance can't reach the provider, and goes down
+    let unreachable = EmailService::disabled("test@example.com".into());
+    let sent = scheduler::send_outbox_emails(
+        &app.db_pool,
+        &app.time_source,
+        &unreachable,
+    )
+    .await?;
+    assert_eq!(sent, 0);
+    drop(unreachable);
+
+    // After a restart, the email is still pending and retried once the
+    // backoff has passed
+    let email_service = email_service();
+    let send = || {
+        scheduler::send_outbox_emails(
+            &app.db_pool,
+            &app.time_source,
+            &email_service,
+        )
+    };
+    assert_eq!(send().await?, 0);
+    app.time_source.advance(Span::new().minutes(1));
+    assert_eq!(send().await?, 1);
+    let sent = email_service.sent_emails();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].0, "someone@example.com");
+    assert_eq!(sent[0].1.subject, "Hello");
+
+    // Sent only once
+    app.time_source.advance(Span::new().hours(1));
+    assert_eq!(send().await?, 0);
+    assert_eq!(email_service.sent_emails().len(), 1);
+
+    let (attempts, last_error): (i32, Option<String>) =
+        sqlx::query_as("SELECT attempts, last_error FROM outbox_emails")
+            .fetch_one(&app.db_pool)
+            .await?;
+    assert_eq!(attempts, 1);
+    assert!(last_error.unwrap().contains("No email provider"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn claimed_outbox_email_is_sent_after_lease() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let template = EmailTemplate {
+        subject: "Hello".into(),
+        html_body: "<p>Hello</p>".into(),
+        text_body: "Hello".into(),
+    };
+    outbox::enqueue_email(
+        "someone@example.com",
+        "greeting",
+        &template,
+        &app.db_pool,
+        &app.time_source,
+    )
+    .await?;
+
+    // An instance claims the email and goes down before sending it
+    let claimed =
+        outbox::claim_due_email(&app.db_pool, &app.time_source).await?;
+    assert!(claimed.is_some());
+
+    // Other instances leave it alone until the lease runs out
+    let email_service = email_service();
+    let send = || {
+        scheduler::send_outbox_emails(
+            &app.db_pool,
+            &app.time_source,
+            &email_service,
+        )
+    };
+    assert_eq!(send().await?, 0);
+    app.time_source.advance(outbox::CLAIM_LEASE.try_into()?);
+    assert_eq!(send().await?, 1);
+    assert_eq!(email_service.sent_emails().len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,931 @@
+//! Which community role each route requires, checked for every role.
+//!
+//! [`ROLE_GATED`] lists every route that needs more than membership, with the
+//! lowest role allowed to call it. The matrix test calls each one as a member,
+//! moderator, coleader, and leader of a fresh community and checks that
+//! exactly the roles at or above that level get past the permission check.
+//! Every other route must be listed in [`NOT_ROLE_GATED`], so a new route
+//! fails [`every_route_declares_its_role`] until it's added to one or the
+//! other.
+
+use std::cell::Cell;
+
+use payloads::{
+    AccountId, ApiError, ClientError, CommunityId, PermissionLevel, Role,
+    UserId, requests, responses,
+};
+use rust_decimal::Decimal;
+use test_helpers::{TestApp, spawn_app};
+
+/// Routes that need more than community membership, by handler as
+/// registered in `api_services`, and the lowest role allowed to call them.
+const ROLE_GATED: &[(&str, PermissionLevel)] = &[
+    (
+        "community::invite_community_member",
+        PermissionLevel::Moderator,
+    ),
+    ("community::get_issued_invites", PermissionLevel::Moderator),
+    ("community::delete_invite", PermissionLevel::Moderator),
+    (
+        "community::set_membership_schedule",
+        PermissionLevel::Moderator,
+    ),
+    (
+        "community::get_membership_schedule",
+        PermissionLevel::Moderator,
+    ),
+    (
+        "community::update_member_active_status",
+        PermissionLevel::Moderator,
+    ),
+    (
+        "community::bulk_activate_members",
+        PermissionLevel::Moderator,
+    ),
+    ("community::remove_member", PermissionLevel::Moderator),
+    ("community::change_member_role", PermissionLevel::Coleader),
+    ("community::get_community_stats", PermissionLevel::Coleader),
+    (
+        "community::update_community_details",
+        PermissionLevel::Coleader,
+    ),
+    (
+        "community::set_community_default_params",
+        PermissionLevel::Coleader,
+    ),
+    ("community::set_registration_open", PermissionLevel::Leader),
+    ("community::set_invite_base_url", PermissionLevel::Leader),
+    (
+        "community::set_round_duration_bounds",
+        PermissionLevel::Leader,
+    ),
+    ("community::delete_community", PermissionLevel::Leader),
+    (
+        "currency::update_credit_limit_override",
+        PermissionLevel::Moderator,
+    ),
+    (
+        "currency::get_member_credit_limit_override",
+        PermissionLevel::Moderator,
+    ),
+    // Members can see their own; these are for seeing someone else's
+    (
+        "currency::get_member_currency_info",
+        PermissionLevel::Coleader,
+    ),
+    (
+        "currency::get_member_transactions",
+        PermissionLevel::Coleader,
+    ),
+    ("currency::get_orphaned_accounts", PermissionLevel::Coleader),
+    (
+        "currency::resolve_orphaned_balance",
+        PermissionLevel::Coleader,
+    ),
+    ("currency::get_treasury_account", PermissionLevel::Coleader),
+    (
+        "currency::get_treasury_transactions",
+        PermissionLevel::Coleader,
+    ),
+    (
+        "currency::treasury_credit_operation",
+        PermissionLevel::Coleader,
+    ),
+    ("currency::reset_all_balances", PermissionLevel::Coleader),
+    (
+        "currency::update_currency_config",
+        PermissionLevel::Coleader,
+    ),
+    ("site::create_site", PermissionLevel::Coleader),
+    ("site::update_site", PermissionLevel::Coleader),
+    ("site::delete_site", PermissionLevel::Coleader),
+    ("site::soft_delete_site", PermissionLevel::Coleader),
+    ("site::restore_site", PermissionLevel::Coleader),
+    ("site::create_site_image", PermissionLevel::Coleader),
+    ("site::update_site_image", PermissionLevel::Coleader),
+    ("site::delete_site_image", PermissionLevel::Coleader),
+    ("site::create_space", PermissionLevel::Coleader),
+    ("site::update_space", PermissionLevel::Coleader),
+    ("site::update_spaces", PermissionLevel::Coleader),
+    ("site::reorder_spaces", PermissionLevel::Coleader),
+    ("site::delete_space", PermissionLevel::Coleader),
+    ("site::soft_delete_space", PermissionLevel::Coleader),
+    ("site::restore_space", PermissionLevel::Coleader),
+    ("auction::create_auction", PermissionLevel::Coleader),
+    ("auction::recreate_auction", PermissionLevel::Coleader),
+    ("auction::simulate_auction", PermissionLevel::Coleader),
+    ("auction::delete_auction", PermissionLevel::Coleader),
+    ("auction::schedule_auction", PermissionLevel::Coleader),
+    ("auction::cancel_auction", PermissionLevel::Coleader),
+    ("auction::update_auction_notes", PermissionLevel::Moderator),
+    ("auction::space_bid_log", PermissionLevel::Moderator),
+    (
+        "proxy_bidding::list_proxy_bidding_participants",
+        PermissionLevel::Coleader,
+    ),
+    (
+        "billing::get_community_storage_usage",
+        PermissionLevel::Coleader,
+    ),
+    ("billing::get_subscription_info", PermissionLevel::Coleader),
+    (
+        "billing::create_checkout_session",
+        PermissionLevel::Coleader,
+    ),
+    ("billing::create_portal_session", PermissionLevel::Coleader),
+];
+
+/// Routes open to anyone, to any signed-in user, or to any member of the
+/// community involved, and operator routes outside community roles.
+const NOT_ROLE_GATED: &[&str] = &[
+    // Public
+    "health_check",
+    "ready",
+    "platform_stats",
+    "login::login",
+    "login::create_account",
+    "login::verify_email",
+    "login::forgot_password",
+    "login::reset_password",
+    "login::recover_account",
+    "community::get_invite_community_name",
+    "community::get_community_preview",
+    "billing::stripe_webhook",
+    "auction::verify_receipt",
+    // Operator token or superuser
+    "admin::set_maintenance_mode",
+    "admin::token_stats",
+    // Development builds with the preview flag set
+    "dev::email_preview_kinds",
+    "dev::email_preview",
+    "login::impersonate",
+    // Any signed-in user, for their own account
+    "login::login_check",
+    "login::user_profile",
+    "login::bootstrap",
+    "login::update_profile",
+    "login::update_notification_preferences",
+    "login::update_locale",
+    "login::export_my_data",
+    "login::generate_recovery_codes",
+    "login::delete_user",
+    "login::logout",
+    "login::stop_impersonating",
+    "login::resend_verification_email",
+    "community::create_community",
+    "community::get_communities",
+    "community::list_my_roles",
+    "community::get_received_invites",
+    "community::count_received_invites",
+    "community::accept_invite",
+    "auction::my_possessions",
+    // The space's winner, or a moderator; see the receipt tests
+    "auction::possession_receipt",
+    // Any member
+    "community::get_community_overview",
+    "community::get_members",
+    "community::get_member_profile",
+    "community::leave_community",
+    "community::get_community_default_params",
+    "currency::create_transfer",
+    "site::get_site",
+    "site::resolve_open_hours",
+    "site::list_sites",
+    "site::get_site_image",
+    "site::get_site_image_bytes",
+    "site::list_site_images",
+    "site::get_space",
+    "site::get_spaces",
+    "site::list_spaces",
+    "site::list_spaces_with_values",
+    "auction::get_auction",
+    "auction::get_auction_report",
+    "auction::list_auctions",
+    "auction::list_community_auctions",
+    "auction::get_auction_round",
+    "auction::list_auction_rounds",
+    "auction::list_auction_rounds_filtered",
+    "auction::list_auction_rounds_since",
+    "auction::list_round_thresholds",
+    "auction::my_standing_wins",
+    "auction::get_space_current_price",
+    "auction::get_round_space_result",
+    "auction::list_round_space_results_for_round",
+    "auction::get_round_prices",
+    "auction::get_eligibility",
+    "auction::list_eligibility",
+    "auction::list_round_eligibility",
+    "auction::create_bid",
+    "auction::get_bid",
+    "auction::list_bids",
+    "auction::get_bid_history",
+    "auction::delete_bid",
+    "auction::clear_my_bids",
+    "proxy_bidding::create_or_update_user_value",
+    "proxy_bidding::set_user_value_and_rebid",
+    "proxy_bidding::get_user_value",
+    "proxy_bidding::delete_user_value",
+    "proxy_bidding::list_user_values",
+    "proxy_bidding::create_or_update_proxy_bidding",
+    "proxy_bidding::get_proxy_bidding",
+    "proxy_bidding::delete_proxy_bidding",
+    "sse::sse_auction",
+];
+
+const ROLES: [Role; 4] =
+    [Role::Member, Role::Moderator, Role::Coleader, Role::Leader];
+
+#[tokio::test]
+async fn permission_matrix() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let serial = Cell::new(0);
+
+    for &(route, required) in ROLE_GATED {
+        let community_id = app.create_four_role_community().await?;
+        let leader_id = app.client.user_profile().await?.user_id;
+        for role in ROLES {
+            let ctx = Ctx {
+                app: &app,
+                community_id,
+                leader_id,
+                role,
+                serial: &serial,
+            };
+            let result = exercise(route, &ctx).await?;
+            app.login_alice().await?;
+
+            if required.validate(role) {
+                assert!(
+                    !is_denied(&result),
+                    "{route} should allow {role}, got {result:?}"
+                );
+            } else {
+                assert!(
+                    is_denied(&result),
+                    "{route} should deny {role}, got {result:?}"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn every_route_declares_its_role() {
+    let registered = registered_routes();
+    for route in &registered {
+        let gated = ROLE_GATED.iter().any(|(r, _)| r == route);
+        let not_gated = NOT_ROLE_GATED.contains(route);
+        assert!(
+            gated != not_gated,
+            "{route} must be in exactly one of ROLE_GATED or NOT_ROLE_GATED"
+        );
+    }
+    for route in ROLE_GATED.iter().map(|(r, _)| r).chain(NOT_ROLE_GATED) {
+        assert!(registered.contains(route), "{route} is not registered");
+    }
+}
+
+/// Handlers registered in `api_services`, read from its source.
+fn registered_routes() -> Vec<&'static str> {
+    let source = include_str!("../../src/routes/mod.rs");
+    let (_, body) = source.split_once("pub fn api_services(").unwrap();
+    let (body, _) = body.split_once("\n}\n").unwrap();
+    body.lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with(".service(") || line.contains(".to("))
+        .filter_map(|line| {
+            let (_, rest) = line.rsplit_once('(')?;
+            let (handler, _) = rest.split_once(')')?;
+            (!handler.is_empty()).then_some(handler)
+        })
+        .collect()
+}
+
+/// Whether the call was refused for the caller's role, in any of the forms
+/// the store reports that.
+fn is_denied(result: &Result<(), ClientError>) -> bool {
+    matches!(
+        result,
+        Err(ClientError::Api(
+            _,
+            ApiError::InsufficientPermissions { .. }
+                | ApiError::RequiresModeratorPermissions
+                | ApiError::RequiresColeaderPermissions
+                | ApiError::RequiresLeaderPermissions
+        ))
+    )
+}
+
+/// One call of one route. Fixtures are created as the leader, who is logged
+/// in until [`Ctx::act`] switches to the role under test.
+struct Ctx<'a> {
+    app: &'a TestApp,
+    community_id: CommunityId,
+    leader_id: UserId,
+    role: Role,
+    /// Keeps names unique across fixtures
+    serial: &'a Cell<usize>,
+}
+
+impl Ctx<'_> {
+    async fn act(&self) -> anyhow::Result<()> {
+        self.app.login_as_role(self.role).await
+    }
+
+    fn next(&self) -> usize {
+        let n = self.serial.get() + 1;
+        self.serial.set(n);
+        n
+    }
+
+    async fn site(&self) -> anyhow::Result<responses::Site> {
+        let mut details = test_helpers::site_details_b(self.community_id);
+        details.name = format!("site {}", self.next());
+        let site_id = self.app.client.create_site(&details.into()).await?;
+        Ok(self.app.client.get_site(&site_id).await?)
+    }
+
+    async fn space(&self) -> anyhow::Result<responses::Space> {
+        let site = self.site().await?;
+        self.app.create_test_space(&site.site_id).await
+    }
+
+    async fn auction(&self) -> anyhow::Result<responses::Auction> {
+        let site = self.site().await?;
+        self.app.create_test_auction(&site.site_id).await
+    }
+
+    async fn site_image(&self) -> anyhow::Result<payloads::SiteImageId> {
+        let mut details = test_helpers::site_image_details_a(self.community_id);
+        details.name = format!("image {}", self.next());
+        Ok(self.app.client.create_site_image(&details).await?)
+    }
+
+    /// A new user who joins the community as a member.
+    async fn member(&self) -> anyhow::Result<(UserId, String)> {
+        let username = format!("member{}", self.next());
+        let invite_id = self
+            .app
+            .client
+            .invite_member(&requests::InviteCommunityMember {
+                community_id: self.community_id,
+                new_member_email: None,
+                single_use: true,
+                role: None,
+            })
+            .await?;
+        self.app
+            .client
+            .create_account(&test_helpers::credentials(&username))
+            .await?;
+        self.app.mark_user_email_verified(&username).await?;
+        self.app.login_as(&username).await?;
+        self.app.client.accept_invite(&invite_id).await?;
+        let user_id = self.app.client.user_profile().await?.user_id;
+        self.app.login_alice().await?;
+        Ok((user_id, username))
+    }
+}
+
+/// Set up and make one call to `route` as `ctx.role`. Setup failures are
+/// the outer error; the call's own result is the inner one.
+async fn exercise(
+    route: &str,
+    ctx: &Ctx<'_>,
+) -> anyhow::Result<Result<(), ClientError>> {
+    let client = &ctx.app.client;
+    let community_id = ctx.community_id;
+    let result = match route {
+        "community::invite_community_member" => {
+            ctx.act().await?;
+            client
+                .invite_member(&requests::InviteCommunityMember {
+                    community_id,
+                    new_member_email: None,
+                    single_use: true,
+                    role: None,
+                })
+                .await
+                .map(drop)
+        }
+        "community::get_issued_invites" => {
+            ctx.act().await?;
+            client.get_issued_invites(&community_id).await.map(drop)
+        }
+        "community::delete_invite" => {
+            let invite_id = client
+                .invite_member(&requests::InviteCommunityMember {
+                    community_id,
+                    new_member_email: None,
+                    single_use: true,
+                    role: None,
+                })
+                .await?;
+            ctx.act().await?;
+            client
+                .delete_invite(&requests::DeleteInvite {
+                    community_id,
+                    invite_id,
+                })
+                .await
+        }
+        "community::set_membership_schedule" => {
+            ctx.act().await?;
+            client
+                .set_membership_schedule(&requests::SetMembershipSchedule {
+                    community_id,
+                    schedule: vec![],
+                })
+                .await
+        }
+        "community::get_membership_schedule" => {
+            ctx.act().await?;
+            client
+                .get_membership_schedule(&community_id)
+                .await
+                .map(drop)
+        }
+        "community::update_member_active_status" => {
+            let (member_user_id, _) = ctx.member().await?;
+            ctx.act().await?;
+            client
+                .update_member_active_status(
+                    &requests::UpdateMemberActiveStatus {
+                        community_id,
+                        member_user_id,
+                        is_active: false,
+                    },
+                )
+                .await
+        }
+        "community::bulk_activate_members" => {
+            let (_, username) = ctx.member().await?;
+            ctx.act().await?;
+            client
+                .bulk_activate_members(&requests::BulkActivateMembers {
+                    community_id,
+                    identifiers: vec![username],
+                })
+                .await
+                .map(drop)
+        }
+        "community::remove_member" => {
+            let (member_user_id, _) = ctx.member().await?;
+            ctx.act().await?;
+            client
+                .remove_member(&requests::RemoveMember {
+                    community_id,
+                    member_user_id,
+                })
+                .await
+        }
+        "community::change_member_role" => {
+            let (member_user_id, _) = ctx.member().await?;
+            ctx.act().await?;
+            client
+                .change_member_role(&requests::ChangeMemberRole {
+                    community_id,
+                    member_user_id,
+                    new_role: Role::Moderator,
+                })
+                .await
+        }
+        "community::get_community_stats" => {
+            ctx.act().await?;
+            client.get_community_stats(&community_id).await.map(drop)
+        }
+        "community::update_community_details" => {
+            ctx.act().await?;
+            client
+                .update_community_details(&requests::UpdateCommunityDetails {
+                    community_id,
+                    name: "Test community".into(),
+                    description: None,
+                    description_public: None,
+                })
+                .await
+                .map(drop)
+        }
+        "community::set_community_default_params" => {
+            ctx.act().await?;
+            client
+                .set_community_default_params(
+                    &requests::SetCommunityDefaultParams {
+                        community_id,
+                        params: None,
+                    },
+                )
+                .await
+        }
+        "community::set_registration_open" => {
+            ctx.act().await?;
+            client
+                .set_registration_open(&requests::SetRegistrationOpen {
+                    community_id,
+                    registration_open: true,
+                })
+                .await
+        }
+        "community::set_invite_base_url" => {
+            ctx.act().await?;
+            client
+                .set_invite_base_url(&requests::SetInviteBaseUrl {
+                    community_id,
+                    invite_base_url: None,
+                })
+                .await
+        }
+        "community::set_round_duration_bounds" => {
+            ctx.act().await?;
+            client
+                .set_round_duration_bounds(&requests::SetRoundDurationBounds {
+                    community_id,
+                    bounds: Default::default(),
+                })
+                .await
+        }
+        "community::delete_community" => {
+            // Its own community, so the leader's call doesn't take the
+            // shared one with it
+            let community_id = ctx.app.create_four_role_community().await?;
+            ctx.act().await?;
+            client.delete_community(&community_id).await
+        }
+        "currency::update_credit_limit_override" => {
+            ctx.act().await?;
+            client
+                .update_credit_limit_override(
+                    &requests::UpdateCreditLimitOverride {
+                        community_id,
+                        member_user_id: ctx.leader_id,
+                        credit_limit_override: None,
+                    },
+                )
+                .await
+                .map(drop)
+        }
+        "currency::get_member_credit_limit_override" => {
+            ctx.act().await?;
+            client
+                .get_member_credit_limit_override(
+                    &requests::GetMemberCreditLimitOverride {
+                        community_id,
+                        member_user_id: ctx.leader_id,
+                    },
+                )
+                .await
+                .map(drop)
+        }
+        "currency::get_member_currency_info" => {
+            ctx.act().await?;
+            client
+                .get_member_currency_info(&requests::GetMemberCurrencyInfo {
+                    community_id,
+                    member_user_id: Some(ctx.leader_id),
+                })
+                .await
+                .map(drop)
+        }
+        "currency::get_member_transactions" => {
+            ctx.act().await?;
+            client
+                .get_member_transactions(&requests::GetMemberTransactions {
+                    community_id,
+                    member_user_id: Some(ctx.leader_id),
+                    limit: 10,
+                    offset: 0,
+                })
+                .await
+                .map(drop)
+        }
+        "currency::get_orphaned_accounts" => {
+            ctx.act().await?;
+            client.get_orphaned_accounts(&community_id).await.map(drop)
+        }
+        "currency::resolve_orphaned_balance" => {
+            ctx.act().await?;
+            client
+                .resolve_orphaned_balance(&requests::ResolveOrphanedBalance {
+                    community_id,
+                    orphaned_account_id: AccountId(uuid::Uuid::new_v4()),
+                    note: None,
+                    idempotency_key: requests::ClientIdempotencyKey::new(),
+                })
+                .await
+                .map(drop)
+        }
+        "currency::get_treasury_account" => {
+            ctx.act().await?;
+            client
+                .get_treasury_account(&requests::GetTreasuryAccount {
+                    community_id,
+                })
+                .await
+                .map(drop)
+        }
+        "currency::get_treasury_transactions" => {
+            ctx.act().await?;
+            client
+                .get_treasury_transactions(&requests::GetTreasuryTransactions {
+                    community_id,
+                    limit: 10,
+                    offset: 0,
+                })
+                .await
+                .map(drop)
+        }
+        "currency::treasury_credit_operation" => {
+            ctx.act().await?;
+            client
+                .treasury_credit_operation(&requests::TreasuryCreditOperation {
+                    community_id,
+                    recipient: payloads::TreasuryRecipient::AllActiveMembers,
+                    amount_per_recipient: Decimal::ONE,
+                    note: None,
+                    idempotency_key: requests::ClientIdempotencyKey::new(),
+                })
+                .await
+                .map(drop)
+        }
+        "currency::reset_all_balances" => {
+            ctx.act().await?;
+            client
+                .reset_all_balances(&requests::ResetAllBalances {
+                    community_id,
+                    note: None,
+                })
+                .await
+                .map(drop)
+        }
+        "currency::update_currency_config" => {
+            let currency = client
+                .get_community_overview(&community_id)
+                .await?
+                .community
+                .community
+                .currency;
+            ctx.act().await?;
+            client
+                .update_currency_config(&requests::UpdateCurrencyConfig {
+                    community_id,
+                    currency,
+                })
+                .await
+        }
+        "site::create_site" => {
+            let mut details = test_helpers::site_details_b(community_id);
+            details.name = format!("site {}", ctx.next());
+            ctx.act().await?;
+            client.create_site(&details.into()).await.map(drop)
+        }
+        "site::update_site" => {
+            let site = ctx.site().await?;
+            ctx.act().await?;
+            client
+                .update_site(&requests::UpdateSite {
+                    site_id: site.site_id,
+                    site_details: site.site_details.into(),
+                })
+                .await
+                .map(drop)
+        }
+        "site::delete_site" => {
+            let site = ctx.site().await?;
+            ctx.act().await?;
+            client
+                .delete_site(&requests::DeleteSite {
+                    site_id: site.site_id,
+                    confirm: true,
+                })
+                .await
+        }
+        "site::soft_delete_site" => {
+            let site = ctx.site().await?;
+            ctx.act().await?;
+            client.soft_delete_site(&site.site_id).await
+        }
+        "site::restore_site" => {
+            let site = ctx.site().await?;
+            client.soft_delete_site(&site.site_id).await?;
+            ctx.act().await?;
+            client.restore_site(&site.site_id).await
+        }
+        "site::create_site_image" => {
+            let mut details = test_helpers::site_image_details_a(community_id);
+            details.name = format!("image {}", ctx.next());
+            ctx.act().await?;
+            client.create_site_image(&details).await.map(drop)
+        }
+        "site::update_site_image" => {
+            let id = ctx.site_image().await?;
+            let name = Some(format!("image {}", ctx.next()));
+            ctx.act().await?;
+            client
+                .update_site_image(&requests::UpdateSiteImage { id, name })
+                .await
+                .map(drop)
+        }
+        "site::delete_site_image" => {
+            let id = ctx.site_image().await?;
+            ctx.act().await?;
+            client.delete_site_image(&id).await
+        }
+        "site::create_space" => {
+            let site = ctx.site().await?;
+            ctx.act().await?;
+            client
+                .create_space(&test_helpers::space_details_a(site.site_id))
+                .await
+                .map(drop)
+        }
+        "site::update_space" => {
+            let space = ctx.space().await?;
+            ctx.act().await?;
+            client
+                .update_space(&requests::UpdateSpace {
+                    space_id: space.space_id,
+                    space_details: space.space_details,
+                })
+                .await
+                .map(drop)
+        }
+        "site::update_spaces" => {
+            let space = ctx.space().await?;
+            ctx.act().await?;
+            client
+                .update_spaces(&requests::UpdateSpaces {
+                    spaces: vec![requests::UpdateSpace {
+                        space_id: space.space_id,
+                        space_details: space.space_details,
+                    }],
+                })
+                .await
+                .map(drop)
+        }
+        "site::reorder_spaces" => {
+            let space = ctx.space().await?;
+            let site_id = space.space_details.site_id;
+            let ordered_space_ids = client
+                .list_spaces(&site_id)
+                .await?
+                .into_iter()
+                .map(|space| space.space_id)
+                .collect();
+            ctx.act().await?;
+            client
+                .reorder_spaces(&requests::ReorderSpaces {
+                    site_id,
+                    ordered_space_ids,
+                })
+                .await
+        }
+        "site::delete_space" => {
+            let space = ctx.space().await?;
+            ctx.act().await?;
+            client.delete_space(&space.space_id).await
+        }
+        "site::soft_delete_space" => {
+            let space = ctx.space().await?;
+            ctx.act().await?;
+            client.soft_delete_space(&space.space_id).await
+        }
+        "site::restore_space" => {
+            let space = ctx.space().await?;
+            client.soft_delete_space(&space.space_id).await?;
+            ctx.act().await?;
+            client.restore_space(&space.space_id).await
+        }
+        "auction::create_auction" => {
+            let site = ctx.site().await?;
+            let details = test_helpers::auction_details_a(
+                site.site_id,
+                &ctx.app.time_source,
+            );
+            ctx.act().await?;
+            client.create_auction(&details).await.map(drop)
+        }
+        "auction::recreate_auction" => {
+            let auction = ctx.auction().await?;
+            ctx.act().await?;
+            client
+                .recreate_auction(&requests::RecreateAuction {
+                    source_auction_id: auction.auction_id,
+                    possession_start_at: None,
+                    possession_end_at: None,
+                    start_at: None,
+                    shift_by: Some(jiff::Span::new().months(1)),
+                })
+                .await
+                .map(drop)
+        }
+        "auction::simulate_auction" => {
+            let site = ctx.site().await?;
+            let auction_params = site.site_details.default_auction_params;
+            ctx.act().await?;
+            client
+                .simulate_auction(&requests::SimulateAuction {
+                    site_id: site.site_id,
+                    auction_params,
+                })
+                .await
+                .map(drop)
+        }
+        "auction::delete_auction" => {
+            let auction = ctx.auction().await?;
+            client.cancel_auction(&auction.auction_id).await?;
+            ctx.act().await?;
+            client.delete_auction(&auction.auction_id).await
+        }
+        "auction::schedule_auction" => {
+            let auction = ctx.auction().await?;
+            ctx.act().await?;
+            client
+                .schedule_auction(&requests::ScheduleAuction {
+                    auction_id: auction.auction_id,
+                    start_at: None,
+                })
+                .await
+        }
+        "auction::cancel_auction" => {
+            let auction = ctx.auction().await?;
+            ctx.act().await?;
+            client.cancel_auction(&auction.auction_id).await
+        }
+        "auction::update_auction_notes" => {
+            let auction = ctx.auction().await?;
+            ctx.act().await?;
+            client
+                .update_auction_notes(&requests::UpdateAuctionNotes {
+                    auction_id: auction.auction_id,
+                    moderator_notes: Some("note".into()),
+                    internal_status: None,
+                })
+                .await
+        }
+        "auction::space_bid_log" => {
+            let auction = ctx.auction().await?;
+            let space = ctx
+                .app
+                .create_test_space(&auction.auction_details.site_id)
+                .await?;
+            client.cancel_auction(&auction.auction_id).await?;
+            ctx.act().await?;
+            client
+                .space_bid_log(&requests::SpaceBidLog {
+                    auction_id: auction.auction_id,
+                    space_id: space.space_id,
+                })
+                .await
+                .map(drop)
+        }
+        "proxy_bidding::list_proxy_bidding_participants" => {
+            let auction = ctx.auction().await?;
+            ctx.act().await?;
+            client
+                .list_proxy_bidding_participants(&auction.auction_id)
+                .await
+                .map(drop)
+        }
+        "billing::get_community_storage_usage" => {
+            ctx.act().await?;
+            client
+                .get_community_storage_usage(
+                    &requests::GetCommunityStorageUsage { community_id },
+                )
+                .await
+                .map(drop)
+        }
+        "billing::get_subscription_info" => {
+            ctx.act().await?;
+            client
+                .get_subscription_info(&requests::GetSubscriptionInfo {
+                    community_id,
+                })
+                .await
+                .map(drop)
+        }
+        "billing::create_checkout_session" => {
+            ctx.act().await?;
+            client
+                .create_checkout_session(&requests::CreateCheckoutSession {
+                    community_id,
+                    billing_interval: payloads::BillingInterval::Month,
+                })
+                .await
+                .map(drop)
+        }
+        "billing::create_portal_session" => {
+            ctx.act().await?;
+            client
+                .create_portal_session(&requests::CreatePortalSession {
+                    community_id,
+                })
+                .await
+                .map(drop)
+        }
+        _ => panic!("no call for {route} in the permission matrix"),
+    };
+    Ok(result)
+}
@@ -0,0 +1,218 @@
+use api::email::EmailService;
+use api::scheduler::{self, PossessionReminders};
+use jiff::{SignedDuration, Span, Timestamp};
+use payloads::requests;
+use reqwest::StatusCode;
+use rust_decimal::Decimal;
+use test_helpers::{TestApp, spawn_app};
+
+/// Run an auction where alice wins a space, with possession starting a day
+/// from now and ending three days from now. Returns the possession end.
+async fn alice_wins_space(app: &TestApp) -> anyhow::Result<Timestamp> {
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+
+    let now = app.time_source.now();
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.possession_start_at = now + Span::new().hours(24);
+    auction_details.possession_end_at = now + Span::new().hours(72);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    // Round 0: alice bids unopposed
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let space = &app.client.list_spaces(&site.site_id).await?[0];
+    app.client
+        .create_bid(&space.space_id, &rounds[0].round_id)
+        .await?;
+    app.time_source
+        .set(rounds[0].round_details.end_at + Span::new().seconds(1));
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+
+    // Round 1: no bids, so the auction concludes
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.time_source
+        .set(rounds[1].round_details.end_at + Span::new().seconds(1));
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    assert!(app.client.get_auction(&auction_id).await?.end_at.is_some());
+
+    Ok(auction_details.possession_end_at)
+}
+
+fn reminders() -> PossessionReminders {
+    PossessionReminders {
+        base_url: "http://localhost:8080".into(),
+        window: SignedDuration::from_hours(24),
+    }
+}
+
+fn email_service() -> EmailService {
+    EmailService::new(
+        secrecy::SecretBox::new(Box::new("test-api-key".into())),
+        "test@example.com".into(),
+    )
+}
+
+#[tokio::test]
+async fn possession_reminder_sent_once() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let possession_end_at = alice_wins_space(&app).await?;
+    let reminders = reminders();
+    let queue = || {
+        scheduler::queue_possession_reminders(
+            &app.db_pool,
+            &app.time_source,
+            &reminders,
+        )
+    };
+
+    // Outside the window: nothing due yet
+    assert_eq!(queue().await?, 0);
+    let possessions = app.client.my_possessions().await?;
+    assert_eq!(possessions.len(), 1);
+    assert_eq!(possessions[0].possession_end_at, possession_end_at);
+    assert!(!possessions[0].ends_soon);
+
+    // Inside the window: exactly one email, even when ticked again
+    app.time_source
+        .set(possession_end_at - SignedDuration::from_hours(12));
+    assert_eq!(queue().await?, 1);
+    assert_eq!(queue().await?, 0);
+    assert!(app.client.my_possessions().await?[0].ends_soon);
+
+    // Further into the window: still nothing more
+    app.time_source
+        .set(possession_end_at - SignedDuration::from_hours(1));
+    assert_eq!(queue().await?, 0);
+
+    let email_service = email_service();
+    let sent = scheduler::send_outbox_emails(
+        &app.db_pool,
+        &app.time_source,
+        &email_service,
+    )
+    .await?;
+    assert_eq!(sent, 1);
+    let sent = email_service.sent_emails();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].0, test_helpers::alice_credentials().email);
+
+    // Past the end, the possession is no longer listed
+    app.time_source.set(possession_end_at);
+    assert!(app.client.my_possessions().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn possession_reminder_respects_opt_out() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let possession_end_at = alice_wins_space(&app).await?;
+
+    let profile = app
+        .client
+        .update_notification_preferences(
+            &requests::UpdateNotificationPreferences {
+                email_possession_reminders: false,
+            },
+        )
+        .await?;
+    assert!(!profile.email_possession_reminders);
+
+    app.time_source
+        .set(possession_end_at - SignedDuration::from_hours(12));
+    let queued = scheduler::queue_possession_reminders(
+        &app.db_pool,
+        &app.time_source,
+        &reminders(),
+    )
+    .await?;
+    assert_eq!(queued, 0);
+
+    // Still listed, just not emailed
+    assert!(app.client.my_possessions().await?[0].ends_soon);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn possession_reminder_failed_send_is_retried() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let possession_end_at = alice_wins_space(&app).await?;
+    app.time_source
+        .set(possession_end_at - SignedDuration::from_hours(12));
+    let reminders = reminders();
+    let queued = scheduler::queue_possession_reminders(
+        &app.db_pool,
+        &app.time_source,
+        &reminders,
+    )
+    .await?;
+    assert_eq!(queued, 1);
+
+    // The send fails, but the tick carries on rather than erroring
+    let unreachable = EmailService::disabled("test@example.com".into());
+    let sent = scheduler::send_outbox_emails(
+        &app.db_pool,
+        &app.time_source,
+        &unreachable,
+    )
+    .await?;
+    assert_eq!(sent, 0);
+
+    // The outbox retries it after a backoff, and it's never queued again
+    app.time_source.advance(Span::new().minutes(1));
+    let queued = scheduler::queue_possession_reminders(
+        &app.db_pool,
+        &app.time_source,
+        &reminders,
+    )
+    .await?;
+    assert_eq!(queued, 0);
+    let email_service = email_service();
+    let sent = scheduler::send_outbox_emails(
+        &app.db_pool,
+        &app.time_source,
+        &email_service,
+    )
+    .await?;
+    assert_eq!(sent, 1);
+    assert_eq!(email_service.sent_emails().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn possession_receipt_verifies() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let possession_end_at = alice_wins_space(&app).await?;
+    let possession = &app.client.my_possessions().await?[0];
+    let details = requests::PossessionReceipt {
+        auction_id: possession.auction_id,
+        space_id: possession.space_id,
+    };
+
+    let signed = app.client.possession_receipt(&details).await?;
+    assert_eq!(signed.receipt.holder_name, "alice");
+    assert_eq!(signed.receipt.space_name, possession.space_name);
+    assert_eq!(signed.receipt.possession_end_at, possession_end_at);
+
+    // Bob is a member but didn't win
+    app.login_bob().await?;
+    test_helpers::assert_status_code(
+        app.client.possession_receipt(&details).await,
+        StatusCode::FORBIDDEN,
+    );
+
+    // Verification needs no login
+    app.client.logout().await?;
+    assert!(app.client.verify_receipt(&signed).await?);
+
+    let mut tampered = signed.clone();
+    tampered.receipt.price -= Decimal::ONE;
+    assert!(!app.client.verify_receipt(&tampered).await?);
+
+    Ok(())
+}
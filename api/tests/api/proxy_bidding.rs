@@ -57,6 +57,7 @@ async fn test_proxy_bidding_two_spaces_auction() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 2,
+            max_total: None,
         })
         .await?;
 
@@ -66,6 +67,7 @@ async fn test_proxy_bidding_two_spaces_auction() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -203,6 +205,7 @@ async fn test_proxy_bidding_basic() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -213,7 +216,8 @@ async fn test_proxy_bidding_basic() -> anyhow::Result<()> {
     let round = &rounds[0];
 
     // Verify proxy bidding is set for Alice
-    let proxy_bidding = app.client.get_proxy_bidding(&auction_id).await?;
+    let proxy_bidding =
+        app.client.get_proxy_bidding(&auction_id).await?.settings;
     assert!(proxy_bidding.is_some());
     let proxy_bidding = proxy_bidding.unwrap();
     assert_eq!(proxy_bidding.max_items, 1);
@@ -229,7 +233,8 @@ async fn test_proxy_bidding_basic() -> anyhow::Result<()> {
     app.client.delete_proxy_bidding(&auction_id).await?;
 
     // Verify proxy bidding is deleted
-    let proxy_bidding = app.client.get_proxy_bidding(&auction_id).await?;
+    let proxy_bidding =
+        app.client.get_proxy_bidding(&auction_id).await?.settings;
     assert!(proxy_bidding.is_none());
 
     Ok(())
@@ -293,6 +298,7 @@ async fn test_proxy_bidding_three_bidders_debug() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 2,
+            max_total: None,
         })
         .await?;
 
@@ -314,6 +320,7 @@ async fn test_proxy_bidding_three_bidders_debug() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -335,6 +342,7 @@ async fn test_proxy_bidding_three_bidders_debug() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -489,6 +497,7 @@ async fn test_list_proxy_bidding_participants_permissions() -> anyhow::Result<()
     let app = spawn_app().await;
     let community_id = app.create_two_person_community().await?;
     let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
 
     // Auction scheduled to start in the future (the list is a pre-start
     // nudge tool).
@@ -504,7 +513,8 @@ async fn test_list_proxy_bidding_participants_permissions() -> anyhow::Result<()
     app.client
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
-            max_items: 3,
+            max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -551,6 +561,7 @@ async fn test_proxy_bidding_deleted_on_community_exit() -> anyhow::Result<()> {
     let app = spawn_app().await;
     let community_id = app.create_two_person_community().await?;
     let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
 
     // Future start so the leader can query the participant list (only
     // available pre-start).
@@ -566,6 +577,7 @@ async fn test_proxy_bidding_deleted_on_community_exit() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
     app.client
@@ -592,6 +604,7 @@ async fn test_proxy_bidding_deleted_on_community_exit() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -706,6 +719,7 @@ async fn setup_processed_round(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -720,6 +734,7 @@ async fn setup_processed_round(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -775,19 +790,21 @@ async fn test_per_user_failure_isolation() -> anyhow::Result<()> {
     .execute(&app.db_pool)
     .await?;
 
-    // Both users change their settings mid-round, making both items due.
+    // Both users save their settings again mid-round, making both items due.
     app.login_alice().await?;
     app.client
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
-            max_items: 2,
+            max_items: 1,
+            max_total: None,
         })
         .await?;
     app.login_bob().await?;
     app.client
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
-            max_items: 2,
+            max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -826,6 +843,7 @@ async fn test_per_user_failure_isolation() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
     app.time_source.advance(Span::new().seconds(1));
@@ -865,7 +883,8 @@ async fn test_per_user_reprocessing() -> anyhow::Result<()> {
     app.client
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
-            max_items: 2,
+            max_items: 1,
+            max_total: Some(Decimal::new(500, 0)),
         })
         .await?;
     assert!(needs_processing(&app.db_pool, &auction_id, "alice").await?);
@@ -1024,3 +1043,308 @@ async fn test_user_value_writers_trigger_reprocessing() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_proxy_bidding_budget_cap() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    let mut spaces = Vec::new();
+    for details in [
+        test_helpers::space_details_a(site.site_id),
+        test_helpers::space_details_b(site.site_id),
+        test_helpers::space_details_c(site.site_id),
+    ] {
+        spaces.push(app.client.create_space(&details).await?);
+    }
+    let (space_a, space_b, space_c) = (spaces[0], spaces[1], spaces[2]);
+
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    // The budget must be non-negative and fit the community's minor units.
+    app.login_alice().await?;
+    for (max_total, expected) in [
+        (Decimal::new(-1, 0), ApiError::AmountMustBeNonNegative),
+        (
+            Decimal::new(1, 3),
+            ApiError::AmountNotQuantized { minor_units: 2 },
+        ),
+    ] {
+        let result = app
+            .client
+            .create_or_update_proxy_bidding(&requests::UseProxyBidding {
+                auction_id,
+                max_items: 3,
+                max_total: Some(max_total),
+            })
+            .await;
+        assert_api_error(result, expected);
+    }
+
+    // Alice values every space above Bob, but can only commit 4 in total.
+    for (space_id, value) in [(space_a, 10), (space_b, 9), (space_c, 8)] {
+        app.client
+            .create_or_update_user_value(&requests::UserValue {
+                space_id,
+                value: Decimal::new(value, 0),
+            })
+            .await?;
+    }
+    app.client
+        .create_or_update_proxy_bidding(&requests::UseProxyBidding {
+            auction_id,
+            max_items: 3,
+            max_total: Some(Decimal::new(4, 0)),
+        })
+        .await?;
+    let settings = app
+        .client
+        .get_proxy_bidding(&auction_id)
+        .await?
+        .settings
+        .unwrap();
+    assert_eq!(settings.max_total, Some(Decimal::new(4, 0)));
+
+    // Bob contests A and B, pushing their prices past Alice's budget.
+    app.login_bob().await?;
+    for space_id in [space_a, space_b] {
+        app.client
+            .create_or_update_user_value(&requests::UserValue {
+                space_id,
+                value: Decimal::new(6, 0),
+            })
+            .await?;
+    }
+    app.client
+        .create_or_update_proxy_bidding(&requests::UseProxyBidding {
+            auction_id,
+            max_items: 2,
+            max_total: None,
+        })
+        .await?;
+
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    for _ in 0..30 {
+        let rounds = app.client.list_auction_rounds(&auction_id).await?;
+        let latest_round = rounds.last().unwrap();
+        app.time_source
+            .set(latest_round.round_details.end_at + Span::new().seconds(1));
+        scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+        if app.client.get_auction(&auction_id).await?.end_at.is_some() {
+            break;
+        }
+    }
+    assert!(
+        app.client.get_auction(&auction_id).await?.end_at.is_some(),
+        "auction should conclude"
+    );
+
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    let results = app
+        .client
+        .list_round_space_results_for_round(&rounds.last().unwrap().round_id)
+        .await?;
+    let winner_of = |space_id| {
+        results
+            .iter()
+            .find(|r| r.space_id == space_id)
+            .map(|r| r.winner.username.as_str())
+    };
+
+    // Alice keeps only the space she can afford; Bob takes the rest.
+    assert_eq!(winner_of(space_a), Some("bob"));
+    assert_eq!(winner_of(space_b), Some("bob"));
+    assert_eq!(winner_of(space_c), Some("alice"));
+    let alice_total: Decimal = results
+        .iter()
+        .filter(|r| r.winner.username == "alice")
+        .map(|r| r.value)
+        .sum();
+    assert!(alice_total <= Decimal::new(4, 0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_user_value_and_rebid() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut space_details = test_helpers::space_details_a(site.site_id);
+    space_details.reserve_price = payloads::ReservePrice(Decimal::from(3));
+    let space_id = app.client.create_space(&space_details).await?;
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    // Alice's value is below the reserve, so round 0's proxy pass skips it
+    app.client
+        .create_or_update_user_value(&requests::UserValue {
+            space_id,
+            value: Decimal::from(2),
+        })
+        .await?;
+    app.client
+        .create_or_update_proxy_bidding(&requests::UseProxyBidding {
+            auction_id,
+            max_items: 1,
+            max_total: None,
+        })
+        .await?;
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round_id =
+        app.client.list_auction_rounds(&auction_id).await?[0].round_id;
+    assert_eq!(bid_count(&app.db_pool, &round_id, "alice").await?, 0);
+
+    // Raising the value bids right away, without another tick
+    app.client
+        .set_user_value_and_rebid(&requests::UserValue {
+            space_id,
+            value: Decimal::from(5),
+        })
+        .await?;
+    assert_eq!(bid_count(&app.db_pool, &round_id, "alice").await?, 1);
+    assert!(!needs_processing(&app.db_pool, &auction_id, "alice").await?);
+    assert_eq!(
+        app.client.get_user_value(&space_id).await?.value,
+        Decimal::from(5)
+    );
+
+    // Without proxy bidding it only stores the value
+    app.login_bob().await?;
+    app.client
+        .set_user_value_and_rebid(&requests::UserValue {
+            space_id,
+            value: Decimal::from(5),
+        })
+        .await?;
+    assert_eq!(bid_count(&app.db_pool, &round_id, "bob").await?, 0);
+
+    Ok(())
+}
+
+/// Proxy bidding opens the site's lead time (24 hours) before the auction
+/// starts and closes when round 0 ends, for changes and removal alike.
+#[tokio::test]
+async fn test_proxy_bidding_window() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+
+    let start_at = app.time_source.now() + Span::new().hours(48);
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(start_at);
+    auction_details.possession_start_at = start_at + Span::new().hours(1);
+    auction_details.possession_end_at = start_at + Span::new().hours(2);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    let opens_at = start_at - Span::new().hours(24);
+    let status = app.client.get_proxy_bidding(&auction_id).await?;
+    assert!(status.settings.is_none());
+    assert_eq!(status.window.opens_at, Some(opens_at));
+    assert_eq!(status.window.closes_at, None);
+
+    let details = requests::UseProxyBidding {
+        auction_id,
+        max_items: 1,
+        max_total: None,
+    };
+
+    // Just before the window opens
+    app.time_source.set(opens_at - Span::new().seconds(1));
+    assert_api_error(
+        app.client.create_or_update_proxy_bidding(&details).await,
+        ApiError::ProxyBiddingNotYetOpen { opens_at },
+    );
+
+    // Once it opens, registration, deletion, and changes are accepted
+    app.time_source.set(opens_at);
+    app.client.create_or_update_proxy_bidding(&details).await?;
+    app.client.delete_proxy_bidding(&auction_id).await?;
+    app.client.create_or_update_proxy_bidding(&details).await?;
+
+    // Still open during round 0
+    app.time_source.set(start_at);
+    scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let round_0 = &app.client.list_auction_rounds(&auction_id).await?[0];
+    let closes_at = round_0.round_details.end_at;
+    let status = app.client.get_proxy_bidding(&auction_id).await?;
+    assert_eq!(status.window.closes_at, Some(closes_at));
+
+    app.time_source.set(closes_at - Span::new().seconds(1));
+    app.client
+        .create_or_update_proxy_bidding(&requests::UseProxyBidding {
+            auction_id,
+            max_items: 2,
+            max_total: None,
+        })
+        .await?;
+
+    // Closed from the end of round 0
+    app.time_source.set(closes_at);
+    assert_api_error(
+        app.client.create_or_update_proxy_bidding(&details).await,
+        ApiError::ProxyBiddingClosed,
+    );
+    assert_api_error(
+        app.client.delete_proxy_bidding(&auction_id).await,
+        ApiError::ProxyBiddingClosed,
+    );
+    let status = app.client.get_proxy_bidding(&auction_id).await?;
+    assert_eq!(status.settings.unwrap().max_items, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_proxy_bidding_max_items_range() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+    // Unavailable spaces can't be won, so they don't raise the limit
+    let mut unavailable = test_helpers::space_details_c(site.site_id);
+    unavailable.is_available = false;
+    app.client.create_space(&unavailable).await?;
+
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at = Some(app.time_source.now());
+    let auction_id = app.client.create_auction(&auction_details).await?;
+
+    let with_max_items = |max_items| requests::UseProxyBidding {
+        auction_id,
+        max_items,
+        max_total: None,
+    };
+    for max_items in [0, -1, 3] {
+        assert_api_error(
+            app.client
+                .create_or_update_proxy_bidding(&with_max_items(max_items))
+                .await,
+            ApiError::InvalidMaxItems { available: 2 },
+        );
+    }
+    let status = app.client.get_proxy_bidding(&auction_id).await?;
+    assert!(status.settings.is_none());
+
+    app.client
+        .create_or_update_proxy_bidding(&with_max_items(2))
+        .await?;
+    let status = app.client.get_proxy_bidding(&auction_id).await?;
+    assert_eq!(status.settings.unwrap().max_items, 2);
+
+    Ok(())
+}
@@ -223,6 +223,7 @@ async fn bids_changed_event_emitted_for_proxy_bidder() -> anyhow::Result<()> {
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
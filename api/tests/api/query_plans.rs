@@ -0,0 +1,224 @@
+//! Guards the indexes behind hot lookups: seeds a few thousand rows into the
+//! tables involved, then checks that the planner reaches them through an
+//! index rather than a sequential scan.
+//!
+//! The queries mirror the store functions noted above each one. When one of
+//! those changes shape, update the copy here so the plan keeps being checked.
+
+use sqlx::{PgPool, Postgres, postgres::PgArguments, query::QueryScalar};
+use test_helpers::spawn_app;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn hot_queries_use_indexes() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+    seed(
+        &app.db_pool,
+        site.site_id,
+        space.space_id,
+        auction.auction_id,
+    )
+    .await?;
+
+    let pool = &app.db_pool;
+    let round_id: Uuid = sqlx::query_scalar(
+        "SELECT id FROM auction_rounds WHERE auction_id = $1 LIMIT 1",
+    )
+    .bind(auction.auction_id)
+    .fetch_one(pool)
+    .await?;
+    let user_id: Uuid =
+        sqlx::query_scalar("SELECT id FROM users WHERE username = 'seed1'")
+            .fetch_one(pool)
+            .await?;
+
+    // store::list_bids, scheduler eligibility updates
+    let plan = explain(
+        sqlx::query_scalar(
+            "EXPLAIN SELECT * FROM bids WHERE round_id = $1 AND user_id = $2",
+        )
+        .bind(round_id)
+        .bind(user_id),
+        pool,
+    )
+    .await?;
+    assert_no_seq_scan(&plan, "bids");
+
+    // store::list_round_space_results_for_round
+    let plan = explain(
+        sqlx::query_scalar(
+            "EXPLAIN SELECT * FROM round_space_results WHERE round_id = $1",
+        )
+        .bind(round_id),
+        pool,
+    )
+    .await?;
+    assert_no_seq_scan(&plan, "round_space_results");
+
+    // store::get_eligibility, scheduler eligibility updates
+    let plan = explain(
+        sqlx::query_scalar(
+            "EXPLAIN SELECT eligibility FROM user_eligibilities
+            WHERE round_id = $1 AND user_id = $2",
+        )
+        .bind(round_id)
+        .bind(user_id),
+        pool,
+    )
+    .await?;
+    assert_no_seq_scan(&plan, "user_eligibilities");
+
+    // store::get_received_invites
+    let plan = explain(
+        sqlx::query_scalar(
+            "EXPLAIN SELECT a.*, b.name as community_name
+            FROM community_invites a
+            JOIN communities b ON a.community_id = b.id
+            WHERE a.email_normalized = lower($1)",
+        )
+        .bind("Invitee1@example.com"),
+        pool,
+    )
+    .await?;
+    assert_no_seq_scan(&plan, "community_invites");
+
+    // store::update_is_active_from_schedule
+    let plan = explain(
+        sqlx::query_scalar(
+            "EXPLAIN SELECT 1 FROM community_membership_schedule a
+            WHERE
+                a.email_normalized = $1
+                AND a.community_id = $2
+                AND a.start_at <= now()
+                AND a.end_at > now()",
+        )
+        .bind("scheduled1@example.com")
+        .bind(community_id),
+        pool,
+    )
+    .await?;
+    assert_no_seq_scan(&plan, "community_membership_schedule");
+
+    // store::list_auctions
+    let plan = explain(
+        sqlx::query_scalar(
+            "EXPLAIN SELECT * FROM auctions WHERE site_id = $1
+            ORDER BY start_at DESC",
+        )
+        .bind(site.site_id),
+        pool,
+    )
+    .await?;
+    assert_no_seq_scan(&plan, "auctions");
+
+    Ok(())
+}
+
+/// Fill the tables behind the checked queries with enough rows that a
+/// sequential scan is no longer the cheap option, spread so each lookup
+/// matches only a small slice.
+async fn seed(
+    pool: &PgPool,
+    site_id: payloads::SiteId,
+    space_id: payloads::SpaceId,
+    auction_id: payloads::AuctionId,
+) -> anyhow::Result<()> {
+    let statements = [
+        // 50 users
+        "INSERT INTO users (
+            username, email, password_hash, email_verified, created_at,
+            updated_at
+        )
+        SELECT 'seed' || i, 'seed' || i || '@example.com', 'x', true, now(),
+            now()
+        FROM generate_series(1, 50) i",
+        // 100 more sites alongside the test site, then 30 auctions on each
+        "INSERT INTO sites (
+            community_id, name, default_auction_params_id, possession_period,
+            auction_lead_time, proxy_bidding_lead_time, auto_schedule,
+            created_at, updated_at
+        )
+        SELECT community_id, 'seed site ' || i, default_auction_params_id,
+            possession_period, auction_lead_time, proxy_bidding_lead_time,
+            false, now(), now()
+        FROM sites, generate_series(1, 100) i
+        WHERE id = $1",
+        "INSERT INTO auctions (
+            site_id, possession_start_at, possession_end_at, start_at,
+            auction_params_id, random_seed, created_at, updated_at
+        )
+        SELECT s.id, now(), now() + interval '1 day',
+            now() + i * interval '1 day', p.auction_params_id, i, now(), now()
+        FROM sites s, generate_series(1, 30) i,
+            (SELECT auction_params_id FROM auctions WHERE id = $3) p",
+        // 100 rounds of the test auction and 30 spaces on the test site
+        "INSERT INTO auction_rounds (
+            auction_id, round_num, start_at, end_at, eligibility_threshold,
+            created_at, updated_at
+        )
+        SELECT $3, i, now(), now(), 0.5, now(), now()
+        FROM generate_series(0, 99) i",
+        "INSERT INTO spaces (
+            site_id, name, eligibility_points, created_at, updated_at
+        )
+        SELECT $1, 'seed space ' || i, 1, now(), now()
+        FROM generate_series(1, 30) i",
+        // A bid and an eligibility per seeded user per round: 5000 each
+        "INSERT INTO bids (space_id, round_id, user_id, created_at, updated_at)
+        SELECT $2, r.id, u.id, now(), now()
+        FROM auction_rounds r, users u
+        WHERE r.auction_id = $3 AND u.username LIKE 'seed%'",
+        "INSERT INTO user_eligibilities (user_id, round_id, eligibility)
+        SELECT u.id, r.id, 10
+        FROM auction_rounds r, users u
+        WHERE r.auction_id = $3 AND u.username LIKE 'seed%'",
+        // A result per seeded space per round: 3000
+        "INSERT INTO round_space_results (
+            space_id, round_id, winning_user_id, value
+        )
+        SELECT s.id, r.id, (SELECT id FROM users WHERE username = 'seed1'), 1
+        FROM auction_rounds r, spaces s
+        WHERE r.auction_id = $3 AND s.name LIKE 'seed space%'",
+        // 3000 pending invites and scheduled members
+        "INSERT INTO community_invites (community_id, email, single_use,
+            created_at)
+        SELECT community_id, 'invitee' || i || '@example.com', true, now()
+        FROM sites, generate_series(1, 3000) i
+        WHERE id = $1",
+        "INSERT INTO community_membership_schedule (
+            community_id, start_at, end_at, email, created_at, updated_at
+        )
+        SELECT community_id, now(), now() + interval '1 day',
+            'scheduled' || i || '@example.com', now(), now()
+        FROM sites, generate_series(1, 3000) i
+        WHERE id = $1",
+    ];
+    for statement in statements {
+        sqlx::query(statement)
+            .bind(site_id)
+            .bind(space_id)
+            .bind(auction_id)
+            .execute(pool)
+            .await?;
+    }
+    sqlx::query("ANALYZE").execute(pool).await?;
+    Ok(())
+}
+
+async fn explain(
+    query: QueryScalar<'_, Postgres, String, PgArguments>,
+    pool: &PgPool,
+) -> anyhow::Result<String> {
+    Ok(query.fetch_all(pool).await?.join("\n"))
+}
+
+fn assert_no_seq_scan(plan: &str, table: &str) {
+    assert!(
+        !plan.contains(&format!("Seq Scan on {table}")),
+        "expected an index on {table} to be used, got plan:\n{plan}"
+    );
+}
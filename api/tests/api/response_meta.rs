@@ -0,0 +1,119 @@
+use payloads::{
+    APIClient, ApiError, ClientError, ClientObserver, REQUEST_ID_HEADER,
+};
+use reqwest::StatusCode;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use test_helpers::spawn_app;
+
+#[tokio::test]
+async fn rate_limited_error_carries_retry_after() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    app.client.export_my_data().await?;
+
+    let err = app.client.export_my_data().await.unwrap_err();
+    let ClientError::Api(meta, ApiError::DataExportRateLimited) = &err else {
+        panic!("expected a rate limit error, got {err:?}");
+    };
+    assert_eq!(meta.status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(meta.retry_after, Some(Duration::from_secs(60 * 60)));
+    assert!(meta.request_id.is_some());
+    assert_eq!(app.client.last_response_meta().as_ref(), err.meta());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_id_is_echoed_or_assigned() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+
+    // Without a client id the server assigns a fresh one per request
+    assert!(app.client.last_response_meta().is_none());
+    app.client.health_check().await?;
+    let first = app.client.last_response_meta().unwrap();
+    assert_eq!(first.status, StatusCode::OK);
+    assert_eq!(first.retry_after, None);
+    app.client.health_check().await?;
+    let second = app.client.last_response_meta().unwrap();
+    assert!(first.request_id.is_some());
+    assert_ne!(first.request_id, second.request_id);
+
+    // A client-supplied id is echoed back
+    let response = app
+        .client
+        .inner_client
+        .get(format!("{}/api/health_check", app.client.address))
+        .header(REQUEST_ID_HEADER, "trace-abc-123")
+        .send()
+        .await?;
+    assert_eq!(
+        response.headers().get(REQUEST_ID_HEADER).unwrap(),
+        "trace-abc-123"
+    );
+
+    Ok(())
+}
+
+/// Records every observer callback as a line of text.
+#[derive(Default)]
+struct RecordingObserver(Mutex<Vec<String>>);
+
+impl ClientObserver for RecordingObserver {
+    fn on_request(&self, path: &str) {
+        self.0.lock().unwrap().push(format!("request {path}"));
+    }
+
+    fn on_response(&self, path: &str, status: StatusCode, _: Duration) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("response {path} {status}"));
+    }
+
+    fn on_error(&self, path: &str, error: &ClientError) {
+        let status = error.meta().map(|meta| meta.status);
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("error {path} {status:?}"));
+    }
+}
+
+#[tokio::test]
+async fn observer_sees_requests_responses_and_errors() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let observer = Arc::new(RecordingObserver::default());
+    let client =
+        APIClient::new(app.client.address.clone(), reqwest::Client::new())
+            .with_observer(observer.clone());
+
+    client.health_check().await?;
+    client.platform_stats().await?;
+    assert_eq!(
+        *observer.0.lock().unwrap(),
+        [
+            "request health_check",
+            "response health_check 200 OK",
+            "request platform_stats",
+            "response platform_stats 200 OK",
+        ]
+    );
+    observer.0.lock().unwrap().clear();
+
+    // Break the query behind platform_stats to force a 500
+    sqlx::query("ALTER TABLE auctions RENAME TO auctions_renamed")
+        .execute(&app.db_pool)
+        .await?;
+    assert!(client.platform_stats().await.is_err());
+    assert_eq!(
+        *observer.0.lock().unwrap(),
+        [
+            "request platform_stats",
+            "response platform_stats 500 Internal Server Error",
+            "error platform_stats Some(500)",
+        ]
+    );
+
+    Ok(())
+}
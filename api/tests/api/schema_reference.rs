@@ -132,7 +132,7 @@ async fn describe_schema(pool: &PgPool) -> anyhow::Result<SchemaDescription> {
          JOIN pg_attribute a ON a.attrelid = c.oid
          LEFT JOIN pg_attrdef d
              ON d.adrelid = c.oid AND d.adnum = a.attnum
-         WHERE n.nspname = 'public'
+         WHERE n.nspname = current_schema()
            AND c.relkind = 'r'
            AND a.attnum > 0
            AND NOT a.attisdropped",
@@ -188,7 +188,7 @@ async fn describe_schema(pool: &PgPool) -> anyhow::Result<SchemaDescription> {
          FROM pg_constraint con
          JOIN pg_class c ON c.oid = con.conrelid
          JOIN pg_namespace n ON n.oid = c.relnamespace
-         WHERE n.nspname = 'public'",
+         WHERE n.nspname = current_schema()",
     )
     .fetch_all(pool)
     .await?;
@@ -213,7 +213,7 @@ async fn describe_schema(pool: &PgPool) -> anyhow::Result<SchemaDescription> {
          JOIN pg_namespace n ON n.oid = i.relnamespace
          JOIN pg_index idx ON idx.indexrelid = i.oid
          JOIN pg_class t ON t.oid = idx.indrelid
-         WHERE n.nspname = 'public'
+         WHERE n.nspname = current_schema()
            AND i.relkind = 'i'
            AND t.relname <> ALL($1)
            AND NOT EXISTS (
@@ -237,7 +237,7 @@ async fn describe_schema(pool: &PgPool) -> anyhow::Result<SchemaDescription> {
          FROM pg_type t
          JOIN pg_namespace n ON n.oid = t.typnamespace
          JOIN pg_enum e ON e.enumtypid = t.oid
-         WHERE n.nspname = 'public'
+         WHERE n.nspname = current_schema()
          GROUP BY t.typname",
     )
     .fetch_all(pool)
@@ -261,7 +261,7 @@ async fn describe_schema(pool: &PgPool) -> anyhow::Result<SchemaDescription> {
          FROM pg_type t
          JOIN pg_namespace n ON n.oid = t.typnamespace
          LEFT JOIN pg_constraint con ON con.contypid = t.oid
-         WHERE n.nspname = 'public'
+         WHERE n.nspname = current_schema()
            AND t.typtype = 'd'
          GROUP BY t.typname, t.typbasetype, t.typtypmod, t.typnotnull",
     )
@@ -126,3 +126,31 @@ async fn test_platform_stats_has_cache_headers() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn cors_preflight_is_cacheable() -> Result<()> {
+    let origin = "https://app.example.com";
+    let app = test_helpers::spawn_app_with_cors(
+        origin,
+        jiff::SignedDuration::from_mins(10),
+    )
+    .await;
+
+    let url = format!("{}/api/login", app.client.address);
+    let response = app
+        .client
+        .inner_client
+        .request(reqwest::Method::OPTIONS, &url)
+        .header("origin", origin)
+        .header("access-control-request-method", "POST")
+        .header("access-control-request-headers", "content-type")
+        .send()
+        .await?;
+
+    assert!(response.status().is_success());
+    let headers = response.headers();
+    assert_eq!(headers.get("access-control-allow-origin").unwrap(), origin);
+    assert_eq!(headers.get("access-control-max-age").unwrap(), "600");
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use payloads::{ApiError, ReservePrice};
+use payloads::{ApiError, ReservePrice, requests, responses};
 use rust_decimal::Decimal;
 use test_helpers::spawn_app;
 
@@ -18,7 +18,12 @@ async fn create_read_update_delete_site() -> anyhow::Result<()> {
     assert!(soft_deleted_site.deleted_at.is_some());
 
     // Test hard delete - site should no longer be accessible
-    app.client.delete_site(&site_id).await?;
+    app.client
+        .delete_site(&requests::DeleteSite {
+            site_id,
+            confirm: true,
+        })
+        .await?;
     assert!(
         app.client
             .get_site(&site_id)
@@ -31,6 +36,151 @@ async fn create_read_update_delete_site() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn outsiders_cannot_tell_sites_exist() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    // Charlie shares no community with Alice, so her site looks the same to
+    // him as one that doesn't exist
+    app.create_charlie_user().await?;
+    app.login_charlie().await?;
+    test_helpers::assert_api_error(
+        app.client.get_site(&site.site_id).await,
+        ApiError::SiteNotFound,
+    );
+    test_helpers::assert_api_error(
+        app.client
+            .get_site(&payloads::SiteId(uuid::Uuid::new_v4()))
+            .await,
+        ApiError::SiteNotFound,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_site_requires_confirmation() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    app.client
+        .create_or_update_user_value(&requests::UserValue {
+            space_id: space.space_id,
+            value: Decimal::new(10, 0),
+        })
+        .await?;
+    // Scheduled for tomorrow, so pending but not active
+    let mut auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    auction_details.start_at =
+        Some(app.time_source.now() + jiff::Span::new().hours(24));
+    app.client.create_auction(&auction_details).await?;
+
+    // Omitting confirmation reports what would go and deletes nothing
+    let result = app
+        .client
+        .delete_site(&requests::DeleteSite {
+            site_id: site.site_id,
+            confirm: false,
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SiteDeletionNotConfirmed(responses::SiteDependencies {
+            spaces: 1,
+            concluded_auctions: 0,
+            pending_auctions: 1,
+            images: 0,
+            user_values: 1,
+        }),
+    );
+    app.client.get_site(&site.site_id).await?;
+    assert_eq!(app.client.list_spaces(&site.site_id).await?.len(), 1);
+
+    // Confirmed, it goes through
+    app.client
+        .delete_site(&requests::DeleteSite {
+            site_id: site.site_id,
+            confirm: true,
+        })
+        .await?;
+    test_helpers::assert_api_error(
+        app.client.get_site(&site.site_id).await,
+        ApiError::SiteNotFound,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_site_refused_during_active_auction() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    app.create_test_space(&site.site_id).await?;
+    // Starts now
+    app.create_test_auction(&site.site_id).await?;
+
+    let result = app
+        .client
+        .delete_site(&requests::DeleteSite {
+            site_id: site.site_id,
+            confirm: true,
+        })
+        .await;
+    test_helpers::assert_api_error(result, ApiError::SiteHasActiveAuction);
+    app.client.get_site(&site.site_id).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn display_strings_are_sanitized() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+
+    // Zero-width characters and surrounding whitespace are dropped
+    let mut site = test_helpers::site_details_b(community_id);
+    site.name = "Community\u{200B}  Garden\n".into();
+    site.description = Some("Beds and paths\r\n\u{FEFF}".into());
+    let site_id = app.client.create_site(&site.into()).await?;
+    let stored = app.client.get_site(&site_id).await?.site_details;
+    assert_eq!(stored.name, "Community Garden");
+    assert_eq!(stored.description.as_deref(), Some("Beds and paths"));
+
+    // An emoji-only name survives
+    let mut space = test_helpers::space_details_a(site_id);
+    space.name = "🌻🌱".into();
+    let space_id = app.client.create_space(&space).await?;
+    assert_eq!(
+        app.client.get_space(&space_id).await?.space_details.name,
+        "🌻🌱"
+    );
+
+    // Control characters are rejected rather than stripped
+    let result = app
+        .client
+        .update_community_details(&requests::UpdateCommunityDetails {
+            community_id,
+            name: "Garden\u{7}".into(),
+            description: None,
+            description_public: None,
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::ControlCharacters {
+            field: "Community name".into(),
+        },
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn create_read_update_delete_space() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -63,6 +213,104 @@ async fn create_read_update_delete_space() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn list_spaces_with_values() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut space_ids = Vec::new();
+    for name in ["a", "b"] {
+        let mut details = test_helpers::space_details_a(site.site_id);
+        details.name = name.into();
+        space_ids.push(app.client.create_space(&details).await?);
+    }
+
+    app.client
+        .create_or_update_user_value(&requests::UserValue {
+            space_id: space_ids[0],
+            value: Decimal::from(5),
+        })
+        .await?;
+    // Another member's value doesn't show up in Alice's listing
+    app.login_bob().await?;
+    app.client
+        .create_or_update_user_value(&requests::UserValue {
+            space_id: space_ids[1],
+            value: Decimal::from(7),
+        })
+        .await?;
+    app.login_alice().await?;
+
+    let spaces = app.client.list_spaces_with_values(&site.site_id).await?;
+    let values: Vec<_> =
+        spaces.iter().map(|s| (s.space.space_id, s.value)).collect();
+    assert_eq!(
+        values,
+        vec![(space_ids[0], Some(Decimal::from(5))), (space_ids[1], None)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reorder_spaces() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let mut space_ids = Vec::new();
+    for name in ["b", "c", "a"] {
+        let mut details = test_helpers::space_details_a(site.site_id);
+        details.name = name.into();
+        space_ids.push(app.client.create_space(&details).await?);
+    }
+    let listed_ids = |spaces: Vec<responses::Space>| -> Vec<_> {
+        spaces.into_iter().map(|s| s.space_id).collect()
+    };
+
+    // Until a site is reordered its spaces list in the order they were
+    // created, not by name
+    let spaces = app.client.list_spaces(&site.site_id).await?;
+    assert_eq!(listed_ids(spaces), space_ids);
+
+    let (b, c, a) = (space_ids[0], space_ids[1], space_ids[2]);
+    let before = app.client.get_space(&a).await?.updated_at;
+    app.time_source.advance(jiff::Span::new().minutes(1));
+    app.client
+        .reorder_spaces(&requests::ReorderSpaces {
+            site_id: site.site_id,
+            ordered_space_ids: vec![c, a, b],
+        })
+        .await?;
+    let spaces = app.client.list_spaces(&site.site_id).await?;
+    assert_eq!(listed_ids(spaces), vec![c, a, b]);
+
+    // Reordering counts as a change to each space
+    let after = app.client.get_space(&a).await?.updated_at;
+    assert_eq!(after, app.time_source.now());
+    assert!(after > before);
+
+    // A space created afterwards goes last, not by its name
+    let mut details = test_helpers::space_details_a(site.site_id);
+    details.name = "0".into();
+    let d = app.client.create_space(&details).await?;
+    let spaces = app.client.list_spaces(&site.site_id).await?;
+    assert_eq!(listed_ids(spaces), vec![c, a, b, d]);
+
+    // The order must name every space exactly once
+    for ordered_space_ids in [vec![c, a, b], vec![c, a, b, d, d]] {
+        let result = app
+            .client
+            .reorder_spaces(&requests::ReorderSpaces {
+                site_id: site.site_id,
+                ordered_space_ids,
+            })
+            .await;
+        test_helpers::assert_api_error(result, ApiError::InvalidSpaceOrder);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn create_read_update_delete_site_image() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -153,6 +401,44 @@ async fn site_image_permissions_require_coleader() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn oversized_bodies_are_rejected() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+
+    // Past the default limit on an ordinary route
+    let mut site = test_helpers::site_details_a(community_id);
+    site.description = Some("x".repeat(api::DEFAULT_MAX_BODY_SIZE));
+    let result = app.client.create_site(&site.into()).await;
+    test_helpers::assert_status_code(
+        result,
+        reqwest::StatusCode::PAYLOAD_TOO_LARGE,
+    );
+
+    // Image routes accept more, so an image just over the size limit gets
+    // past the body limit and is rejected by validation instead
+    let mut body = test_helpers::site_image_details_a(community_id);
+    body.image_data = vec![255; payloads::MAX_IMAGE_SIZE + 1];
+    let result = app.client.create_site_image(&body).await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::ImageTooLarge {
+            size: payloads::MAX_IMAGE_SIZE + 1,
+        },
+    );
+
+    // Past the image body limit
+    body.image_data = vec![255; api::DEFAULT_MAX_IMAGE_BODY_SIZE / 4 + 1];
+    let result = app.client.create_site_image(&body).await;
+    test_helpers::assert_status_code(
+        result,
+        reqwest::StatusCode::PAYLOAD_TOO_LARGE,
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn site_image_unique_names_per_community() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -170,6 +456,305 @@ async fn site_image_unique_names_per_community() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn site_inherits_community_default_params() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+
+    let site_details = test_helpers::site_details_b(community_id);
+    let mut community_params = site_details.default_auction_params.clone();
+    let site_details = payloads::requests::SiteDetails {
+        default_auction_params: None,
+        ..site_details.into()
+    };
+
+    // Without a community default there is nothing to inherit
+    let result = app.client.create_site(&site_details).await;
+    test_helpers::assert_api_error(result, ApiError::NoDefaultAuctionParams);
+    assert_eq!(
+        app.client
+            .get_community_default_params(&community_id)
+            .await?,
+        None
+    );
+
+    community_params.bid_increment = payloads::BidIncrement(Decimal::new(5, 0));
+    app.client
+        .set_community_default_params(
+            &payloads::requests::SetCommunityDefaultParams {
+                community_id,
+                params: Some(community_params.clone()),
+            },
+        )
+        .await?;
+    assert_eq!(
+        app.client
+            .get_community_default_params(&community_id)
+            .await?
+            .map(|p| p.bid_increment),
+        Some(community_params.bid_increment)
+    );
+
+    let site_id = app.client.create_site(&site_details).await?;
+    let site = app.client.get_site(&site_id).await?;
+    assert_eq!(
+        site.site_details.default_auction_params.bid_increment,
+        community_params.bid_increment
+    );
+
+    // Clearing the community default leaves existing sites untouched
+    app.client
+        .set_community_default_params(
+            &payloads::requests::SetCommunityDefaultParams {
+                community_id,
+                params: None,
+            },
+        )
+        .await?;
+    let site = app.client.get_site(&site_id).await?;
+    assert_eq!(
+        site.site_details.default_auction_params.bid_increment,
+        community_params.bid_increment
+    );
+
+    // An update that omits the params keeps the site's own
+    let updated = app
+        .client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id,
+            site_details: payloads::requests::SiteDetails {
+                name: "renamed site".into(),
+                ..site_details.clone()
+            },
+        })
+        .await?;
+    assert_eq!(updated.site_details.name, "renamed site");
+    assert_eq!(
+        updated.site_details.default_auction_params,
+        site.site_details.default_auction_params
+    );
+
+    // Only coleaders can set the default
+    app.create_bob_user().await?;
+    app.invite_bob().await?;
+    app.login_bob().await?;
+    app.accept_invite().await?;
+    let result = app
+        .client
+        .set_community_default_params(
+            &payloads::requests::SetCommunityDefaultParams {
+                community_id,
+                params: Some(community_params),
+            },
+        )
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::RequiresColeaderPermissions,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_image_from_same_community_accepted() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site_image = app.create_test_site_image(&community_id).await?;
+
+    let mut site_details = test_helpers::site_details_b(community_id);
+    site_details.site_image_id = Some(site_image.id);
+    let site_id = app.client.create_site(&site_details.into()).await?;
+    let site = app.client.get_site(&site_id).await?;
+    assert_eq!(site.site_details.site_image_id, Some(site_image.id));
+
+    let mut space_details = test_helpers::space_details_a(site_id);
+    space_details.site_image_id = Some(site_image.id);
+    let space_id = app.client.create_space(&space_details).await?;
+    let space = app.client.get_space(&space_id).await?;
+    assert_eq!(space.space_details.site_image_id, Some(site_image.id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_image_from_other_community_rejected() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let other_community_id = app.create_test_community().await?;
+    let foreign_image = app.create_test_site_image(&other_community_id).await?;
+
+    // Sites
+    let mut site_details = test_helpers::site_details_b(community_id);
+    site_details.site_image_id = Some(foreign_image.id);
+    let result = app.client.create_site(&site_details.clone().into()).await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SiteImageCommunityMismatch,
+    );
+
+    let site = app.create_test_site(&community_id).await?;
+    let result = app
+        .client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site_details.clone().into(),
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SiteImageCommunityMismatch,
+    );
+
+    // Spaces
+    let mut space_details = test_helpers::space_details_b(site.site_id);
+    space_details.site_image_id = Some(foreign_image.id);
+    let result = app.client.create_space(&space_details).await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SiteImageCommunityMismatch,
+    );
+
+    let space = app.create_test_space(&site.site_id).await?;
+    let result = app
+        .client
+        .update_space(&payloads::requests::UpdateSpace {
+            space_id: space.space_id,
+            space_details: space_details.clone(),
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SiteImageCommunityMismatch,
+    );
+
+    let result = app
+        .client
+        .update_spaces(&payloads::requests::UpdateSpaces {
+            spaces: vec![payloads::requests::UpdateSpace {
+                space_id: space.space_id,
+                space_details,
+            }],
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SiteImageCommunityMismatch,
+    );
+
+    // Nothing was written
+    let site = app.client.get_site(&site.site_id).await?;
+    assert_eq!(site.site_details.site_image_id, None);
+    let space = app.client.get_space(&space.space_id).await?;
+    assert_eq!(space.space_details.site_image_id, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_op_updates_bump_updated_at() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let site_image = app.create_test_site_image(&community_id).await?;
+
+    app.time_source.advance(jiff::Span::new().minutes(1));
+
+    // Resubmit every resource unchanged
+    let updated_site = app
+        .client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site.site_details.clone().into(),
+        })
+        .await?;
+    assert!(updated_site.updated_at > site.updated_at);
+
+    let results = app
+        .client
+        .update_spaces(&payloads::requests::UpdateSpaces {
+            spaces: vec![payloads::requests::UpdateSpace {
+                space_id: space.space_id,
+                space_details: space.space_details.clone(),
+            }],
+        })
+        .await?;
+    assert!(results[0].space.updated_at > space.updated_at);
+
+    let updated_image = app
+        .client
+        .update_site_image(&payloads::requests::UpdateSiteImage {
+            id: site_image.id,
+            name: None,
+        })
+        .await?;
+    assert_eq!(updated_image.name, site_image.name);
+    assert!(updated_image.updated_at > site_image.updated_at);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_getters_send_cache_validators() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let site_image = app.create_test_site_image(&community_id).await?;
+
+    let fetch_validators = |path: &'static str, body: serde_json::Value| {
+        let request = app
+            .client
+            .inner_client
+            .post(format!("{}/api/{path}", app.client.address))
+            .json(&body);
+        async move {
+            let response = request.send().await?;
+            assert!(response.status().is_success());
+            let header =
+                |name| response.headers()[name].to_str().unwrap().to_string();
+            anyhow::Ok((header("last-modified"), header("etag")))
+        }
+    };
+
+    let site_before =
+        fetch_validators("get_site", serde_json::json!(site.site_id)).await?;
+    let image_before =
+        fetch_validators("get_site_image", serde_json::json!(site_image.id))
+            .await?;
+
+    app.time_source.advance(jiff::Span::new().minutes(1));
+    app.client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site.site_details.clone().into(),
+        })
+        .await?;
+    app.client
+        .update_site_image(&payloads::requests::UpdateSiteImage {
+            id: site_image.id,
+            name: None,
+        })
+        .await?;
+
+    let site_after =
+        fetch_validators("get_site", serde_json::json!(site.site_id)).await?;
+    let image_after =
+        fetch_validators("get_site_image", serde_json::json!(site_image.id))
+            .await?;
+    assert_ne!(site_before.0, site_after.0);
+    assert_ne!(site_before.1, site_after.1);
+    assert_ne!(image_before.0, image_after.0);
+    assert_ne!(image_before.1, image_after.1);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn list_sites() -> anyhow::Result<()> {
     let app = spawn_app().await;
@@ -192,19 +777,22 @@ async fn list_sites() -> anyhow::Result<()> {
             round_duration: jiff::Span::new().hours(2), // Different duration
             bid_increment: payloads::BidIncrement(Decimal::new(200, 2)), // $2
             activity_rule_params: payloads::ActivityRuleParams {
-                eligibility_progression: vec![(1, 0.8)], /* 80% eligibility
-                                                          * required */
+                // 80% eligibility required, then 100% from round 10
+                eligibility_progression: vec![(0, 0.8), (10, 1.0)],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
         possession_period: jiff::Span::new().days(14), // 14 days
-        auction_lead_time: jiff::Span::new().days(3),  // 3 days
+        auction_lead_time: jiff::Span::new().hours(72), // 3 days
         proxy_bidding_lead_time: jiff::Span::new().hours(12), // 12 hours
         open_hours: None,
         auto_schedule: false,
         timezone: Some("America/New_York".to_string()),
         site_image_id: None,
     };
-    let site2_id = app.client.create_site(&site2_details).await?;
+    let site2_id = app.client.create_site(&site2_details.into()).await?;
     let site2 = app.client.get_site(&site2_id).await?;
 
     // List all sites
@@ -290,6 +878,41 @@ async fn space_name_must_be_unique() -> anyhow::Result<()> {
         err
     );
 
+    // Renaming another space onto the taken name is rejected too
+    let space2_details = payloads::Space {
+        name: "Other Name".to_string(),
+        ..space2_details
+    };
+    let space2 = app.client.create_space(&space2_details).await?;
+    let result = app
+        .client
+        .update_space(&payloads::requests::UpdateSpace {
+            space_id: space2,
+            space_details: payloads::Space {
+                name: "Duplicate Name".to_string(),
+                ..space2_details
+            },
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::SpaceNameNotUnique {
+            name: "Duplicate Name".to_string(),
+        },
+    );
+
+    // The same name is fine in a different site
+    let other_site = app
+        .client
+        .create_site(&test_helpers::site_details_b(community_id).into())
+        .await?;
+    app.client
+        .create_space(&payloads::Space {
+            site_id: other_site,
+            ..space1_details
+        })
+        .await?;
+
     Ok(())
 }
 
@@ -500,3 +1123,497 @@ async fn space_copy_on_write_with_auction_history() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn site_timezone_required_with_auto_schedule_or_open_hours()
+-> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let open_hours = test_helpers::site_details_b(community_id).open_hours;
+
+    // Without a timezone, only a site with neither auto_schedule nor open
+    // hours is allowed; otherwise the error names the dependent field.
+    let cases = [
+        (false, false, None),
+        (true, false, Some("auto_schedule")),
+        (false, true, Some("open_hours")),
+        (true, true, Some("auto_schedule")),
+    ];
+    for (i, (auto_schedule, with_open_hours, expected_field)) in
+        cases.into_iter().enumerate()
+    {
+        let mut details = test_helpers::site_details_b(community_id);
+        details.name = format!("site {i}");
+        details.auto_schedule = auto_schedule;
+        details.open_hours =
+            with_open_hours.then(|| open_hours.clone().unwrap());
+        details.timezone = None;
+        let result = app.client.create_site(&details.into()).await;
+        match expected_field {
+            None => {
+                result?;
+            }
+            Some(field) => test_helpers::assert_api_error(
+                result,
+                ApiError::TimezoneRequired {
+                    field: field.to_string(),
+                },
+            ),
+        }
+    }
+
+    // The same rule applies when updating an existing site.
+    let site = app.create_test_site(&community_id).await?;
+    let mut site_details = site.site_details.clone();
+    site_details.timezone = None;
+    let result = app
+        .client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: site_details.into(),
+        })
+        .await;
+    test_helpers::assert_api_error(
+        result,
+        ApiError::TimezoneRequired {
+            field: "auto_schedule".to_string(),
+        },
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_configuration_warnings_for_legacy_rows() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    assert!(site.configuration_warnings.is_empty());
+
+    // Simulate a row written before the timezone rule existed.
+    sqlx::query("UPDATE sites SET timezone = NULL WHERE id = $1")
+        .bind(site.site_id)
+        .execute(&app.db_pool)
+        .await?;
+
+    // Reads still succeed, and moderators see a warning.
+    let site = app.client.get_site(&site.site_id).await?;
+    assert_eq!(
+        site.configuration_warnings,
+        vec!["auto_schedule is set but the site has no timezone".to_string()]
+    );
+    let sites = app.client.list_sites(&community_id).await?;
+    assert_eq!(sites[0].configuration_warnings.len(), 1);
+
+    // Plain members don't.
+    app.login_bob().await?;
+    let site = app.client.get_site(&site.site_id).await?;
+    assert!(site.configuration_warnings.is_empty());
+    let sites = app.client.list_sites(&community_id).await?;
+    assert!(sites[0].configuration_warnings.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn open_hours_exceptions_round_trip() -> anyhow::Result<()> {
+    use jiff::civil::date;
+
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+
+    let closed = payloads::OpenHoursException {
+        date: date(2026, 12, 25),
+        closed: true,
+        open_time: None,
+        close_time: None,
+    };
+    let special_opening = payloads::OpenHoursException {
+        date: date(2026, 12, 26),
+        closed: false,
+        open_time: Some("12:00".parse()?),
+        close_time: Some("15:00".parse()?),
+    };
+    let mut site_details = test_helpers::site_details_b(community_id);
+    let open_hours = site_details.open_hours.as_mut().unwrap();
+    open_hours.exceptions = vec![special_opening.clone(), closed.clone()];
+
+    // Exceptions come back in date order
+    let site_id = app.client.create_site(&site_details.clone().into()).await?;
+    let site = app.client.get_site(&site_id).await?;
+    let open_hours = site.site_details.open_hours.unwrap();
+    assert_eq!(open_hours.exceptions, vec![closed.clone(), special_opening]);
+    assert_eq!(open_hours.hours_on(date(2026, 12, 25)), None);
+
+    // A closed exception can't carry hours
+    let mut invalid = site_details.clone();
+    invalid.open_hours.as_mut().unwrap().exceptions =
+        vec![payloads::OpenHoursException {
+            open_time: Some("09:00".parse()?),
+            ..closed.clone()
+        }];
+    test_helpers::assert_api_error(
+        app.client
+            .update_site(&payloads::requests::UpdateSite {
+                site_id,
+                site_details: invalid.into(),
+            })
+            .await,
+        ApiError::InvalidOpenHours(payloads::OpenHoursError::IncompleteHours {
+            date: closed.date,
+        }),
+    );
+
+    // Updating replaces the exceptions
+    site_details.open_hours.as_mut().unwrap().exceptions = vec![closed];
+    let site = app
+        .client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id,
+            site_details: site_details.clone().into(),
+        })
+        .await?;
+    assert_eq!(site.site_details.open_hours, site_details.open_hours);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_round_trips_equal() -> anyhow::Result<()> {
+    use jiff::Span;
+
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+
+    let mut site_details = test_helpers::site_details_b(community_id);
+    site_details.default_auction_params.round_duration =
+        Span::new().minutes(90);
+    site_details.possession_period = Span::new().weeks(1);
+    site_details.description = Some(String::new());
+    let site_id = app.client.create_site(&site_details.clone().into()).await?;
+    let site = app.client.get_site(&site_id).await?;
+    assert_eq!(site.site_details, site_details);
+    assert_eq!(site.site_details, site_details.normalized());
+    assert_eq!(site.site_details.description, None);
+
+    // The same duration written differently is the same site
+    site_details.default_auction_params.round_duration =
+        Span::new().hours(1).minutes(30);
+    assert_eq!(site.site_details, site_details);
+
+    // ...and comes back fieldwise identical from an update
+    let updated = app
+        .client
+        .update_site(&payloads::requests::UpdateSite {
+            site_id,
+            site_details: site_details.clone().into(),
+        })
+        .await?;
+    let round_duration =
+        |site: &payloads::Site| site.default_auction_params.round_duration;
+    assert_eq!(
+        round_duration(&updated.site_details).fieldwise(),
+        round_duration(&site.site_details).fieldwise()
+    );
+    assert_eq!(updated.site_details, site.site_details);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_space_with_auction_history() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    let community_id = app.create_two_person_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let space = app.create_test_space(&site.site_id).await?;
+    let auction_details =
+        test_helpers::auction_details_a(site.site_id, &app.time_source);
+    let auction_id = app.client.create_auction(&auction_details).await?;
+    api::scheduler::schedule_tick(&app.db_pool, &app.time_source).await;
+    let rounds = app.client.list_auction_rounds(&auction_id).await?;
+    app.client
+        .create_bid(&space.space_id, &rounds[0].round_id)
+        .await?;
+
+    // Refused by default, history intact
+    let result = app.client.delete_space(&space.space_id).await;
+    test_helpers::assert_api_error(result, ApiError::SpaceHasAuctionHistory);
+    let result = app
+        .client
+        .delete_space_with_options(&payloads::requests::DeleteSpace {
+            space_id: space.space_id,
+            force: false,
+        })
+        .await;
+    test_helpers::assert_api_error(result, ApiError::SpaceHasAuctionHistory);
+    assert!(
+        app.client
+            .get_space(&space.space_id)
+            .await?
+            .deleted_at
+            .is_none()
+    );
+
+    // Forcing archives it instead
+    let archived = app
+        .client
+        .delete_space_with_options(&payloads::requests::DeleteSpace {
+            space_id: space.space_id,
+            force: true,
+        })
+        .await?;
+    assert!(archived);
+    let archived_space = app.client.get_space(&space.space_id).await?;
+    assert_eq!(archived_space.deleted_at, Some(app.time_source.now()));
+    assert_eq!(
+        app.client
+            .list_bids(&rounds[0].round_id)
+            .await?
+            .into_iter()
+            .map(|bid| bid.space_id)
+            .collect::<Vec<_>>(),
+        vec![space.space_id]
+    );
+
+    // A space that was never auctioned is deleted outright, forced or not
+    let unused = app
+        .client
+        .create_space(&test_helpers::space_details_b(site.site_id))
+        .await?;
+    let archived = app
+        .client
+        .delete_space_with_options(&payloads::requests::DeleteSpace {
+            space_id: unused,
+            force: true,
+        })
+        .await?;
+    assert!(!archived);
+    assert!(app.client.get_space(&unused).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_spaces_omits_inaccessible_ids() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let alice_community = app.create_test_community().await?;
+    let alice_site = app.create_test_site(&alice_community).await?;
+    let alice_space = app.create_test_space(&alice_site.site_id).await?;
+
+    app.create_bob_user().await?;
+    app.login_bob().await?;
+    let bob_community = app.create_test_community().await?;
+    let bob_site = app.create_test_site(&bob_community).await?;
+    let bob_space = app.create_test_space(&bob_site.site_id).await?;
+    let mut details = test_helpers::space_details_b(bob_site.site_id);
+    details.name = "another space".into();
+    let bob_space_b = app.client.create_space(&details).await?;
+
+    // Alice's space and an unknown id are dropped without an error, so the
+    // response is the same whether a foreign id exists or not
+    let missing = payloads::SpaceId(uuid::Uuid::new_v4());
+    let spaces = app
+        .client
+        .get_spaces(&requests::GetSpaces {
+            space_ids: vec![
+                bob_space.space_id,
+                alice_space.space_id,
+                missing,
+                bob_space_b,
+            ],
+        })
+        .await?;
+    let ids: Vec<_> = spaces.iter().map(|s| s.space_id).collect();
+    assert_eq!(ids, vec![bob_space_b, bob_space.space_id]);
+    assert_eq!(spaces[1], bob_space);
+
+    let foreign_only = app
+        .client
+        .get_spaces(&requests::GetSpaces {
+            space_ids: vec![alice_space.space_id],
+        })
+        .await?;
+    let missing_only = app
+        .client
+        .get_spaces(&requests::GetSpaces {
+            space_ids: vec![missing],
+        })
+        .await?;
+    assert!(foreign_only.is_empty());
+    assert!(missing_only.is_empty());
+
+    let too_many = vec![missing; requests::MAX_GET_SPACES_IDS + 1];
+    assert!(
+        app.client
+            .get_spaces(&requests::GetSpaces {
+                space_ids: too_many
+            })
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resolve_open_hours_across_dst() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    // Open Mondays 09:22:45-17:30 in America/Los_Angeles
+    let site = app.create_test_site(&community_id).await?;
+    let (client, site_id) = (&app.client, site.site_id);
+    let resolve = |date| async move {
+        client
+            .resolve_open_hours(&requests::ResolveOpenHours { site_id, date })
+            .await
+    };
+    let ts = |s: &str| s.parse::<jiff::Timestamp>().unwrap();
+
+    // Clocks move forward on Sunday 2026-03-08, so the same local hours
+    // start an hour earlier in UTC the following Monday
+    assert_eq!(
+        resolve(jiff::civil::date(2026, 3, 2)).await?,
+        vec![(ts("2026-03-02T17:22:45Z"), ts("2026-03-03T01:30:00Z"))]
+    );
+    assert_eq!(
+        resolve(jiff::civil::date(2026, 3, 9)).await?,
+        vec![(ts("2026-03-09T16:22:45Z"), ts("2026-03-10T00:30:00Z"))]
+    );
+    assert!(resolve(jiff::civil::date(2026, 3, 8)).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_sites_announces_next_and_active_auctions() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let upcoming_site = app.create_test_site(&community_id).await?.site_id;
+    let past_site = app
+        .client
+        .create_site(&test_helpers::site_details_b(community_id).into())
+        .await?;
+
+    let auction_starting_in = |site_id, hours: i64, sandbox| {
+        let now = app.time_source.now();
+        payloads::Auction {
+            start_at: Some(now + jiff::Span::new().hours(hours)),
+            possession_start_at: now + jiff::Span::new().hours(240),
+            possession_end_at: now + jiff::Span::new().hours(264),
+            sandbox,
+            ..test_helpers::auction_details_a(site_id, &app.time_source)
+        }
+    };
+    let later = app
+        .client
+        .create_auction(&auction_starting_in(upcoming_site, 96, false))
+        .await?;
+    let next = app
+        .client
+        .create_auction(&auction_starting_in(upcoming_site, 72, false))
+        .await?;
+    // Neither a sandbox auction nor a canceled one is announced
+    app.client
+        .create_auction(&auction_starting_in(upcoming_site, 24, true))
+        .await?;
+    let canceled = app
+        .client
+        .create_auction(&auction_starting_in(past_site, 0, false))
+        .await?;
+    app.client.cancel_auction(&canceled).await?;
+
+    let listed = |sites: &[responses::Site], site_id| {
+        sites.iter().find(|s| s.site_id == site_id).unwrap().clone()
+    };
+    let sites = app.client.list_sites(&community_id).await?;
+    let upcoming = listed(&sites, upcoming_site);
+    assert_eq!(
+        upcoming.next_auction,
+        Some(responses::UpcomingAuction {
+            auction_id: next,
+            start_at: app.time_source.now() + jiff::Span::new().hours(72),
+        })
+    );
+    assert_eq!(upcoming.active_auction_id, None);
+    let past = listed(&sites, past_site);
+    assert_eq!(past.next_auction, None);
+    assert_eq!(past.active_auction_id, None);
+
+    // Once the next auction starts, it's the active one
+    app.time_source.advance(jiff::Span::new().hours(73));
+    let sites = app.client.list_sites(&community_id).await?;
+    let upcoming = listed(&sites, upcoming_site);
+    assert_eq!(upcoming.active_auction_id, Some(next));
+    assert_eq!(
+        upcoming.next_auction.map(|next| next.auction_id),
+        Some(later)
+    );
+
+    // Only listings carry announcements
+    let site = app.client.get_site(&upcoming_site).await?;
+    assert_eq!(site.next_auction, None);
+    assert_eq!(site.active_auction_id, None);
+
+    Ok(())
+}
+
+/// Sites saved before lead times had to be in hours still pass validation
+/// once migrated, so they can be edited without changing those fields.
+#[tokio::test]
+async fn day_based_spans_are_migrated_to_fixed_lengths() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    sqlx::query(
+        "UPDATE sites
+        SET auction_lead_time = '1 day',
+            proxy_bidding_lead_time = '2 days 3 hours',
+            possession_period = '1 mon 2 days'
+        WHERE id = $1",
+    )
+    .bind(site.site_id)
+    .execute(&app.db_pool)
+    .await?;
+    sqlx::query(
+        "UPDATE auction_params SET round_duration = '1 day'
+        WHERE id = (SELECT default_auction_params_id FROM sites WHERE id = $1)",
+    )
+    .bind(site.site_id)
+    .execute(&app.db_pool)
+    .await?;
+    sqlx::raw_sql(include_str!(
+        "../../migrations/20261112000000_absolute_lead_times.up.sql"
+    ))
+    .execute(&app.db_pool)
+    .await?;
+
+    let details = app.client.get_site(&site.site_id).await?.site_details;
+    let hours = |span: jiff::Span| span.total(jiff::Unit::Hour).unwrap();
+    assert!(payloads::is_absolute_span(&details.auction_lead_time));
+    assert_eq!(hours(details.auction_lead_time), 24.0);
+    assert!(payloads::is_absolute_span(&details.proxy_bidding_lead_time));
+    assert_eq!(hours(details.proxy_bidding_lead_time), 51.0);
+    assert_eq!(details.possession_period.get_months(), 0);
+    assert_eq!(details.possession_period.get_days(), 32);
+    let params = details.default_auction_params.clone();
+    assert!(payloads::is_absolute_span(&params.round_duration));
+    assert_eq!(hours(params.round_duration), 24.0);
+
+    // Saving the site back unchanged passes validation
+    app.client
+        .update_site(&requests::UpdateSite {
+            site_id: site.site_id,
+            site_details: details.into(),
+        })
+        .await?;
+
+    Ok(())
+}
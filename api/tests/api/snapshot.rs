@@ -0,0 +1,43 @@
+//! Full-body snapshots of API responses. Ids come from the test app's
+//! sequential `IdSource` and timestamps from its mock `TimeSource`, so the
+//! bodies are identical on every run and any changed field shows up as a
+//! snapshot diff. Review changes with `cargo insta review`.
+
+use test_helpers::spawn_app;
+
+#[tokio::test]
+async fn user_profile_snapshot() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+
+    insta::assert_json_snapshot!(app.client.user_profile().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_site_snapshot() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+
+    insta::assert_json_snapshot!(app.client.get_site(&site.site_id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_auction_snapshot() -> anyhow::Result<()> {
+    let app = spawn_app().await;
+    app.create_alice_user().await?;
+    let community_id = app.create_test_community().await?;
+    let site = app.create_test_site(&community_id).await?;
+    let auction = app.create_test_auction(&site.site_id).await?;
+
+    insta::assert_json_snapshot!(
+        app.client.get_auction(&auction.auction_id).await?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use reqwest::{
+    StatusCode,
+    header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH},
+};
+use test_helpers::spawn_app_serving_static;
+
+const INDEX_HTML: &str = "<html><body>tinylvt</body></html>";
+
+/// A minimal UI build in a fresh temporary directory.
+fn ui_dist() -> anyhow::Result<std::path::PathBuf> {
+    let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("index.html"), INDEX_HTML)?;
+    std::fs::write(dir.join("ui-0123abcd.js"), "console.log('hi');")?;
+    Ok(dir)
+}
+
+#[tokio::test]
+async fn deep_links_fall_back_to_index() -> anyhow::Result<()> {
+    let app = spawn_app_serving_static(ui_dist()?).await;
+    let client = &app.client.inner_client;
+    let address = &app.client.address;
+
+    for path in ["/", "/communities/5f0c3a3e-8b4a-4c1e-9d2b-1b7e7a4f9c10"] {
+        let response = client.get(format!("{address}{path}")).send().await?;
+        assert_eq!(response.status(), StatusCode::OK, "{path}");
+        assert_eq!(response.headers()[CACHE_CONTROL], "no-cache");
+        assert_eq!(response.text().await?, INDEX_HTML);
+    }
+
+    // Hashed assets are served with a long max-age
+    let response = client
+        .get(format!("{address}/ui-0123abcd.js"))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[CACHE_CONTROL],
+        "public, max-age=31536000, immutable"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_assets_and_api_routes_are_not_found() -> anyhow::Result<()> {
+    let app = spawn_app_serving_static(ui_dist()?).await;
+    let client = &app.client.inner_client;
+    let address = &app.client.address;
+
+    for path in ["/missing.js", "/images/missing.png", "/api/no_such_route"] {
+        let response = client.get(format!("{address}{path}")).send().await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{path}");
+    }
+
+    // The API still works alongside the static files
+    app.client.health_check().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn matching_etag_is_not_modified() -> anyhow::Result<()> {
+    let app = spawn_app_serving_static(ui_dist()?).await;
+    let client = &app.client.inner_client;
+    let address = &app.client.address;
+
+    for (path, policy) in [
+        ("/ui-0123abcd.js", "public, max-age=31536000, immutable"),
+        ("/communities", "no-cache"),
+    ] {
+        let url = format!("{address}{path}");
+        let response = client.get(&url).send().await?;
+        assert_eq!(response.status(), StatusCode::OK, "{path}");
+        let etag = response.headers()[ETAG].clone();
+
+        // The 304 keeps the file's cache policy rather than picking up
+        // the API's no-store headers
+        let response =
+            client.get(&url).header(IF_NONE_MATCH, etag).send().await?;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "{path}");
+        assert_eq!(response.headers()[CACHE_CONTROL], policy, "{path}");
+        assert!(response.bytes().await?.is_empty());
+    }
+
+    Ok(())
+}
@@ -1,17 +1,143 @@
 use crate::{
-    Account, ApiError, Auction, AuctionId, AuctionRoundId, Bid, CommunityId,
-    InviteId, MembershipSchedule, RoundSpaceResult, Site, SiteId, SiteImageId,
-    Space, SpaceId, TreasuryOperationResult, requests, responses,
+    Account, ApiError, Auction, AuctionId, AuctionParams, AuctionRoundId, Bid,
+    CommunityId, InviteId, MembershipSchedule, RoundSpacePrice,
+    RoundSpaceResult, SiteId, SiteImageId, Space, SpaceId,
+    TreasuryOperationResult, requests, responses,
 };
 use reqwest::StatusCode;
 use serde::Serialize;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+type ResponseResult = Result<reqwest::Response, ClientError>;
 
-type ReqwestResult = Result<reqwest::Response, reqwest::Error>;
+/// Header the backend uses to echo (or assign) a per-request id.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
 /// An API client for interfacing with the backend.
 pub struct APIClient {
     pub address: String,
     pub inner_client: reqwest::Client,
+    /// Metadata from the most recent response. A plain mutex is fine on
+    /// wasm, where there is only one thread and it is never contended.
+    last_response_meta: Mutex<Option<ResponseMeta>>,
+    observer: Arc<dyn ClientObserver>,
+}
+
+/// Callbacks around each request an [`APIClient`] makes, for logging and
+/// metrics. `path` is the endpoint path under `/api/`, such as `get_space`.
+/// Every method defaults to doing nothing.
+pub trait ClientObserver: Send + Sync {
+    /// A request is about to be sent.
+    fn on_request(&self, _path: &str) {}
+    /// A response arrived, successful or not.
+    fn on_response(
+        &self,
+        _path: &str,
+        _status: StatusCode,
+        _elapsed: Duration,
+    ) {
+    }
+    /// The call failed: no response arrived, the response was an error, or
+    /// its body couldn't be read as the expected type.
+    fn on_error(&self, _path: &str, _error: &ClientError) {}
+}
+
+/// The default observer, which ignores every call.
+pub struct NoopObserver;
+
+impl ClientObserver for NoopObserver {}
+
+/// Selected headers and the status of an API response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    pub status: StatusCode,
+    /// The `X-Request-Id` assigned to the request, for correlating with
+    /// server logs.
+    pub request_id: Option<String>,
+    /// How long to wait before retrying, from a `Retry-After` header given
+    /// in seconds.
+    pub retry_after: Option<Duration>,
+}
+
+impl ResponseMeta {
+    fn from_response(response: &reqwest::Response) -> Self {
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+        Self {
+            status: response.status(),
+            request_id: header(REQUEST_ID_HEADER).map(str::to_string),
+            retry_after: header(reqwest::header::RETRY_AFTER.as_str())
+                .and_then(|value| value.trim().parse().ok())
+                .map(Duration::from_secs),
+        }
+    }
+}
+
+/// Measures request latency. `Instant` is unsupported on wasm, so the
+/// browser's `performance.now()` is used there instead.
+#[cfg(not(target_arch = "wasm32"))]
+struct Stopwatch(std::time::Instant);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Stopwatch {
+    fn start() -> Self {
+        Self(std::time::Instant::now())
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+struct Stopwatch(f64);
+
+#[cfg(target_arch = "wasm32")]
+impl Stopwatch {
+    /// Milliseconds since page load.
+    fn now() -> f64 {
+        web_sys::window()
+            .and_then(|window| window.performance())
+            .map_or(0.0, |performance| performance.now())
+    }
+
+    fn start() -> Self {
+        Self(Self::now())
+    }
+
+    fn elapsed(&self) -> Duration {
+        Duration::from_secs_f64((Self::now() - self.0).max(0.0) / 1000.0)
+    }
+}
+
+impl APIClient {
+    pub fn new(address: String, inner_client: reqwest::Client) -> Self {
+        Self {
+            address,
+            inner_client,
+            last_response_meta: Mutex::new(None),
+            observer: Arc::new(NoopObserver),
+        }
+    }
+
+    /// Report every request this client makes to `observer`.
+    pub fn with_observer(mut self, observer: Arc<dyn ClientObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Metadata from the most recent response received by this client, if
+    /// any request has completed.
+    pub fn last_response_meta(&self) -> Option<ResponseMeta> {
+        self.last_response_meta.lock().unwrap().clone()
+    }
 }
 
 /// Helper methods for http actions
@@ -20,31 +146,107 @@ impl APIClient {
         format!("{}/api/{path}", &self.address)
     }
 
-    async fn post(&self, path: &str, body: &impl Serialize) -> ReqwestResult {
-        let request = self.inner_client.post(self.format_url(path)).json(body);
+    /// The endpoint path of a response's URL, as passed to the helpers
+    /// below.
+    fn api_path<'a>(&self, url: &'a reqwest::Url) -> &'a str {
+        let base = self.format_url("");
+        url.as_str()
+            .split('?')
+            .next()
+            .and_then(|url| url.strip_prefix(base.as_str()))
+            .unwrap_or(url.path())
+    }
 
+    /// Send a request, recording the response metadata and reporting it to
+    /// the observer.
+    async fn send(
+        &self,
+        path: &str,
+        request: reqwest::RequestBuilder,
+    ) -> ResponseResult {
         #[cfg(target_arch = "wasm32")]
         let request = request.fetch_credentials_include();
 
-        request.send().await
+        self.observer.on_request(path);
+        let stopwatch = Stopwatch::start();
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                let error = ClientError::Network(e);
+                self.observer.on_error(path, &error);
+                return Err(error);
+            }
+        };
+        self.observer
+            .on_response(path, response.status(), stopwatch.elapsed());
+        *self.last_response_meta.lock().unwrap() =
+            Some(ResponseMeta::from_response(&response));
+        Ok(response)
+    }
+
+    /// [`ok_body`], reporting a failure to the observer.
+    async fn body<T: serde::de::DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<T, ClientError> {
+        let url = response.url().clone();
+        ok_body(response)
+            .await
+            .inspect_err(|e| self.observer.on_error(self.api_path(&url), e))
+    }
+
+    /// [`ok_empty`], reporting a failure to the observer.
+    async fn empty(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<(), ClientError> {
+        let url = response.url().clone();
+        ok_empty(response)
+            .await
+            .inspect_err(|e| self.observer.on_error(self.api_path(&url), e))
     }
 
-    async fn empty_post(&self, path: &str) -> ReqwestResult {
-        let request = self.inner_client.post(self.format_url(path));
+    /// [`error_response`], reporting the error to the observer.
+    async fn error(&self, response: reqwest::Response) -> ClientError {
+        let url = response.url().clone();
+        let error = match error_response(response).await {
+            Ok(error) | Err(error) => error,
+        };
+        self.observer.on_error(self.api_path(&url), &error);
+        error
+    }
 
-        #[cfg(target_arch = "wasm32")]
-        let request = request.fetch_credentials_include();
+    async fn post(&self, path: &str, body: &impl Serialize) -> ResponseResult {
+        let request = self.inner_client.post(self.format_url(path)).json(body);
 
-        request.send().await
+        self.send(path, request).await
     }
 
-    async fn empty_get(&self, path: &str) -> ReqwestResult {
-        let request = self.inner_client.get(self.format_url(path));
+    async fn post_with_query(
+        &self,
+        path: &str,
+        query: &impl Serialize,
+        body: &impl Serialize,
+    ) -> ResponseResult {
+        let request = self
+            .inner_client
+            .post(self.format_url(path))
+            .query(query)
+            .json(body);
 
-        #[cfg(target_arch = "wasm32")]
-        let request = request.fetch_credentials_include();
+        self.send(path, request).await
+    }
+
+    async fn empty_post(&self, path: &str) -> ResponseResult {
+        let request = self.inner_client.post(self.format_url(path));
+
+        self.send(path, request).await
+    }
+
+    async fn empty_get(&self, path: &str) -> ResponseResult {
+        let request = self.inner_client.get(self.format_url(path));
 
-        request.send().await
+        self.send(path, request).await
     }
 }
 
@@ -61,14 +263,20 @@ impl APIClient {
 
     pub async fn health_check(&self) -> Result<(), ClientError> {
         let response = self.empty_get("health_check").await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    /// Whether the server can serve traffic, beyond being up.
+    pub async fn ready(&self) -> Result<(), ClientError> {
+        let response = self.empty_get("ready").await?;
+        self.empty(response).await
     }
 
     pub async fn platform_stats(
         &self,
     ) -> Result<responses::PlatformStats, ClientError> {
         let response = self.empty_get("platform_stats").await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_account(
@@ -76,7 +284,7 @@ impl APIClient {
         details: &requests::CreateAccount,
     ) -> Result<(), ClientError> {
         let response = self.post("create_account", details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn login(
@@ -84,18 +292,43 @@ impl APIClient {
         details: &requests::LoginCredentials,
     ) -> Result<(), ClientError> {
         let response = self.post("login", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn logout(&self) -> Result<(), ClientError> {
         let response = self.empty_post("logout").await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    /// Act as another user for support (superuser only). The session logs
+    /// out when the impersonation expires.
+    pub async fn impersonate(
+        &self,
+        details: &requests::Impersonate,
+    ) -> Result<(), ClientError> {
+        let response = self.post("impersonate", details).await?;
+        self.empty(response).await
+    }
+
+    /// End an impersonation, returning to the superuser's own session.
+    pub async fn stop_impersonating(&self) -> Result<(), ClientError> {
+        let response = self.empty_post("stop_impersonating").await?;
+        self.empty(response).await
     }
 
     /// Delete the current user's account.
     pub async fn delete_user(&self) -> Result<(), ClientError> {
         let response = self.empty_post("delete_user").await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    /// Download everything stored about the current user. Limited to once
+    /// per hour.
+    pub async fn export_my_data(
+        &self,
+    ) -> Result<responses::DataExport, ClientError> {
+        let response = self.empty_post("export_my_data").await?;
+        self.body(response).await
     }
 
     /// Delete a community (leader only).
@@ -104,7 +337,7 @@ impl APIClient {
         community_id: &CommunityId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_community", community_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     /// Check if the user is logged in.
@@ -113,7 +346,7 @@ impl APIClient {
         match response.status() {
             StatusCode::OK => Ok(true),
             StatusCode::UNAUTHORIZED => Ok(false),
-            _ => Err(error_response(response).await?),
+            _ => Err(self.error(response).await),
         }
     }
 
@@ -122,7 +355,14 @@ impl APIClient {
         &self,
     ) -> Result<responses::UserProfile, ClientError> {
         let response = self.empty_get("user_profile").await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// The profile, communities, and pending invite count the UI needs on
+    /// startup, in a single request.
+    pub async fn bootstrap(&self) -> Result<responses::Bootstrap, ClientError> {
+        let response = self.empty_get("bootstrap").await?;
+        self.body(response).await
     }
 
     /// Verify email address using a token from the verification email.
@@ -131,7 +371,7 @@ impl APIClient {
         details: &requests::VerifyEmail,
     ) -> Result<responses::SuccessMessage, ClientError> {
         let response = self.post("verify_email", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     /// Request a password reset email for the given email address.
@@ -140,7 +380,7 @@ impl APIClient {
         details: &requests::ForgotPassword,
     ) -> Result<responses::SuccessMessage, ClientError> {
         let response = self.post("forgot_password", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     /// Reset password using a token from the password reset email.
@@ -149,7 +389,26 @@ impl APIClient {
         details: &requests::ResetPassword,
     ) -> Result<responses::SuccessMessage, ClientError> {
         let response = self.post("reset_password", details).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Replace the current user's recovery codes with a new set. The codes
+    /// can't be retrieved again later.
+    pub async fn generate_recovery_codes(
+        &self,
+        details: &requests::GenerateRecoveryCodes,
+    ) -> Result<responses::RecoveryCodes, ClientError> {
+        let response = self.post("generate_recovery_codes", details).await?;
+        self.body(response).await
+    }
+
+    /// Set a new password with a recovery code, logging out every session.
+    pub async fn recover_account(
+        &self,
+        details: &requests::RecoverAccount,
+    ) -> Result<responses::SuccessMessage, ClientError> {
+        let response = self.post("recover_account", details).await?;
+        self.body(response).await
     }
 
     /// Resend email verification for the currently authenticated user.
@@ -157,7 +416,7 @@ impl APIClient {
         &self,
     ) -> Result<responses::SuccessMessage, ClientError> {
         let response = self.empty_post("resend_verification_email").await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_community(
@@ -165,7 +424,7 @@ impl APIClient {
         details: &requests::CreateCommunity,
     ) -> Result<CommunityId, ClientError> {
         let response = self.post("create_community", &details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     /// Update currency configuration for a community (coleader+ only).
@@ -174,7 +433,7 @@ impl APIClient {
         details: &requests::UpdateCurrencyConfig,
     ) -> Result<(), ClientError> {
         let response = self.post("update_currency_config", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     /// Update community name and description (coleader+ only).
@@ -183,7 +442,55 @@ impl APIClient {
         details: &requests::UpdateCommunityDetails,
     ) -> Result<responses::Community, ClientError> {
         let response = self.post("update_community_details", &details).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Open or close the community to new members.
+    pub async fn set_registration_open(
+        &self,
+        details: &requests::SetRegistrationOpen,
+    ) -> Result<(), ClientError> {
+        let response = self.post("set_registration_open", &details).await?;
+        self.empty(response).await
+    }
+
+    /// Set or clear the base URL of the community's invite links.
+    pub async fn set_invite_base_url(
+        &self,
+        details: &requests::SetInviteBaseUrl,
+    ) -> Result<(), ClientError> {
+        let response = self.post("set_invite_base_url", &details).await?;
+        self.empty(response).await
+    }
+
+    /// Set or clear the community's round duration bounds.
+    pub async fn set_round_duration_bounds(
+        &self,
+        details: &requests::SetRoundDurationBounds,
+    ) -> Result<(), ClientError> {
+        let response = self.post("set_round_duration_bounds", &details).await?;
+        self.empty(response).await
+    }
+
+    /// Set or clear the auction params that new sites in the community
+    /// inherit when created without their own.
+    pub async fn set_community_default_params(
+        &self,
+        details: &requests::SetCommunityDefaultParams,
+    ) -> Result<(), ClientError> {
+        let response =
+            self.post("set_community_default_params", &details).await?;
+        self.empty(response).await
+    }
+
+    pub async fn get_community_default_params(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<Option<AuctionParams>, ClientError> {
+        let response = self
+            .post("get_community_default_params", &community_id)
+            .await?;
+        self.body(response).await
     }
 
     /// Get the communities for the currently logged in user.
@@ -191,14 +498,34 @@ impl APIClient {
         &self,
     ) -> Result<Vec<responses::CommunityWithRole>, ClientError> {
         let response = self.empty_get("communities").await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    pub async fn list_my_roles(
+        &self,
+    ) -> Result<Vec<responses::CommunityRole>, ClientError> {
+        let response = self.empty_get("my_roles").await?;
+        self.body(response).await
+    }
+
+    pub async fn get_community_overview(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<responses::CommunityOverview, ClientError> {
+        let response = self.post("community_overview", community_id).await?;
+        self.body(response).await
     }
 
     pub async fn get_received_invites(
         &self,
     ) -> Result<Vec<responses::CommunityInviteReceived>, ClientError> {
         let response = self.empty_get("received_invites").await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    pub async fn count_received_invites(&self) -> Result<i64, ClientError> {
+        let response = self.empty_get("received_invites/count").await?;
+        self.body(response).await
     }
 
     pub async fn invite_member(
@@ -206,15 +533,15 @@ impl APIClient {
         details: &requests::InviteCommunityMember,
     ) -> Result<InviteId, ClientError> {
         let response = self.post("invite_member", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_issued_invites(
         &self,
         community_id: &CommunityId,
-    ) -> Result<Vec<responses::IssuedCommunityInvite>, ClientError> {
+    ) -> Result<responses::IssuedInvites, ClientError> {
         let response = self.post("issued_invites", community_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_invite_community_name(
@@ -224,7 +551,19 @@ impl APIClient {
         let response = self
             .empty_get(&format!("invite_community_name/{invite_id}"))
             .await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Public preview of the community behind an invite. Does not require
+    /// a session.
+    pub async fn get_community_preview(
+        &self,
+        invite_id: &InviteId,
+    ) -> Result<responses::CommunityPreview, ClientError> {
+        let response = self
+            .empty_get(&format!("community_preview/{invite_id}"))
+            .await?;
+        self.body(response).await
     }
 
     pub async fn accept_invite(
@@ -234,7 +573,7 @@ impl APIClient {
         let response = self
             .empty_post(&format!("accept_invite/{invite_id}"))
             .await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn delete_invite(
@@ -242,7 +581,7 @@ impl APIClient {
         details: &requests::DeleteInvite,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_invite", details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     /// Get the communities for the currently logged in user.
@@ -251,7 +590,25 @@ impl APIClient {
         community_id: &CommunityId,
     ) -> Result<Vec<responses::CommunityMember>, ClientError> {
         let response = self.post("members", community_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// List members, optionally only the active ones.
+    pub async fn list_members(
+        &self,
+        details: &requests::GetMembers,
+    ) -> Result<Vec<responses::CommunityMember>, ClientError> {
+        let response = self.post("members", details).await?;
+        self.body(response).await
+    }
+
+    /// Get a fellow member's profile within a shared community.
+    pub async fn get_member_profile(
+        &self,
+        details: &requests::GetMemberProfile,
+    ) -> Result<responses::MemberProfile, ClientError> {
+        let response = self.post("member_profile", details).await?;
+        self.body(response).await
     }
 
     /// Get the communities for the currently logged in user.
@@ -260,7 +617,7 @@ impl APIClient {
         details: &requests::SetMembershipSchedule,
     ) -> Result<(), ClientError> {
         let response = self.post("membership_schedule", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     /// Get the communities for the currently logged in user.
@@ -270,7 +627,16 @@ impl APIClient {
     ) -> Result<Vec<MembershipSchedule>, ClientError> {
         let response =
             self.post("get_membership_schedule", &community_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Get aggregate stats for a community (coleader+ only).
+    pub async fn get_community_stats(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<responses::CommunityStats, ClientError> {
+        let response = self.post("get_community_stats", &community_id).await?;
+        self.body(response).await
     }
 
     pub async fn update_member_active_status(
@@ -279,7 +645,7 @@ impl APIClient {
     ) -> Result<(), ClientError> {
         let response =
             self.post("update_member_active_status", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn bulk_activate_members(
@@ -287,7 +653,7 @@ impl APIClient {
         details: &requests::BulkActivateMembers,
     ) -> Result<responses::BulkActivateMembersResult, ClientError> {
         let response = self.post("bulk_activate_members", &details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn remove_member(
@@ -295,7 +661,7 @@ impl APIClient {
         details: &requests::RemoveMember,
     ) -> Result<(), ClientError> {
         let response = self.post("remove_member", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn change_member_role(
@@ -303,7 +669,7 @@ impl APIClient {
         details: &requests::ChangeMemberRole,
     ) -> Result<(), ClientError> {
         let response = self.post("change_member_role", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn leave_community(
@@ -311,7 +677,7 @@ impl APIClient {
         details: &requests::LeaveCommunity,
     ) -> Result<(), ClientError> {
         let response = self.post("leave_community", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn get_orphaned_accounts(
@@ -319,7 +685,7 @@ impl APIClient {
         community_id: &CommunityId,
     ) -> Result<responses::OrphanedAccountsList, ClientError> {
         let response = self.post("orphaned_accounts", &community_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn resolve_orphaned_balance(
@@ -327,23 +693,42 @@ impl APIClient {
         details: &requests::ResolveOrphanedBalance,
     ) -> Result<TreasuryOperationResult, ClientError> {
         let response = self.post("resolve_orphaned_balance", &details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_site(
         &self,
-        site: &Site,
+        site: &requests::SiteDetails,
     ) -> Result<SiteId, ClientError> {
         let response = self.post("create_site", &site).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_site(
         &self,
         site_id: &SiteId,
     ) -> Result<responses::Site, ClientError> {
-        let response = self.post("get_site", &site_id).await?;
-        ok_body(response).await
+        self.get_site_with(site_id, &requests::ReadOptions::default())
+            .await
+    }
+
+    pub async fn get_site_with(
+        &self,
+        site_id: &SiteId,
+        options: &requests::ReadOptions,
+    ) -> Result<responses::Site, ClientError> {
+        let response =
+            self.post_with_query("get_site", options, &site_id).await?;
+        self.body(response).await
+    }
+
+    /// The site's open hours on a date as UTC `(opens, closes)` windows.
+    pub async fn resolve_open_hours(
+        &self,
+        details: &requests::ResolveOpenHours,
+    ) -> Result<Vec<(jiff::Timestamp, jiff::Timestamp)>, ClientError> {
+        let response = self.post("resolve_open_hours", details).await?;
+        self.body(response).await
     }
 
     pub async fn update_site(
@@ -351,15 +736,18 @@ impl APIClient {
         details: &requests::UpdateSite,
     ) -> Result<responses::Site, ClientError> {
         let response = self.post("site", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
+    /// Permanently delete a site. Unless `details.confirm` is set this fails
+    /// with [`ApiError::SiteDeletionNotConfirmed`] carrying what would be
+    /// deleted.
     pub async fn delete_site(
         &self,
-        site_id: &SiteId,
+        details: &requests::DeleteSite,
     ) -> Result<(), ClientError> {
-        let response = self.post("delete_site", &site_id).await?;
-        ok_empty(response).await
+        let response = self.post("delete_site", details).await?;
+        self.empty(response).await
     }
 
     pub async fn soft_delete_site(
@@ -367,7 +755,7 @@ impl APIClient {
         site_id: &SiteId,
     ) -> Result<(), ClientError> {
         let response = self.post("soft_delete_site", &site_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn restore_site(
@@ -375,7 +763,7 @@ impl APIClient {
         site_id: &SiteId,
     ) -> Result<(), ClientError> {
         let response = self.post("restore_site", &site_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn list_sites(
@@ -383,7 +771,7 @@ impl APIClient {
         community_id: &CommunityId,
     ) -> Result<Vec<responses::Site>, ClientError> {
         let response = self.post("sites", &community_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_space(
@@ -391,7 +779,7 @@ impl APIClient {
         space: &Space,
     ) -> Result<SpaceId, ClientError> {
         let response = self.post("create_space", &space).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_space(
@@ -399,7 +787,17 @@ impl APIClient {
         space_id: &SpaceId,
     ) -> Result<responses::Space, ClientError> {
         let response = self.post("get_space", &space_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Fetch several spaces by id. Spaces the caller can't see are left
+    /// out rather than failing the request.
+    pub async fn get_spaces(
+        &self,
+        details: &requests::GetSpaces,
+    ) -> Result<Vec<responses::Space>, ClientError> {
+        let response = self.post("get_spaces", details).await?;
+        self.body(response).await
     }
 
     pub async fn update_space(
@@ -407,7 +805,7 @@ impl APIClient {
         details: &requests::UpdateSpace,
     ) -> Result<responses::UpdateSpaceResult, ClientError> {
         let response = self.post("space", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn update_spaces(
@@ -415,7 +813,15 @@ impl APIClient {
         details: &requests::UpdateSpaces,
     ) -> Result<Vec<responses::UpdateSpaceResult>, ClientError> {
         let response = self.post("spaces_batch", details).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    pub async fn reorder_spaces(
+        &self,
+        details: &requests::ReorderSpaces,
+    ) -> Result<(), ClientError> {
+        let response = self.post("reorder_spaces", details).await?;
+        self.empty(response).await
     }
 
     pub async fn delete_space(
@@ -423,7 +829,17 @@ impl APIClient {
         space_id: &SpaceId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_space", &space_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    /// Delete a space, optionally archiving it if it has auction history.
+    /// Returns whether it was archived.
+    pub async fn delete_space_with_options(
+        &self,
+        details: &requests::DeleteSpace,
+    ) -> Result<bool, ClientError> {
+        let response = self.post("delete_space", details).await?;
+        self.body(response).await
     }
 
     pub async fn soft_delete_space(
@@ -431,7 +847,7 @@ impl APIClient {
         space_id: &SpaceId,
     ) -> Result<(), ClientError> {
         let response = self.post("soft_delete_space", &space_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn restore_space(
@@ -439,7 +855,7 @@ impl APIClient {
         space_id: &SpaceId,
     ) -> Result<(), ClientError> {
         let response = self.post("restore_space", &space_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn list_spaces(
@@ -447,7 +863,16 @@ impl APIClient {
         site_id: &SiteId,
     ) -> Result<Vec<responses::Space>, ClientError> {
         let response = self.post("spaces", &site_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// The site's spaces, each with the value the caller has declared for it.
+    pub async fn list_spaces_with_values(
+        &self,
+        site_id: &SiteId,
+    ) -> Result<Vec<responses::SpaceWithValue>, ClientError> {
+        let response = self.post("spaces_with_values", &site_id).await?;
+        self.body(response).await
     }
 
     pub async fn create_auction(
@@ -455,15 +880,43 @@ impl APIClient {
         auction: &Auction,
     ) -> Result<AuctionId, ClientError> {
         let response = self.post("create_auction", &auction).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Copy an auction's parameters into a new auction with new times.
+    pub async fn recreate_auction(
+        &self,
+        details: &requests::RecreateAuction,
+    ) -> Result<responses::Auction, ClientError> {
+        let response = self.post("recreate_auction", &details).await?;
+        self.body(response).await
+    }
+
+    pub async fn simulate_auction(
+        &self,
+        details: &requests::SimulateAuction,
+    ) -> Result<responses::SimulationResult, ClientError> {
+        let response = self.post("simulate_auction", details).await?;
+        self.body(response).await
     }
 
     pub async fn get_auction(
         &self,
         auction_id: &AuctionId,
     ) -> Result<responses::Auction, ClientError> {
-        let response = self.post("auction", &auction_id).await?;
-        ok_body(response).await
+        self.get_auction_with(auction_id, &requests::ReadOptions::default())
+            .await
+    }
+
+    pub async fn get_auction_with(
+        &self,
+        auction_id: &AuctionId,
+        options: &requests::ReadOptions,
+    ) -> Result<responses::Auction, ClientError> {
+        let response = self
+            .post_with_query("auction", options, &auction_id)
+            .await?;
+        self.body(response).await
     }
 
     pub async fn delete_auction(
@@ -471,7 +924,7 @@ impl APIClient {
         auction_id: &AuctionId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_auction", &auction_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn schedule_auction(
@@ -479,7 +932,7 @@ impl APIClient {
         details: &requests::ScheduleAuction,
     ) -> Result<(), ClientError> {
         let response = self.post("schedule_auction", &details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn cancel_auction(
@@ -487,7 +940,31 @@ impl APIClient {
         auction_id: &AuctionId,
     ) -> Result<(), ClientError> {
         let response = self.post("cancel_auction", &auction_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    pub async fn update_auction_notes(
+        &self,
+        details: &requests::UpdateAuctionNotes,
+    ) -> Result<(), ClientError> {
+        let response = self.post("update_auction_notes", details).await?;
+        self.empty(response).await
+    }
+
+    /// The auction's report, once it has concluded.
+    pub async fn get_auction_report(
+        &self,
+        auction_id: &AuctionId,
+    ) -> Result<Option<responses::AuctionReport>, ClientError> {
+        let response = self
+            .post(
+                "auction_report",
+                &requests::GetAuctionReport {
+                    auction_id: *auction_id,
+                },
+            )
+            .await?;
+        self.body(response).await
     }
 
     pub async fn list_auctions(
@@ -495,7 +972,16 @@ impl APIClient {
         site_id: &SiteId,
     ) -> Result<Vec<responses::Auction>, ClientError> {
         let response = self.post("auctions", &site_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Auctions on every site of a community, newest start first.
+    pub async fn list_community_auctions(
+        &self,
+        community_id: &CommunityId,
+    ) -> Result<Vec<responses::Auction>, ClientError> {
+        let response = self.post("community_auctions", &community_id).await?;
+        self.body(response).await
     }
 
     pub async fn get_auction_round(
@@ -503,7 +989,7 @@ impl APIClient {
         round_id: &AuctionRoundId,
     ) -> Result<responses::AuctionRound, ClientError> {
         let response = self.post("auction_round", &round_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn list_auction_rounds(
@@ -511,7 +997,52 @@ impl APIClient {
         auction_id: &AuctionId,
     ) -> Result<Vec<responses::AuctionRound>, ClientError> {
         let response = self.post("auction_rounds", &auction_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Rounds of an auction within a round number range.
+    pub async fn list_auction_rounds_filtered(
+        &self,
+        details: &requests::ListAuctionRounds,
+    ) -> Result<Vec<responses::AuctionRound>, ClientError> {
+        let response = self.post("auction_rounds_filtered", details).await?;
+        self.body(response).await
+    }
+
+    /// Rounds created or updated after `details.since`, plus the server time
+    /// to use as the next `since`.
+    pub async fn list_auction_rounds_since(
+        &self,
+        details: &requests::ListAuctionRoundsSince,
+    ) -> Result<responses::AuctionRoundsSince, ClientError> {
+        let response = self.post("auction_rounds_since", details).await?;
+        self.body(response).await
+    }
+
+    pub async fn list_round_thresholds(
+        &self,
+        auction_id: &AuctionId,
+    ) -> Result<Vec<responses::RoundThreshold>, ClientError> {
+        let response = self.post("round_thresholds", &auction_id).await?;
+        self.body(response).await
+    }
+
+    pub async fn my_standing_wins(
+        &self,
+        details: &requests::MyStandingWins,
+    ) -> Result<Vec<responses::StandingWin>, ClientError> {
+        let response = self.post("my_standing_wins", details).await?;
+        self.body(response).await
+    }
+
+    /// Standing price of a space in its site's ongoing auction, if anyone
+    /// has bid on it.
+    pub async fn get_space_current_price(
+        &self,
+        space_id: &SpaceId,
+    ) -> Result<Option<rust_decimal::Decimal>, ClientError> {
+        let response = self.post("space_current_price", space_id).await?;
+        self.body(response).await
     }
 
     pub async fn get_round_space_result(
@@ -522,7 +1053,7 @@ impl APIClient {
         let response = self
             .post("round_space_result", &(space_id, round_id))
             .await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn list_round_space_results_for_round(
@@ -532,7 +1063,16 @@ impl APIClient {
         let response = self
             .post("round_space_results_for_round", &round_id)
             .await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// What a bid on each space costs in the round.
+    pub async fn get_round_prices(
+        &self,
+        round_id: &AuctionRoundId,
+    ) -> Result<Vec<RoundSpacePrice>, ClientError> {
+        let response = self.post("round_prices", &round_id).await?;
+        self.body(response).await
     }
 
     pub async fn create_bid(
@@ -541,7 +1081,7 @@ impl APIClient {
         round_id: &AuctionRoundId,
     ) -> Result<(), ClientError> {
         let response = self.post("create_bid", &(space_id, round_id)).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn get_bid(
@@ -550,7 +1090,7 @@ impl APIClient {
         round_id: &AuctionRoundId,
     ) -> Result<Bid, ClientError> {
         let response = self.post("bid", &(space_id, round_id)).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn list_bids(
@@ -558,7 +1098,53 @@ impl APIClient {
         round_id: &AuctionRoundId,
     ) -> Result<Vec<Bid>, ClientError> {
         let response = self.post("bids", &round_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    pub async fn get_bid_history(
+        &self,
+        details: &requests::GetBidHistory,
+    ) -> Result<responses::BidHistoryPage, ClientError> {
+        let response = self.post("bid_history", details).await?;
+        self.body(response).await
+    }
+
+    /// Every bid on a space in a concluded auction, oldest first, including
+    /// withdrawn ones. Moderator only.
+    pub async fn space_bid_log(
+        &self,
+        details: &requests::SpaceBidLog,
+    ) -> Result<Vec<responses::SpaceBidLogEntry>, ClientError> {
+        let response = self.post("space_bid_log", details).await?;
+        self.body(response).await
+    }
+
+    /// A signed receipt for a space won in a concluded auction. Winner or
+    /// moderator only.
+    pub async fn possession_receipt(
+        &self,
+        details: &requests::PossessionReceipt,
+    ) -> Result<responses::SignedPossessionReceipt, ClientError> {
+        let response = self.post("possession_receipt", details).await?;
+        self.body(response).await
+    }
+
+    /// Whether a receipt was signed by this instance and is unaltered. Needs
+    /// no login.
+    pub async fn verify_receipt(
+        &self,
+        receipt: &responses::SignedPossessionReceipt,
+    ) -> Result<bool, ClientError> {
+        let response = self.post("verify_receipt", receipt).await?;
+        self.body(response).await
+    }
+
+    /// Current and upcoming possessions won at auction, soonest-ending first.
+    pub async fn my_possessions(
+        &self,
+    ) -> Result<Vec<responses::Possession>, ClientError> {
+        let response = self.empty_get("my_possessions").await?;
+        self.body(response).await
     }
 
     pub async fn delete_bid(
@@ -567,7 +1153,17 @@ impl APIClient {
         round_id: &AuctionRoundId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_bid", &(space_id, round_id)).await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    /// Withdraw all of the caller's bids in an ongoing round. Returns how
+    /// many were withdrawn.
+    pub async fn clear_my_bids(
+        &self,
+        round_id: &AuctionRoundId,
+    ) -> Result<u64, ClientError> {
+        let response = self.post("clear_my_bids", &round_id).await?;
+        self.body(response).await
     }
 
     pub async fn get_eligibility(
@@ -575,15 +1171,28 @@ impl APIClient {
         round_id: &AuctionRoundId,
     ) -> Result<crate::Eligibility, ClientError> {
         let response = self.post("get_eligibility", &round_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
+    /// Eligibility for each round, aligned to rounds only by index.
+    /// Deprecated in favor of [`Self::list_round_eligibility`]; kept until
+    /// existing clients have moved over.
     pub async fn list_eligibility(
         &self,
         auction_id: &AuctionId,
     ) -> Result<Vec<crate::Eligibility>, ClientError> {
         let response = self.post("list_eligibility", &auction_id).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    /// Eligibility for every round of the auction, labeled with each round's
+    /// id and number.
+    pub async fn list_round_eligibility(
+        &self,
+        auction_id: &AuctionId,
+    ) -> Result<Vec<responses::RoundEligibility>, ClientError> {
+        let response = self.post("list_round_eligibility", &auction_id).await?;
+        self.body(response).await
     }
 
     pub async fn create_or_update_user_value(
@@ -592,7 +1201,16 @@ impl APIClient {
     ) -> Result<(), ClientError> {
         let response =
             self.post("create_or_update_user_value", details).await?;
-        ok_empty(response).await
+        self.empty(response).await
+    }
+
+    /// Set a value and rerun proxy bidding for the current round right away.
+    pub async fn set_user_value_and_rebid(
+        &self,
+        details: &requests::UserValue,
+    ) -> Result<(), ClientError> {
+        let response = self.post("set_user_value_and_rebid", details).await?;
+        self.empty(response).await
     }
 
     pub async fn get_user_value(
@@ -600,7 +1218,7 @@ impl APIClient {
         space_id: &SpaceId,
     ) -> Result<responses::UserValue, ClientError> {
         let response = self.post("get_user_value", space_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn delete_user_value(
@@ -608,7 +1226,7 @@ impl APIClient {
         space_id: &SpaceId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_user_value", space_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn list_user_values(
@@ -616,7 +1234,7 @@ impl APIClient {
         site_id: &SiteId,
     ) -> Result<Vec<responses::UserValue>, ClientError> {
         let response = self.post("user_values", site_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_or_update_proxy_bidding(
@@ -625,15 +1243,15 @@ impl APIClient {
     ) -> Result<(), ClientError> {
         let response =
             self.post("create_or_update_proxy_bidding", details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn get_proxy_bidding(
         &self,
         auction_id: &AuctionId,
-    ) -> Result<Option<responses::UseProxyBidding>, ClientError> {
+    ) -> Result<responses::ProxyBiddingStatus, ClientError> {
         let response = self.post("get_proxy_bidding", auction_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     /// Lists members who have enabled proxy bidding for an auction.
@@ -645,7 +1263,7 @@ impl APIClient {
         let response = self
             .post("list_proxy_bidding_participants", auction_id)
             .await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn delete_proxy_bidding(
@@ -653,7 +1271,7 @@ impl APIClient {
         auction_id: &AuctionId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_proxy_bidding", auction_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     // Currency operations
@@ -664,7 +1282,7 @@ impl APIClient {
     ) -> Result<Account, ClientError> {
         let response =
             self.post("update_credit_limit_override", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_member_credit_limit_override(
@@ -674,7 +1292,7 @@ impl APIClient {
         let response = self
             .post("get_member_credit_limit_override", details)
             .await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_member_currency_info(
@@ -682,7 +1300,7 @@ impl APIClient {
         details: &requests::GetMemberCurrencyInfo,
     ) -> Result<responses::MemberCurrencyInfo, ClientError> {
         let response = self.post("get_member_currency_info", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_member_transactions(
@@ -690,7 +1308,7 @@ impl APIClient {
         details: &requests::GetMemberTransactions,
     ) -> Result<Vec<responses::MemberTransaction>, ClientError> {
         let response = self.post("get_member_transactions", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_transfer(
@@ -698,7 +1316,7 @@ impl APIClient {
         details: &requests::CreateTransfer,
     ) -> Result<(), ClientError> {
         let response = self.post("create_transfer", details).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn get_treasury_account(
@@ -706,7 +1324,7 @@ impl APIClient {
         details: &requests::GetTreasuryAccount,
     ) -> Result<Account, ClientError> {
         let response = self.post("get_treasury_account", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_treasury_transactions(
@@ -714,7 +1332,7 @@ impl APIClient {
         details: &requests::GetTreasuryTransactions,
     ) -> Result<Vec<responses::MemberTransaction>, ClientError> {
         let response = self.post("get_treasury_transactions", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn treasury_credit_operation(
@@ -722,7 +1340,7 @@ impl APIClient {
         details: &requests::TreasuryCreditOperation,
     ) -> Result<TreasuryOperationResult, ClientError> {
         let response = self.post("treasury_credit_operation", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn reset_all_balances(
@@ -730,7 +1348,7 @@ impl APIClient {
         details: &requests::ResetAllBalances,
     ) -> Result<responses::BalanceResetResult, ClientError> {
         let response = self.post("reset_all_balances", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn update_profile(
@@ -738,7 +1356,25 @@ impl APIClient {
         details: &requests::UpdateProfile,
     ) -> Result<responses::UserProfile, ClientError> {
         let response = self.post("update_profile", details).await?;
-        ok_body(response).await
+        self.body(response).await
+    }
+
+    pub async fn update_notification_preferences(
+        &self,
+        details: &requests::UpdateNotificationPreferences,
+    ) -> Result<responses::UserProfile, ClientError> {
+        let response = self
+            .post("update_notification_preferences", details)
+            .await?;
+        self.body(response).await
+    }
+
+    pub async fn update_locale(
+        &self,
+        details: &requests::UpdateLocale,
+    ) -> Result<responses::UserProfile, ClientError> {
+        let response = self.post("update_locale", details).await?;
+        self.body(response).await
     }
 
     pub async fn create_site_image(
@@ -746,7 +1382,7 @@ impl APIClient {
         details: &requests::CreateSiteImage,
     ) -> Result<SiteImageId, ClientError> {
         let response = self.post("create_site_image", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     /// Fetches full image data including metadata. Primarily for tests.
@@ -756,7 +1392,7 @@ impl APIClient {
         site_image_id: &SiteImageId,
     ) -> Result<responses::SiteImage, ClientError> {
         let response = self.post("get_site_image", site_image_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     /// Returns the URL for fetching raw image bytes.
@@ -770,7 +1406,7 @@ impl APIClient {
         details: &requests::UpdateSiteImage,
     ) -> Result<responses::SiteImage, ClientError> {
         let response = self.post("update_site_image", details).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn delete_site_image(
@@ -778,7 +1414,7 @@ impl APIClient {
         site_image_id: &SiteImageId,
     ) -> Result<(), ClientError> {
         let response = self.post("delete_site_image", site_image_id).await?;
-        ok_empty(response).await
+        self.empty(response).await
     }
 
     pub async fn list_site_images(
@@ -786,7 +1422,7 @@ impl APIClient {
         community_id: &CommunityId,
     ) -> Result<Vec<responses::SiteImageInfo>, ClientError> {
         let response = self.post("list_site_images", community_id).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     // Billing
@@ -797,7 +1433,7 @@ impl APIClient {
     ) -> Result<crate::CommunityStorageUsage, ClientError> {
         let response =
             self.post("get_community_storage_usage", request).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn get_subscription_info(
@@ -805,7 +1441,7 @@ impl APIClient {
         request: &requests::GetSubscriptionInfo,
     ) -> Result<Option<crate::SubscriptionInfo>, ClientError> {
         let response = self.post("get_subscription_info", request).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_checkout_session(
@@ -813,7 +1449,7 @@ impl APIClient {
         request: &requests::CreateCheckoutSession,
     ) -> Result<crate::CheckoutSessionResponse, ClientError> {
         let response = self.post("create_checkout_session", request).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 
     pub async fn create_portal_session(
@@ -821,7 +1457,7 @@ impl APIClient {
         request: &requests::CreatePortalSession,
     ) -> Result<crate::CheckoutSessionResponse, ClientError> {
         let response = self.post("create_portal_session", request).await?;
-        ok_body(response).await
+        self.body(response).await
     }
 }
 
@@ -829,13 +1465,32 @@ impl APIClient {
 pub enum ClientError {
     /// A typed error deserialized from a JSON error response body.
     #[error("{1}")]
-    Api(StatusCode, ApiError),
+    Api(ResponseMeta, ApiError),
     /// An error response with a non-JSON body (auth failures, 500s,
     /// infrastructure errors), containing the response text.
     #[error("{1}")]
-    APIError(StatusCode, String),
+    APIError(ResponseMeta, String),
     #[error("Network error. Please check your connection.")]
     Network(#[from] reqwest::Error),
+    /// A successful response whose body didn't match the expected type,
+    /// meaning the client and backend disagree on the API contract. Carries
+    /// the raw body for diagnostics.
+    #[error("Unexpected response from {endpoint}: {source}")]
+    Deserialization {
+        endpoint: String,
+        body: String,
+        source: serde_json::Error,
+    },
+}
+
+impl ClientError {
+    /// Response metadata, for errors that came from an HTTP response.
+    pub fn meta(&self) -> Option<&ResponseMeta> {
+        match self {
+            Self::Api(meta, _) | Self::APIError(meta, _) => Some(meta),
+            Self::Network(_) | Self::Deserialization { .. } => None,
+        }
+    }
 }
 
 /// Convert a non-success response into a ClientError, deserializing the
@@ -843,11 +1498,11 @@ pub enum ClientError {
 async fn error_response(
     response: reqwest::Response,
 ) -> Result<ClientError, ClientError> {
-    let status = response.status();
+    let meta = ResponseMeta::from_response(&response);
     let text = response.text().await?;
     Ok(match serde_json::from_str::<ApiError>(&text) {
-        Ok(err) => ClientError::Api(status, err),
-        Err(_) => ClientError::APIError(status, text),
+        Ok(err) => ClientError::Api(meta, err),
+        Err(_) => ClientError::APIError(meta, text),
     })
 }
 
@@ -859,7 +1514,13 @@ pub async fn ok_body<T: serde::de::DeserializeOwned>(
     if !response.status().is_success() {
         return Err(error_response(response).await?);
     }
-    Ok(response.json::<T>().await?)
+    let endpoint = response.url().path().to_string();
+    let body = response.text().await?;
+    serde_json::from_str(&body).map_err(|source| ClientError::Deserialization {
+        endpoint,
+        body,
+        source,
+    })
 }
 
 /// Check that an empty response is OK, returning a ClientError if not.
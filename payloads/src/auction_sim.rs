@@ -6,7 +6,7 @@ use uuid::Uuid;
 
 use crate::{AuctionRoundId, RoundSpaceResult, SpaceId, UserId, responses};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SimInput {
     /// (space_id, name) — name is used for deterministic ordering
     pub spaces: Vec<(SpaceId, String)>,
@@ -141,7 +141,7 @@ pub struct SubscriptionInfo {
 }
 
 /// Response from creating a Stripe Checkout session.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CheckoutSessionResponse {
     pub checkout_url: String,
 }
@@ -0,0 +1,67 @@
+//! Keyset pagination cursors.
+//!
+//! Offset pagination skips or repeats rows when new rows land between page
+//! fetches. Keyset pagination instead resumes strictly after the last row
+//! seen, ordered by `(created_at, id)`, so concurrent inserts can't shift the
+//! page boundary.
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::ApiError;
+
+/// Opaque position in a list ordered by `(created_at DESC, id DESC)`.
+/// Clients pass back the `next_cursor` of the previous page unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Cursor positioned at the given row.
+    pub fn encode(created_at: Timestamp, id: Uuid) -> Self {
+        Self(URL_SAFE_NO_PAD.encode(format!("{created_at}|{id}")))
+    }
+
+    /// The `(created_at, id)` of the row this cursor points at.
+    pub fn decode(&self) -> Result<(Timestamp, Uuid), ApiError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(&self.0)
+            .map_err(|_| ApiError::InvalidCursor)?;
+        let text =
+            String::from_utf8(bytes).map_err(|_| ApiError::InvalidCursor)?;
+        let (created_at, id) =
+            text.split_once('|').ok_or(ApiError::InvalidCursor)?;
+        let created_at =
+            created_at.parse().map_err(|_| ApiError::InvalidCursor)?;
+        let id = id.parse().map_err(|_| ApiError::InvalidCursor)?;
+        Ok((created_at, id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let created_at: Timestamp =
+            "2026-01-02T03:04:05.123456Z".parse().unwrap();
+        let id = Uuid::new_v4();
+        let cursor = Cursor::encode(created_at, id);
+        assert_eq!(cursor.decode().unwrap(), (created_at, id));
+
+        // Serializes as a bare string
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(serde_json::from_str::<Cursor>(&json).unwrap(), cursor);
+    }
+
+    #[test]
+    fn garbage_rejected() {
+        for raw in ["", "not base64!", "bm8gc2VwYXJhdG9y"] {
+            let cursor = Cursor(raw.into());
+            assert_eq!(cursor.decode(), Err(ApiError::InvalidCursor));
+        }
+    }
+}
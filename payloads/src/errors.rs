@@ -5,18 +5,23 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::{AuctionParamsError, PermissionLevel};
+use crate::{
+    AuctionParamsError, OpenHoursError, PermissionLevel, WinnerConstraintError,
+};
 
 /// A client-facing API error. The server serializes this as the error
 /// response body; the client deserializes it back so callers can match on
 /// the exact variant. The adjacent tagging gives every body a uniform
 /// shape: `{"code": "...", "details": ...}`, with `details` only present
-/// for variants that carry data.
+/// for variants that carry data. The server adds a `message` in the
+/// caller's language, which deserializing ignores.
 #[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize, Deserialize)]
 #[serde(tag = "code", content = "details")]
 pub enum ApiError {
     #[error("Invalid username: {0}")]
     InvalidUsername(String),
+    #[error("Invalid display name: {0}")]
+    InvalidDisplayName(String),
     #[error("That username is already taken")]
     UsernameTaken,
     #[error("Invalid email: {0}")]
@@ -27,16 +32,32 @@ pub enum ApiError {
     InvalidPassword(String),
     #[error("Email not yet verified")]
     UnverifiedEmail,
+    #[error("This community is not accepting new members right now")]
+    RegistrationClosed,
+    #[error("Invalid invite base URL: {0}")]
+    InvalidInviteBaseUrl(String),
     #[error("Moderator permissions required")]
     RequiresModeratorPermissions,
     #[error("Coleader permissions required")]
     RequiresColeaderPermissions,
     #[error("Leader permissions required")]
     RequiresLeaderPermissions,
+    #[error("Superuser permissions required")]
+    RequiresSuperuser,
+    #[error("Cannot impersonate a superuser")]
+    CannotImpersonateSuperuser,
+    #[error("Not impersonating a user")]
+    NotImpersonating,
+    #[error("Not allowed while impersonating a user")]
+    NotAllowedWhileImpersonating,
+    #[error("Incorrect password")]
+    IncorrectPassword,
     #[error("Cannot delete space with auction history")]
     SpaceHasAuctionHistory,
     #[error("Mismatched invite email")]
     MismatchedInviteEmail,
+    #[error("{field} cannot contain control characters")]
+    ControlCharacters { field: String },
     #[error("Field too long")]
     FieldTooLong,
     #[error("Invalid invite")]
@@ -51,6 +72,8 @@ pub enum ApiError {
     CannotRemoveHigherRole,
     #[error("Cannot change role of this user")]
     CannotChangeRole,
+    #[error("Invites can only grant roles below your own")]
+    CannotInviteRole,
     #[error("Cannot change own role")]
     CannotChangeSelfRole,
     #[error("Cannot promote to leader")]
@@ -80,8 +103,21 @@ pub enum ApiError {
     AuctionAlreadyStarted,
     #[error("Auction has already ended")]
     AuctionAlreadyEnded,
+    #[error("Proxy bidding for this auction opens at {opens_at}")]
+    ProxyBiddingNotYetOpen { opens_at: jiff::Timestamp },
+    #[error("Proxy bidding can't be changed once the first round has ended")]
+    ProxyBiddingClosed,
+    #[error(
+        "Max items must be at least 1 and at most the {available} spaces \
+        available in the auction"
+    )]
+    InvalidMaxItems { available: i64 },
     #[error("Only canceled auctions can be permanently deleted")]
     AuctionNotCanceled,
+    #[error("Auction hasn't concluded yet")]
+    AuctionNotConcluded,
+    #[error("Only the space's winner or a moderator can get its receipt")]
+    NotPossessionHolder,
     #[error("Auction start time must be in the future")]
     AuctionStartNotInFuture,
     #[error("Auction start time cannot be in the past")]
@@ -90,16 +126,27 @@ pub enum ApiError {
     InvalidPossessionPeriod,
     #[error("Invalid auction parameters: {0}")]
     InvalidAuctionParams(AuctionParamsError),
+    #[error(
+        "Round duration bounds can't be negative, and the minimum can't \
+         exceed the maximum"
+    )]
+    InvalidRoundDurationBounds,
+    #[error("Invalid winner constraints: {0}")]
+    InvalidWinnerConstraints(WinnerConstraintError),
     #[error("Round space result not found")]
     RoundSpaceResultNotFound,
     #[error("Bid not found")]
     BidNotFound,
+    #[error("You have already bid on this space in this round")]
+    DuplicateBid,
     #[error("Round has ended")]
     RoundEnded,
     #[error("Auction round not found")]
     AuctionRoundNotFound,
     #[error("Round has not started yet")]
     RoundNotStarted,
+    #[error("Invalid pagination cursor")]
+    InvalidCursor,
     #[error("User not found")]
     UserNotFound,
     #[error("Community not found")]
@@ -110,6 +157,8 @@ pub enum ApiError {
     SpaceNotFound,
     #[error("Site image not found")]
     SiteImageNotFound,
+    #[error("Site image belongs to a different community")]
+    SiteImageCommunityMismatch,
     #[error("Image too large. Maximum size is 1MB, received {size} bytes")]
     ImageTooLarge { size: usize },
     #[error(
@@ -140,24 +189,51 @@ pub enum ApiError {
         "Journal note too long. Maximum is {max} characters, received {size}"
     )]
     JournalNoteTooLong { size: usize, max: usize },
+    #[error(
+        "Moderator notes too long. Maximum is {max} characters, received {size}"
+    )]
+    ModeratorNotesTooLong { size: usize, max: usize },
+    #[error(
+        "Internal status too long. Maximum is {max} characters, received {size}"
+    )]
+    InternalStatusTooLong { size: usize, max: usize },
     #[error("Invalid timezone: {timezone}")]
     InvalidTimezone { timezone: String },
+    #[error("A timezone is required when {field} is set")]
+    TimezoneRequired { field: String },
+    #[error("{field} can't use {units}")]
+    InvalidSpanUnits { field: String, units: String },
     #[error("Community invite not found")]
     CommunityInviteNotFound,
+    #[error(
+        "The community has {count} outstanding invites, the most allowed; \
+        delete one to issue another"
+    )]
+    TooManyPendingInvites { count: i64, max: i64 },
+    #[error("You already lead {max} communities, the most allowed")]
+    CommunityLimitReached { max: i64 },
     #[error("Open hours not found")]
     OpenHoursNotFound,
+    #[error("Invalid open hours: {0}")]
+    InvalidOpenHours(OpenHoursError),
     #[error("Auction params not found")]
     AuctionParamsNotFound,
+    #[error("No auction params given and the community has no default")]
+    NoDefaultAuctionParams,
     #[error(
         "Exceeds eligibility. Available: {available}, Required: {required}"
     )]
     ExceedsEligibility { available: f64, required: f64 },
-    #[error("Cannot bid on a space you are already winning")]
-    AlreadyWinningSpace,
+    #[error(
+        "Cannot bid on a space you are already winning at a price of {price}"
+    )]
+    AlreadyWinningSpace { price: Decimal },
     #[error("Space is not available for bidding")]
     SpaceNotAvailable,
     #[error("Space has been deleted")]
     SpaceDeleted,
+    #[error("A new space order must list each of the site's spaces once")]
+    InvalidSpaceOrder,
     #[error("Site has been deleted")]
     SiteDeleted,
     #[error("User value not found")]
@@ -172,6 +248,10 @@ pub enum ApiError {
     TokenAlreadyUsed,
     #[error("Token expired")]
     TokenExpired,
+    #[error("Invalid username or recovery code")]
+    InvalidRecoveryCode,
+    #[error("Too many account recovery attempts. Please try again later.")]
+    AccountRecoveryRateLimited,
     #[error("Cannot delete user who is a leader of a community")]
     UserIsLeader,
     #[error("Account not found")]
@@ -193,6 +273,8 @@ pub enum ApiError {
     },
     #[error("Amount must be positive")]
     AmountMustBePositive,
+    #[error("Amount must not be negative")]
+    AmountMustBeNonNegative,
     #[error("Amount must be non-zero")]
     AmountMustBeNonZero,
     #[error(
@@ -220,6 +302,15 @@ pub enum ApiError {
     CannotResetDuringActiveAuction,
     #[error("Cannot delete site with financial history")]
     SiteHasFinancialHistory,
+    #[error(
+        "Deleting this site also deletes its {} spaces and {} auctions; \
+         confirm to proceed",
+        .0.spaces,
+        .0.concluded_auctions + .0.pending_auctions
+    )]
+    SiteDeletionNotConfirmed(crate::responses::SiteDependencies),
+    #[error("Cannot delete a site while one of its auctions is active")]
+    SiteHasActiveAuction,
     #[error("Community already has an active subscription")]
     AlreadySubscribed,
     #[error("No subscription found for this community")]
@@ -239,6 +330,16 @@ pub enum ApiError {
         limit: i64,
         estimated_size_after_operation: i64,
     },
+    #[error("TinyLVT is down for maintenance. Please try again shortly.")]
+    MaintenanceMode,
+    #[error("Your data can only be exported once per hour")]
+    DataExportRateLimited,
+    #[error(
+        "Request body must be JSON, sent as Content-Type: application/json"
+    )]
+    UnsupportedContentType,
+    #[error("Invalid request body: {0}")]
+    InvalidRequestBody(String),
 }
 
 #[cfg(test)]
@@ -17,6 +17,47 @@ pub const MAX_COMMUNITY_DESCRIPTION_LENGTH: usize = 10_000;
 /// Maximum allowed length for space descriptions (500 characters)
 pub const MAX_SPACE_DESCRIPTION_LENGTH: usize = 500;
 
+/// Maximum allowed length for an auction's moderator notes (10,000
+/// characters)
+pub const MAX_AUCTION_MODERATOR_NOTES_LENGTH: usize = 10_000;
+
+/// Maximum allowed length for an auction's internal status (100 characters)
+pub const MAX_AUCTION_INTERNAL_STATUS_LENGTH: usize = 100;
+
+/// A language the backend has messages in, such as for API errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "use-sqlx", derive(Type))]
+#[cfg_attr(
+    feature = "use-sqlx",
+    sqlx(type_name = "locale", rename_all = "lowercase")
+)]
+pub enum Locale {
+    En,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Es];
+
+    /// The BCP 47 language tag, such as `en`.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// The locale for a language tag, matching its primary language subtag
+    /// case-insensitively, so `es-MX` is Spanish.
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['-', '_']).next()?;
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(language))
+    }
+}
+
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize,
 )]
@@ -107,6 +148,21 @@ impl Role {
         }
     }
 
+    /// Check if actor can issue an invite that grants `role` on acceptance.
+    /// Invites grant at most one level below the inviter's own role:
+    /// - Leader can invite up to coleader
+    /// - Coleader can invite members or moderators
+    /// - Moderator can only invite members
+    /// - Member cannot invite anyone
+    pub fn can_invite_role(&self, role: &Role) -> bool {
+        match self {
+            Role::Leader => !role.is_leader(),
+            Role::Coleader => matches!(role, Role::Member | Role::Moderator),
+            Role::Moderator => matches!(role, Role::Member),
+            Role::Member => false,
+        }
+    }
+
     /// Moderators can change per-member attributes like active status and
     /// credit limits.
     pub fn can_edit_credit_limit(&self) -> bool {
@@ -165,13 +221,40 @@ pub struct AuctionParams {
     pub round_duration: Span,
     pub bid_increment: BidIncrement,
     pub activity_rule_params: ActivityRuleParams,
+    /// Maps a round number to the bid increment for bids placed from that
+    /// round onwards. Rounds before the first breakpoint (or all rounds, when
+    /// empty) use the flat `bid_increment`.
+    #[serde(default)]
+    pub increment_schedule: Vec<(i32, Decimal)>,
+    /// The auction can't conclude before this many rounds have run, even if
+    /// a round passes with no new bids. None lets it end after the first
+    /// quiet round.
+    #[serde(default)]
+    pub min_rounds: Option<i32>,
+    /// How eligibility recomputed between rounds is rounded to whole points.
+    #[serde(default)]
+    pub eligibility_rounding: RoundingPolicy,
+}
+
+impl AuctionParams {
+    /// The canonical form that a round trip through the database returns.
+    pub fn normalized(&self) -> Self {
+        Self {
+            round_duration: normalize_span(self.round_duration),
+            ..self.clone()
+        }
+    }
 }
 
 impl PartialEq for AuctionParams {
     fn eq(&self, other: &Self) -> bool {
-        self.round_duration.fieldwise() == other.round_duration.fieldwise()
+        normalize_span(self.round_duration).fieldwise()
+            == normalize_span(other.round_duration).fieldwise()
             && self.bid_increment == other.bid_increment
             && self.activity_rule_params == other.activity_rule_params
+            && self.increment_schedule == other.increment_schedule
+            && self.min_rounds == other.min_rounds
+            && self.eligibility_rounding == other.eligibility_rounding
     }
 }
 
@@ -192,6 +275,63 @@ pub const MIN_ROUND_DURATION_SECS: i64 = 5;
 /// `process_locked_auction`. Users should retry with a larger increment.
 pub const MAX_AUCTION_ROUNDS: i32 = 10_000;
 
+/// Shortest and longest round duration allowed for a community's auctions,
+/// so a careless coleader can't set rounds the scheduler would hammer, or
+/// ones nobody waits for. Either bound may be absent.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct RoundDurationBounds {
+    pub min: Option<jiff::SignedDuration>,
+    pub max: Option<jiff::SignedDuration>,
+}
+
+impl RoundDurationBounds {
+    /// These bounds, with each one that's absent taken from `fallback`.
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            min: self.min.or(fallback.min),
+            max: self.max.or(fallback.max),
+        }
+    }
+
+    /// Whether the bounds can be met: neither is negative and the minimum
+    /// isn't above the maximum.
+    pub fn is_valid(&self) -> bool {
+        self.min.is_none_or(|min| !min.is_negative())
+            && self.max.is_none_or(|max| !max.is_negative())
+            && match (self.min, self.max) {
+                (Some(min), Some(max)) => min <= max,
+                _ => true,
+            }
+    }
+
+    /// Whether a round of length `span` is within the bounds. Days count as
+    /// 24 hours; a span in months or years is longer than any minimum and
+    /// beyond any maximum.
+    pub fn contains(&self, span: Span) -> bool {
+        let relative = jiff::SpanRelativeTo::days_are_24_hours();
+        let Ok(duration) = span.to_duration(relative) else {
+            return self.max.is_none();
+        };
+        self.min.is_none_or(|min| duration >= min)
+            && self.max.is_none_or(|max| duration <= max)
+    }
+}
+
+impl std::fmt::Display for RoundDurationBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                write!(f, "between {min:#} and {max:#}")
+            }
+            (Some(min), None) => write!(f, "at least {min:#}"),
+            (None, Some(max)) => write!(f, "at most {max:#}"),
+            (None, None) => write!(f, "any length"),
+        }
+    }
+}
+
 /// Why an [`AuctionParams`] is invalid.
 #[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize, Deserialize)]
 pub enum AuctionParamsError {
@@ -201,6 +341,10 @@ pub enum AuctionParamsError {
         MIN_ROUND_DURATION_SECS
     )]
     RoundDurationTooShort,
+    /// The round duration has units of days or larger. Rounds are a fixed
+    /// length of time, which a day isn't across a DST change.
+    #[error("Round duration must be given in hours, minutes, and seconds")]
+    RoundDurationNotAbsolute,
     /// The bid increment is zero or negative. A simultaneous ascending auction
     /// terminates when each space's price rises past every bidder's value, so a
     /// non-positive increment never raises the price and the auction would
@@ -210,6 +354,20 @@ pub enum AuctionParamsError {
     /// The eligibility progression is invalid.
     #[error(transparent)]
     EligibilityProgression(EligibilityProgressionError),
+    /// The bid increment schedule is invalid.
+    #[error(transparent)]
+    IncrementSchedule(IncrementScheduleError),
+    /// The minimum round count is below one or would reach
+    /// [`MAX_AUCTION_ROUNDS`], where the auction is canceled instead.
+    #[error(
+        "Minimum round count must be between 1 and {}",
+        MAX_AUCTION_ROUNDS - 1
+    )]
+    MinRoundsOutOfRange,
+    /// The round duration is outside the community's bounds, or the
+    /// instance's where the community sets none.
+    #[error("Round duration must be {0}")]
+    RoundDurationOutOfBounds(RoundDurationBounds),
 }
 
 impl AuctionParams {
@@ -217,21 +375,15 @@ impl AuctionParams {
     /// site updates all route through this single validator, so every
     /// constraint the scheduler relies on belongs here.
     pub fn validate(&self) -> Result<(), AuctionParamsError> {
-        // A negative span is shorter than any minimum regardless of its units,
-        // and `total` below can't resolve a negative calendar-unit span to
-        // check it, so reject the negative case up front.
-        if self.round_duration.is_negative() {
-            return Err(AuctionParamsError::RoundDurationTooShort);
+        if !is_absolute_span(&self.round_duration) {
+            return Err(AuctionParamsError::RoundDurationNotAbsolute);
         }
-
-        // `total` resolves a span to seconds only when its largest unit is
-        // hours or smaller; days and up need a calendar reference. A
-        // non-negative span with calendar units is necessarily far longer than
-        // the minimum, so treat the resolvable case as the only remaining one
-        // that can be too short.
-        if let Ok(secs) = self.round_duration.total(jiff::Unit::Second)
-            && secs < MIN_ROUND_DURATION_SECS as f64
-        {
+        // With no calendar units, `total` needs no reference date
+        let secs = self
+            .round_duration
+            .total(jiff::Unit::Second)
+            .map_err(|_| AuctionParamsError::RoundDurationTooShort)?;
+        if secs < MIN_ROUND_DURATION_SECS as f64 {
             return Err(AuctionParamsError::RoundDurationTooShort);
         }
 
@@ -243,8 +395,152 @@ impl AuctionParams {
             .validate()
             .map_err(AuctionParamsError::EligibilityProgression)?;
 
+        validate_increment_schedule(&self.increment_schedule)
+            .map_err(AuctionParamsError::IncrementSchedule)?;
+
+        if let Some(min_rounds) = self.min_rounds
+            && !(1..MAX_AUCTION_ROUNDS).contains(&min_rounds)
+        {
+            return Err(AuctionParamsError::MinRoundsOutOfRange);
+        }
+
         Ok(())
     }
+
+    /// Bid increment for bids placed in `round_num`.
+    pub fn bid_increment_for_round(&self, round_num: i32) -> BidIncrement {
+        bid_increment_for_round(
+            round_num,
+            &self.increment_schedule,
+            self.bid_increment,
+        )
+    }
+
+    /// Check a proposed amount against the current high value of a space in
+    /// `round_num`: it must raise by at least that round's increment, in
+    /// whole increments. With no high value yet any amount is accepted, since
+    /// the opening price is the space's reserve rather than an increment.
+    pub fn is_valid_bid(
+        &self,
+        round_num: i32,
+        current_high: Option<Decimal>,
+        proposed: Decimal,
+    ) -> Result<(), BidError> {
+        let Some(current_high) = current_high else {
+            return Ok(());
+        };
+        let increment = self.bid_increment_for_round(round_num).0;
+        let raise = proposed - current_high;
+        if raise < increment {
+            return Err(BidError::BelowMinimumRaise {
+                minimum: current_high + increment,
+            });
+        }
+        if !(raise % increment).is_zero() {
+            return Err(BidError::NotAligned { increment });
+        }
+        Ok(())
+    }
+}
+
+/// Why a proposed bid amount is invalid, from [`AuctionParams::is_valid_bid`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, Serialize, Deserialize,
+)]
+pub enum BidError {
+    /// The amount doesn't raise the current value by a full increment.
+    #[error("Bid must be at least {minimum}")]
+    BelowMinimumRaise { minimum: Decimal },
+    /// The raise isn't a whole number of increments.
+    #[error("Bid must rise in steps of {increment}")]
+    NotAligned { increment: Decimal },
+}
+
+/// Bid increment for bids placed in `round_num`: the increment of the last
+/// schedule breakpoint at or before the round, or `flat` if there is none.
+/// The schedule must be validated (ascending rounds), since it's
+/// binary-searched.
+pub fn bid_increment_for_round(
+    round_num: i32,
+    schedule: &[(i32, Decimal)],
+    flat: BidIncrement,
+) -> BidIncrement {
+    match schedule.binary_search_by(|(round, _)| round.cmp(&round_num)) {
+        Ok(idx) => BidIncrement(schedule[idx].1),
+        Err(0) => flat,
+        Err(idx) => BidIncrement(schedule[idx - 1].1),
+    }
+}
+
+/// Why a bid increment schedule is invalid. Like
+/// [`EligibilityProgressionError`], each variant names the offending entry.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, Serialize, Deserialize,
+)]
+pub enum IncrementScheduleError {
+    /// An increment was zero or negative, which would stall the auction (see
+    /// [`AuctionParamsError::BidIncrementNotPositive`]).
+    #[error("Bid increment for round {round} must be greater than zero")]
+    IncrementNotPositive { index: usize, round: i32 },
+    /// Round numbers must be strictly ascending. `index` is the position of
+    /// the entry that is not greater than its predecessor.
+    #[error("Round numbers must be in strictly ascending order")]
+    RoundsNotAscending { index: usize },
+    /// A round number was negative.
+    #[error("Round number {round} cannot be negative")]
+    NegativeRound { index: usize, round: i32 },
+}
+
+fn validate_increment_schedule(
+    schedule: &[(i32, Decimal)],
+) -> Result<(), IncrementScheduleError> {
+    for (index, &(round, increment)) in schedule.iter().enumerate() {
+        if round < 0 {
+            return Err(IncrementScheduleError::NegativeRound { index, round });
+        }
+        if increment <= Decimal::ZERO {
+            return Err(IncrementScheduleError::IncrementNotPositive {
+                index,
+                round,
+            });
+        }
+        if index > 0 && round <= schedule[index - 1].0 {
+            return Err(IncrementScheduleError::RoundsNotAscending { index });
+        }
+    }
+    Ok(())
+}
+
+/// How a user's eligibility for the next round is rounded. Eligibility is
+/// activity divided by the round's threshold, which rarely lands on a whole
+/// point: 6 points of activity at a 0.8 threshold gives 7.5.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "use-sqlx", derive(Type))]
+#[cfg_attr(
+    feature = "use-sqlx",
+    sqlx(type_name = "eligibility_rounding", rename_all = "lowercase")
+)]
+pub enum RoundingPolicy {
+    /// Round down, so eligibility never exceeds what the activity earned.
+    #[default]
+    Floor,
+    /// Round to the nearest point, with halves rounded up.
+    Round,
+}
+
+impl RoundingPolicy {
+    /// Round `eligibility` to whole points. A value within float error of a
+    /// whole number, such as 3 / 0.1 = 29.999999999999996, counts as that
+    /// number rather than being floored below it.
+    pub fn apply(self, eligibility: f64) -> f64 {
+        let eligibility = (eligibility * 1e9).round() / 1e9;
+        match self {
+            RoundingPolicy::Floor => eligibility.floor(),
+            RoundingPolicy::Round => eligibility.round(),
+        }
+    }
 }
 
 /// Contents of the `activity_rule_params` JSONB column of `auction_params`.
@@ -261,6 +557,14 @@ pub struct ActivityRuleParams {
     Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, Serialize, Deserialize,
 )]
 pub enum EligibilityProgressionError {
+    /// The progression was empty. Every auction needs an activity rule, even
+    /// if it's a single `(0, 1.0)` breakpoint.
+    #[error("At least one eligibility breakpoint is required")]
+    Empty,
+    /// The first breakpoint wasn't for round 0, leaving the rounds before it
+    /// without a defined threshold.
+    #[error("The first breakpoint must be for round 0, not round {round}")]
+    FirstRoundNotZero { round: i32 },
     /// A threshold fell outside `[0.0, 1.0]`. `round` is the breakpoint's round
     /// number; `index` is its position in the list.
     #[error("Threshold for round {round} must be between 0% and 100%")]
@@ -275,12 +579,19 @@ pub enum EligibilityProgressionError {
     /// have no meaning.
     #[error("Round number {round} cannot be negative")]
     NegativeRound { index: usize, round: i32 },
+    /// The last threshold was below 100%, which would let bidders keep
+    /// shedding activity for the rest of the auction.
+    #[error("The final threshold (round {round}) must be 100%")]
+    FinalThresholdNotFull { index: usize, round: i32 },
 }
 
 impl ActivityRuleParams {
     /// Validate the eligibility progression. The scheduler binary-searches this
-    /// list by round number (see `get_eligibility_for_round_num`), so the
+    /// list by round number (see [`eligibility_for_round_num`]), so the
     /// ascending invariant is a correctness requirement, not just hygiene.
+    /// The progression must start at round 0 and end at a 100% threshold.
+    /// A 0% threshold stays valid: it's how early rounds leave eligibility
+    /// unconstrained.
     ///
     /// Returns the first error encountered, scanning in list order.
     pub fn validate(&self) -> Result<(), EligibilityProgressionError> {
@@ -292,6 +603,11 @@ impl ActivityRuleParams {
                     round,
                 });
             }
+            if index == 0 && round != 0 {
+                return Err(EligibilityProgressionError::FirstRoundNotZero {
+                    round,
+                });
+            }
             if !(0.0..=1.0).contains(&threshold) {
                 return Err(EligibilityProgressionError::ThresholdOutOfRange {
                     index,
@@ -304,13 +620,121 @@ impl ActivityRuleParams {
                 });
             }
         }
-        Ok(())
+        match progression.last() {
+            None => Err(EligibilityProgressionError::Empty),
+            Some(&(round, threshold)) if threshold != 1.0 => {
+                Err(EligibilityProgressionError::FinalThresholdNotFull {
+                    index: progression.len() - 1,
+                    round,
+                })
+            }
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+/// Fraction of eligibility required in `round_num`: the threshold of the last
+/// progression breakpoint at or before the round. Like
+/// [`bid_increment_for_round`], the progression must be validated.
+pub fn eligibility_for_round_num(
+    round_num: i32,
+    progression: &[(i32, f64)],
+) -> f64 {
+    // binary_search_by returns either the index of an exact match, or the
+    // insert location where round_num would go. The eligibility progression
+    // defines the threshold for a breakpoint's round onwards, so on a miss we
+    // want the breakpoint just before the insert location (idx - 1).
+    match progression.binary_search_by(|(round, _)| round.cmp(&round_num)) {
+        Ok(idx) => progression[idx].1,
+        // Before the first breakpoint (insert location 0): no breakpoint
+        // applies yet, so eligibility is unconstrained (0.0). This also
+        // covers an empty progression, whose only insert location is 0.
+        Err(0) => 0.0,
+        Err(idx) => progression[idx - 1].1,
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OpenHours {
     pub days_of_week: Vec<OpenHoursWeekday>,
+    /// Per-date overrides of the weekly hours, such as a holiday closure or
+    /// a special opening.
+    #[serde(default)]
+    pub exceptions: Vec<OpenHoursException>,
+}
+
+impl OpenHours {
+    /// Weekdays sorted by day and exceptions by date, the order the store
+    /// returns them in.
+    pub fn normalized(&self) -> Self {
+        let mut normalized = self.clone();
+        normalized.days_of_week.sort_by_key(|day| day.day_of_week);
+        normalized
+            .exceptions
+            .sort_by_key(|exception| exception.date);
+        normalized
+    }
+
+    pub fn validate(&self) -> Result<(), OpenHoursError> {
+        for (index, exception) in self.exceptions.iter().enumerate() {
+            let date = exception.date;
+            let has_hours = match (exception.open_time, exception.close_time) {
+                (Some(_), Some(_)) => true,
+                (None, None) => false,
+                _ => return Err(OpenHoursError::IncompleteHours { date }),
+            };
+            if exception.closed == has_hours {
+                return Err(OpenHoursError::IncompleteHours { date });
+            }
+            if self.exceptions[..index].iter().any(|e| e.date == date) {
+                return Err(OpenHoursError::DuplicateException { date });
+            }
+        }
+        Ok(())
+    }
+
+    /// Opening and closing times on `date`, or None if the site is closed
+    /// that day. An exception for the date takes precedence over the weekly
+    /// hours for its weekday.
+    pub fn hours_on(&self, date: jiff::civil::Date) -> Option<(Time, Time)> {
+        if let Some(exception) = self.exceptions.iter().find(|e| e.date == date)
+        {
+            return match (exception.open_time, exception.close_time) {
+                (Some(open), Some(close)) if !exception.closed => {
+                    Some((open, close))
+                }
+                _ => None,
+            };
+        }
+        let day_of_week = i16::from(date.weekday().to_monday_one_offset());
+        self.days_of_week
+            .iter()
+            .find(|day| day.day_of_week == day_of_week)
+            .map(|day| (day.open_time, day.close_time))
+    }
+
+    /// The hours on `date` as instants, with the civil times read in `tz`.
+    /// A closing time at or before the opening time is on the next day.
+    /// Across a DST change, a time skipped by the clocks moving forward
+    /// resolves to after the gap, and a time repeated when they move back
+    /// resolves to its first occurrence.
+    pub fn windows_on(
+        &self,
+        date: jiff::civil::Date,
+        tz: &jiff::tz::TimeZone,
+    ) -> Result<Vec<(Timestamp, Timestamp)>, jiff::Error> {
+        let Some((open, close)) = self.hours_on(date) else {
+            return Ok(vec![]);
+        };
+        let close_date = if close <= open {
+            date.tomorrow()?
+        } else {
+            date
+        };
+        let opens_at = date.to_datetime(open).to_zoned(tz.clone())?;
+        let closes_at = close_date.to_datetime(close).to_zoned(tz.clone())?;
+        Ok(vec![(opens_at.timestamp(), closes_at.timestamp())])
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -323,6 +747,77 @@ pub struct OpenHoursWeekday {
     pub close_time: Time,
 }
 
+/// Open hours for a single date, replacing the weekly hours for that day.
+/// A closed exception has no times; an open one has both.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(FromRow))]
+pub struct OpenHoursException {
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "jiff_sqlx::Date"))]
+    pub date: jiff::civil::Date,
+    pub closed: bool,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "OptionalTime"))]
+    pub open_time: Option<Time>,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "OptionalTime"))]
+    pub close_time: Option<Time>,
+}
+
+/// Why an [`OpenHours`] is invalid.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, Serialize, Deserialize,
+)]
+pub enum OpenHoursError {
+    /// A closed exception had times, or an open one was missing either.
+    #[error(
+        "Exception on {date} must either be closed or have both an opening \
+         and closing time"
+    )]
+    IncompleteHours { date: jiff::civil::Date },
+    /// More than one exception was given for the same date.
+    #[error("More than one exception for {date}")]
+    DuplicateException { date: jiff::civil::Date },
+}
+
+/// The canonical form of a span in the units a Postgres interval stores:
+/// years fold into months, weeks into days, and the clock units balance up
+/// to hours. Spans describing the same interval normalize to fieldwise-equal
+/// spans, so this is what a span looks like after a database round trip.
+/// Spans too large to rebalance are returned unchanged.
+pub fn normalize_span(span: Span) -> Span {
+    fn normalize(span: Span) -> Result<Span, jiff::Error> {
+        let months =
+            i64::from(span.get_years()) * 12 + i64::from(span.get_months());
+        let days = i64::from(span.get_weeks()) * 7 + i64::from(span.get_days());
+        let nanos = i128::from(span.get_hours()) * 3_600_000_000_000
+            + i128::from(span.get_minutes()) * 60_000_000_000
+            + i128::from(span.get_seconds()) * 1_000_000_000
+            + i128::from(span.get_milliseconds()) * 1_000_000
+            + i128::from(span.get_microseconds()) * 1_000
+            + i128::from(span.get_nanoseconds());
+        // Every unit of a span shares its sign, so each part is in range of
+        // the span's own units after dividing down.
+        let part = |unit: i128, per: i128| (nanos / unit % per) as i64;
+        Span::new()
+            .try_months(months)?
+            .try_days(days)?
+            .try_hours((nanos / 3_600_000_000_000) as i64)?
+            .try_minutes(part(60_000_000_000, 60))?
+            .try_seconds(part(1_000_000_000, 60))?
+            .try_milliseconds(part(1_000_000, 1_000))?
+            .try_microseconds(part(1_000, 1_000))?
+            .try_nanoseconds(part(1, 1_000))
+    }
+    normalize(span).unwrap_or(span)
+}
+
+/// Whether a span has no units of days or larger, so that it's the same
+/// length of time wherever it's added.
+pub fn is_absolute_span(span: &Span) -> bool {
+    span.get_years() == 0
+        && span.get_months() == 0
+        && span.get_weeks() == 0
+        && span.get_days() == 0
+}
+
 /// An empty schedule can be used to delete the schedule entirely.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Site {
@@ -330,8 +825,13 @@ pub struct Site {
     pub name: String,
     pub description: Option<String>,
     pub default_auction_params: AuctionParams,
+    /// May use days and weeks, which are added in the site's timezone (UTC
+    /// if unset) so that a possession keeps its local time of day across a
+    /// DST change. Months and years aren't allowed.
     pub possession_period: Span,
+    /// Hours and smaller only, so that it's a fixed length of time
     pub auction_lead_time: Span,
+    /// Hours and smaller only, so that it's a fixed length of time
     pub proxy_bidding_lead_time: Span,
     pub open_hours: Option<OpenHours>,
     pub auto_schedule: bool,
@@ -339,22 +839,81 @@ pub struct Site {
     pub site_image_id: Option<SiteImageId>,
 }
 
+/// Compares normalized forms, so a site equals itself after a database
+/// round trip.
 impl PartialEq for Site {
     fn eq(&self, other: &Self) -> bool {
-        self.community_id == other.community_id
-            && self.name == other.name
-            && self.description == other.description
-            && self.default_auction_params == other.default_auction_params
-            && self.possession_period.fieldwise()
-                == other.possession_period.fieldwise()
-            && self.auction_lead_time.fieldwise()
-                == other.auction_lead_time.fieldwise()
-            && self.proxy_bidding_lead_time.fieldwise()
-                == other.proxy_bidding_lead_time.fieldwise()
-            && self.open_hours == other.open_hours
-            && self.auto_schedule == other.auto_schedule
-            && self.timezone == other.timezone
-            && self.site_image_id == other.site_image_id
+        let (a, b) = (self.normalized(), other.normalized());
+        a.community_id == b.community_id
+            && a.name == b.name
+            && a.description == b.description
+            && a.default_auction_params == b.default_auction_params
+            && a.possession_period.fieldwise()
+                == b.possession_period.fieldwise()
+            && a.auction_lead_time.fieldwise()
+                == b.auction_lead_time.fieldwise()
+            && a.proxy_bidding_lead_time.fieldwise()
+                == b.proxy_bidding_lead_time.fieldwise()
+            && a.open_hours == b.open_hours
+            && a.auto_schedule == b.auto_schedule
+            && a.timezone == b.timezone
+            && a.site_image_id == b.site_image_id
+    }
+}
+
+impl Site {
+    /// The canonical form the store persists and returns: spans balanced
+    /// with [`normalize_span`], open hours sorted, and a blank description
+    /// treated as none.
+    pub fn normalized(&self) -> Self {
+        Self {
+            description: self
+                .description
+                .clone()
+                .filter(|description| !description.trim().is_empty()),
+            default_auction_params: self.default_auction_params.normalized(),
+            possession_period: normalize_span(self.possession_period),
+            auction_lead_time: normalize_span(self.auction_lead_time),
+            proxy_bidding_lead_time: normalize_span(
+                self.proxy_bidding_lead_time,
+            ),
+            open_hours: self.open_hours.as_ref().map(OpenHours::normalized),
+            ..self.clone()
+        }
+    }
+
+    /// A span field using units it doesn't allow, if any, and those units:
+    /// days or larger in a lead time, or months or years in the possession
+    /// period.
+    pub fn field_with_invalid_span_units(
+        &self,
+    ) -> Option<(&'static str, &'static str)> {
+        const CALENDAR: &str = "days, weeks, months, or years";
+        let period = &self.possession_period;
+        if period.get_years() != 0 || period.get_months() != 0 {
+            Some(("possession_period", "months or years"))
+        } else if !is_absolute_span(&self.auction_lead_time) {
+            Some(("auction_lead_time", CALENDAR))
+        } else if !is_absolute_span(&self.proxy_bidding_lead_time) {
+            Some(("proxy_bidding_lead_time", CALENDAR))
+        } else {
+            None
+        }
+    }
+
+    /// The name of a set field that needs a timezone to be meaningful, if
+    /// the timezone is missing. Auto-scheduling and open-hours math are both
+    /// evaluated in the site's local time.
+    pub fn field_requiring_timezone(&self) -> Option<&'static str> {
+        if self.timezone.is_some() {
+            None
+        } else if self.auto_schedule {
+            Some("auto_schedule")
+        } else if self.open_hours.is_some() {
+            Some("open_hours")
+        } else {
+            None
+        }
     }
 }
 
@@ -426,6 +985,69 @@ pub struct Auction {
     /// auction manually or schedules a start time.
     pub start_at: Option<Timestamp>,
     pub auction_params: AuctionParams,
+    /// A practice auction: bidding and results work as usual, but balances
+    /// are never checked or changed and wins don't count as possessions.
+    #[serde(default)]
+    pub sandbox: bool,
+    /// Community rules on who may win, enforced when the auction concludes.
+    #[serde(default)]
+    pub winner_constraints: Vec<WinnerConstraint>,
+}
+
+/// A rule restricting the final allocation of an auction. Bidding is
+/// unaffected; when the auction concludes, wins that break a constraint are
+/// dropped, keeping each member's highest-priced wins first, and the dropped
+/// spaces go unawarded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WinnerConstraint {
+    /// No member may win more than this many spaces.
+    MaxWinsPerUser(u32),
+    /// The eligibility points of a member's won spaces may not exceed this.
+    MaxPointsPerUser(f64),
+    /// Members who are inactive when the auction concludes win nothing.
+    MustBeActiveMember,
+}
+
+/// Why a list of [`WinnerConstraint`]s is invalid. Each variant names the
+/// offending entry.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, thiserror::Error, Serialize, Deserialize,
+)]
+pub enum WinnerConstraintError {
+    /// A win limit of zero would leave every space unawarded.
+    #[error("Maximum wins per member must be at least 1")]
+    MaxWinsZero { index: usize },
+    /// Point limits must be finite and greater than zero.
+    #[error("Maximum points per member must be a positive number")]
+    InvalidMaxPoints { index: usize },
+    /// Each kind of constraint may appear at most once.
+    #[error("Each winner constraint may only be given once")]
+    Duplicate { index: usize },
+}
+
+pub fn validate_winner_constraints(
+    constraints: &[WinnerConstraint],
+) -> Result<(), WinnerConstraintError> {
+    for (index, constraint) in constraints.iter().enumerate() {
+        match constraint {
+            WinnerConstraint::MaxWinsPerUser(0) => {
+                return Err(WinnerConstraintError::MaxWinsZero { index });
+            }
+            WinnerConstraint::MaxPointsPerUser(points)
+                if !points.is_finite() || *points <= 0.0 =>
+            {
+                return Err(WinnerConstraintError::InvalidMaxPoints { index });
+            }
+            _ => {}
+        }
+        if constraints[..index].iter().any(|earlier| {
+            std::mem::discriminant(earlier)
+                == std::mem::discriminant(constraint)
+        }) {
+            return Err(WinnerConstraintError::Duplicate { index });
+        }
+    }
+    Ok(())
 }
 
 /// The lifecycle state of an auction, derived from its timestamps and
@@ -500,8 +1122,15 @@ pub struct RoundSpaceResult {
     pub value: rust_decimal::Decimal,
 }
 
+/// What a bid on a space costs in a round, fixed when the round is created.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundSpacePrice {
+    pub space_id: SpaceId,
+    pub ask_price: rust_decimal::Decimal,
+}
+
 /// Visible only to the creator
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
 pub struct Bid {
     pub space_id: SpaceId,
@@ -920,7 +1549,7 @@ pub struct Account {
     pub credit_limit_override: Option<Decimal>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "use-sqlx", derive(FromRow))]
 pub struct JournalLine {
     pub id: JournalLineId,
@@ -931,10 +1560,13 @@ pub struct JournalLine {
 
 pub mod auction_sim;
 pub mod billing;
+pub mod cursor;
 pub mod errors;
 pub mod requests;
 pub mod responses;
+pub mod sanitize;
 
+pub use cursor::Cursor;
 pub use errors::ApiError;
 
 /// Live update events delivered to the UI over Server-Sent Events. Payloads
@@ -1004,6 +1636,18 @@ impl From<OptionalTimestamp> for Option<jiff::Timestamp> {
     }
 }
 
+#[cfg(feature = "use-sqlx")]
+#[derive(sqlx::Type)]
+#[sqlx(transparent)]
+pub struct OptionalTime(pub Option<jiff_sqlx::Time>);
+
+#[cfg(feature = "use-sqlx")]
+impl From<OptionalTime> for Option<Time> {
+    fn from(x: OptionalTime) -> Option<Time> {
+        x.0.map(|x| x.to_jiff())
+    }
+}
+
 /// Id type wrappers help ensure we don't mix up ids for different tables.
 #[derive(
     Debug, Copy, Clone, PartialEq, Eq, Hash, Display, Serialize, Deserialize,
@@ -1094,7 +1738,10 @@ pub struct SiteImageId(pub Uuid);
 
 pub mod api_client;
 
-pub use api_client::{APIClient, ClientError, ok_body, ok_empty};
+pub use api_client::{
+    APIClient, ClientError, ClientObserver, NoopObserver, REQUEST_ID_HEADER,
+    ResponseMeta, ok_body, ok_empty,
+};
 
 #[cfg(test)]
 mod tests {
@@ -1107,25 +1754,46 @@ mod tests {
     }
 
     #[test]
-    fn empty_progression_is_valid() {
-        assert!(params(vec![]).validate().is_ok());
+    fn valid_progression() {
+        assert!(
+            params(vec![(0, 0.0), (3, 0.5), (10, 1.0)])
+                .validate()
+                .is_ok()
+        );
+        assert!(params(vec![(0, 1.0)]).validate().is_ok());
     }
 
     #[test]
-    fn round_zero_is_allowed() {
-        // Round 0 sets eligibility going into round 1
-        assert!(params(vec![(0, 0.5)]).validate().is_ok());
+    fn empty_progression_is_rejected() {
+        assert_eq!(
+            params(vec![]).validate(),
+            Err(EligibilityProgressionError::Empty)
+        );
     }
 
     #[test]
-    fn boundary_thresholds_are_valid() {
-        assert!(params(vec![(1, 0.0), (2, 1.0)]).validate().is_ok());
+    fn progression_must_start_at_round_zero() {
+        assert_eq!(
+            params(vec![(1, 0.5), (2, 1.0)]).validate(),
+            Err(EligibilityProgressionError::FirstRoundNotZero { round: 1 })
+        );
+    }
+
+    #[test]
+    fn final_threshold_must_be_full() {
+        assert_eq!(
+            params(vec![(0, 0.5), (3, 0.75)]).validate(),
+            Err(EligibilityProgressionError::FinalThresholdNotFull {
+                index: 1,
+                round: 3,
+            })
+        );
     }
 
     #[test]
     fn threshold_above_one_is_rejected() {
         assert_eq!(
-            params(vec![(1, 0.5), (2, 1.5)]).validate(),
+            params(vec![(0, 0.5), (2, 1.5)]).validate(),
             Err(EligibilityProgressionError::ThresholdOutOfRange {
                 index: 1,
                 round: 2,
@@ -1136,10 +1804,10 @@ mod tests {
     #[test]
     fn negative_threshold_is_rejected() {
         assert_eq!(
-            params(vec![(1, -0.1)]).validate(),
+            params(vec![(0, -0.1), (1, 1.0)]).validate(),
             Err(EligibilityProgressionError::ThresholdOutOfRange {
                 index: 0,
-                round: 1,
+                round: 0,
             })
         );
     }
@@ -1147,15 +1815,15 @@ mod tests {
     #[test]
     fn non_ascending_rounds_are_rejected() {
         assert_eq!(
-            params(vec![(5, 0.5), (3, 0.75)]).validate(),
-            Err(EligibilityProgressionError::RoundsNotAscending { index: 1 })
+            params(vec![(0, 0.5), (5, 0.5), (3, 1.0)]).validate(),
+            Err(EligibilityProgressionError::RoundsNotAscending { index: 2 })
         );
     }
 
     #[test]
     fn duplicate_rounds_are_rejected() {
         assert_eq!(
-            params(vec![(5, 0.5), (5, 0.75)]).validate(),
+            params(vec![(0, 0.5), (0, 1.0)]).validate(),
             Err(EligibilityProgressionError::RoundsNotAscending { index: 1 })
         );
     }
@@ -1182,7 +1850,10 @@ mod tests {
         AuctionParams {
             round_duration,
             bid_increment: BidIncrement(increment),
-            activity_rule_params: params(vec![]),
+            activity_rule_params: params(vec![(0, 1.0)]),
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: RoundingPolicy::Floor,
         }
     }
 
@@ -1211,19 +1882,27 @@ mod tests {
     }
 
     #[test]
-    fn calendar_unit_round_duration_is_valid() {
-        // A span measured in days can't resolve to seconds without a calendar
-        // reference, but it is necessarily longer than the minimum, so it must
-        // pass rather than be treated as zero.
-        assert!(auction_params(Span::new().days(1)).validate().is_ok());
+    fn calendar_unit_round_duration_is_rejected() {
+        // A day isn't a fixed length across a DST change, so rounds are
+        // given in hours instead
+        for span in [
+            Span::new().days(1),
+            Span::new().weeks(1),
+            Span::new().months(1),
+            Span::new().days(-1),
+        ] {
+            assert_eq!(
+                auction_params(span).validate(),
+                Err(AuctionParamsError::RoundDurationNotAbsolute)
+            );
+        }
+        assert!(auction_params(Span::new().hours(48)).validate().is_ok());
     }
 
     #[test]
-    fn negative_calendar_unit_round_duration_is_rejected() {
-        // A negative calendar-unit span can't resolve to seconds, so the sign
-        // check rather than `total` must catch it.
+    fn negative_round_duration_is_rejected() {
         assert_eq!(
-            auction_params(Span::new().days(-1)).validate(),
+            auction_params(Span::new().hours(-1)).validate(),
             Err(AuctionParamsError::RoundDurationTooShort)
         );
     }
@@ -1261,12 +1940,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eligibility_rounding_at_half_point() {
+        // 0.75 * 10 sits exactly between two whole points
+        assert_eq!(RoundingPolicy::Floor.apply(0.75 * 10.0), 7.0);
+        assert_eq!(RoundingPolicy::Round.apply(0.75 * 10.0), 8.0);
+        assert_eq!(RoundingPolicy::Floor.apply(6.0 / 0.8), 7.0);
+        assert_eq!(RoundingPolicy::Round.apply(6.0 / 0.8), 8.0);
+        // Float error just below a whole number doesn't lose a point
+        assert_eq!(RoundingPolicy::Floor.apply(3.0 / 0.1), 30.0);
+        assert_eq!(RoundingPolicy::Floor.apply(20.0), 20.0);
+    }
+
+    #[test]
+    fn min_rounds_must_be_in_range() {
+        let with_min = |min_rounds| AuctionParams {
+            min_rounds,
+            ..auction_params(Span::new().minutes(5))
+        };
+        assert_eq!(with_min(None).validate(), Ok(()));
+        assert_eq!(with_min(Some(1)).validate(), Ok(()));
+        for min_rounds in [0, -1, MAX_AUCTION_ROUNDS] {
+            assert_eq!(
+                with_min(Some(min_rounds)).validate(),
+                Err(AuctionParamsError::MinRoundsOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_span_balances_to_interval_units() {
+        let normalized = |span: Span| normalize_span(span).fieldwise();
+        assert_eq!(
+            normalized(Span::new().minutes(90)),
+            Span::new().hours(1).minutes(30).fieldwise()
+        );
+        assert_eq!(
+            normalized(Span::new().weeks(1).seconds(3_661)),
+            Span::new()
+                .days(7)
+                .hours(1)
+                .minutes(1)
+                .seconds(1)
+                .fieldwise()
+        );
+        assert_eq!(
+            normalized(Span::new().years(1).months(2)),
+            Span::new().months(14).fieldwise()
+        );
+        assert_eq!(
+            normalized(Span::new().minutes(-90)),
+            Span::new().hours(-1).minutes(-30).fieldwise()
+        );
+        // Days aren't a fixed number of hours, so they don't balance up
+        assert_eq!(
+            normalized(Span::new().hours(36)),
+            Span::new().hours(36).fieldwise()
+        );
+    }
+
+    #[test]
+    fn site_equality_ignores_representation() {
+        let site = Site {
+            community_id: CommunityId(Uuid::nil()),
+            name: "site".into(),
+            description: None,
+            default_auction_params: auction_params(Span::new().minutes(90)),
+            possession_period: Span::new().weeks(1),
+            auction_lead_time: Span::new().minutes(60),
+            proxy_bidding_lead_time: Span::new().days(1),
+            open_hours: Some(OpenHours {
+                days_of_week: vec![
+                    OpenHoursWeekday {
+                        day_of_week: 3,
+                        open_time: Time::constant(9, 0, 0, 0),
+                        close_time: Time::constant(17, 0, 0, 0),
+                    },
+                    OpenHoursWeekday {
+                        day_of_week: 1,
+                        open_time: Time::constant(9, 0, 0, 0),
+                        close_time: Time::constant(17, 0, 0, 0),
+                    },
+                ],
+                exceptions: vec![],
+            }),
+            auto_schedule: false,
+            timezone: None,
+            site_image_id: None,
+        };
+        let mut equivalent = site.normalized();
+        equivalent.description = Some("  ".into());
+        equivalent.default_auction_params =
+            auction_params(Span::new().hours(1).minutes(30));
+        assert_eq!(site, equivalent);
+        assert_eq!(equivalent.normalized().possession_period.get_days(), 7);
+        assert_eq!(
+            site.normalized().open_hours.unwrap().days_of_week[0].day_of_week,
+            1
+        );
+
+        equivalent.auction_lead_time = Span::new().minutes(61);
+        assert_ne!(site, equivalent);
+    }
+
     #[test]
     fn auction_params_validate_surfaces_progression_errors() {
         let p = AuctionParams {
             round_duration: Span::new().minutes(5),
             bid_increment: BidIncrement(Decimal::ONE),
             activity_rule_params: params(vec![(-1, 0.5)]),
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: RoundingPolicy::Floor,
         };
         assert_eq!(
             p.validate(),
@@ -1278,4 +2063,329 @@ mod tests {
             ))
         );
     }
+
+    fn with_schedule(schedule: Vec<(i32, Decimal)>) -> AuctionParams {
+        AuctionParams {
+            increment_schedule: schedule,
+            min_rounds: None,
+            eligibility_rounding: RoundingPolicy::Floor,
+            ..auction_params(Span::new().minutes(5))
+        }
+    }
+
+    #[test]
+    fn bid_must_raise_by_whole_increments() {
+        let p = with_schedule(vec![(10, Decimal::new(25, 1))]);
+        let high = Some(Decimal::new(5, 0));
+        // Opening bids aren't tied to the increment
+        assert_eq!(p.is_valid_bid(0, None, Decimal::new(333, 2)), Ok(()));
+        // Raises of one or more whole increments
+        assert_eq!(p.is_valid_bid(0, high, Decimal::new(6, 0)), Ok(()));
+        assert_eq!(p.is_valid_bid(0, high, Decimal::new(800, 2)), Ok(()));
+        assert_eq!(p.is_valid_bid(10, high, Decimal::new(10, 0)), Ok(()));
+        // Misaligned raises
+        assert_eq!(
+            p.is_valid_bid(0, high, Decimal::new(65, 1)),
+            Err(BidError::NotAligned {
+                increment: Decimal::ONE
+            })
+        );
+        assert_eq!(
+            p.is_valid_bid(10, high, Decimal::new(9, 0)),
+            Err(BidError::NotAligned {
+                increment: Decimal::new(25, 1)
+            })
+        );
+        // Below the minimum raise, including no raise at all
+        for proposed in [Decimal::new(55, 1), Decimal::new(5, 0), Decimal::ONE]
+        {
+            assert_eq!(
+                p.is_valid_bid(0, high, proposed),
+                Err(BidError::BelowMinimumRaise {
+                    minimum: Decimal::new(6, 0)
+                })
+            );
+        }
+        assert_eq!(
+            p.is_valid_bid(12, high, Decimal::new(6, 0)),
+            Err(BidError::BelowMinimumRaise {
+                minimum: Decimal::new(75, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn increment_schedule_lookup() {
+        let p = with_schedule(vec![
+            (2, Decimal::new(10, 1)),
+            (5, Decimal::new(50, 1)),
+        ]);
+        let at = |round| p.bid_increment_for_round(round).0;
+        // Before the first breakpoint, the flat increment applies
+        assert_eq!(at(0), Decimal::ONE);
+        assert_eq!(at(1), Decimal::ONE);
+        assert_eq!(at(2), Decimal::new(10, 1));
+        assert_eq!(at(4), Decimal::new(10, 1));
+        assert_eq!(at(5), Decimal::new(50, 1));
+        assert_eq!(at(100), Decimal::new(50, 1));
+
+        // An empty schedule always uses the flat increment
+        assert_eq!(
+            with_schedule(vec![]).bid_increment_for_round(7).0,
+            Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn invalid_increment_schedules_are_rejected() {
+        let cases = [
+            (
+                vec![(0, Decimal::ONE), (0, Decimal::TWO)],
+                IncrementScheduleError::RoundsNotAscending { index: 1 },
+            ),
+            (
+                vec![(3, Decimal::ONE), (1, Decimal::TWO)],
+                IncrementScheduleError::RoundsNotAscending { index: 1 },
+            ),
+            (
+                vec![(0, Decimal::ONE), (4, Decimal::ZERO)],
+                IncrementScheduleError::IncrementNotPositive {
+                    index: 1,
+                    round: 4,
+                },
+            ),
+            (
+                vec![(-1, Decimal::ONE)],
+                IncrementScheduleError::NegativeRound {
+                    index: 0,
+                    round: -1,
+                },
+            ),
+        ];
+        for (schedule, expected) in cases {
+            assert_eq!(
+                with_schedule(schedule).validate(),
+                Err(AuctionParamsError::IncrementSchedule(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn open_hours_windows_across_dst_gap() {
+        use jiff::civil::{date, time};
+
+        // Clocks in New York skip 02:00-03:00 on 2026-03-08
+        let tz = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let hours = OpenHours {
+            days_of_week: vec![],
+            exceptions: vec![OpenHoursException {
+                date: date(2026, 3, 8),
+                closed: false,
+                open_time: Some(time(2, 30, 0, 0)),
+                close_time: Some(time(1, 0, 0, 0)),
+            }],
+        };
+        let ts = |s: &str| s.parse::<Timestamp>().unwrap();
+        assert_eq!(
+            hours.windows_on(date(2026, 3, 8), &tz).unwrap(),
+            // Opens after the gap, closes the next morning
+            vec![(ts("2026-03-08T07:30:00Z"), ts("2026-03-09T05:00:00Z"))]
+        );
+        assert_eq!(hours.windows_on(date(2026, 3, 9), &tz).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn open_hours_exceptions_override_weekly_hours() {
+        use jiff::civil::{date, time};
+
+        // 2026-12-25 is a Friday, 2026-12-26 a Saturday
+        let hours = OpenHours {
+            days_of_week: vec![OpenHoursWeekday {
+                day_of_week: 5,
+                open_time: time(9, 0, 0, 0),
+                close_time: time(17, 0, 0, 0),
+            }],
+            exceptions: vec![
+                OpenHoursException {
+                    date: date(2026, 12, 25),
+                    closed: true,
+                    open_time: None,
+                    close_time: None,
+                },
+                OpenHoursException {
+                    date: date(2026, 12, 26),
+                    closed: false,
+                    open_time: Some(time(10, 0, 0, 0)),
+                    close_time: Some(time(14, 0, 0, 0)),
+                },
+            ],
+        };
+        assert_eq!(hours.validate(), Ok(()));
+        assert_eq!(hours.hours_on(date(2026, 12, 25)), None);
+        assert_eq!(
+            hours.hours_on(date(2026, 12, 18)),
+            Some((time(9, 0, 0, 0), time(17, 0, 0, 0)))
+        );
+        assert_eq!(
+            hours.hours_on(date(2026, 12, 26)),
+            Some((time(10, 0, 0, 0), time(14, 0, 0, 0)))
+        );
+        assert_eq!(hours.hours_on(date(2026, 12, 19)), None);
+
+        let mut duplicate = hours.clone();
+        duplicate.exceptions[1].date = date(2026, 12, 25);
+        assert_eq!(
+            duplicate.validate(),
+            Err(OpenHoursError::DuplicateException {
+                date: date(2026, 12, 25)
+            })
+        );
+        let mut incomplete = hours;
+        incomplete.exceptions[1].close_time = None;
+        assert_eq!(
+            incomplete.validate(),
+            Err(OpenHoursError::IncompleteHours {
+                date: date(2026, 12, 26)
+            })
+        );
+    }
+
+    #[test]
+    fn winner_constraint_validation() {
+        use WinnerConstraint::*;
+        assert_eq!(
+            validate_winner_constraints(&[
+                MaxWinsPerUser(2),
+                MaxPointsPerUser(3.5),
+                MustBeActiveMember,
+            ]),
+            Ok(())
+        );
+
+        let cases = [
+            (
+                vec![MustBeActiveMember, MaxWinsPerUser(0)],
+                WinnerConstraintError::MaxWinsZero { index: 1 },
+            ),
+            (
+                vec![MaxPointsPerUser(0.0)],
+                WinnerConstraintError::InvalidMaxPoints { index: 0 },
+            ),
+            (
+                vec![MaxPointsPerUser(f64::NAN)],
+                WinnerConstraintError::InvalidMaxPoints { index: 0 },
+            ),
+            (
+                vec![MaxWinsPerUser(1), MaxWinsPerUser(2)],
+                WinnerConstraintError::Duplicate { index: 1 },
+            ),
+        ];
+        for (constraints, expected) in cases {
+            assert_eq!(
+                validate_winner_constraints(&constraints),
+                Err(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_auction_round_response_equality() {
+        let start: Timestamp = "2025-01-01T00:00:00Z".parse().unwrap();
+        let round = responses::AuctionRound {
+            round_id: AuctionRoundId(Uuid::from_u128(1)),
+            round_details: AuctionRound {
+                auction_id: AuctionId(Uuid::from_u128(2)),
+                round_num: 0,
+                start_at: start,
+                end_at: start + jiff::Span::new().minutes(5),
+                eligibility_threshold: 0.5,
+            },
+            created_at: start,
+            updated_at: start,
+        };
+
+        assert_eq!(round, round.clone());
+
+        let mut later = round.clone();
+        later.round_details.round_num = 1;
+        assert_ne!(round, later);
+    }
+
+    #[test]
+    fn eligibility_for_round_num_uses_last_breakpoint() {
+        let progression: &[(i32, f64)] =
+            &[(0, 0.5), (10, 0.75), (20, 0.9), (30, 1.0)];
+        let f = eligibility_for_round_num;
+        assert_eq!(f(0, &progression[..1]), 0.5);
+        assert_eq!(f(0, progression), 0.5);
+        assert_eq!(f(1, progression), 0.5);
+        assert_eq!(f(10, progression), 0.75);
+        assert_eq!(f(11, progression), 0.75);
+        assert_eq!(f(31, progression), 1.0);
+        assert_eq!(f(0, &[]), 0.0);
+        assert_eq!(f(0, &[(5, 0.5)]), 0.0);
+        assert_eq!(f(5, &[(5, 0.5)]), 0.5);
+        // Multiple breakpoints all in the future: rounds before the first
+        // breakpoint are unconstrained, not a panic from index underflow.
+        assert_eq!(f(2, &[(5, 0.5), (10, 0.75)]), 0.0);
+        assert_eq!(f(0, &[(5, 0.5), (10, 0.75)]), 0.0);
+        assert_eq!(f(5, &[(5, 0.5), (10, 0.75)]), 0.5);
+        assert_eq!(f(7, &[(5, 0.5), (10, 0.75)]), 0.5);
+        assert_eq!(f(10, &[(5, 0.5), (10, 0.75)]), 0.75);
+    }
+
+    #[test]
+    fn effective_name_falls_back_to_username() {
+        let mut profile = responses::UserProfile {
+            user_id: UserId(Uuid::nil()),
+            username: "alice".into(),
+            email: "alice@example.com".into(),
+            display_name: None,
+            email_verified: true,
+            email_possession_reminders: true,
+            locale: None,
+            impersonated_by: None,
+            recovery_codes_remaining: 0,
+        };
+        assert_eq!(profile.effective_name(), "alice");
+        profile.display_name = Some("  Alice Smith ".into());
+        assert_eq!(profile.effective_name(), "Alice Smith");
+        profile.display_name = Some("   ".into());
+        assert_eq!(profile.effective_name(), "alice");
+
+        let member = responses::CommunityMember {
+            user: responses::UserIdentity {
+                user_id: profile.user_id,
+                username: "bob".into(),
+                display_name: Some("Bob".into()),
+            },
+            role: Role::Member,
+            is_active: true,
+            balance: None,
+        };
+        assert_eq!(member.effective_name(), "Bob");
+    }
+
+    #[test]
+    fn display_name_validation() {
+        use requests::{DisplayNameValidation, validate_display_name};
+        assert!(validate_display_name("Alice Smith").is_valid());
+        assert!(validate_display_name(&"X".repeat(255)).is_valid());
+        // Surrounding whitespace doesn't count against the limit
+        assert!(
+            validate_display_name(&format!(" {} ", "X".repeat(255))).is_valid()
+        );
+        assert_eq!(
+            validate_display_name(&"X".repeat(256)),
+            DisplayNameValidation::TooLong
+        );
+        assert_eq!(
+            validate_display_name("Alice\nSmith"),
+            DisplayNameValidation::ControlCharacters
+        );
+        assert_eq!(
+            validate_display_name("Alice\u{7}"),
+            DisplayNameValidation::ControlCharacters
+        );
+    }
 }
@@ -186,6 +186,55 @@ pub fn validate_password(password: &str) -> PasswordValidation {
     PasswordValidation::Valid
 }
 
+/// Validation result for display names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisplayNameValidation {
+    Valid,
+    TooLong,
+    ControlCharacters,
+}
+
+impl DisplayNameValidation {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub fn error_message(&self) -> Option<&'static str> {
+        match self {
+            Self::Valid => None,
+            Self::TooLong => {
+                Some("Display name must be at most 255 characters")
+            }
+            Self::ControlCharacters => {
+                Some("Display name cannot contain control characters")
+            }
+        }
+    }
+}
+
+impl From<crate::sanitize::SanitizeError> for DisplayNameValidation {
+    fn from(error: crate::sanitize::SanitizeError) -> Self {
+        match error {
+            crate::sanitize::SanitizeError::TooLong { .. } => Self::TooLong,
+            crate::sanitize::SanitizeError::ControlCharacters => {
+                Self::ControlCharacters
+            }
+        }
+    }
+}
+
+/// Validate a display name as normalized by [`crate::sanitize::name`].
+///
+/// Rules:
+/// - At most 255 characters
+/// - No control characters (including newlines and tabs)
+pub fn validate_display_name(display_name: &str) -> DisplayNameValidation {
+    match crate::sanitize::name(display_name, DISPLAY_NAME_MAX_LEN) {
+        Ok(_) => DisplayNameValidation::Valid,
+        Err(error) => error.into(),
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct LoginCredentials {
     pub username: String,
@@ -222,6 +271,40 @@ pub struct UpdateCommunityDetails {
     pub community_id: CommunityId,
     pub name: String,
     pub description: Option<String>,
+    /// Show the description on the public invite preview. Left unchanged
+    /// when omitted, as by clients from before the setting existed.
+    #[serde(default)]
+    pub description_public: Option<bool>,
+}
+
+/// Open or close a community to new members (leader only).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetRegistrationOpen {
+    pub community_id: CommunityId,
+    pub registration_open: bool,
+}
+
+/// Set or clear the base URL used in the community's invite links (leader
+/// only). Must be an absolute http(s) URL; None falls back to the instance's
+/// base URL.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetInviteBaseUrl {
+    pub community_id: CommunityId,
+    pub invite_base_url: Option<String>,
+}
+
+/// A bound set to `None` falls back to the instance's.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetRoundDurationBounds {
+    pub community_id: CommunityId,
+    pub bounds: crate::RoundDurationBounds,
+}
+
+/// Setting `params` to `None` removes the community default.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetCommunityDefaultParams {
+    pub community_id: CommunityId,
+    pub params: Option<crate::AuctionParams>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -229,6 +312,10 @@ pub struct InviteCommunityMember {
     pub community_id: CommunityId,
     pub new_member_email: Option<String>,
     pub single_use: bool,
+    /// Role granted on acceptance, at most one level below the inviter's.
+    /// None means [`crate::Role::Member`].
+    #[serde(default)]
+    pub role: Option<crate::Role>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -237,7 +324,9 @@ pub struct DeleteInvite {
     pub invite_id: crate::InviteId,
 }
 
-/// An empty schedule can be used to delete the schedule entirely.
+/// An empty schedule can be used to delete the schedule entirely. Members
+/// no longer in the schedule have their active status reset to the
+/// community's default for new members.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetMembershipSchedule {
     pub community_id: CommunityId,
@@ -250,6 +339,47 @@ pub struct RemoveMember {
     pub member_user_id: crate::UserId,
 }
 
+/// List a community's members. The members endpoint also still accepts a
+/// bare community id, listing everyone.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetMembers {
+    pub community_id: CommunityId,
+    /// Only include members whose `is_active` is set
+    #[serde(default)]
+    pub active_only: bool,
+}
+
+/// Replace an auction's moderator notes and internal status. None clears a
+/// field.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateAuctionNotes {
+    pub auction_id: crate::AuctionId,
+    pub moderator_notes: Option<String>,
+    pub internal_status: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetAuctionReport {
+    pub auction_id: crate::AuctionId,
+}
+
+/// Delete a space. The delete endpoint also still accepts a bare space id,
+/// which never forces.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteSpace {
+    pub space_id: crate::SpaceId,
+    /// Archive (soft delete) the space if it has auction history, rather
+    /// than refusing.
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetMemberProfile {
+    pub community_id: CommunityId,
+    pub username: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ChangeMemberRole {
     pub community_id: CommunityId,
@@ -275,11 +405,82 @@ pub struct ResolveOrphanedBalance {
     pub idempotency_key: ClientIdempotencyKey,
 }
 
+/// A site's details as sent to create or update it. Unlike [`crate::Site`],
+/// the default auction params may be omitted: creation then inherits the
+/// community's default params, and an update keeps the site's existing ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteDetails {
+    pub community_id: CommunityId,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub default_auction_params: Option<crate::AuctionParams>,
+    pub possession_period: jiff::Span,
+    pub auction_lead_time: jiff::Span,
+    pub proxy_bidding_lead_time: jiff::Span,
+    pub open_hours: Option<crate::OpenHours>,
+    pub auto_schedule: bool,
+    pub timezone: Option<String>,
+    pub site_image_id: Option<crate::SiteImageId>,
+}
+
+impl SiteDetails {
+    /// The full site, with `params` filling in omitted auction params.
+    pub fn with_default_params(
+        self,
+        params: crate::AuctionParams,
+    ) -> crate::Site {
+        crate::Site {
+            community_id: self.community_id,
+            name: self.name,
+            description: self.description,
+            default_auction_params: self
+                .default_auction_params
+                .unwrap_or(params),
+            possession_period: self.possession_period,
+            auction_lead_time: self.auction_lead_time,
+            proxy_bidding_lead_time: self.proxy_bidding_lead_time,
+            open_hours: self.open_hours,
+            auto_schedule: self.auto_schedule,
+            timezone: self.timezone,
+            site_image_id: self.site_image_id,
+        }
+    }
+}
+
+impl From<crate::Site> for SiteDetails {
+    fn from(site: crate::Site) -> Self {
+        Self {
+            community_id: site.community_id,
+            name: site.name,
+            description: site.description,
+            default_auction_params: Some(site.default_auction_params),
+            possession_period: site.possession_period,
+            auction_lead_time: site.auction_lead_time,
+            proxy_bidding_lead_time: site.proxy_bidding_lead_time,
+            open_hours: site.open_hours,
+            auto_schedule: site.auto_schedule,
+            timezone: site.timezone,
+            site_image_id: site.site_image_id,
+        }
+    }
+}
+
 /// Update site settings.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UpdateSite {
     pub site_id: crate::SiteId,
-    pub site_details: crate::Site,
+    pub site_details: SiteDetails,
+}
+
+/// Permanently delete a site. Without `confirm` nothing is deleted and the
+/// request fails with [`crate::ApiError::SiteDeletionNotConfirmed`], which
+/// reports what the deletion would destroy.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteSite {
+    pub site_id: crate::SiteId,
+    #[serde(default)]
+    pub confirm: bool,
 }
 
 /// Details about a community member for a community one is a part of.
@@ -295,6 +496,58 @@ pub struct UpdateSpaces {
     pub spaces: Vec<UpdateSpace>,
 }
 
+/// Set the display order of a site's spaces. `ordered_space_ids` lists each
+/// of the site's spaces once, first to last.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReorderSpaces {
+    pub site_id: crate::SiteId,
+    pub ordered_space_ids: Vec<crate::SpaceId>,
+}
+
+/// Fetch several spaces at once. Ids that don't exist or belong to a
+/// community the caller isn't in are left out of the response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetSpaces {
+    pub space_ids: Vec<crate::SpaceId>,
+}
+
+/// Maximum number of ids accepted in a single [`GetSpaces`] request.
+pub const MAX_GET_SPACES_IDS: usize = 500;
+
+/// Preview how auction params would play out on a site, without creating
+/// an auction.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateAuction {
+    pub site_id: crate::SiteId,
+    pub auction_params: crate::AuctionParams,
+}
+
+/// Create an auction on the same site as an existing one, copying its
+/// parameters, sandbox flag, and winner constraints. Times are either given
+/// outright or, with `shift_by`, derived from the source auction's.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecreateAuction {
+    pub source_auction_id: crate::AuctionId,
+    /// Required unless `shift_by` is set
+    pub possession_start_at: Option<jiff::Timestamp>,
+    /// Required unless `shift_by` is set
+    pub possession_end_at: Option<jiff::Timestamp>,
+    /// None leaves the new auction unscheduled
+    pub start_at: Option<jiff::Timestamp>,
+    /// Move each of the source auction's times by this span instead, in the
+    /// site's timezone so that a one-month shift keeps the local time of day.
+    /// Can't be combined with the times above.
+    pub shift_by: Option<jiff::Span>,
+}
+
+/// A site's open hours on a date, as UTC windows.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolveOpenHours {
+    pub site_id: crate::SiteId,
+    /// A calendar date in the site's timezone
+    pub date: jiff::civil::Date,
+}
+
 /// Set or clear an auction's scheduled start time. Only valid before the
 /// auction has started.
 #[derive(Debug, Serialize, Deserialize)]
@@ -305,6 +558,60 @@ pub struct ScheduleAuction {
     pub start_at: Option<jiff::Timestamp>,
 }
 
+/// A range of an auction's rounds, in round order. Both bounds are inclusive
+/// and optional; `limit` is capped at [`MAX_PAGE_LIMIT`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAuctionRounds {
+    pub auction_id: crate::AuctionId,
+    pub from_round: Option<i32>,
+    pub to_round: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+/// An auction's rounds created or updated after `since`, for polling. Pass
+/// the `server_time` of the previous response as the next `since`; None
+/// returns every round.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAuctionRoundsSince {
+    pub auction_id: crate::AuctionId,
+    pub since: Option<jiff::Timestamp>,
+}
+
+/// A page of the caller's bids across every round of an auction, newest
+/// first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetBidHistory {
+    pub auction_id: crate::AuctionId,
+    /// `next_cursor` from the previous page; None for the first page.
+    pub cursor: Option<crate::Cursor>,
+    pub limit: i64,
+}
+
+/// Every bid on a space across all rounds of a concluded auction, oldest
+/// first. Moderator only.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpaceBidLog {
+    pub auction_id: crate::AuctionId,
+    pub space_id: crate::SpaceId,
+}
+
+/// A signed receipt for a space won in a concluded auction. Available to the
+/// winner and to moderators.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PossessionReceipt {
+    pub auction_id: crate::AuctionId,
+    pub space_id: crate::SpaceId,
+}
+
+/// The caller's standing wins in an auction.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MyStandingWins {
+    pub auction_id: crate::AuctionId,
+}
+
+/// Largest page accepted by cursor-paginated endpoints.
+pub const MAX_PAGE_LIMIT: i64 = 100;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserValue {
     pub space_id: crate::SpaceId,
@@ -315,6 +622,11 @@ pub struct UserValue {
 pub struct UseProxyBidding {
     pub auction_id: crate::AuctionId,
     pub max_items: i32,
+    /// Cap on the summed prices of spaces the proxy is standing on or
+    /// bidding for. Must be non-negative and representable in the
+    /// community's minor units. None means no budget cap.
+    #[serde(default)]
+    pub max_total: Option<Decimal>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -328,6 +640,22 @@ pub struct ResetPassword {
     pub password: String,
 }
 
+/// Replace the account's recovery codes, confirming the current password.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GenerateRecoveryCodes {
+    pub password: String,
+}
+
+/// Set a new password using one of the account's recovery codes, for when
+/// its email is unavailable.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecoverAccount {
+    pub username: String,
+    /// Case and separators are ignored.
+    pub recovery_code: String,
+    pub new_password: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct VerifyEmail {
     pub token: String,
@@ -338,6 +666,30 @@ pub struct UpdateProfile {
     pub display_name: Option<String>,
 }
 
+/// Act as another user for support. Superuser-only.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Impersonate {
+    pub target_user_id: crate::UserId,
+}
+
+/// Turn maintenance mode on or off. Operator-only.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetMaintenanceMode {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateNotificationPreferences {
+    pub email_possession_reminders: bool,
+}
+
+/// Choose the language for messages such as API errors. None follows the
+/// browser's `Accept-Language`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateLocale {
+    pub locale: Option<crate::Locale>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateSiteImage {
     pub community_id: CommunityId,
@@ -478,3 +830,23 @@ pub struct CreateCheckoutSession {
 pub struct CreatePortalSession {
     pub community_id: crate::CommunityId,
 }
+
+/// An optional expansion for read endpoints, sent as `?include=...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Include {
+    /// Bundle the caller's role and active status in the owning community.
+    CommunityRole,
+}
+
+/// Query options for reading a single site or auction.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReadOptions {
+    pub include: Option<Include>,
+}
+
+impl ReadOptions {
+    pub fn includes(&self, include: Include) -> bool {
+        self.include == Some(include)
+    }
+}
@@ -19,6 +19,26 @@ pub struct UserIdentity {
     pub display_name: Option<String>,
 }
 
+impl UserIdentity {
+    /// The name to show for this user: the display name if set, else the
+    /// username.
+    pub fn effective_name(&self) -> &str {
+        effective_name(&self.display_name, &self.username)
+    }
+}
+
+/// A trimmed, non-blank display name, falling back to the username.
+fn effective_name<'a>(
+    display_name: &'a Option<String>,
+    username: &'a str,
+) -> &'a str {
+    display_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(username)
+}
+
 /// Summary of a [`crate::requests::BulkActivateMembers`] operation.
 ///
 /// `unmatched` echoes back only identifiers the caller supplied, so it does
@@ -32,17 +52,53 @@ pub struct BulkActivateMembersResult {
     pub unmatched: Vec<String>,
 }
 
+/// Aggregate figures about a community, for its leaders.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct CommunityStats {
+    pub total_members: i64,
+    pub active_members: i64,
+    /// Sites and spaces that haven't been deleted
+    pub sites: i64,
+    pub spaces: i64,
+    /// Auctions that have concluded, excluding canceled and sandbox ones
+    pub auctions_run: i64,
+    /// Sum of the amounts moved by auction settlements and transfers.
+    /// Balance resets and rounding adjustments aren't counted.
+    pub total_value_transacted: Decimal,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Community {
     pub id: CommunityId,
     pub name: String,
     pub description: Option<String>,
+    /// Whether `description` is shown on the public invite preview.
+    pub description_public: bool,
+    /// Whether invites can currently be accepted.
+    pub registration_open: bool,
+    /// Base URL of invite links, when it differs from the instance's.
+    pub invite_base_url: Option<String>,
+    /// The community's own round duration bounds. Absent ones fall back to
+    /// the instance's.
+    pub round_duration_bounds: crate::RoundDurationBounds,
     pub community_image_id: Option<crate::SiteImageId>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub currency: crate::CurrencySettings,
 }
 
+/// Public preview of a community, shown on an invite landing page before
+/// the invitee has signed in. Deliberately limited to what the community has
+/// chosen to reveal to non-members.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommunityPreview {
+    pub name: String,
+    pub member_count: i64,
+    /// Only present if the community has made its description public.
+    pub description: Option<String>,
+}
+
 /// A community invite that has been issued from a given community.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
@@ -50,16 +106,30 @@ pub struct IssuedCommunityInvite {
     pub id: InviteId,
     pub new_member_email: Option<String>,
     pub single_use: bool,
+    /// Role granted on acceptance
+    pub role: crate::Role,
     #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
     pub created_at: Timestamp,
 }
 
+/// A community's outstanding invites and its cap on them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssuedInvites {
+    /// Newest first
+    pub invites: Vec<IssuedCommunityInvite>,
+    pub pending_invite_count: i64,
+    /// No more invites can be issued once `pending_invite_count` reaches this
+    pub max_pending_invites: i64,
+}
+
 /// Details about a community invite, excluding the target community id.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
 pub struct CommunityInviteReceived {
     pub id: InviteId,
     pub community_name: String,
+    /// Role granted on acceptance
+    pub role: crate::Role,
     #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
     pub created_at: Timestamp,
 }
@@ -75,6 +145,27 @@ pub struct CommunityMember {
     pub balance: Option<rust_decimal::Decimal>,
 }
 
+impl CommunityMember {
+    /// See [`UserIdentity::effective_name`].
+    pub fn effective_name(&self) -> &str {
+        self.user.effective_name()
+    }
+}
+
+/// Public profile of a fellow community member. Never includes email or
+/// balance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct MemberProfile {
+    pub username: String,
+    pub display_name: Option<String>,
+    pub role: crate::Role,
+    pub is_active: bool,
+    /// When the member joined this community
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub member_since: Timestamp,
+}
+
 /// Community information with the current user's role in that community.
 /// This is used by the get_communities endpoint to provide role information
 /// so the frontend can show/hide controls based on permissions.
@@ -95,6 +186,16 @@ impl std::ops::Deref for CommunityWithRole {
     }
 }
 
+/// A community along with the counts and sites its detail page shows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommunityOverview {
+    pub community: CommunityWithRole,
+    pub member_count: i64,
+    pub sites: Vec<Site>,
+    /// Outstanding invites; only present for moderators and above.
+    pub pending_invite_count: Option<i64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrphanedAccount {
     pub account: crate::Account,
@@ -114,17 +215,65 @@ pub struct Site {
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub deleted_at: Option<Timestamp>,
+    /// Problems with the stored configuration that predate current
+    /// validation, such as a missing timezone on an auto-scheduled site.
+    /// Only populated for moderators and above.
+    #[serde(default)]
+    pub configuration_warnings: Vec<String>,
+    /// Present when requested with `include=community_role`.
+    #[serde(default)]
+    pub community_role: Option<CommunityRole>,
+    /// The soonest auction yet to start. Only filled in by `list_sites`.
+    #[serde(default)]
+    pub next_auction: Option<UpcomingAuction>,
+    /// The auction running now, if any. Only filled in by `list_sites`.
+    #[serde(default)]
+    pub active_auction_id: Option<crate::AuctionId>,
+}
+
+/// An auction that hasn't started yet, as announced on a site listing.
+/// Canceled and sandbox auctions aren't announced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpcomingAuction {
+    pub auction_id: crate::AuctionId,
+    pub start_at: Timestamp,
+}
+
+/// What permanently deleting a site would destroy along with it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SiteDependencies {
+    pub spaces: i64,
+    /// Auctions that have ended.
+    pub concluded_auctions: i64,
+    /// Auctions that haven't ended yet, whether or not they've started.
+    pub pending_auctions: i64,
+    /// Distinct images used by the site or its spaces. The images themselves
+    /// belong to the community and are kept.
+    pub images: i64,
+    /// Members' values for the site's spaces.
+    pub user_values: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Space {
     pub space_id: crate::SpaceId,
     pub space_details: crate::Space,
+    /// Display order within the site; listings sort by this, then by name.
+    #[serde(default)]
+    pub display_order: i32,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub deleted_at: Option<Timestamp>,
 }
 
+/// A space and the value the caller has declared for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpaceWithValue {
+    pub space: Space,
+    /// None if the caller hasn't set a value for this space
+    pub value: Option<Decimal>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UpdateSpaceResult {
     pub space: Space,
@@ -139,11 +288,68 @@ pub struct UpdateSpaceResult {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Auction {
     pub auction_id: crate::AuctionId,
+    pub community_id: crate::CommunityId,
     pub auction_details: crate::Auction,
     pub end_at: Option<Timestamp>,
     pub was_canceled: bool,
+    /// Seed for the auction's tie-breaks, for replaying results. Only
+    /// present for coleaders and above.
+    pub random_seed: Option<i64>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    /// Present when requested with `include=community_role`.
+    #[serde(default)]
+    pub community_role: Option<CommunityRole>,
+    /// Moderators' scratchpad. Only present for moderators and above.
+    #[serde(default)]
+    pub moderator_notes: Option<String>,
+    /// Free-form status moderators track the auction by, such as "waiting on
+    /// sign-off". Only present for moderators and above.
+    #[serde(default)]
+    pub internal_status: Option<String>,
+}
+
+/// How an auction concluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "use-sqlx",
+    sqlx(type_name = "auction_conclusion", rename_all = "lowercase")
+)]
+pub enum AuctionConclusion {
+    /// Bidding finished and the final round's results were awarded.
+    Ended,
+    /// Ended by the system before bidding finished, such as on reaching the
+    /// round cap or when its site was deleted. Nothing is awarded.
+    Terminated,
+    /// Canceled by a coleader. Nothing is awarded.
+    Canceled,
+}
+
+/// An auction's outcome, recorded once when it concludes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct AuctionReport {
+    pub auction_id: crate::AuctionId,
+    pub conclusion: AuctionConclusion,
+    /// Sum of the final prices of awarded spaces. Negative for chores.
+    pub total_revenue: Decimal,
+    pub awarded_spaces: i32,
+    /// Available spaces at the site that weren't awarded
+    pub unawarded_spaces: i32,
+    pub distinct_winners: i32,
+    pub round_count: i32,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub created_at: Timestamp,
+}
+
+/// The caller's standing in the community that owns a site or auction, so a
+/// page can decide whether to show admin controls without another request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommunityRole {
+    pub community_id: crate::CommunityId,
+    pub role: crate::Role,
+    pub is_active: bool,
 }
 
 impl Auction {
@@ -173,7 +379,66 @@ pub struct AuctionRound {
     pub updated_at: Timestamp,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Rounds changed since a poll's `since`, in round order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuctionRoundsSince {
+    pub rounds: Vec<AuctionRound>,
+    /// Server time as of the query, to pass as the next poll's `since`.
+    pub server_time: Timestamp,
+}
+
+/// The eligibility threshold a round was created with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct RoundThreshold {
+    pub round_num: i32,
+    pub eligibility_threshold: f64,
+}
+
+/// A space the caller is standing winner on as of the latest processed round.
+/// They can't bid on it again, and can't abandon it, until someone outbids
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct StandingWin {
+    pub space_id: crate::SpaceId,
+    pub space_name: String,
+    pub round_num: i32,
+    /// The space's price as of that round
+    pub value: Decimal,
+}
+
+/// The user's eligibility in one round of an auction. Every round has an
+/// entry, so the series can be aligned with rounds by id or number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundEligibility {
+    pub round_id: crate::AuctionRoundId,
+    pub round_num: i32,
+    pub eligibility: crate::Eligibility,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BidHistoryPage {
+    pub bids: Vec<crate::Bid>,
+    /// Pass back to fetch the next page; None once the history is exhausted.
+    pub next_cursor: Option<crate::Cursor>,
+}
+
+/// One bid on a space, from the log moderators use to settle disputes once
+/// an auction has concluded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct SpaceBidLogEntry {
+    pub round_num: i32,
+    pub username: String,
+    /// When the bid was placed
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub created_at: Timestamp,
+    /// Whether the bidder withdrew it before the round ended
+    pub withdrawn: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserValue {
     pub space_id: crate::SpaceId,
     pub value: Decimal,
@@ -185,9 +450,39 @@ pub struct UserValue {
 pub struct UseProxyBidding {
     pub auction_id: crate::AuctionId,
     pub max_items: i32,
+    pub max_total: Option<Decimal>,
     pub created_at: Timestamp,
 }
 
+/// The user's proxy bidding settings for an auction, if enabled, and when
+/// they can be changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProxyBiddingStatus {
+    pub settings: Option<UseProxyBidding>,
+    pub window: ProxyBiddingWindow,
+}
+
+/// When proxy bidding can be enabled, changed, or removed for an auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyBiddingWindow {
+    /// The site's proxy bidding lead time before the auction starts. None
+    /// while the auction has no start time, in which case it's open.
+    pub opens_at: Option<Timestamp>,
+    /// When round 0 ends, or when the auction ended if it was canceled
+    /// first. None until round 0 is created.
+    pub closes_at: Option<Timestamp>,
+}
+
+impl ProxyBiddingWindow {
+    pub fn has_opened(&self, now: Timestamp) -> bool {
+        self.opens_at.is_none_or(|opens_at| opens_at <= now)
+    }
+
+    pub fn has_closed(&self, now: Timestamp) -> bool {
+        self.closes_at.is_some_and(|closes_at| closes_at <= now)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserProfile {
     pub user_id: UserId,
@@ -195,9 +490,102 @@ pub struct UserProfile {
     pub email: String,
     pub display_name: Option<String>,
     pub email_verified: bool,
+    /// Whether to email a reminder shortly before a possession ends
+    pub email_possession_reminders: bool,
+    /// The language chosen for messages, or None to follow the browser's.
+    #[serde(default)]
+    pub locale: Option<crate::Locale>,
+    /// Set while a superuser is acting as this user, so the UI can show a
+    /// banner.
+    #[serde(default)]
+    pub impersonated_by: Option<Impersonator>,
+    /// Unused recovery codes left from the most recent set.
+    #[serde(default)]
+    pub recovery_codes_remaining: u32,
+}
+
+/// A new set of recovery codes. They are only ever shown here; the server
+/// keeps hashes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryCodes {
+    pub codes: Vec<String>,
+}
+
+/// What the UI shell loads on startup, in one response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bootstrap {
+    pub profile: UserProfile,
+    pub communities: Vec<CommunityWithRole>,
+    /// Invites received and not yet accepted.
+    pub pending_invites: u32,
+}
+
+/// The superuser behind an impersonated session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Impersonator {
+    pub user_id: UserId,
+    pub username: String,
+    /// When the session stops acting as the user and logs out.
+    pub expires_at: Timestamp,
+}
+
+impl UserProfile {
+    /// The display name if set, else the username.
+    pub fn effective_name(&self) -> &str {
+        effective_name(&self.display_name, &self.username)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Proof that a user won a space, for showing to whoever manages the site.
+/// Only what a verifier needs to check the holder is included.
+///
+/// The signature in [`SignedPossessionReceipt`] covers this struct's compact
+/// JSON serialization, fields in the order declared here.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct PossessionReceipt {
+    pub community_name: String,
+    pub site_name: String,
+    pub space_name: String,
+    /// The winner's display name if set, else their username
+    pub holder_name: String,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub possession_start_at: Timestamp,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub possession_end_at: Timestamp,
+    /// The winning price in the final round
+    pub price: Decimal,
+}
+
+/// A possession receipt and its hex-encoded HMAC-SHA256 signature, made with
+/// the issuing instance's secret. Check it with `verify_receipt`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedPossessionReceipt {
+    pub receipt: PossessionReceipt,
+    pub signature: String,
+}
+
+/// A space the user won at auction, with its possession period. Only
+/// current and upcoming possessions are listed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct Possession {
+    pub auction_id: crate::AuctionId,
+    pub community_id: CommunityId,
+    pub site_id: crate::SiteId,
+    pub site_name: String,
+    pub space_id: crate::SpaceId,
+    pub space_name: String,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub possession_start_at: Timestamp,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub possession_end_at: Timestamp,
+    /// The possession ends within the reminder window, so a reminder email
+    /// is (or was) due.
+    pub ends_soon: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SuccessMessage {
     pub message: String,
 }
@@ -275,6 +663,77 @@ pub struct MemberTransaction {
     pub lines: Vec<TransactionLine>,
 }
 
+/// Everything stored about the requesting user, downloadable before deleting
+/// their account. Contains no other users' data: transactions omit their
+/// counterparties, and notes are only included on entries the user wrote.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataExport {
+    pub exported_at: Timestamp,
+    pub profile: UserProfile,
+    pub memberships: Vec<ExportedMembership>,
+    pub bids: Vec<ExportedBid>,
+    pub user_values: Vec<ExportedUserValue>,
+    pub proxy_bidding: Vec<ExportedProxyBidding>,
+    pub transactions: Vec<ExportedTransaction>,
+    pub possessions: Vec<Possession>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct ExportedMembership {
+    pub community_id: CommunityId,
+    pub community_name: String,
+    pub role: crate::Role,
+    pub is_active: bool,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub joined_at: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct ExportedBid {
+    pub auction_id: crate::AuctionId,
+    pub round_num: i32,
+    pub site_name: String,
+    pub space_name: String,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub created_at: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct ExportedUserValue {
+    pub site_name: String,
+    pub space_name: String,
+    pub value: Decimal,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub updated_at: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct ExportedProxyBidding {
+    pub auction_id: crate::AuctionId,
+    pub site_name: String,
+    pub max_items: i32,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub updated_at: Timestamp,
+}
+
+/// One of the user's journal lines. `amount` is positive when received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "use-sqlx", derive(sqlx::FromRow))]
+pub struct ExportedTransaction {
+    pub community_name: String,
+    pub entry_type: crate::EntryType,
+    pub auction_id: Option<crate::AuctionId>,
+    pub amount: Decimal,
+    /// Only present if the user initiated the entry
+    pub note: Option<String>,
+    #[cfg_attr(feature = "use-sqlx", sqlx(try_from = "SqlxTs"))]
+    pub created_at: Timestamp,
+}
+
 /// Result of resetting all member balances to zero
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BalanceResetResult {
@@ -289,3 +748,40 @@ pub struct PlatformStats {
     pub auctions_held: i64,
     pub spaces_allocated: i64,
 }
+
+/// Predicted eligibility schedule for auction params on a site.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationResult {
+    /// Sum of eligibility points over the site's available spaces
+    pub total_eligibility_points: f64,
+    /// First round requiring full eligibility, or None if the progression
+    /// never reaches 100% within the round cap
+    pub rounds_until_full_eligibility: Option<i32>,
+    /// Round 0 through the last breakpoint
+    pub rounds: Vec<SimulatedRound>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedRound {
+    pub round_num: i32,
+    /// Fraction of a bidder's eligibility they must use this round to keep it
+    pub eligibility_threshold: f64,
+    /// Activity needed this round to stay eligible for every space
+    pub activity_for_all_spaces: f64,
+}
+
+/// Email verification and password reset token counts, for operators.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenStats {
+    pub email_verification: TokenCounts,
+    pub password_reset: TokenCounts,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenCounts {
+    pub used: i64,
+    /// Unused and not yet expired
+    pub unused: i64,
+    /// Unused and past their expiry, awaiting cleanup
+    pub expired: i64,
+}
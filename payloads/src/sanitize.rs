@@ -0,0 +1,149 @@
+//! Normalization of user-provided display strings: names of users,
+//! communities, sites, and spaces, and their descriptions.
+//!
+//! Strings are cleaned up where that can't change what a reader sees
+//! (surrounding whitespace, invisible formatting characters) and rejected
+//! where it could (control characters, over-long input). Length limits are
+//! in characters and apply after normalization, so input that only exceeds
+//! them because of stripped characters is accepted.
+
+/// Why a string was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SanitizeError {
+    /// Longer than `max` characters after normalization.
+    TooLong { len: usize, max: usize },
+    /// Contains a control character other than the line breaks and tabs
+    /// allowed in multi-line text.
+    ControlCharacters,
+}
+
+/// Normalize a single-line name: strip invisible formatting characters,
+/// trim, and collapse each run of internal whitespace to one space.
+pub fn name(input: &str, max_len: usize) -> Result<String, SanitizeError> {
+    let stripped = strip_invisible(input);
+    let trimmed = stripped.trim();
+    if trimmed.chars().any(char::is_control) {
+        return Err(SanitizeError::ControlCharacters);
+    }
+    let collapsed = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
+    check_len(collapsed, max_len)
+}
+
+/// Normalize multi-line text such as a Markdown description: strip
+/// invisible formatting characters, convert line endings to `\n`, and trim.
+/// Internal whitespace is kept since Markdown gives it meaning.
+pub fn text(input: &str, max_len: usize) -> Result<String, SanitizeError> {
+    let stripped = strip_invisible(input).replace("\r\n", "\n");
+    let trimmed = stripped.trim();
+    if trimmed
+        .chars()
+        .any(|c| c.is_control() && c != '\n' && c != '\t')
+    {
+        return Err(SanitizeError::ControlCharacters);
+    }
+    check_len(trimmed.to_string(), max_len)
+}
+
+fn check_len(s: String, max: usize) -> Result<String, SanitizeError> {
+    let len = s.chars().count();
+    if len > max {
+        return Err(SanitizeError::TooLong { len, max });
+    }
+    Ok(s)
+}
+
+/// Characters that render as nothing but can hide text or reorder it:
+/// zero-width spaces, word joiners, byte order marks, soft hyphens, and
+/// bidirectional overrides.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{180E}'
+            | '\u{200B}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Zero-width joiner and non-joiner. These are invisible too, but scripts
+/// such as Persian and Devanagari and emoji sequences such as 👨‍👩‍👧
+/// depend on them.
+fn is_joiner(c: char) -> bool {
+    matches!(c, '\u{200C}' | '\u{200D}')
+}
+
+/// Remove invisible characters. A joiner is kept only between two visible
+/// non-ASCII characters, the only place it changes how text renders.
+fn strip_invisible(input: &str) -> String {
+    let joins = |c: Option<char>| {
+        c.is_some_and(|c| {
+            !c.is_ascii()
+                && !c.is_whitespace()
+                && !c.is_control()
+                && !is_invisible(c)
+                && !is_joiner(c)
+        })
+    };
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_invisible(c) {
+            continue;
+        }
+        if is_joiner(c)
+            && !(joins(out.chars().last()) && joins(chars.peek().copied()))
+        {
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            name("  Garden\u{200B}  Plots\n", 255).unwrap(),
+            "Garden Plots"
+        );
+        assert_eq!(name("🌻🌱", 255).unwrap(), "🌻🌱");
+        // Joiners survive inside an emoji sequence, not around ASCII
+        assert_eq!(
+            name("👨\u{200D}👩\u{200D}👧", 255).unwrap(),
+            "👨\u{200D}👩\u{200D}👧"
+        );
+        assert_eq!(name("a\u{200D}b", 255).unwrap(), "ab");
+        assert_eq!(
+            name("Plot\u{7}", 255),
+            Err(SanitizeError::ControlCharacters)
+        );
+        assert_eq!(name("Plot\nA", 255), Err(SanitizeError::ControlCharacters));
+        // Limits count characters after normalization
+        assert!(name(&format!("{}\u{200B}", "é".repeat(5)), 5).is_ok());
+        assert_eq!(
+            name(&"x".repeat(6), 5),
+            Err(SanitizeError::TooLong { len: 6, max: 5 })
+        );
+    }
+
+    #[test]
+    fn texts() {
+        assert_eq!(
+            text("# Title\r\n\r\n- item\u{FEFF}\n\tcode\n", 100).unwrap(),
+            "# Title\n\n- item\n\tcode"
+        );
+        assert_eq!(
+            text("a\u{1B}[31m", 100),
+            Err(SanitizeError::ControlCharacters)
+        );
+    }
+}
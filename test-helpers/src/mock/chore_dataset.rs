@@ -129,6 +129,7 @@ async fn invite_existing_user(
         community_id: *community_id,
         new_member_email: Some(creds.email.clone()),
         single_use: false,
+        role: None,
     };
     app.client.invite_member(&invite).await?;
 
@@ -164,17 +165,20 @@ async fn create_chore_site(
             activity_rule_params: ActivityRuleParams {
                 eligibility_progression: vec![(0, 1.0)],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
         possession_period: Span::new().days(7), // One week of chores
-        auction_lead_time: Span::new().days(2),
-        proxy_bidding_lead_time: Span::new().days(1),
+        auction_lead_time: Span::new().hours(48),
+        proxy_bidding_lead_time: Span::new().hours(24),
         open_hours: None,
         auto_schedule: false,
         timezone: Some(TZ.to_string()),
         site_image_id: None,
     };
 
-    let site_id = app.client.create_site(&site_details).await?;
+    let site_id = app.client.create_site(&site_details.into()).await?;
     Ok(app.client.get_site(&site_id).await?)
 }
 
@@ -270,6 +274,8 @@ async fn run_chore_auction(
         possession_end_at,
         start_at: Some(auction_start),
         auction_params,
+        sandbox: false,
+        winner_constraints: vec![],
     };
 
     app.login_alice().await?;
@@ -298,6 +304,7 @@ async fn run_chore_auction(
             .create_or_update_proxy_bidding(&requests::UseProxyBidding {
                 auction_id,
                 max_items: 3,
+                max_total: None,
             })
             .await
     };
@@ -179,6 +179,7 @@ async fn create_points_allocation_community(
             community_id: *community_id,
             new_member_email: Some(creds.email.clone()),
             single_use: false,
+            role: None,
         };
         app.client.invite_member(&invite).await?;
 
@@ -231,17 +232,20 @@ async fn create_grad_office_site(
                 // participate every round.
                 eligibility_progression: vec![(0, 1.0)],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
         possession_period: Span::new().days(90), // One term
-        auction_lead_time: Span::new().days(7),
-        proxy_bidding_lead_time: Span::new().days(3),
+        auction_lead_time: Span::new().hours(168),
+        proxy_bidding_lead_time: Span::new().hours(72),
         open_hours: None,
         auto_schedule: false,
         timezone: Some(TZ.to_string()),
         site_image_id: None,
     };
 
-    let site_id = app.client.create_site(&site_details).await?;
+    let site_id = app.client.create_site(&site_details.into()).await?;
     let site_response = app.client.get_site(&site_id).await?;
     Ok(site_response)
 }
@@ -338,6 +342,8 @@ async fn create_desk_auction_with_bidding(
         possession_end_at,
         start_at: Some(auction_start),
         auction_params,
+        sandbox: false,
+        winner_constraints: vec![],
     };
 
     app.login_alice().await?;
@@ -378,6 +384,7 @@ async fn create_desk_auction_with_bidding(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -400,6 +407,7 @@ async fn create_desk_auction_with_bidding(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -417,6 +425,7 @@ async fn create_desk_auction_with_bidding(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -435,6 +444,7 @@ async fn create_desk_auction_with_bidding(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -453,6 +463,7 @@ async fn create_desk_auction_with_bidding(
         .create_or_update_proxy_bidding(&requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
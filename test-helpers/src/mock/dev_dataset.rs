@@ -177,6 +177,9 @@ async fn create_coworking_site(
                     (30, 1.0),
                 ],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
         possession_period: Span::new().hours(12), // How long the site is open
         auction_lead_time: Span::new().hours(24), // 1 day advance booking
@@ -188,7 +191,7 @@ async fn create_coworking_site(
         site_image_id: None,
     };
 
-    let site_id = app.client.create_site(&site_details).await?;
+    let site_id = app.client.create_site(&site_details.into()).await?;
     let site_response = app.client.get_site(&site_id).await?;
     Ok(site_response)
 }
@@ -218,6 +221,9 @@ async fn create_meetup_site(
                     (30, 1.0),
                 ],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
         possession_period: Span::new().hours(4), // 4-hour meeting blocks
         auction_lead_time: Span::new().hours(48), // 2 days advance for planning
@@ -229,7 +235,7 @@ async fn create_meetup_site(
         site_image_id: None,
     };
 
-    let site_id = app.client.create_site(&site_details).await?;
+    let site_id = app.client.create_site(&site_details.into()).await?;
     let site_response = app.client.get_site(&site_id).await?;
     Ok(site_response)
 }
@@ -298,7 +304,12 @@ async fn create_ongoing_auction_with_rounds(
                     (30, 1.0),
                 ],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
+        sandbox: false,
+        winner_constraints: vec![],
     };
 
     let auction_id = app.client.create_auction(&auction_details).await?;
@@ -358,6 +369,7 @@ async fn create_ongoing_auction_with_rounds(
         .create_or_update_proxy_bidding(&payloads::requests::UseProxyBidding {
             auction_id,
             max_items: 2,
+            max_total: None,
         })
         .await?;
 
@@ -379,6 +391,7 @@ async fn create_ongoing_auction_with_rounds(
         .create_or_update_proxy_bidding(&payloads::requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -400,6 +413,7 @@ async fn create_ongoing_auction_with_rounds(
         .create_or_update_proxy_bidding(&payloads::requests::UseProxyBidding {
             auction_id,
             max_items: 1,
+            max_total: None,
         })
         .await?;
 
@@ -484,7 +498,12 @@ async fn create_work_day_auction(
                     (30, 1.0),
                 ],
             },
+            increment_schedule: vec![],
+            min_rounds: None,
+            eligibility_rounding: payloads::RoundingPolicy::Floor,
         },
+        sandbox: false,
+        winner_constraints: vec![],
     };
 
     let auction_id = app.client.create_auction(&auction_details).await?;
@@ -503,6 +522,7 @@ async fn create_cross_community_invite(
         community_id: *bob_community_id,
         new_member_email: Some(alice_credentials.email.clone()),
         single_use: false,
+        role: None,
     };
     let invite_id = app.client.invite_member(&invite_details).await?;
     Ok(invite_id)
@@ -351,7 +351,8 @@ pub fn AuctionParamsEditor(props: &Props) -> Html {
                                         .map(|(r, _)| r)
                                         .max()
                                         .unwrap_or(&0) + 1;
-                                    updated.activity_rule_params.eligibility_progression.push((next_round, 0.5));
+                                    // The last breakpoint must require full eligibility
+                                    updated.activity_rule_params.eligibility_progression.push((next_round, 1.0));
                                     on_change.emit(updated);
                                 })
                             }}
@@ -1,5 +1,5 @@
 use jiff::{Span, SpanRound, Unit};
-use payloads::{AuctionParams, CurrencySettings};
+use payloads::{AuctionParams, CurrencySettings, RoundingPolicy};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -47,6 +47,49 @@ pub fn AuctionParamsViewer(props: &Props) -> Html {
                 <p class="text-neutral-900 dark:text-neutral-100">
                     {props.currency.format_amount(props.auction_params.bid_increment.0)}
                 </p>
+                {if props.auction_params.increment_schedule.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <div class="space-y-1 mt-2">
+                            {props.auction_params.increment_schedule.iter().map(|(round, increment)| {
+                                html! {
+                                    <div class="flex items-center gap-4 text-sm text-neutral-900 dark:text-neutral-100">
+                                        <span class="font-medium">{"From round "}{round}{":"}</span>
+                                        <span>{props.currency.format_amount(*increment)}</span>
+                                    </div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                    }
+                }}
+            </div>
+
+            {if let Some(min_rounds) = props.auction_params.min_rounds {
+                html! {
+                    <div>
+                        <label class="block text-sm font-medium text-neutral-700 dark:text-neutral-300 mb-2">
+                            {"Minimum Rounds"}
+                        </label>
+                        <p class="text-neutral-900 dark:text-neutral-100">
+                            {min_rounds}
+                        </p>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            <div>
+                <label class="block text-sm font-medium text-neutral-700 dark:text-neutral-300 mb-2">
+                    {"Eligibility Rounding"}
+                </label>
+                <p class="text-neutral-900 dark:text-neutral-100">
+                    {match props.auction_params.eligibility_rounding {
+                        RoundingPolicy::Floor => "Down to whole points",
+                        RoundingPolicy::Round => "To the nearest whole point",
+                    }}
+                </p>
             </div>
 
             <div>
@@ -1,6 +1,7 @@
 use payloads::{
     CommunityId, CurrencySettings, ReservePrice, SiteId, SiteImageId, Space,
     requests::SPACE_NAME_MAX_LEN,
+    sanitize::{self, SanitizeError},
 };
 use rust_decimal::Decimal;
 use wasm_bindgen::JsCast;
@@ -100,21 +101,30 @@ pub fn CreateSpaceModal(props: &Props) -> Html {
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            let name = space_name.trim().to_string();
+            // Normalized the same way the server will store it
+            let name = match sanitize::name(&space_name, SPACE_NAME_MAX_LEN) {
+                Ok(name) => name,
+                Err(SanitizeError::TooLong { .. }) => {
+                    error.set(Some(format!(
+                        "Space name must be at most {} characters",
+                        SPACE_NAME_MAX_LEN
+                    )));
+                    return;
+                }
+                Err(SanitizeError::ControlCharacters) => {
+                    error.set(Some(
+                        "Space name cannot contain control characters"
+                            .to_string(),
+                    ));
+                    return;
+                }
+            };
 
             if name.is_empty() {
                 error.set(Some("Name is required".to_string()));
                 return;
             }
 
-            if name.len() > SPACE_NAME_MAX_LEN {
-                error.set(Some(format!(
-                    "Space name must be at most {} characters",
-                    SPACE_NAME_MAX_LEN
-                )));
-                return;
-            }
-
             let space_name_for_display = name.clone();
 
             let description_input =
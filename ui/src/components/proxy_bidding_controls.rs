@@ -1,12 +1,14 @@
+use payloads::responses::{ProxyBiddingStatus, ProxyBiddingWindow};
 use yew::prelude::*;
 
+use crate::components::TimestampDisplay;
 use crate::hooks::{ProxyBiddingSettingsHookReturn, render_section};
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
     /// The proxy-bidding settings hook. The component reads is_enabled /
-    /// max_items from the fetched settings and triggers update/delete via
-    /// the hook's callbacks.
+    /// max_items and the change window from the fetched status and triggers
+    /// update/delete via the hook's callbacks.
     pub settings: ProxyBiddingSettingsHookReturn,
 }
 
@@ -15,16 +17,15 @@ pub fn ProxyBiddingControls(props: &Props) -> Html {
     render_section(&props.settings.inner, "proxy bidding settings", {
         let update = props.settings.update.clone();
         let delete = props.settings.delete.clone();
-        move |settings_opt: &Option<payloads::responses::UseProxyBidding>,
-              _is_loading,
-              _errors| {
-            let is_enabled = settings_opt.is_some();
+        move |status: &ProxyBiddingStatus, _is_loading, _errors| {
+            let is_enabled = status.settings.is_some();
             let max_items =
-                settings_opt.as_ref().map(|s| s.max_items).unwrap_or(1);
+                status.settings.as_ref().map(|s| s.max_items).unwrap_or(1);
             html! {
                 <ProxyBiddingControlsLoaded
                     is_enabled={is_enabled}
                     max_items={max_items}
+                    window={status.window}
                     update={update.clone()}
                     delete={delete.clone()}
                 />
@@ -37,6 +38,7 @@ pub fn ProxyBiddingControls(props: &Props) -> Html {
 struct LoadedProps {
     is_enabled: bool,
     max_items: i32,
+    window: ProxyBiddingWindow,
     update: Callback<i32>,
     delete: Callback<()>,
 }
@@ -142,6 +144,8 @@ fn ProxyBiddingControlsLoaded(props: &LoadedProps) -> Html {
                     </button>
                 </div>
 
+                <ProxyBiddingWindowNote window={props.window} />
+
                 {if props.is_enabled {
                     html! {
                         <div class="space-y-3">
@@ -248,3 +252,34 @@ fn ProxyBiddingControlsLoaded(props: &LoadedProps) -> Html {
         </div>
     }
 }
+
+#[derive(Properties, PartialEq)]
+struct WindowNoteProps {
+    window: ProxyBiddingWindow,
+}
+
+/// When proxy bidding settings can be changed, relative to now.
+#[function_component]
+fn ProxyBiddingWindowNote(props: &WindowNoteProps) -> Html {
+    let now = jiff::Timestamp::now();
+    let window = props.window;
+    let (label, timestamp) = if !window.has_opened(now) {
+        ("Proxy bidding opens", window.opens_at)
+    } else if window.has_closed(now) {
+        ("Proxy bidding closed", window.closes_at)
+    } else if let Some(closes_at) = window.closes_at {
+        ("Changes allowed until", Some(closes_at))
+    } else {
+        return html! {};
+    };
+    let Some(timestamp) = timestamp else {
+        return html! {};
+    };
+
+    html! {
+        <p class="text-xs text-neutral-500 dark:text-neutral-400">
+            {label}{" "}
+            <TimestampDisplay timestamp={timestamp} />
+        </p>
+    }
+}
@@ -41,6 +41,10 @@ pub struct Props {
     /// the "Price" column). The parent gates the list on this fetch
     /// resolving, so it's a plain Vec here.
     pub prices: Vec<RoundSpaceResult>,
+    /// What a bid on each space costs in the current round. Empty before the
+    /// auction has a round, when bids open at the reserve price.
+    #[prop_or_default]
+    pub ask_prices: HashMap<SpaceId, Decimal>,
     /// User's bids in the current round, keyed by space.
     pub user_bids: HashSet<SpaceId>,
     /// User's per-space values (their max willingness-to-pay).
@@ -319,6 +323,7 @@ pub fn SpaceListForBidding(props: &Props) -> Html {
                                 key={space_id.0.to_string()}
                                 space={space.clone()}
                                 price={row.price}
+                                ask_price={props.ask_prices.get(&space_id).copied()}
                                 bid_increment={props.bid_increment}
                                 currency={props.currency.clone()}
                                 user_value={row.user_value}
@@ -403,6 +408,8 @@ struct SpaceRowProps {
     space: responses::Space,
     /// Per-row price. `None` if no prior bids on this space.
     price: Option<Decimal>,
+    /// The round's ask price for this space, if the round has one.
+    ask_price: Option<Decimal>,
     bid_increment: payloads::BidIncrement,
     currency: CurrencySettings,
     /// Per-row user value (their max willingness-to-pay for this space).
@@ -448,11 +455,13 @@ fn SpaceRow(props: &SpaceRowProps) -> Html {
     let space_id = props.space.space_id;
     let reserve_price = props.space.space_details.reserve_price;
 
-    let bid_price = payloads::next_bid_amount(
-        props.price,
-        props.bid_increment,
-        reserve_price,
-    );
+    let bid_price = props.ask_price.unwrap_or_else(|| {
+        payloads::next_bid_amount(
+            props.price,
+            props.bid_increment,
+            reserve_price,
+        )
+    });
 
     // The price shown in the row's "Price" column. Falls back to the
     // reserve when no prior round has produced a price, so the user sees
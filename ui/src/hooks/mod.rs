@@ -144,6 +144,7 @@ impl<T> FetchData<T> {
 }
 
 pub mod auction_subscription;
+pub mod request_cache;
 pub mod use_auction_detail;
 pub mod use_auction_round_results;
 pub mod use_auction_rounds;
@@ -166,6 +167,7 @@ pub mod use_proxy_bidding_participants;
 pub mod use_proxy_bidding_settings;
 pub mod use_push_route;
 pub mod use_require_auth;
+pub mod use_round_ask_prices;
 pub mod use_round_prices;
 pub mod use_site;
 pub mod use_sites;
@@ -210,6 +212,7 @@ pub use use_proxy_bidding_settings::{
 };
 pub use use_push_route::use_push_route;
 pub use use_require_auth::{login_form, use_require_auth};
+pub use use_round_ask_prices::use_round_ask_prices;
 pub use use_round_prices::use_round_prices;
 pub use use_site::use_site;
 pub use use_sites::use_sites;
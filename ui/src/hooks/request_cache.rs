@@ -0,0 +1,249 @@
+//! Deduplication and short-lived caching of read requests.
+//!
+//! Several components on a page often mount together and each ask for the
+//! same data (e.g. `use_site` and `use_spaces` both resolving the site before
+//! yewdux has been populated). Routing those reads through `fetch_shared`
+//! collapses concurrent identical requests into a single in-flight future,
+//! and reuses the result for `TTL_MS` afterwards.
+//!
+//! Entries are keyed by endpoint name plus serialized params (see
+//! `request_key`). Failed requests are never cached. The manual `refetch`
+//! returned by `use_fetch_with_cache` clears the cache first, since callers
+//! emit it right after a mutation; logout clears it as well.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::FutureExt;
+use futures::future::{LocalBoxFuture, Shared};
+use serde::Serialize;
+
+/// How long a completed response is reused for identical requests.
+const TTL_MS: f64 = 2_000.0;
+
+type SharedResult = Result<Rc<dyn Any>, String>;
+
+enum Entry {
+    /// A request is running; later callers await the same future.
+    InFlight {
+        generation: u64,
+        future: Shared<LocalBoxFuture<'static, SharedResult>>,
+    },
+    /// A request completed successfully at `fetched_at_ms`.
+    Ready {
+        value: Rc<dyn Any>,
+        fetched_at_ms: f64,
+    },
+}
+
+pub struct RequestCache {
+    ttl_ms: f64,
+    now_ms: fn() -> f64,
+    next_generation: Cell<u64>,
+    entries: RefCell<HashMap<String, Entry>>,
+}
+
+thread_local! {
+    static REQUEST_CACHE: Rc<RequestCache> =
+        Rc::new(RequestCache::new(TTL_MS, js_sys::Date::now));
+}
+
+/// Build a cache key from an endpoint name and its request params.
+pub fn request_key<P: Serialize>(endpoint: &str, params: &P) -> String {
+    let params = serde_json::to_string(params).unwrap_or_default();
+    format!("{endpoint}:{params}")
+}
+
+/// Run `fetch` through the global request cache. See the module docs.
+pub async fn fetch_shared<T, F, Fut>(key: String, fetch: F) -> Result<T, String>
+where
+    T: Clone + 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, String>> + 'static,
+{
+    let cache = REQUEST_CACHE.with(Rc::clone);
+    cache.get_or_fetch(key, fetch).await
+}
+
+/// Drop every cached and in-flight entry from the global request cache.
+/// In-flight requests still resolve for their current awaiters, but their
+/// results are not stored.
+pub fn clear() {
+    REQUEST_CACHE.with(|cache| cache.clear());
+}
+
+impl RequestCache {
+    pub fn new(ttl_ms: f64, now_ms: fn() -> f64) -> Self {
+        Self {
+            ttl_ms,
+            now_ms,
+            next_generation: Cell::new(0),
+            entries: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub async fn get_or_fetch<T, F, Fut>(
+        self: &Rc<Self>,
+        key: String,
+        fetch: F,
+    ) -> Result<T, String>
+    where
+        T: Clone + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>> + 'static,
+    {
+        let future = {
+            let mut entries = self.entries.borrow_mut();
+            match entries.get(&key) {
+                Some(Entry::Ready {
+                    value,
+                    fetched_at_ms,
+                }) if (self.now_ms)() - fetched_at_ms < self.ttl_ms => {
+                    return downcast(value);
+                }
+                Some(Entry::InFlight { future, .. }) => future.clone(),
+                _ => {
+                    let generation = self.next_generation.get();
+                    self.next_generation.set(generation + 1);
+
+                    let cache = Rc::clone(self);
+                    let entry_key = key.clone();
+                    let request = fetch();
+                    let future = async move {
+                        let result = request
+                            .await
+                            .map(|value| Rc::new(value) as Rc<dyn Any>);
+                        cache.complete(&entry_key, generation, &result);
+                        result
+                    }
+                    .boxed_local()
+                    .shared();
+
+                    entries.insert(
+                        key,
+                        Entry::InFlight {
+                            generation,
+                            future: future.clone(),
+                        },
+                    );
+                    future
+                }
+            }
+        };
+
+        downcast(&future.await?)
+    }
+
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+
+    /// Record a finished request, unless the entry was cleared or replaced
+    /// while it was in flight.
+    fn complete(&self, key: &str, generation: u64, result: &SharedResult) {
+        let mut entries = self.entries.borrow_mut();
+        let is_current = matches!(
+            entries.get(key),
+            Some(Entry::InFlight { generation: g, .. }) if *g == generation
+        );
+        if !is_current {
+            return;
+        }
+        match result {
+            Ok(value) => {
+                entries.insert(
+                    key.to_string(),
+                    Entry::Ready {
+                        value: Rc::clone(value),
+                        fetched_at_ms: (self.now_ms)(),
+                    },
+                );
+            }
+            Err(_) => {
+                entries.remove(key);
+            }
+        }
+    }
+}
+
+fn downcast<T: Clone + 'static>(value: &Rc<dyn Any>) -> Result<T, String> {
+    value
+        .downcast_ref::<T>()
+        .cloned()
+        .ok_or_else(|| "request cache entry has unexpected type".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+    use futures::executor::block_on;
+
+    thread_local! {
+        static NOW_MS: Cell<f64> = const { Cell::new(0.0) };
+    }
+
+    fn mock_now() -> f64 {
+        NOW_MS.with(Cell::get)
+    }
+
+    fn counted_fetch(
+        calls: &Rc<Cell<usize>>,
+        value: u32,
+    ) -> impl Future<Output = Result<u32, String>> + 'static {
+        calls.set(calls.get() + 1);
+        async move { Ok(value) }
+    }
+
+    #[test]
+    fn concurrent_identical_fetches_share_one_request() {
+        let cache = Rc::new(RequestCache::new(TTL_MS, mock_now));
+        let calls = Rc::new(Cell::new(0));
+        let (tx, rx) = oneshot::channel::<u32>();
+
+        let first = cache.get_or_fetch("get_site:1".into(), || {
+            calls.set(calls.get() + 1);
+            async move { rx.await.map_err(|e| e.to_string()) }
+        });
+        let second = cache
+            .get_or_fetch("get_site:1".into(), || counted_fetch(&calls, 0));
+        let resolve = async move {
+            tx.send(7).unwrap();
+        };
+
+        let (first, second, ()) =
+            block_on(async { futures::join!(first, second, resolve) });
+
+        assert_eq!(first, Ok(7));
+        assert_eq!(second, Ok(7));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn results_expire_and_clear_forces_refetch() {
+        NOW_MS.with(|now| now.set(0.0));
+        let cache = Rc::new(RequestCache::new(TTL_MS, mock_now));
+        let calls = Rc::new(Cell::new(0));
+        let fetch = |value| {
+            block_on(cache.get_or_fetch("list_spaces:1".into(), || {
+                counted_fetch(&calls, value)
+            }))
+        };
+
+        assert_eq!(fetch(1), Ok(1));
+        // Within the TTL the cached value is reused
+        assert_eq!(fetch(2), Ok(1));
+        assert_eq!(calls.get(), 1);
+
+        NOW_MS.with(|now| now.set(TTL_MS));
+        assert_eq!(fetch(3), Ok(3));
+        assert_eq!(calls.get(), 2);
+
+        cache.clear();
+        assert_eq!(fetch(4), Ok(4));
+        assert_eq!(calls.get(), 3);
+    }
+}
@@ -1,4 +1,8 @@
-use payloads::{AuctionId, responses};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use jiff::{SignedDuration, Timestamp};
+use payloads::{AuctionId, requests, responses};
 use yew::prelude::*;
 
 use crate::get_api_client;
@@ -6,26 +10,87 @@ use crate::hooks::{
     SubscribedEvent, SubscribedFetchHookReturn, use_subscribed_fetch,
 };
 
+/// How far before the last poll's server time the next poll starts. A round
+/// is stamped when its transaction starts but only visible once it commits,
+/// so the overlap covers rounds committed just after a poll. Rounds fetched
+/// twice are merged by id.
+const POLL_OVERLAP: SignedDuration = SignedDuration::from_secs(30);
+
+/// Rounds fetched so far for one auction, and where the next poll starts.
+#[derive(Default)]
+struct RoundsCache {
+    auction_id: Option<AuctionId>,
+    rounds: Vec<responses::AuctionRound>,
+    next_since: Option<Timestamp>,
+}
+
 /// Hook to fetch and manage rounds for a specific auction.
 ///
 /// Subscribed to `RoundCreated`. Round rows themselves don't change after
 /// creation — `round_space_results` are tracked separately — so RoundEnded
 /// isn't needed here. This hook does not cache in global state since rounds
 /// are only used in specific views.
+///
+/// After the first fetch, each refetch only asks for rounds changed since
+/// the previous one and merges them into the rounds already held.
 #[hook]
 pub fn use_auction_rounds(
     auction_id: AuctionId,
 ) -> SubscribedFetchHookReturn<Vec<responses::AuctionRound>> {
+    let cache = use_mut_ref(RoundsCache::default);
+
     use_subscribed_fetch(
         auction_id,
         auction_id,
         &[SubscribedEvent::RoundCreated],
-        move || async move {
-            let api_client = get_api_client();
-            api_client
-                .list_auction_rounds(&auction_id)
-                .await
-                .map_err(|e| e.to_string())
+        move || {
+            let cache = cache.clone();
+            async move { fetch_rounds(auction_id, cache).await }
         },
     )
 }
+
+async fn fetch_rounds(
+    auction_id: AuctionId,
+    cache: Rc<RefCell<RoundsCache>>,
+) -> Result<Vec<responses::AuctionRound>, String> {
+    let since = {
+        let cache = cache.borrow();
+        if cache.auction_id == Some(auction_id) {
+            cache.next_since.map(|since| since - POLL_OVERLAP)
+        } else {
+            None
+        }
+    };
+
+    let api_client = get_api_client();
+    let delta = api_client
+        .list_auction_rounds_since(&requests::ListAuctionRoundsSince {
+            auction_id,
+            since,
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut cache = cache.borrow_mut();
+    if cache.auction_id != Some(auction_id) || since.is_none() {
+        cache.auction_id = Some(auction_id);
+        cache.rounds.clear();
+    }
+    for round in delta.rounds {
+        match cache
+            .rounds
+            .iter_mut()
+            .find(|r| r.round_id == round.round_id)
+        {
+            Some(existing) => *existing = round,
+            None => cache.rounds.push(round),
+        }
+    }
+    cache
+        .rounds
+        .sort_by_key(|round| round.round_details.round_num);
+    cache.next_since = Some(delta.server_time);
+
+    Ok(cache.rounds.clone())
+}
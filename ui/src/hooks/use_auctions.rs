@@ -4,7 +4,11 @@ use yewdux::prelude::*;
 
 use crate::{
     State, get_api_client,
-    hooks::{FetchHookReturn, use_fetch_with_cache},
+    hooks::{
+        FetchHookReturn,
+        request_cache::{fetch_shared, request_key},
+        use_fetch_with_cache,
+    },
 };
 
 /// Hook to manage auctions data with global state caching.
@@ -40,11 +44,16 @@ pub fn use_auctions(
         move || {
             let dispatch = fetch_dispatch.clone();
             async move {
-                let api_client = get_api_client();
-                let auctions = api_client
-                    .list_auctions(&site_id)
-                    .await
-                    .map_err(|e| e.to_string())?;
+                let auctions = fetch_shared(
+                    request_key("list_auctions", &site_id),
+                    || async move {
+                        get_api_client()
+                            .list_auctions(&site_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                )
+                .await?;
                 dispatch.reduce_mut(|s| {
                     s.set_auctions_for_site(site_id, auctions.clone());
                 });
@@ -13,31 +13,17 @@ pub fn use_authentication() {
         let dispatch = dispatch.clone();
         move |_| {
             yew::platform::spawn_local(async move {
-                let api_client = get_api_client();
-                match api_client.login_check().await {
-                    Ok(true) => {
-                        // User has valid session, get their profile
-                        match api_client.user_profile().await {
-                            Ok(profile) => {
-                                dispatch.reduce_mut(|state| {
-                                    state.auth_state =
-                                        AuthState::LoggedIn(profile);
-                                });
-                            }
-                            Err(_) => {
-                                dispatch.reduce_mut(|state| {
-                                    state.logout();
-                                });
-                            }
-                        }
-                    }
-                    Ok(false) => {
+                // One request for the profile and the communities the
+                // layout shows; any failure, including 401, means logged out
+                match get_api_client().bootstrap().await {
+                    Ok(bootstrap) => {
                         dispatch.reduce_mut(|state| {
-                            state.logout();
+                            state.auth_state =
+                                AuthState::LoggedIn(bootstrap.profile);
+                            state.set_communities(bootstrap.communities);
                         });
                     }
                     Err(_) => {
-                        // Network error or other issue, assume logged out
                         dispatch.reduce_mut(|state| {
                             state.logout();
                         });
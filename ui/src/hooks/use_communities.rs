@@ -4,7 +4,11 @@ use yewdux::prelude::*;
 
 use crate::{
     State, get_api_client,
-    hooks::{FetchHookReturn, use_fetch_with_cache},
+    hooks::{
+        FetchHookReturn,
+        request_cache::{fetch_shared, request_key},
+        use_fetch_with_cache,
+    },
 };
 
 /// Hook to manage communities data with lazy loading and global state caching
@@ -31,11 +35,16 @@ pub fn use_communities() -> FetchHookReturn<Vec<responses::CommunityWithRole>> {
         move || {
             let dispatch = fetch_dispatch.clone();
             async move {
-                let api_client = get_api_client();
-                let communities = api_client
-                    .get_communities()
-                    .await
-                    .map_err(|e| e.to_string())?;
+                let communities = fetch_shared(
+                    request_key("get_communities", &()),
+                    || async move {
+                        get_api_client()
+                            .get_communities()
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                )
+                .await?;
                 dispatch.reduce_mut(|s| {
                     s.set_communities(communities.clone());
                 });
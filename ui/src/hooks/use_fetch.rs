@@ -8,7 +8,7 @@ use std::time::Duration;
 use gloo_timers::callback::Timeout;
 use yew::prelude::*;
 
-use super::FetchData;
+use super::{FetchData, request_cache};
 use payloads::AuctionId;
 
 use super::auction_subscription::{
@@ -551,13 +551,25 @@ where
     let effective_is_loading = inner.inner.is_loading
         || (!data.is_fetched() && inner.inner.errors.is_empty());
 
+    // A caller-driven refetch almost always follows a mutation, so drop any
+    // deduplicated responses first rather than serving them back within the
+    // request cache TTL. The auto-fetch above goes through `inner.refetch`
+    // directly and keeps sharing in-flight requests.
+    let refetch = {
+        let refetch = inner.refetch;
+        Callback::from(move |_| {
+            request_cache::clear();
+            refetch.emit(());
+        })
+    };
+
     FetchHookReturn {
         inner: Fetch {
             data,
             is_loading: effective_is_loading,
             errors: inner.inner.errors,
         },
-        refetch: inner.refetch,
+        refetch,
     }
 }
 
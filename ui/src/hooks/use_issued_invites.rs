@@ -8,7 +8,7 @@ use crate::hooks::{FetchHookReturn, use_fetch};
 #[hook]
 pub fn use_issued_invites(
     community_id: CommunityId,
-) -> FetchHookReturn<Vec<responses::IssuedCommunityInvite>> {
+) -> FetchHookReturn<responses::IssuedInvites> {
     use_fetch(community_id, move || async move {
         let api_client = get_api_client();
         api_client
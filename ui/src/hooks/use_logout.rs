@@ -19,6 +19,7 @@ pub fn use_logout() -> Callback<()> {
             dispatch.reduce_mut(|state| {
                 state.logout();
             });
+            crate::hooks::request_cache::clear();
 
             push_route.emit(Route::Landing);
         });
@@ -4,7 +4,11 @@ use yewdux::prelude::*;
 
 use crate::{
     State, get_api_client,
-    hooks::{FetchHookReturn, use_fetch_with_cache},
+    hooks::{
+        FetchHookReturn,
+        request_cache::{fetch_shared, request_key},
+        use_fetch_with_cache,
+    },
 };
 
 /// Hook to manage members data with global state caching.
@@ -30,11 +34,16 @@ pub fn use_members(
         move || {
             let dispatch = fetch_dispatch.clone();
             async move {
-                let api_client = get_api_client();
-                let members = api_client
-                    .get_members(&community_id)
-                    .await
-                    .map_err(|e| e.to_string())?;
+                let members = fetch_shared(
+                    request_key("get_members", &community_id),
+                    || async move {
+                        get_api_client()
+                            .get_members(&community_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                )
+                .await?;
                 dispatch.reduce_mut(|s| {
                     s.set_members_for_community(community_id, members.clone());
                 });
@@ -6,21 +6,21 @@ use crate::hooks::{Fetch, use_fetch};
 
 /// Hook return type for proxy bidding settings
 ///
-/// `data` is `Fetch<Option<UseProxyBidding>>`: `NotFetched` while
-/// loading, `Fetched(None)` if proxy bidding is disabled, `Fetched(Some)` if
-/// enabled. Derefs to `Fetch` so render-only consumers can
-/// take `&Fetch<Option<UseProxyBidding>>`.
+/// `data` is `Fetch<ProxyBiddingStatus>`: `NotFetched` while loading, then
+/// the settings (`None` if proxy bidding is disabled) along with the window
+/// in which they can be changed. Derefs to `Fetch` so render-only consumers
+/// can take `&Fetch<ProxyBiddingStatus>`.
 #[derive(Clone, Debug, PartialEq)]
 #[allow(dead_code)]
 pub struct ProxyBiddingSettingsHookReturn {
-    pub inner: Fetch<Option<responses::UseProxyBidding>>,
+    pub inner: Fetch<responses::ProxyBiddingStatus>,
     pub refetch: Callback<()>,
     pub update: Callback<i32>,
     pub delete: Callback<()>,
 }
 
 impl std::ops::Deref for ProxyBiddingSettingsHookReturn {
-    type Target = Fetch<Option<responses::UseProxyBidding>>;
+    type Target = Fetch<responses::ProxyBiddingStatus>;
     fn deref(&self) -> &Self::Target {
         &self.inner
     }
@@ -29,7 +29,7 @@ impl std::ops::Deref for ProxyBiddingSettingsHookReturn {
 /// Hook to manage proxy bidding settings for an auction
 ///
 /// Provides methods to get, update, and delete proxy bidding settings.
-/// The inner Option tracks whether proxy bidding is enabled (None =
+/// The inner `settings` tracks whether proxy bidding is enabled (None =
 /// disabled, Some = enabled with settings). Mutation errors are merged
 /// into `inner.errors` via `map_err`, so they surface through the same
 /// `stale_data_banner` / `render_section` paths as fetch errors.
@@ -58,9 +58,20 @@ pub fn use_proxy_bidding_settings(
 
             yew::platform::spawn_local(async move {
                 let api_client = get_api_client();
+                // Carry over any budget cap so changing max_items here
+                // doesn't clear it.
+                let max_total =
+                    match api_client.get_proxy_bidding(&auction_id).await {
+                        Ok(status) => status.settings.and_then(|s| s.max_total),
+                        Err(e) => {
+                            mutation_errors.set(vec![e.to_string()]);
+                            return;
+                        }
+                    };
                 let request = requests::UseProxyBidding {
                     auction_id,
                     max_items,
+                    max_total,
                 };
 
                 match api_client.create_or_update_proxy_bidding(&request).await
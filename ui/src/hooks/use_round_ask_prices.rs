@@ -0,0 +1,29 @@
+use payloads::{AuctionRoundId, SpaceId};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use yew::prelude::*;
+
+use crate::get_api_client;
+use crate::hooks::{FetchHookReturn, use_fetch};
+
+/// Hook to fetch what a bid on each space costs in a round, keyed by space.
+///
+/// Ask prices are fixed by the scheduler when the round is created, so like
+/// `use_round_prices` this doesn't subscribe to SSE; a new round id drives
+/// the refetch.
+#[hook]
+pub fn use_round_ask_prices(
+    round_id: AuctionRoundId,
+) -> FetchHookReturn<HashMap<SpaceId, Decimal>> {
+    use_fetch(round_id, move || async move {
+        let api_client = get_api_client();
+        let prices = api_client
+            .get_round_prices(&round_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(prices
+            .into_iter()
+            .map(|price| (price.space_id, price.ask_price))
+            .collect())
+    })
+}
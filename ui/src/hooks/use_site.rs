@@ -4,7 +4,11 @@ use yewdux::prelude::*;
 
 use crate::{
     State, get_api_client,
-    hooks::{FetchHookReturn, use_fetch_with_cache},
+    hooks::{
+        FetchHookReturn,
+        request_cache::{fetch_shared, request_key},
+        use_fetch_with_cache,
+    },
 };
 
 /// Hook to manage single site data with lazy loading and global state caching
@@ -34,11 +38,16 @@ pub fn use_site(site_id: SiteId) -> FetchHookReturn<responses::Site> {
         move || {
             let dispatch = fetch_dispatch.clone();
             async move {
-                let api_client = get_api_client();
-                let site = api_client
-                    .get_site(&site_id)
-                    .await
-                    .map_err(|e| e.to_string())?;
+                let site = fetch_shared(
+                    request_key("get_site", &site_id),
+                    || async move {
+                        get_api_client()
+                            .get_site(&site_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                )
+                .await?;
                 dispatch.reduce_mut(|s| {
                     s.set_site(site_id, site.clone());
                 });
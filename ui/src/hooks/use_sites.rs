@@ -4,7 +4,11 @@ use yewdux::prelude::*;
 
 use crate::{
     State, get_api_client,
-    hooks::{FetchHookReturn, use_fetch_with_cache},
+    hooks::{
+        FetchHookReturn,
+        request_cache::{fetch_shared, request_key},
+        use_fetch_with_cache,
+    },
 };
 
 /// Hook to manage sites data with lazy loading and global state caching
@@ -31,11 +35,16 @@ pub fn use_sites(
         move || {
             let dispatch = fetch_dispatch.clone();
             async move {
-                let api_client = get_api_client();
-                let sites = api_client
-                    .list_sites(&community_id)
-                    .await
-                    .map_err(|e| e.to_string())?;
+                let sites = fetch_shared(
+                    request_key("list_sites", &community_id),
+                    || async move {
+                        get_api_client()
+                            .list_sites(&community_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                )
+                .await?;
                 dispatch.reduce_mut(|s| {
                     s.set_sites_for_community(community_id, sites.clone());
                 });
@@ -4,7 +4,11 @@ use yewdux::prelude::*;
 
 use crate::{
     State, get_api_client,
-    hooks::{FetchHookReturn, use_fetch_with_cache},
+    hooks::{
+        FetchHookReturn,
+        request_cache::{fetch_shared, request_key},
+        use_fetch_with_cache,
+    },
 };
 
 /// Hook to manage spaces data with lazy loading and global state caching
@@ -37,11 +41,16 @@ pub fn use_spaces(site_id: SiteId) -> FetchHookReturn<Vec<responses::Space>> {
         move || {
             let dispatch = fetch_dispatch.clone();
             async move {
-                let api_client = get_api_client();
-                let spaces = api_client
-                    .list_spaces(&site_id)
-                    .await
-                    .map_err(|e| e.to_string())?;
+                let spaces = fetch_shared(
+                    request_key("list_spaces", &site_id),
+                    || async move {
+                        get_api_client()
+                            .list_spaces(&site_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                )
+                .await?;
                 dispatch.reduce_mut(|s| {
                     s.set_spaces_for_site(site_id, spaces.clone());
                 });
@@ -49,10 +49,8 @@ pub(crate) fn get_api_client() -> APIClient {
             location.origin().unwrap()
         });
 
-    APIClient {
-        address,
-        inner_client: reqwest::Client::new(),
-    }
+    APIClient::new(address, reqwest::Client::new())
+        .with_observer(std::sync::Arc::new(logs::ApiClientLogger))
 }
 
 #[derive(Clone, Routable, PartialEq, Debug)]
@@ -1,5 +1,9 @@
 //! Logging to the javascript console and to the backend.
 
+use std::time::Duration;
+
+use payloads::{ClientError, ClientObserver};
+use reqwest::StatusCode;
 use tracing_subscriber::{EnvFilter, prelude::*};
 use tracing_web::MakeWebConsoleWriter;
 
@@ -21,3 +25,27 @@ pub fn init_logging() {
 
     tracing::info!("Initialized logs");
 }
+
+/// Logs API client activity to the console: each response with its latency,
+/// and failures with the request id for finding them in server logs.
+pub struct ApiClientLogger;
+
+impl ClientObserver for ApiClientLogger {
+    fn on_request(&self, path: &str) {
+        tracing::trace!(path, "API request");
+    }
+
+    fn on_response(&self, path: &str, status: StatusCode, elapsed: Duration) {
+        tracing::debug!(
+            path,
+            %status,
+            elapsed_ms = elapsed.as_millis() as u64,
+            "API response"
+        );
+    }
+
+    fn on_error(&self, path: &str, error: &ClientError) {
+        let request_id = error.meta().and_then(|meta| meta.request_id.clone());
+        tracing::warn!(path, ?request_id, "API error: {error}");
+    }
+}
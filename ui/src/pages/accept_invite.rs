@@ -1,8 +1,8 @@
-use payloads::InviteId;
+use payloads::{InviteId, responses};
 use yew::prelude::*;
 use yewdux::prelude::*;
 
-use crate::components::AuthForm;
+use crate::components::{AuthForm, MarkdownText};
 use crate::hooks::{use_push_route, use_title};
 use crate::{AuthState, Route, State};
 
@@ -21,31 +21,31 @@ pub fn AcceptInvitePage(props: &AcceptInvitePageProps) -> Html {
     let is_accepting = use_state(|| false);
     let error_message = use_state(|| None::<String>);
     let success_message = use_state(|| false);
-    let community_name = use_state(|| None::<String>);
+    let preview = use_state(|| None::<responses::CommunityPreview>);
     let is_loading_name = use_state(|| true);
 
-    // Fetch community name when component loads
+    // Fetch the public community preview when component loads
     {
-        let community_name = community_name.clone();
+        let preview = preview.clone();
         let is_loading_name = is_loading_name.clone();
         let error_message = error_message.clone();
 
         use_effect_with(invite_id, move |invite_id| {
-            let community_name = community_name.clone();
+            let preview = preview.clone();
             let is_loading_name = is_loading_name.clone();
             let error_message = error_message.clone();
             let invite_id = *invite_id; // Copy the InviteId since it implements Copy
 
             yew::platform::spawn_local(async move {
                 let api_client = crate::get_api_client();
-                match api_client.get_invite_community_name(&invite_id).await {
-                    Ok(name) => {
-                        community_name.set(Some(name));
+                match api_client.get_community_preview(&invite_id).await {
+                    Ok(community_preview) => {
+                        preview.set(Some(community_preview));
                         is_loading_name.set(false);
                     }
                     Err(err) => {
                         error_message.set(Some(format!(
-                            "Failed to load community name: {}",
+                            "Failed to load community details: {}",
                             err
                         )));
                         is_loading_name.set(false);
@@ -54,6 +54,7 @@ pub fn AcceptInvitePage(props: &AcceptInvitePageProps) -> Html {
             });
         });
     }
+    let community_name = preview.as_ref().map(|p| p.name.clone());
 
     // Handle accepting the invite (closure that takes no arguments)
     let accept_invite = {
@@ -127,7 +128,7 @@ pub fn AcceptInvitePage(props: &AcceptInvitePageProps) -> Html {
                         login_title="Community Invite"
                         login_description={
                             AttrValue::from(
-                                if let Some(name) = &*community_name {
+                                if let Some(name) = &community_name {
                                     format!("You've been invited to join {} on TinyLVT. Please sign in to accept this invitation.", name)
                                 } else {
                                     "You've been invited to join a community on TinyLVT. Please sign in to accept this invitation.".to_string()
@@ -138,7 +139,7 @@ pub fn AcceptInvitePage(props: &AcceptInvitePageProps) -> Html {
                         signup_title="Create Account for Invite"
                         signup_description={
                             AttrValue::from(
-                                if let Some(name) = &*community_name {
+                                if let Some(name) = &community_name {
                                     format!("You've been invited to join {} on TinyLVT. Create an account to accept this invitation.", name)
                                 } else {
                                     "You've been invited to join a community on TinyLVT. Create an account to accept this invitation.".to_string()
@@ -184,13 +185,14 @@ pub fn AcceptInvitePage(props: &AcceptInvitePageProps) -> Html {
                                     </h1>
                                     <p class="text-neutral-600 dark:text-neutral-400">
                                         {
-                                            if let Some(name) = &*community_name {
+                                            if let Some(name) = &community_name {
                                                 format!("You've been invited to join {} on TinyLVT.", name)
                                             } else {
                                                 "You've been invited to join a community on TinyLVT.".to_string()
                                             }
                                         }
                                     </p>
+                                    {community_preview_details(preview.as_ref())}
                                 </div>
 
                                 if let Some(error) = &*error_message {
@@ -244,3 +246,30 @@ pub fn AcceptInvitePage(props: &AcceptInvitePageProps) -> Html {
         }
     }
 }
+
+/// Member count and (if the community made it public) description from the
+/// invite preview.
+fn community_preview_details(
+    preview: Option<&responses::CommunityPreview>,
+) -> Html {
+    let Some(preview) = preview else {
+        return html! {};
+    };
+    let members = if preview.member_count == 1 {
+        "1 member".to_string()
+    } else {
+        format!("{} members", preview.member_count)
+    };
+    html! {
+        <>
+            <p class="mt-1 text-sm text-neutral-500 dark:text-neutral-400">
+                {members}
+            </p>
+            if let Some(description) = &preview.description {
+                <div class="mt-4 text-left prose prose-sm dark:prose-invert max-w-none">
+                    <MarkdownText text={description.clone()} />
+                </div>
+            }
+        </>
+    }
+}
@@ -11,8 +11,8 @@ use crate::components::{
 use crate::hooks::{
     Fetch, ProxyBiddingSettingsHookReturn, UserSpaceValuesHookReturn,
     render_section, stale_data_banner, use_last_round,
-    use_proxy_bidding_settings, use_round_prices, use_spaces, use_user_bids,
-    use_user_eligibility, use_user_space_values,
+    use_proxy_bidding_settings, use_round_ask_prices, use_round_prices,
+    use_spaces, use_user_bids, use_user_eligibility, use_user_space_values,
 };
 use payloads::AuctionId;
 
@@ -208,7 +208,7 @@ fn AuctionCancelledContent(props: &AuctionCancelledContentProps) -> Html {
                             current_user={props.current_user.clone()}
                             bid_increment={
                                 props.auction.auction_details
-                                    .auction_params.bid_increment
+                                    .auction_params.bid_increment_for_round(0)
                             }
                             currency={props.currency.clone()}
                             on_bid={no_op_bid.clone()}
@@ -322,19 +322,19 @@ fn AuctionNotStartedContent(props: &AuctionNotStartedContentProps) -> Html {
                     .zip_ref(&props.user_values.inner)
                     .zip_ref(&props.proxy_bidding.inner),
                 "spaces",
-                |((spaces, user_values), proxy_bidding_opt), _, errors| html! {
+                |((spaces, user_values), proxy_bidding), _, errors| html! {
                     <>
                         {stale_data_banner(errors)}
                         <SpaceListForBidding
                             spaces={(*spaces).clone()}
                             prices={Vec::new()}
                             user_values={(*user_values).clone()}
-                            proxy_bidding_enabled={proxy_bidding_opt.is_some()}
+                            proxy_bidding_enabled={proxy_bidding.settings.is_some()}
                             user_bids={HashSet::new()}
                             current_user={props.current_user.clone()}
                             bid_increment={
                                 props.auction.auction_details
-                                    .auction_params.bid_increment
+                                    .auction_params.bid_increment_for_round(0)
                             }
                             currency={props.currency.clone()}
                             on_bid={no_op_bid.clone()}
@@ -379,6 +379,7 @@ fn AuctionRoundContent(props: &AuctionRoundContentProps) -> Html {
     let bid_error = use_state(|| None::<String>);
 
     let round_prices_hook = use_round_prices(props.previous_round_id);
+    let ask_prices_hook = use_round_ask_prices(round_id);
     let eligibility_hook = use_user_eligibility(round_id);
     let user_bids_hook = use_user_bids(auction_id, round_id);
 
@@ -544,7 +545,7 @@ fn AuctionRoundContent(props: &AuctionRoundContentProps) -> Html {
                 html! {}
             }}
 
-            // Space list for bidding. Gate on the six hooks the list
+            // Space list for bidding. Gate on the seven hooks the list
             // depends on. user_eligibility and current_activity also flow
             // into UserEligibilityDisplay separately (with its own
             // gating); here they're collapsed to plain values via the
@@ -556,23 +557,25 @@ fn AuctionRoundContent(props: &AuctionRoundContentProps) -> Html {
                 &props
                     .spaces
                     .zip_ref(&round_prices_hook.inner)
+                    .zip_ref(&ask_prices_hook.inner)
                     .zip_ref(&user_bids_hook.inner)
                     .zip_ref(&props.user_values.inner)
                     .zip_ref(&eligibility)
                     .zip_ref(&current_activity)
                     .zip_ref(&props.proxy_bidding.inner),
                 "spaces",
-                |((((((spaces, prices), user_bids), user_values), eligibility_opt), activity), proxy_bidding_opt), _, errors| html! {
+                |(((((((spaces, prices), ask_prices), user_bids), user_values), eligibility_opt), activity), proxy_bidding), _, errors| html! {
                     <>
                         {stale_data_banner(errors)}
                         <SpaceListForBidding
                             spaces={(*spaces).clone()}
                             prices={(*prices).clone()}
+                            ask_prices={(*ask_prices).clone()}
                             user_values={(*user_values).clone()}
-                            proxy_bidding_enabled={proxy_bidding_opt.is_some()}
+                            proxy_bidding_enabled={proxy_bidding.settings.is_some()}
                             user_bids={(*user_bids).clone()}
                             current_user={props.current_user.clone()}
-                            bid_increment={props.auction.auction_details.auction_params.bid_increment}
+                            bid_increment={props.auction.auction_details.auction_params.bid_increment_for_round(props.last_round.round_details.round_num)}
                             currency={props.currency.clone()}
                             on_bid={on_bid.clone()}
                             on_delete_bid={on_delete_bid.clone()}
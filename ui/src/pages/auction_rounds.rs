@@ -163,7 +163,7 @@ fn RoundsContent(props: &RoundsContentProps) -> Html {
                                     <RoundCard
                                         key={round_id.0.to_string()}
                                         round={round.clone()}
-                                        bid_increment={props.auction.auction_details.auction_params.bid_increment}
+                                        bid_increment={props.auction.auction_details.auction_params.bid_increment_for_round(round_num)}
                                         currency={props.currency.clone()}
                                         user_bids={user_bids_for_round}
                                         previous_round_results={previous_round_results}
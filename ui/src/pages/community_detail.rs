@@ -1,4 +1,8 @@
-use payloads::{CommunityId, responses::CommunityWithRole};
+use jiff::Timestamp;
+use payloads::{
+    CommunityId,
+    responses::{CommunityWithRole, Site},
+};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -200,6 +204,8 @@ fn SitesTab(props: &SitesTabProps) -> Html {
                                             </div>
                                         </div>
 
+                                        {auction_announcement(site, Timestamp::now())}
+
                                         <div class="text-sm text-neutral-600 dark:text-neutral-400">
                                             <p>{"Created: "}{site.created_at.to_zoned(jiff::tz::TimeZone::system()).strftime("%B %d, %Y").to_string()}</p>
                                         </div>
@@ -223,3 +229,49 @@ fn SitesTab(props: &SitesTabProps) -> Html {
         }
     })
 }
+
+/// The site's running auction, or when its next one starts. The listing may
+/// have been cached since, so an announced start that has passed counts as
+/// running.
+fn auction_announcement(site: &Site, now: Timestamp) -> Html {
+    let running = site.active_auction_id.or_else(|| {
+        site.next_auction
+            .as_ref()
+            .filter(|next| next.start_at <= now)
+            .map(|next| next.auction_id)
+    });
+    if let Some(auction_id) = running {
+        return html! {
+            <Link<Route>
+                to={Route::AuctionDetail { id: auction_id }}
+                classes="inline-flex items-center px-2 py-1 rounded text-xs font-medium bg-green-100 dark:bg-green-900/30 text-green-800 dark:text-green-400 border border-green-200 dark:border-green-800"
+            >
+                {"Auction running"}
+            </Link<Route>>
+        };
+    }
+    let Some(next) = &site.next_auction else {
+        return html! {};
+    };
+    html! {
+        <Link<Route>
+            to={Route::AuctionDetail { id: next.auction_id }}
+            classes="text-sm text-neutral-600 dark:text-neutral-400 hover:underline"
+        >
+            {format!("Next auction starts in {}", format_wait(next.start_at.duration_since(now)))}
+        </Link<Route>>
+    }
+}
+
+/// A wait rounded down to its largest whole unit, e.g. "3 days".
+fn format_wait(wait: jiff::SignedDuration) -> String {
+    let secs = wait.as_secs();
+    let (n, unit) = if secs >= 86400 {
+        (secs / 86400, "day")
+    } else if secs >= 3600 {
+        (secs / 3600, "hour")
+    } else {
+        ((secs / 60).max(1), "minute")
+    };
+    format!("{n} {unit}{}", if n == 1 { "" } else { "s" })
+}
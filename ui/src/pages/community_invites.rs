@@ -42,6 +42,16 @@ pub struct InvitesContentProps {
 #[function_component]
 fn InvitesContent(props: &InvitesContentProps) -> Html {
     let issued_invites_hook = use_issued_invites(props.community.id);
+    // The server enforces the cap too; this just saves a rejected request
+    let invite_button = |at_capacity: bool| {
+        html! {
+            <InviteMemberButton
+                community={props.community.clone()}
+                on_invite_created={issued_invites_hook.refetch.clone()}
+                {at_capacity}
+            />
+        }
+    };
 
     html! {
         <div class="space-y-6">
@@ -49,7 +59,14 @@ fn InvitesContent(props: &InvitesContentProps) -> Html {
                 <h2 class="text-xl font-semibold text-neutral-900 dark:text-neutral-100">
                     {"Community Invites"}
                 </h2>
-                <InviteMemberButton community={props.community.clone()} on_invite_created={issued_invites_hook.refetch.clone()} />
+                {issued_invites_hook.inner.render(
+                    |issued, _, _| invite_button(
+                        issued.pending_invite_count
+                            >= issued.max_pending_invites,
+                    ),
+                    || invite_button(false),
+                    |_| invite_button(false),
+                )}
             </div>
 
             // Display issued invites
@@ -67,7 +84,18 @@ fn InvitesContent(props: &InvitesContentProps) -> Html {
                     {render_section(
                         &issued_invites_hook.inner,
                         "invites",
-                        |invites, _is_loading, _errors| {
+                        |issued, _is_loading, _errors| {
+                            let headroom = html! {
+                                <p class="text-sm text-neutral-600 \
+                                          dark:text-neutral-400 mb-4">
+                                    {format!(
+                                        "{} of {} invites outstanding",
+                                        issued.pending_invite_count,
+                                        issued.max_pending_invites,
+                                    )}
+                                </p>
+                            };
+                            let invites = &issued.invites;
                             if invites.is_empty() {
                                 html! {
                                     <div class="text-center py-8">
@@ -80,6 +108,7 @@ fn InvitesContent(props: &InvitesContentProps) -> Html {
                             } else {
                                 html! {
                                     <div class="space-y-4">
+                                        {headroom}
                                         {for invites.iter().map(|invite| html! {
                                             <IssuedInviteCard
                                                 invite={invite.clone()}
@@ -104,6 +133,8 @@ fn InvitesContent(props: &InvitesContentProps) -> Html {
 pub struct InviteMemberButtonProps {
     pub community: CommunityWithRole,
     pub on_invite_created: Callback<()>,
+    /// The community has as many outstanding invites as it may
+    pub at_capacity: bool,
 }
 
 #[function_component]
@@ -128,7 +159,11 @@ fn InviteMemberButton(props: &InviteMemberButtonProps) -> Html {
         <>
             <button
                 onclick={on_click}
-                class="bg-neutral-900 hover:bg-neutral-800 dark:bg-neutral-100 dark:text-neutral-900 dark:hover:bg-neutral-200 text-white px-4 py-2 rounded-md text-sm font-medium transition-colors"
+                disabled={props.at_capacity}
+                title={props.at_capacity.then_some(
+                    "Delete an outstanding invite to issue another",
+                )}
+                class="bg-neutral-900 hover:bg-neutral-800 dark:bg-neutral-100 dark:text-neutral-900 dark:hover:bg-neutral-200 text-white px-4 py-2 rounded-md text-sm font-medium transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
             >
                 {"Invite Member"}
             </button>
@@ -234,6 +269,7 @@ fn InviteMemberModal(props: &InviteMemberModalProps) -> Html {
                             Some((*email).clone())
                         },
                         single_use: *single_use,
+                        role: None,
                     };
 
                 match api_client.invite_member(&invite_details).await {
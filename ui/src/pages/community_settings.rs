@@ -69,6 +69,8 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
             .clone()
             .unwrap_or_default()
     });
+    let edited_description_public =
+        use_state(|| props.community.community.description_public);
     let is_saving_details = use_state(|| false);
     let details_error = use_state(|| None::<String>);
     let details_success = use_state(|| false);
@@ -90,7 +92,9 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
             .clone()
             .unwrap_or_default();
         let desc_changed = *edited_description != original_desc;
-        name_changed || desc_changed
+        let desc_public_changed = *edited_description_public
+            != props.community.community.description_public;
+        name_changed || desc_changed || desc_public_changed
     };
 
     // Check if currency config has changes
@@ -192,9 +196,23 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
         })
     };
 
+    let on_description_public_change = {
+        let edited_description_public = edited_description_public.clone();
+        let details_success = details_success.clone();
+        let details_error = details_error.clone();
+
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            edited_description_public.set(input.checked());
+            details_success.set(false);
+            details_error.set(None);
+        })
+    };
+
     let on_save_details = {
         let edited_name = edited_name.clone();
         let edited_description = edited_description.clone();
+        let edited_description_public = edited_description_public.clone();
         let is_saving_details = is_saving_details.clone();
         let details_error = details_error.clone();
         let details_success = details_success.clone();
@@ -204,6 +222,7 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
         Callback::from(move |_: MouseEvent| {
             let name = (*edited_name).clone();
             let description = (*edited_description).clone();
+            let description_public = *edited_description_public;
             let is_saving_details = is_saving_details.clone();
             let details_error = details_error.clone();
             let details_success = details_success.clone();
@@ -223,6 +242,7 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
                     } else {
                         Some(description)
                     },
+                    description_public: Some(description_public),
                 };
 
                 match client.update_community_details(&details).await {
@@ -242,6 +262,9 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
     let on_cancel_details = {
         let edited_name = edited_name.clone();
         let edited_description = edited_description.clone();
+        let edited_description_public = edited_description_public.clone();
+        let original_description_public =
+            props.community.community.description_public;
         let details_success = details_success.clone();
         let details_error = details_error.clone();
         let original_name = props.community.community.name.clone();
@@ -255,6 +278,7 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
         Callback::from(move |_| {
             edited_name.set(original_name.clone());
             edited_description.set(original_desc.clone());
+            edited_description_public.set(original_description_public);
             details_success.set(false);
             details_error.set(None);
         })
@@ -365,6 +389,24 @@ fn CommunitySettingsContent(props: &ContentProps) -> Html {
                                         community_id={props.community_id}
                                         disabled={*is_saving_details}
                                     />
+                                    <div class="flex items-start mt-3">
+                                        <input
+                                            id="description-public-checkbox"
+                                            type="checkbox"
+                                            checked={*edited_description_public}
+                                            onchange={on_description_public_change}
+                                            disabled={*is_saving_details}
+                                            class="mt-1 mr-2"
+                                        />
+                                        <div>
+                                            <label for="description-public-checkbox" class="text-sm font-medium cursor-pointer">
+                                                {"Show description on invite links"}
+                                            </label>
+                                            <p class="text-sm text-neutral-600 dark:text-neutral-400">
+                                                {"People opening an invite link can read the description before signing in"}
+                                            </p>
+                                        </div>
+                                    </div>
                                 </div>
                             </div>
                         }
@@ -212,6 +212,8 @@ pub fn CreateAuctionForm(props: &CreateAuctionFormProps) -> Html {
                 possession_end_at: possession_end,
                 start_at: auction_start,
                 auction_params: (*auction_params).clone(),
+                sandbox: false,
+                winner_constraints: vec![],
             };
 
             let error_message = error_message.clone();
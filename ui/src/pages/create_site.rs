@@ -1,6 +1,7 @@
 use payloads::{
-    ActivityRuleParams, AuctionParams, CommunityId, Site,
-    requests::SITE_NAME_MAX_LEN, responses::CommunityWithRole,
+    ActivityRuleParams, AuctionParams, CommunityId,
+    requests::{SITE_NAME_MAX_LEN, SiteDetails},
+    responses::CommunityWithRole,
 };
 use wasm_bindgen::JsCast;
 use web_sys::{HtmlInputElement, HtmlSelectElement};
@@ -114,11 +115,11 @@ pub fn CreateSiteForm(props: &CreateSiteFormProps) -> Html {
             };
 
             // Create site object with sensible defaults
-            let site = Site {
+            let site = SiteDetails {
                 community_id,
                 name,
                 description,
-                default_auction_params: AuctionParams {
+                default_auction_params: Some(AuctionParams {
                     round_duration: jiff::Span::new().minutes(5),
                     bid_increment: payloads::BidIncrement(
                         rust_decimal::Decimal::new(100, 2), // $1.00
@@ -128,7 +129,10 @@ pub fn CreateSiteForm(props: &CreateSiteFormProps) -> Html {
                         // items while maintaining participation each round.
                         eligibility_progression: vec![(0, 1.0)],
                     },
-                },
+                    increment_schedule: vec![],
+                    min_rounds: None,
+                    eligibility_rounding: payloads::RoundingPolicy::Floor,
+                }),
                 // Default values for MVP - auctions will be manually created
                 possession_period: jiff::Span::new().days(7), // Default 7 days
                 auction_lead_time: jiff::Span::new().hours(24), /* Default 24
@@ -42,6 +42,10 @@ fn ProfilePageInner(props: &ProfilePageInnerProps) -> Html {
     let display_name_error = use_state(|| None::<String>);
     let is_saving_display_name = use_state(|| false);
 
+    // Notification preference state
+    let reminders_error = use_state(|| None::<String>);
+    let is_saving_reminders = use_state(|| false);
+
     // Use communities hook to determine leader status. The Danger Zone
     // section gates on this; the rest of the page renders unconditionally.
     let communities_hook = use_communities();
@@ -172,6 +176,40 @@ fn ProfilePageInner(props: &ProfilePageInnerProps) -> Html {
         })
     };
 
+    let on_toggle_reminders = {
+        let reminders_error = reminders_error.clone();
+        let is_saving_reminders = is_saving_reminders.clone();
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let enabled = input.checked();
+            let reminders_error = reminders_error.clone();
+            let is_saving_reminders = is_saving_reminders.clone();
+            let dispatch = dispatch.clone();
+
+            is_saving_reminders.set(true);
+            reminders_error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let client = get_api_client();
+                let request = requests::UpdateNotificationPreferences {
+                    email_possession_reminders: enabled,
+                };
+                match client.update_notification_preferences(&request).await {
+                    Ok(updated) => {
+                        dispatch.reduce_mut(|state| {
+                            state.auth_state = AuthState::LoggedIn(updated);
+                        });
+                    }
+                    Err(e) => {
+                        reminders_error.set(Some(e.to_string()));
+                    }
+                }
+                is_saving_reminders.set(false);
+            });
+        })
+    };
+
     html! {
         <div class="max-w-2xl mx-auto py-8 px-4">
             <h1 class="text-2xl font-bold text-neutral-900 dark:text-neutral-100 mb-8">
@@ -272,6 +310,27 @@ fn ProfilePageInner(props: &ProfilePageInnerProps) -> Html {
                 </div>
             </div>
 
+            // Notifications Section
+            <div class="bg-white dark:bg-neutral-800 rounded-lg shadow-sm border border-neutral-200 dark:border-neutral-700 p-6 mb-8">
+                <h2 class="text-lg font-semibold text-neutral-900 dark:text-neutral-100 mb-4">
+                    {"Notifications"}
+                </h2>
+                <label class="flex items-center gap-2 text-sm text-neutral-900 dark:text-neutral-100">
+                    <input
+                        type="checkbox"
+                        checked={profile.email_possession_reminders}
+                        disabled={*is_saving_reminders}
+                        onchange={on_toggle_reminders}
+                    />
+                    {"Email me shortly before my possession of a space ends"}
+                </label>
+                if let Some(error) = &*reminders_error {
+                    <p class="mt-2 text-sm text-red-600 dark:text-red-400">
+                        {error}
+                    </p>
+                }
+            </div>
+
             // Danger Zone Section
             <div class="bg-red-50 dark:bg-red-900/10 rounded-lg border border-red-200 dark:border-red-800 p-6">
                 <h2 class="text-lg font-semibold text-red-800 dark:text-red-200 mb-2">
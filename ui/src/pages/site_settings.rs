@@ -1,7 +1,8 @@
 use payloads::{
-    AuctionParams, CurrencySettings, Role, Site, SiteId, SiteImageId,
-    requests::{SITE_NAME_MAX_LEN, UpdateSite},
-    responses::Site as SiteResponse,
+    ApiError, AuctionParams, ClientError, CurrencySettings, Role, SiteId,
+    SiteImageId,
+    requests::{DeleteSite, SITE_NAME_MAX_LEN, SiteDetails, UpdateSite},
+    responses::{Site as SiteResponse, SiteDependencies},
 };
 use wasm_bindgen::JsCast;
 use web_sys::{HtmlInputElement, HtmlSelectElement};
@@ -87,6 +88,8 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
     let show_delete_modal = use_state(|| false);
     let is_deleting = use_state(|| false);
     let delete_error_message = use_state(|| None::<String>);
+    // What the permanent delete would remove, fetched when the modal opens
+    let delete_dependencies = use_state(|| None::<SiteDependencies>);
 
     // State for confirmation modal (soft delete)
     let show_soft_delete_modal = use_state(|| false);
@@ -183,7 +186,7 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
 
             // Create updated site object with description from state
             let desc_value = (*description).clone();
-            let updated_site = Site {
+            let updated_site = SiteDetails {
                 community_id: site.site_details.community_id,
                 name,
                 description: if desc_value.trim().is_empty() {
@@ -191,7 +194,7 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
                 } else {
                     Some(desc_value)
                 },
-                default_auction_params: (*auction_params).clone(),
+                default_auction_params: Some((*auction_params).clone()),
                 // Keep existing values for MVP fields
                 possession_period: site.site_details.possession_period,
                 auction_lead_time: site.site_details.auction_lead_time,
@@ -345,7 +348,11 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
                 delete_error_message.set(None);
 
                 let api_client = crate::get_api_client();
-                match api_client.delete_site(&site_id).await {
+                let details = DeleteSite {
+                    site_id,
+                    confirm: true,
+                };
+                match api_client.delete_site(&details).await {
                     Ok(_) => {
                         // Refetch community sites to update UI state
                         refetch_sites.emit(());
@@ -366,12 +373,35 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
         let site = props.site.clone();
         let show_delete_modal = show_delete_modal.clone();
         let show_soft_delete_modal = show_soft_delete_modal.clone();
+        let delete_dependencies = delete_dependencies.clone();
+        let delete_error_message = delete_error_message.clone();
 
         Callback::from(move |_| {
             if site.deleted_at.is_some() {
                 // Already soft-deleted, show confirmation modal for permanent
                 // delete
                 show_delete_modal.set(true);
+
+                // An unconfirmed delete deletes nothing and reports what a
+                // confirmed one would
+                let delete_dependencies = delete_dependencies.clone();
+                let delete_error_message = delete_error_message.clone();
+                let details = DeleteSite {
+                    site_id: site.site_id,
+                    confirm: false,
+                };
+                yew::platform::spawn_local(async move {
+                    delete_dependencies.set(None);
+                    let api_client = crate::get_api_client();
+                    match api_client.delete_site(&details).await {
+                        Err(ClientError::Api(
+                            _,
+                            ApiError::SiteDeletionNotConfirmed(dependencies),
+                        )) => delete_dependencies.set(Some(dependencies)),
+                        Err(e) => delete_error_message.set(Some(e.to_string())),
+                        Ok(()) => {}
+                    }
+                });
             } else {
                 // Not deleted yet, show confirmation modal for soft delete
                 show_soft_delete_modal.set(true);
@@ -850,7 +880,7 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
                 html! {
                     <ConfirmationModal
                         title="Permanently Delete Site"
-                        message="This will permanently delete the site and remove all spaces and auctions associated with it. This action cannot be undone."
+                        message={delete_site_message((*delete_dependencies).as_ref())}
                         confirm_text="Permanently Delete"
                         confirmation_value={props.site.site_details.name.clone()}
                         confirmation_label="the site name"
@@ -886,3 +916,23 @@ pub fn SiteSettingsForm(props: &SiteSettingsFormProps) -> Html {
         </div>
     }
 }
+
+/// Permanent delete warning, listing what goes with the site once the
+/// counts are known.
+fn delete_site_message(dependencies: Option<&SiteDependencies>) -> String {
+    let base = "This will permanently delete the site and remove all spaces \
+        and auctions associated with it. This action cannot be undone.";
+    match dependencies {
+        Some(d) => format!(
+            "{base} Deleting it removes {} spaces, {} concluded and {} \
+             pending auctions, and {} space values set by members. The {} \
+             images it uses stay in the community's library.",
+            d.spaces,
+            d.concluded_auctions,
+            d.pending_auctions,
+            d.user_values,
+            d.images,
+        ),
+        None => base.to_string(),
+    }
+}
@@ -14,19 +14,14 @@ pub enum AuthState {
     LoggedIn(responses::UserProfile),
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Default)]
 pub enum ThemeMode {
     Light,
     Dark,
+    #[default]
     System,
 }
 
-impl Default for ThemeMode {
-    fn default() -> Self {
-        Self::System
-    }
-}
-
 #[derive(Default, Clone, PartialEq, Store)]
 pub struct State {
     // === Core App State (managed by various components) ===
@@ -156,7 +151,15 @@ impl State {
         self.individual_sites.get(&site_id)
     }
 
-    pub fn set_site(&mut self, site_id: SiteId, site: responses::Site) {
+    pub fn set_site(&mut self, site_id: SiteId, mut site: responses::Site) {
+        // Auction announcements only come with the listing, so keep the
+        // listed ones rather than clearing them
+        if let Some(listed) = self.individual_sites.get(&site_id) {
+            site.next_auction =
+                site.next_auction.or_else(|| listed.next_auction.clone());
+            site.active_auction_id =
+                site.active_auction_id.or(listed.active_auction_id);
+        }
         self.individual_sites.insert(site_id, site);
     }
 